@@ -0,0 +1,437 @@
+use std::mem;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::SetSparseMatrixElement;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{Size, SparseMatrix};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::collections::Collection;
+use graphblas_sparse_linear_algebra::context::{Context as GraphBLASContext, GetContext};
+use graphblas_sparse_linear_algebra::operators::apply::{ApplyBinaryOperator, ApplyUnaryOperator};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    Assignment, Divide, Plus, ReverseDivide, Times,
+};
+use graphblas_sparse_linear_algebra::operators::element_wise_multiplication::ApplyElementWiseMatrixMultiplicationBinaryOperator;
+use graphblas_sparse_linear_algebra::operators::monoid::Plus as PlusMonoid;
+use graphblas_sparse_linear_algebra::operators::multiplication::MultiplyMatrices;
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixArgument, OptionsForOperatorWithMatrixArguments,
+    OptionsForOperatorWithMatrixAsFirstArgument,
+};
+use graphblas_sparse_linear_algebra::operators::reduce::{MonoidReducer, MonoidVectorReducer};
+use graphblas_sparse_linear_algebra::operators::unary_operator::{Identity, One};
+
+use crate::error::{GraphComputingError, UserError, UserErrorType};
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix_cached_attributes::GetAdjacencyMatrixCachedAttributes;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection};
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{ElementIndex, GetEdgeTypeIndex, GetVertexTypeIndex};
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::graph::vertex_store::GetVertexElementIndexer;
+use crate::operators::change_data_capture::GraphChange;
+
+use super::graphblas_extensions::{
+    OptionsForMatrixMultiplicationWithStructuralMask,
+    OptionsForMultiplicationWithStructuralComplementMask, PlusFirst,
+};
+use super::results::{try_vertex_type_value_type, write_to_vertex_vector, zero_for_each_vertex};
+
+// The number of sources searched together, i.e. the row height of the batched search matrices.
+const BATCH_SIZE: usize = 64;
+
+pub trait BetweennessCentrality {
+    /// Writes the betweenness centrality of each vertex along the directed edges of an edge type into the centrality vertex type.
+    /// Edge values are ignored, all edges have unit length. The centrality vertex type must have value type f64.
+    fn betweenness_centrality(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        centrality: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+
+    /// Approximates the betweenness centrality from a uniform sample of source vertices.
+    /// The dependencies are scaled by the number of vertices over the number of sources, the same seed selects the same sources.
+    /// Samples every vertex if the number of sources exceeds the number of vertices.
+    fn sampled_betweenness_centrality(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        number_of_sources: usize,
+        seed: u64,
+        centrality: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+}
+
+impl BetweennessCentrality for Graph {
+    fn betweenness_centrality(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        centrality: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(centrality)?;
+        try_vertex_type_value_type::<f64>(&self.public_vertex_store, centrality)?;
+
+        let sources = self
+            .public_vertex_store
+            .element_indexer_ref()
+            .valid_indices()?;
+
+        let centralities = betweenness_centrality_unchecked(
+            &self.public_vertex_store,
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            &sources,
+            1.0,
+        )?;
+
+        write_to_vertex_vector(
+            &mut self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+            &centralities,
+            centrality,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(centrality))
+    }
+
+    fn sampled_betweenness_centrality(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        number_of_sources: usize,
+        seed: u64,
+        centrality: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        if number_of_sources == 0 {
+            return Err(UserError::new(
+                UserErrorType::Other,
+                String::from("The number of sources must be positive"),
+                None,
+            )
+            .into());
+        }
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(centrality)?;
+        try_vertex_type_value_type::<f64>(&self.public_vertex_store, centrality)?;
+
+        let vertices = self
+            .public_vertex_store
+            .element_indexer_ref()
+            .valid_indices()?;
+        let number_of_vertices = vertices.len();
+        let sources = sample_sources(vertices, number_of_sources, seed);
+        let scale = if sources.is_empty() {
+            1.0
+        } else {
+            number_of_vertices as f64 / sources.len() as f64
+        };
+
+        let centralities = betweenness_centrality_unchecked(
+            &self.public_vertex_store,
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            &sources,
+            scale,
+        )?;
+
+        write_to_vertex_vector(
+            &mut self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+            &centralities,
+            centrality,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(centrality))
+    }
+}
+
+/// Returns the sum of the dependencies of all valid vertices on the sources, multiplied by the scale.
+pub(crate) fn betweenness_centrality_unchecked(
+    vertex_store: &impl GetVertexElementIndexer,
+    edge_store: &mut (impl GetAdjacencyMatrix + GetAdjacencyMatrixCachedAttributes),
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    sources: &[ElementIndex],
+    scale: f64,
+) -> Result<SparseVector<f64>, GraphComputingError> {
+    let mut centralities =
+        zero_for_each_vertex::<f64>(vertex_store, graphblas_operator_applier_collection)?;
+    for batch in sources.chunks(BATCH_SIZE) {
+        let dependencies = dependencies_on_sources(
+            edge_store,
+            graphblas_operator_applier_collection,
+            centralities.context_ref(),
+            edge_type,
+            batch,
+        )?;
+        graphblas_operator_applier_collection
+            .binary_operator_applier()
+            .apply_with_vector_as_left_argument(
+                &dependencies,
+                &Times::<f64>::new(),
+                scale,
+                &Plus::<f64>::new(),
+                &mut centralities,
+                graphblas_operator_applier_collection.entire_vector_selector(),
+                &OperatorOptions::new_default(),
+            )?;
+    }
+    Ok(centralities)
+}
+
+/// Returns the summed dependency of each vertex on a batch of sources, following Brandes' algorithm.
+/// Each row of the search matrices belongs to one source.
+fn dependencies_on_sources(
+    edge_store: &mut (impl GetAdjacencyMatrix + GetAdjacencyMatrixCachedAttributes),
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    graphblas_context: &Arc<GraphBLASContext>,
+    edge_type: &impl GetEdgeTypeIndex,
+    sources: &[ElementIndex],
+) -> Result<SparseVector<f64>, GraphComputingError> {
+    let vertex_capacity = *edge_store.adjacency_matrix_size_ref();
+    let size = Size::new(sources.len(), vertex_capacity);
+
+    let entire_matrix = graphblas_operator_applier_collection.entire_matrix_selector();
+    let options_for_step = OptionsForMultiplicationWithStructuralComplementMask::new();
+
+    // The number of shortest paths from each source to each visited vertex
+    let mut paths = SparseMatrix::<f64>::new(graphblas_context.to_owned(), size)?;
+    for (row, source) in sources.iter().enumerate() {
+        paths.set_value(row, *source, 1.0)?;
+    }
+
+    let mut frontier = SparseMatrix::<f64>::new(graphblas_context.to_owned(), size)?;
+    let mut next_frontier = SparseMatrix::<f64>::new(graphblas_context.to_owned(), size)?;
+    graphblas_operator_applier_collection
+        .matrix_multiplication_operator()
+        .apply(
+            &paths,
+            &PlusFirst::new(),
+            edge_store.adjacency_matrix_ref_unchecked(edge_type),
+            &Assignment::new(),
+            &mut frontier,
+            &paths,
+            &options_for_step,
+        )?;
+
+    // levels[depth] holds the vertices at distance depth + 1 from each source
+    let mut levels = Vec::new();
+    while frontier.number_of_stored_elements()? > 0 {
+        let mut level = SparseMatrix::<bool>::new(graphblas_context.to_owned(), size)?;
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_matrix(
+                &One::<bool>::new(),
+                &frontier,
+                &Assignment::new(),
+                &mut level,
+                entire_matrix,
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )?;
+        levels.push(level);
+
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_matrix(
+                &Identity::<f64>::new(),
+                &frontier,
+                &Plus::<f64>::new(),
+                &mut paths,
+                entire_matrix,
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )?;
+
+        graphblas_operator_applier_collection
+            .matrix_multiplication_operator()
+            .apply(
+                &frontier,
+                &PlusFirst::new(),
+                edge_store.adjacency_matrix_ref_unchecked(edge_type),
+                &Assignment::new(),
+                &mut next_frontier,
+                &paths,
+                &options_for_step,
+            )?;
+        mem::swap(&mut frontier, &mut next_frontier);
+    }
+
+    // Walks the levels back towards the sources, accumulating
+    // dependency(v) += paths(v) * sum over successors w of (1 + dependency(w)) / paths(w)
+    let mut dependencies = SparseMatrix::<f64>::new(graphblas_context.to_owned(), size)?;
+    let mut weights = SparseMatrix::<f64>::new(graphblas_context.to_owned(), size)?;
+    let mut propagated_weights = SparseMatrix::<f64>::new(graphblas_context.to_owned(), size)?;
+    for depth in (1..levels.len()).rev() {
+        graphblas_operator_applier_collection
+            .binary_operator_applier()
+            .apply_with_matrix_as_left_argument(
+                &paths,
+                &ReverseDivide::<f64>::new(),
+                1.0,
+                &Assignment::new(),
+                &mut weights,
+                &levels[depth],
+                &OptionsForOperatorWithMatrixAsFirstArgument::new(true, false, false, false),
+            )?;
+        graphblas_operator_applier_collection
+            .element_wise_matrix_multiplication_binary_operator()
+            .apply(
+                &dependencies,
+                &Divide::<f64>::new(),
+                &paths,
+                &Plus::<f64>::new(),
+                &mut weights,
+                &levels[depth],
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )?;
+
+        graphblas_operator_applier_collection
+            .matrix_multiplication_operator()
+            .apply(
+                &weights,
+                &PlusFirst::new(),
+                edge_store.transposed_adjacency_matrix_ref_unchecked(edge_type),
+                &Assignment::new(),
+                &mut propagated_weights,
+                &levels[depth - 1],
+                &OptionsForMatrixMultiplicationWithStructuralMask::new(),
+            )?;
+        graphblas_operator_applier_collection
+            .element_wise_matrix_multiplication_binary_operator()
+            .apply(
+                &propagated_weights,
+                &Times::<f64>::new(),
+                &paths,
+                &Plus::<f64>::new(),
+                &mut dependencies,
+                entire_matrix,
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )?;
+    }
+
+    let mut summed_dependencies =
+        SparseVector::<f64>::new(graphblas_context.to_owned(), vertex_capacity)?;
+    MonoidReducer::new().to_row_vector(
+        &PlusMonoid::<f64>::new(),
+        &dependencies,
+        &Assignment::new(),
+        &mut summed_dependencies,
+        graphblas_operator_applier_collection.entire_vector_selector(),
+        &OptionsForOperatorWithMatrixArgument::new_default(),
+    )?;
+    Ok(summed_dependencies)
+}
+
+/// Draws the sources with a partial Fisher-Yates shuffle, seeded for reproducibility.
+fn sample_sources(
+    mut vertices: Vec<ElementIndex>,
+    number_of_sources: usize,
+    seed: u64,
+) -> Vec<ElementIndex> {
+    let number_of_sources = number_of_sources.min(vertices.len());
+    let mut state = seed;
+    for position in 0..number_of_sources {
+        let remaining = (vertices.len() - position) as u64;
+        let offset = (split_mix_64(&mut state) % remaining) as usize;
+        vertices.swap(position, position + offset);
+    }
+    vertices.truncate(number_of_sources);
+    vertices
+}
+
+fn split_mix_64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::{EdgeTypeIndex, VertexIndex};
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertexIndex, NewVertexType};
+    use crate::operators::operators::read::GetVertexValue;
+
+    // 0 -> 1 -> 3 -> 4
+    // 0 -> 2 -> 3
+    fn diamond_with_tail(graph: &mut Graph) -> (EdgeTypeIndex, Vec<VertexIndex>) {
+        let edge_type = NewEdgeType::<bool>::apply(graph).unwrap();
+        let vertices: Vec<VertexIndex> =
+            (0..5).map(|_| graph.new_vertex_index().unwrap()).collect();
+        for (tail, head) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)] {
+            graph
+                .new_edge(&edge_type, &vertices[tail], &vertices[head], true)
+                .unwrap();
+        }
+        (edge_type, vertices)
+    }
+
+    #[test]
+    fn betweenness_centrality_splits_over_shortest_paths() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let (edge_type, vertices) = diamond_with_tail(&mut graph);
+        let centrality = NewVertexType::<f64>::apply(&mut graph).unwrap();
+
+        graph
+            .betweenness_centrality(&edge_type, &centrality)
+            .unwrap();
+
+        for (vertex, expected) in vertices.iter().zip([0.0, 1.0, 1.0, 3.0, 0.0]) {
+            assert_eq!(
+                GetVertexValue::<f64>::vertex_value(&graph, &centrality, vertex).unwrap(),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn sampling_every_vertex_is_exact() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let (edge_type, vertices) = diamond_with_tail(&mut graph);
+        let exact = NewVertexType::<f64>::apply(&mut graph).unwrap();
+        let sampled = NewVertexType::<f64>::apply(&mut graph).unwrap();
+
+        graph.betweenness_centrality(&edge_type, &exact).unwrap();
+        graph
+            .sampled_betweenness_centrality(&edge_type, 10, 7, &sampled)
+            .unwrap();
+
+        for vertex in vertices.iter() {
+            assert_eq!(
+                GetVertexValue::<f64>::vertex_value(&graph, &sampled, vertex).unwrap(),
+                GetVertexValue::<f64>::vertex_value(&graph, &exact, vertex).unwrap()
+            );
+        }
+
+        assert_eq!(
+            sample_sources(vec![0, 1, 2, 3, 4], 3, 11),
+            sample_sources(vec![0, 1, 2, 3, 4], 3, 11)
+        );
+        assert!(graph
+            .sampled_betweenness_centrality(&edge_type, 0, 7, &sampled)
+            .is_err());
+    }
+
+    #[test]
+    fn reject_centrality_vertex_type_that_is_not_f64() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let (edge_type, vertices) = diamond_with_tail(&mut graph);
+        let centrality = NewVertexType::<u8>::apply(&mut graph).unwrap();
+
+        assert!(graph
+            .betweenness_centrality(&edge_type, &centrality)
+            .is_err());
+        assert!(graph
+            .sampled_betweenness_centrality(&edge_type, 2, 7, &centrality)
+            .is_err());
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&graph, &centrality, &vertices[3]).unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use std::mem;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::SetSparseVectorElement;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::collections::Collection;
+use graphblas_sparse_linear_algebra::context::Context as GraphBLASContext;
+use graphblas_sparse_linear_algebra::operators::apply::{ApplyBinaryOperator, ApplyUnaryOperator};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{Assignment, Second};
+use graphblas_sparse_linear_algebra::operators::multiplication::{
+    MultiplyMatrixByVector, MultiplyVectorByMatrix,
+};
+use graphblas_sparse_linear_algebra::operators::options::OperatorOptions;
+use graphblas_sparse_linear_algebra::operators::unary_operator::Identity;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix_cached_attributes::GetAdjacencyMatrixCachedAttributes;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection};
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex};
+use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::operators::change_data_capture::GraphChange;
+
+use super::graphblas_extensions::{
+    AnySecondIndex, OptionsForMultiplicationWithStructuralComplementMask,
+};
+use super::results::write_to_vertex_vector;
+
+// The search pulls through the cached transpose once the frontier holds more than
+// one in DIRECTION_SWITCH_RATIO of the unvisited vertices, and pushes otherwise.
+const DIRECTION_SWITCH_RATIO: usize = 8;
+
+pub trait BreadthFirstSearch {
+    /// Writes the level and the parent of each vertex reachable from the source into the level and parent vertex types.
+    /// The source has level zero and is its own parent. Vertices that are not reachable have no level or parent.
+    /// The vertex types must be able to hold vertex indices, values are cast otherwise.
+    fn breadth_first_search(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        source: &impl GetVertexIndexIndex,
+        level: &impl GetVertexTypeIndex,
+        parent: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+}
+
+impl BreadthFirstSearch for Graph {
+    fn breadth_first_search(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        source: &impl GetVertexIndexIndex,
+        level: &impl GetVertexTypeIndex,
+        parent: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        breadth_first_search(
+            &mut self.public_vertex_store,
+            &mut self.public_edge_store,
+            &self.graphblas_context,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            source,
+            level,
+            parent,
+        )?;
+        self.publish_changes(vec![
+            GraphChange::vertex_vector_overwritten(level),
+            GraphChange::vertex_vector_overwritten(parent),
+        ])
+    }
+}
+
+pub(crate) fn breadth_first_search(
+    vertex_store: &mut (impl GetVertexVector + CheckVertexTypeIndex + CheckVertexIndex),
+    edge_store: &mut (impl GetAdjacencyMatrix + GetAdjacencyMatrixCachedAttributes + EdgeTypeIndexing),
+    graphblas_context: &Arc<GraphBLASContext>,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    source: &impl GetVertexIndexIndex,
+    level: &impl GetVertexTypeIndex,
+    parent: &impl GetVertexTypeIndex,
+) -> Result<(), GraphComputingError> {
+    edge_store.try_edge_type_index_validity(edge_type)?;
+    vertex_store.try_vertex_index_validity(source)?;
+    vertex_store.try_vertex_type_index_validity(level)?;
+    vertex_store.try_vertex_type_index_validity(parent)?;
+
+    let (levels, parents) = breadth_first_search_unchecked(
+        edge_store,
+        graphblas_context,
+        graphblas_operator_applier_collection,
+        edge_type,
+        source,
+    )?;
+
+    write_to_vertex_vector(
+        vertex_store,
+        graphblas_operator_applier_collection,
+        &levels,
+        level,
+    )?;
+    write_to_vertex_vector(
+        vertex_store,
+        graphblas_operator_applier_collection,
+        &parents,
+        parent,
+    )
+}
+
+/// Returns the level and parent vectors.
+pub(crate) fn breadth_first_search_unchecked(
+    edge_store: &mut (impl GetAdjacencyMatrix + GetAdjacencyMatrixCachedAttributes),
+    graphblas_context: &Arc<GraphBLASContext>,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    source: &impl GetVertexIndexIndex,
+) -> Result<(SparseVector<i64>, SparseVector<i64>), GraphComputingError> {
+    let vertex_capacity = *edge_store.adjacency_matrix_size_ref();
+    let source_index = source.index();
+
+    let mut levels = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut parents = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut frontier = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut next_frontier = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+
+    levels.set_value(source_index, 0)?;
+    parents.set_value(source_index, source_index as i64)?;
+    frontier.set_value(source_index, source_index as i64)?;
+
+    let semiring = AnySecondIndex::new();
+    let options_for_step = OptionsForMultiplicationWithStructuralComplementMask::new();
+    let options_for_update = OperatorOptions::new(false, true, false);
+
+    let mut number_of_visited_vertices = 1;
+    let mut depth = 0;
+    loop {
+        depth += 1;
+
+        // The parents vector is the set of visited vertices.
+        // A vertex in the next frontier stores the index of its parent in the frontier.
+        let number_of_unvisited_vertices = vertex_capacity - number_of_visited_vertices;
+        if frontier.number_of_stored_elements()? * DIRECTION_SWITCH_RATIO
+            > number_of_unvisited_vertices
+        {
+            graphblas_operator_applier_collection
+                .matrix_vector_multiplication_operator()
+                .apply(
+                    edge_store.transposed_adjacency_matrix_ref_unchecked(edge_type),
+                    &semiring,
+                    &frontier,
+                    &Assignment::new(),
+                    &mut next_frontier,
+                    &parents,
+                    &options_for_step,
+                )?;
+        } else {
+            graphblas_operator_applier_collection
+                .vector_matrix_multiplication_operator()
+                .apply(
+                    &frontier,
+                    &semiring,
+                    edge_store.adjacency_matrix_ref_unchecked(edge_type),
+                    &Assignment::new(),
+                    &mut next_frontier,
+                    &parents,
+                    &options_for_step,
+                )?;
+        }
+        mem::swap(&mut frontier, &mut next_frontier);
+
+        let frontier_size = frontier.number_of_stored_elements()?;
+        if frontier_size == 0 {
+            break;
+        }
+        number_of_visited_vertices += frontier_size;
+
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<i64>::new(),
+                &frontier,
+                &Assignment::new(),
+                &mut parents,
+                &frontier,
+                &options_for_update,
+            )?;
+        graphblas_operator_applier_collection
+            .binary_operator_applier()
+            .apply_with_vector_as_left_argument(
+                &frontier,
+                &Second::<i64>::new(),
+                depth,
+                &Assignment::new(),
+                &mut levels,
+                &frontier,
+                &options_for_update,
+            )?;
+    }
+
+    Ok((levels, parents))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::VertexIndex;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertexIndex, NewVertexType};
+    use crate::operators::operators::read::GetVertexValue;
+
+    #[test]
+    fn breadth_first_search_along_chain_with_branch() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let successor = NewEdgeType::<bool>::apply(&mut graph).unwrap();
+        let level = NewVertexType::<u32>::apply(&mut graph).unwrap();
+        let parent = NewVertexType::<u64>::apply(&mut graph).unwrap();
+
+        // A chain long enough for the search to switch from pushing to pulling
+        let chain: Vec<VertexIndex> = (0..20).map(|_| graph.new_vertex_index().unwrap()).collect();
+        for pair in chain.windows(2) {
+            graph
+                .new_edge(&successor, &pair[0], &pair[1], true)
+                .unwrap();
+        }
+        let branch = graph.new_vertex_index().unwrap();
+        let unreachable = graph.new_vertex_index().unwrap();
+        graph
+            .new_edge(&successor, &chain[2], &branch, true)
+            .unwrap();
+        graph
+            .new_edge(&successor, &unreachable, &chain[0], true)
+            .unwrap();
+
+        graph
+            .breadth_first_search(&successor, &chain[0], &level, &parent)
+            .unwrap();
+
+        for (depth, vertex) in chain.iter().enumerate() {
+            assert_eq!(
+                GetVertexValue::<u32>::vertex_value(&graph, &level, vertex).unwrap(),
+                Some(depth as u32)
+            );
+        }
+        assert_eq!(
+            GetVertexValue::<u64>::vertex_value(&graph, &parent, &chain[0]).unwrap(),
+            Some(chain[0].index() as u64)
+        );
+        assert_eq!(
+            GetVertexValue::<u64>::vertex_value(&graph, &parent, &chain[19]).unwrap(),
+            Some(chain[18].index() as u64)
+        );
+        assert_eq!(
+            GetVertexValue::<u32>::vertex_value(&graph, &level, &branch).unwrap(),
+            Some(3)
+        );
+        assert_eq!(
+            GetVertexValue::<u64>::vertex_value(&graph, &parent, &branch).unwrap(),
+            Some(chain[2].index() as u64)
+        );
+        assert_eq!(
+            GetVertexValue::<u32>::vertex_value(&graph, &level, &unreachable).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn reject_invalid_source() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<bool>::apply(&mut graph).unwrap();
+        let level = NewVertexType::<u32>::apply(&mut graph).unwrap();
+        let parent = NewVertexType::<u64>::apply(&mut graph).unwrap();
+
+        assert!(graph
+            .breadth_first_search(&edge_type, &VertexIndex::new(3), &level, &parent)
+            .is_err());
+    }
+}
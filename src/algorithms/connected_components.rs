@@ -0,0 +1,437 @@
+use std::mem;
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorLength;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::collections::Collection;
+use graphblas_sparse_linear_algebra::context::GetContext;
+use graphblas_sparse_linear_algebra::operators::apply::ApplyUnaryOperator;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{Assignment, IsEqual, Min};
+use graphblas_sparse_linear_algebra::operators::element_wise_multiplication::ApplyElementWiseVectorMultiplicationBinaryOperator;
+use graphblas_sparse_linear_algebra::operators::monoid::Plus as PlusMonoid;
+use graphblas_sparse_linear_algebra::operators::multiplication::{
+    MultiplyMatrixByVector, MultiplyVectorByMatrix,
+};
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixAsFirstArgument,
+};
+use graphblas_sparse_linear_algebra::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+use graphblas_sparse_linear_algebra::operators::semiring::{MaxSecond, MinFirst, MinSecond};
+use graphblas_sparse_linear_algebra::operators::unary_operator::{Identity, RowIndex};
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix_cached_attributes::GetAdjacencyMatrixCachedAttributes;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection};
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexTypeIndex};
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::graph::vertex_store::GetVertexElementIndexer;
+use crate::operators::change_data_capture::GraphChange;
+
+use super::graphblas_extensions::OptionsForMultiplicationWithStructuralComplementMask;
+use super::results::write_to_vertex_vector;
+
+/// Both methods label each vertex with the smallest vertex index in its component, and write the labels into the component vertex type.
+/// Only the structure of the edge type is used, whatever its value type.
+pub trait ConnectedComponents {
+    /// Ignores the direction of the edges.
+    fn weakly_connected_components(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        component: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+
+    fn strongly_connected_components(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        component: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+}
+
+impl ConnectedComponents for Graph {
+    fn weakly_connected_components(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        component: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(component)?;
+
+        let vertex_indices = vertex_indices(
+            &self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+        )?;
+        let components = weakly_connected_components_unchecked(
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            &vertex_indices,
+        )?;
+        write_to_vertex_vector(
+            &mut self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+            &components,
+            component,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(component))
+    }
+
+    fn strongly_connected_components(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        component: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(component)?;
+
+        let vertex_indices = vertex_indices(
+            &self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+        )?;
+        let components = strongly_connected_components_unchecked(
+            &self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            &vertex_indices,
+        )?;
+        write_to_vertex_vector(
+            &mut self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+            &components,
+            component,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(component))
+    }
+}
+
+/// Stores its own index for each valid vertex index.
+fn vertex_indices(
+    vertex_store: &impl GetVertexElementIndexer,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+) -> Result<SparseVector<i64>, GraphComputingError> {
+    let valid_vertices = vertex_store
+        .element_indexer_ref()
+        .mask_with_valid_indices_ref();
+    let mut vertex_indices = SparseVector::<i64>::new(
+        valid_vertices.context_ref().to_owned(),
+        valid_vertices.length()?,
+    )?;
+    graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_vector(
+            &RowIndex::<i64>::new(),
+            valid_vertices,
+            &Assignment::new(),
+            &mut vertex_indices,
+            graphblas_operator_applier_collection.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+    Ok(vertex_indices)
+}
+
+/// Propagates the smallest label along edges in both directions, until no label changes.
+pub(crate) fn weakly_connected_components_unchecked(
+    edge_store: &mut (impl GetAdjacencyMatrix + GetAdjacencyMatrixCachedAttributes),
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    vertex_indices: &SparseVector<i64>,
+) -> Result<SparseVector<i64>, GraphComputingError> {
+    let entire_vector = graphblas_operator_applier_collection.entire_vector_selector();
+    let options = OptionsForOperatorWithMatrixAsFirstArgument::new_default();
+
+    let mut labels = copy(graphblas_operator_applier_collection, vertex_indices)?;
+    let mut next_labels = copy(graphblas_operator_applier_collection, vertex_indices)?;
+    let mut sum_of_labels = sum(&labels)?;
+
+    // Labels only decrease, so an unchanged sum means that no label changed
+    loop {
+        graphblas_operator_applier_collection
+            .matrix_vector_multiplication_operator()
+            .apply(
+                edge_store.transposed_adjacency_matrix_ref_unchecked(edge_type),
+                &MinSecond::<i64>::new(),
+                &labels,
+                &Min::<i64>::new(),
+                &mut next_labels,
+                entire_vector,
+                &options,
+            )?;
+        graphblas_operator_applier_collection
+            .matrix_vector_multiplication_operator()
+            .apply(
+                edge_store.adjacency_matrix_ref_unchecked(edge_type),
+                &MinSecond::<i64>::new(),
+                &labels,
+                &Min::<i64>::new(),
+                &mut next_labels,
+                entire_vector,
+                &options,
+            )?;
+
+        let next_sum_of_labels = sum(&next_labels)?;
+        if next_sum_of_labels == sum_of_labels {
+            return Ok(next_labels);
+        }
+        sum_of_labels = next_sum_of_labels;
+        mem::swap(&mut labels, &mut next_labels);
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<i64>::new(),
+                &labels,
+                &Assignment::new(),
+                &mut next_labels,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+    }
+}
+
+/// Each round colors the remaining vertices with the smallest index that reaches them.
+/// A vertex whose color is its own index is the root of a component,
+/// which holds the vertices of the same color that reach the root.
+pub(crate) fn strongly_connected_components_unchecked(
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    vertex_indices: &SparseVector<i64>,
+) -> Result<SparseVector<i64>, GraphComputingError> {
+    let adjacency_matrix = edge_store.adjacency_matrix_ref_unchecked(edge_type);
+    let graphblas_context = vertex_indices.context_ref();
+    let vertex_capacity = vertex_indices.length()?;
+    let entire_vector = graphblas_operator_applier_collection.entire_vector_selector();
+    let options_to_mask_complement = OptionsForMultiplicationWithStructuralComplementMask::new();
+
+    let new_vector = || SparseVector::<i64>::new(graphblas_context.to_owned(), vertex_capacity);
+    let mut components = new_vector()?;
+    let mut colors = new_vector()?;
+    let mut next_colors = new_vector()?;
+    let mut members = new_vector()?;
+    let mut candidates = new_vector()?;
+    let mut is_member = SparseVector::<bool>::new(graphblas_context.to_owned(), vertex_capacity)?;
+
+    let number_of_vertices = vertex_indices.number_of_stored_elements()?;
+    while components.number_of_stored_elements()? < number_of_vertices {
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<i64>::new(),
+                vertex_indices,
+                &Assignment::new(),
+                &mut colors,
+                &components,
+                &OperatorOptions::new(true, true, true),
+            )?;
+
+        let mut sum_of_colors = sum(&colors)?;
+        loop {
+            graphblas_operator_applier_collection
+                .unary_operator_applier()
+                .apply_to_vector(
+                    &Identity::<i64>::new(),
+                    &colors,
+                    &Assignment::new(),
+                    &mut next_colors,
+                    entire_vector,
+                    &OperatorOptions::new_default(),
+                )?;
+            graphblas_operator_applier_collection
+                .vector_matrix_multiplication_operator()
+                .apply(
+                    &colors,
+                    &MinFirst::<i64>::new(),
+                    adjacency_matrix,
+                    &Min::<i64>::new(),
+                    &mut next_colors,
+                    &components,
+                    &options_to_mask_complement,
+                )?;
+            mem::swap(&mut colors, &mut next_colors);
+
+            let next_sum_of_colors = sum(&colors)?;
+            if next_sum_of_colors == sum_of_colors {
+                break;
+            }
+            sum_of_colors = next_sum_of_colors;
+        }
+
+        graphblas_operator_applier_collection
+            .element_wise_vector_multiplication_binary_operator()
+            .apply(
+                &colors,
+                &IsEqual::<i64>::new(),
+                vertex_indices,
+                &Assignment::new(),
+                &mut is_member,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<i64>::new(),
+                &colors,
+                &Assignment::new(),
+                &mut members,
+                &is_member,
+                &OperatorOptions::new(true, false, false),
+            )?;
+
+        // The color of a vertex is at least the color of its successors.
+        // The largest color among its member successors is thus its own color, if any successor of its color is a member.
+        loop {
+            graphblas_operator_applier_collection
+                .matrix_vector_multiplication_operator()
+                .apply(
+                    adjacency_matrix,
+                    &MaxSecond::<i64>::new(),
+                    &members,
+                    &Assignment::new(),
+                    &mut candidates,
+                    &members,
+                    &options_to_mask_complement,
+                )?;
+            graphblas_operator_applier_collection
+                .element_wise_vector_multiplication_binary_operator()
+                .apply(
+                    &candidates,
+                    &IsEqual::<i64>::new(),
+                    &colors,
+                    &Assignment::new(),
+                    &mut is_member,
+                    entire_vector,
+                    &OperatorOptions::new_default(),
+                )?;
+
+            let number_of_members = members.number_of_stored_elements()?;
+            graphblas_operator_applier_collection
+                .unary_operator_applier()
+                .apply_to_vector(
+                    &Identity::<i64>::new(),
+                    &candidates,
+                    &Assignment::new(),
+                    &mut members,
+                    &is_member,
+                    &OperatorOptions::new_default(),
+                )?;
+            if members.number_of_stored_elements()? == number_of_members {
+                break;
+            }
+        }
+
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<i64>::new(),
+                &members,
+                &Assignment::new(),
+                &mut components,
+                &members,
+                &OperatorOptions::new(false, true, false),
+            )?;
+    }
+
+    Ok(components)
+}
+
+fn copy(
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    vector: &SparseVector<i64>,
+) -> Result<SparseVector<i64>, GraphComputingError> {
+    let mut copy = SparseVector::<i64>::new(vector.context_ref().to_owned(), vector.length()?)?;
+    graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_vector(
+            &Identity::<i64>::new(),
+            vector,
+            &Assignment::new(),
+            &mut copy,
+            graphblas_operator_applier_collection.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+    Ok(copy)
+}
+
+fn sum(vector: &SparseVector<i64>) -> Result<i64, GraphComputingError> {
+    let mut sum = 0;
+    MonoidReducer::new().vector_to_scalar(
+        &PlusMonoid::<i64>::new(),
+        vector,
+        &Assignment::new(),
+        &mut sum,
+        &OperatorOptions::new_default(),
+    )?;
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::{GetVertexIndexIndex, VertexIndex};
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertexIndex, NewVertexType};
+    use crate::operators::operators::read::GetVertexValue;
+
+    fn assert_components(
+        graph: &Graph,
+        component: &impl GetVertexTypeIndex,
+        vertices: &[VertexIndex],
+        expected_roots: &[usize],
+    ) {
+        for (vertex, root) in vertices.iter().zip(expected_roots.iter()) {
+            assert_eq!(
+                GetVertexValue::<u64>::vertex_value(graph, component, vertex).unwrap(),
+                Some(vertices[*root].index() as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn weakly_connected_components_ignore_direction() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<bool>::apply(&mut graph).unwrap();
+        let component = NewVertexType::<u64>::apply(&mut graph).unwrap();
+
+        let vertices: Vec<VertexIndex> =
+            (0..6).map(|_| graph.new_vertex_index().unwrap()).collect();
+        for (tail, head) in [(0, 1), (2, 1), (4, 3)] {
+            graph
+                .new_edge(&edge_type, &vertices[tail], &vertices[head], true)
+                .unwrap();
+        }
+
+        graph
+            .weakly_connected_components(&edge_type, &component)
+            .unwrap();
+
+        assert_components(&graph, &component, &vertices, &[0, 0, 0, 3, 3, 5]);
+    }
+
+    #[test]
+    fn strongly_connected_components_of_weighted_edge_type() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<f32>::apply(&mut graph).unwrap();
+        let component = NewVertexType::<u64>::apply(&mut graph).unwrap();
+
+        let vertices: Vec<VertexIndex> =
+            (0..6).map(|_| graph.new_vertex_index().unwrap()).collect();
+        for (tail, head) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 4)] {
+            graph
+                .new_edge(&edge_type, &vertices[tail], &vertices[head], 0.0)
+                .unwrap();
+        }
+
+        graph
+            .strongly_connected_components(&edge_type, &component)
+            .unwrap();
+
+        assert_components(&graph, &component, &vertices, &[0, 0, 0, 3, 3, 5]);
+    }
+}
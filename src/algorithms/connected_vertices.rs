@@ -1,15 +1,95 @@
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorElementIndices;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::index::ElementIndexSelector;
+use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+use graphblas_sparse_linear_algebra::operators::extract::ExtractMatrixRow;
+
 use crate::error::GraphComputingError;
-use crate::graph::graph::Graph;
-use crate::graph::vertex::{Vertex, VertexKey, VertexValue, VertexValueType};
-use crate::graph::edge::{DirectedEdge, EdgeKey, EdgeType};
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{GetGraphblasOperatorAppliers, Graph};
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex, VertexIndex};
+use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArgument;
 
 pub trait ConnectedVertices {
-    pub fn vertices_connected_to_vertex(&self, vertex: VertexKey) -> Result(Vec<Vertex>, GraphComputingError);
-};
+    /// Returns the heads of the edges of the edge type that leave the vertex.
+    fn vertices_connected_to_vertex(
+        &self,
+        edge_type: &impl GetEdgeTypeIndex,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError>;
+}
 
 impl ConnectedVertices for Graph {
-    pub fn vertices_connected_to_vertex(&self, vertex: VertexKey, ) -> Result(Vec<Vertex>, GraphComputingError) {
+    fn vertices_connected_to_vertex(
+        &self,
+        edge_type: &impl GetEdgeTypeIndex,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store.try_vertex_index_validity(vertex)?;
 
+        let mut heads = SparseVector::<bool>::new(
+            self.graphblas_context.clone(),
+            *self.public_edge_store.adjacency_matrix_size_ref(),
+        )?;
+        self.graphblas_operator_applier_collection
+            .matrix_row_extractor()
+            .apply(
+                self.public_edge_store
+                    .adjacency_matrix_ref_unchecked(edge_type),
+                vertex.index(),
+                &ElementIndexSelector::All,
+                &Assignment::<bool>::new(),
+                &mut heads,
+                self.graphblas_operator_applier_collection
+                    .entire_vector_selector(),
+                &OptionsForOperatorWithAdjacencyMatrixArgument::new_default(),
+            )?;
+
+        Ok(heads
+            .element_indices()?
+            .into_iter()
+            .map(VertexIndex::new)
+            .collect())
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+
+    #[test]
+    fn vertices_connected_to_vertex() {
+        let mut graph = Graph::with_initial_capacity(1, 5, 1).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex(&vertex_type, 1u8).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type, 2u8).unwrap();
+        let vertex_3 = graph.new_vertex(&vertex_type, 3u8).unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_1, &vertex_2, 1u8)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_1, &vertex_3, 1u8)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_2, &vertex_3, 1u8)
+            .unwrap();
+
+        assert_eq!(
+            graph
+                .vertices_connected_to_vertex(&edge_type, &vertex_1)
+                .unwrap(),
+            vec![vertex_2, vertex_3]
+        );
+        assert!(graph
+            .vertices_connected_to_vertex(&edge_type, &vertex_3)
+            .unwrap()
+            .is_empty());
+    }
+}
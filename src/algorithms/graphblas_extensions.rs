@@ -0,0 +1,257 @@
+use std::ptr;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::{
+    GetGraphblasSparseVector, SparseVector,
+};
+use graphblas_sparse_linear_algebra::context::{CallGraphBlasContext, Context as GraphBLASContext};
+use graphblas_sparse_linear_algebra::graphblas_bindings::{
+    GrB_ABS_FP64, GrB_DESC_RS, GrB_DESC_RSC, GrB_Descriptor, GrB_Semiring, GrB_UnaryOp,
+    GxB_ANY_PAIR_BOOL, GxB_ANY_SECONDI_INT64, GxB_MIN_SECONDI_INT64, GxB_Matrix_diag,
+    GxB_PLUS_FIRST_FP64, GxB_PLUS_SECOND_FP64,
+};
+use graphblas_sparse_linear_algebra::operators::options::{
+    GetClearOutputBeforeUse, GetGraphblasDescriptor, GetOperatorMaskOptions, GetOperatorOptions,
+    GetOptionsForOperatorWithMatrixArguments, GetOptionsForOperatorWithMatrixAsFirstArgument,
+    GetOptionsForOperatorWithMatrixAsSecondArgument, GetTransposeArguments,
+    GetTransposeFirstMatrixArgument, GetTransposeSecondMatrixArgument,
+};
+use graphblas_sparse_linear_algebra::operators::semiring::Semiring;
+use graphblas_sparse_linear_algebra::operators::unary_operator::UnaryOperator;
+use graphblas_sparse_linear_algebra::value_type::ValueType;
+
+use crate::error::GraphComputingError;
+
+/// Returns the index of the second argument, i.e. the vertex index of the frontier element.
+#[derive(Debug, Clone)]
+pub(crate) struct AnySecondIndex {}
+
+impl AnySecondIndex {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Semiring<i64> for AnySecondIndex {
+    fn graphblas_type(&self) -> GrB_Semiring {
+        unsafe { GxB_ANY_SECONDI_INT64 }
+    }
+}
+
+/// Returns true for any pair of stored elements, i.e. multiplies the structure of the arguments.
+#[derive(Debug, Clone)]
+pub(crate) struct AnyPair {}
+
+impl AnyPair {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Semiring<bool> for AnyPair {
+    fn graphblas_type(&self) -> GrB_Semiring {
+        unsafe { GxB_ANY_PAIR_BOOL }
+    }
+}
+
+/// Returns the smallest index of the second arguments, such that the choice among frontier elements is deterministic.
+#[derive(Debug, Clone)]
+pub(crate) struct MinSecondIndex {}
+
+impl MinSecondIndex {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Semiring<i64> for MinSecondIndex {
+    fn graphblas_type(&self) -> GrB_Semiring {
+        unsafe { GxB_MIN_SECONDI_INT64 }
+    }
+}
+
+/// Sums the first arguments, ignoring the values of the second arguments.
+#[derive(Debug, Clone)]
+pub(crate) struct PlusFirst {}
+
+impl PlusFirst {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Semiring<f64> for PlusFirst {
+    fn graphblas_type(&self) -> GrB_Semiring {
+        unsafe { GxB_PLUS_FIRST_FP64 }
+    }
+}
+
+/// Sums the second arguments, ignoring the values of the first arguments.
+#[derive(Debug, Clone)]
+pub(crate) struct PlusSecond {}
+
+impl PlusSecond {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Semiring<f64> for PlusSecond {
+    fn graphblas_type(&self) -> GrB_Semiring {
+        unsafe { GxB_PLUS_SECOND_FP64 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct AbsoluteValue {}
+
+impl AbsoluteValue {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl UnaryOperator<f64> for AbsoluteValue {
+    fn graphblas_type(&self) -> GrB_UnaryOp {
+        unsafe { GrB_ABS_FP64 }
+    }
+}
+
+/// Replaces the product and masks it by the complement of the structure of the mask.
+/// The multiplication options of graphblas_sparse_linear_algebra do not pass mask options to GraphBLAS.
+#[derive(Debug, Clone)]
+pub(crate) struct OptionsForMultiplicationWithStructuralComplementMask {}
+
+impl OptionsForMultiplicationWithStructuralComplementMask {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl GetClearOutputBeforeUse for OptionsForMultiplicationWithStructuralComplementMask {
+    fn clear_output_before_use(&self) -> bool {
+        true
+    }
+}
+
+impl GetOperatorMaskOptions for OptionsForMultiplicationWithStructuralComplementMask {
+    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
+        true
+    }
+
+    fn use_mask_complement(&self) -> bool {
+        true
+    }
+}
+
+impl GetGraphblasDescriptor for OptionsForMultiplicationWithStructuralComplementMask {
+    fn graphblas_descriptor(&self) -> GrB_Descriptor {
+        unsafe { GrB_DESC_RSC }
+    }
+}
+
+impl GetTransposeFirstMatrixArgument for OptionsForMultiplicationWithStructuralComplementMask {
+    fn transpose_first_matrix_argument(&self) -> bool {
+        false
+    }
+}
+
+impl GetTransposeSecondMatrixArgument for OptionsForMultiplicationWithStructuralComplementMask {
+    fn transpose_second_matrix_argument(&self) -> bool {
+        false
+    }
+}
+
+impl GetTransposeArguments for OptionsForMultiplicationWithStructuralComplementMask {
+    fn transpose_first_argument(&self) -> bool {
+        false
+    }
+
+    fn transpose_second_argument(&self) -> bool {
+        false
+    }
+}
+
+impl GetOperatorOptions for OptionsForMultiplicationWithStructuralComplementMask {}
+impl GetOptionsForOperatorWithMatrixArguments
+    for OptionsForMultiplicationWithStructuralComplementMask
+{
+}
+impl GetOptionsForOperatorWithMatrixAsFirstArgument
+    for OptionsForMultiplicationWithStructuralComplementMask
+{
+}
+impl GetOptionsForOperatorWithMatrixAsSecondArgument
+    for OptionsForMultiplicationWithStructuralComplementMask
+{
+}
+
+/// Replaces the product and masks it by the structure of the mask.
+/// Like the vector multiplication options, the matrix multiplication options do not pass mask options to GraphBLAS.
+#[derive(Debug, Clone)]
+pub(crate) struct OptionsForMatrixMultiplicationWithStructuralMask {}
+
+impl OptionsForMatrixMultiplicationWithStructuralMask {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl GetClearOutputBeforeUse for OptionsForMatrixMultiplicationWithStructuralMask {
+    fn clear_output_before_use(&self) -> bool {
+        true
+    }
+}
+
+impl GetOperatorMaskOptions for OptionsForMatrixMultiplicationWithStructuralMask {
+    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
+        true
+    }
+
+    fn use_mask_complement(&self) -> bool {
+        false
+    }
+}
+
+impl GetGraphblasDescriptor for OptionsForMatrixMultiplicationWithStructuralMask {
+    fn graphblas_descriptor(&self) -> GrB_Descriptor {
+        unsafe { GrB_DESC_RS }
+    }
+}
+
+impl GetTransposeArguments for OptionsForMatrixMultiplicationWithStructuralMask {
+    fn transpose_first_argument(&self) -> bool {
+        false
+    }
+
+    fn transpose_second_argument(&self) -> bool {
+        false
+    }
+}
+
+impl GetOperatorOptions for OptionsForMatrixMultiplicationWithStructuralMask {}
+impl GetOptionsForOperatorWithMatrixArguments for OptionsForMatrixMultiplicationWithStructuralMask {}
+
+/// Square matrix with the elements of the vector on its diagonal.
+pub(crate) fn diagonal_matrix<T: ValueType>(
+    graphblas_context: &Arc<GraphBLASContext>,
+    vector: &SparseVector<T>,
+    length: usize,
+) -> Result<SparseMatrix<T>, GraphComputingError> {
+    let matrix = SparseMatrix::<T>::new(graphblas_context.clone(), Size::new(length, length))?;
+    graphblas_context.call(
+        || unsafe {
+            GxB_Matrix_diag(
+                matrix.graphblas_matrix(),
+                vector.graphblas_vector(),
+                0,
+                ptr::null_mut(),
+            )
+        },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(matrix)
+}
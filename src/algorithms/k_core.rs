@@ -0,0 +1,374 @@
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::SparseMatrix;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorLength;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::collections::Collection;
+use graphblas_sparse_linear_algebra::context::GetContext;
+use graphblas_sparse_linear_algebra::operators::apply::{ApplyBinaryOperator, ApplyUnaryOperator};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    Assignment, Minus, Plus, Second,
+};
+use graphblas_sparse_linear_algebra::operators::index_unary_operator::{
+    IsValueGreaterThan, IsValueLessThanOrEqualTo,
+};
+use graphblas_sparse_linear_algebra::operators::monoid::Plus as PlusMonoid;
+use graphblas_sparse_linear_algebra::operators::multiplication::{
+    MultiplyMatrices, MultiplyMatrixByVector,
+};
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixArgument,
+};
+use graphblas_sparse_linear_algebra::operators::reduce::{MonoidReducer, MonoidVectorReducer};
+use graphblas_sparse_linear_algebra::operators::select::{SelectFromMatrix, SelectFromVector};
+use graphblas_sparse_linear_algebra::operators::semiring::PlusTimes;
+use graphblas_sparse_linear_algebra::operators::unary_operator::{Identity, One, UnaryOperator};
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{
+    GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection,
+};
+use crate::graph::indexing::{EdgeTypeIndex, GetEdgeTypeIndex, GetVertexTypeIndex};
+use crate::graph::value_type::{GetValueTypeIdentifier, ValueType};
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::graph::vertex_store::GetVertexElementIndexer;
+use crate::operators::change_data_capture::GraphChange;
+use crate::operators::operators::new::NewEdgeType;
+
+use super::graphblas_extensions::{
+    OptionsForMatrixMultiplicationWithStructuralMask,
+    OptionsForMultiplicationWithStructuralComplementMask,
+};
+use super::results::{write_to_vertex_vector, zero_for_each_vertex};
+use super::triangle_count::{neighbours, undirected_structure};
+
+/// Uses the undirected structure of an edge type, like TriangleCount.
+pub trait KCoreDecomposition {
+    /// Writes the coreness of each vertex into the coreness vertex type.
+    /// A vertex with coreness k is part of the k-core, the largest subgraph in which each vertex has at least k neighbours, but not of the (k + 1)-core.
+    fn k_core_decomposition(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        coreness: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+}
+
+/// Uses the undirected structure of an edge type, like TriangleCount.
+pub trait KTruss {
+    /// Adds an edge type with the edges of the k-truss, and returns its index.
+    /// Each edge of the k-truss is part of at least k - 2 triangles within the k-truss. For k up to two, all edges except self-loops survive.
+    /// Surviving edges keep their direction, and their value is cast to T.
+    fn k_truss<T>(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        k: u32,
+    ) -> Result<EdgeTypeIndex, GraphComputingError>
+    where
+        T: ValueType + GetValueTypeIdentifier,
+        Identity<T>: UnaryOperator<T>;
+}
+
+impl KCoreDecomposition for Graph {
+    fn k_core_decomposition(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        coreness: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(coreness)?;
+
+        let (lower, upper) = undirected_structure(
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+        )?;
+        let neighbours = neighbours(&self.graphblas_operator_applier_collection, &lower, &upper)?;
+        let core_numbers = coreness_unchecked(
+            &self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+            &neighbours,
+        )?;
+
+        write_to_vertex_vector(
+            &mut self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+            &core_numbers,
+            coreness,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(coreness))
+    }
+}
+
+impl KTruss for Graph {
+    fn k_truss<T>(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        k: u32,
+    ) -> Result<EdgeTypeIndex, GraphComputingError>
+    where
+        T: ValueType + GetValueTypeIdentifier,
+        Identity<T>: UnaryOperator<T>,
+    {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+
+        let (lower, upper) = undirected_structure(
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+        )?;
+        let neighbours = neighbours(&self.graphblas_operator_applier_collection, &lower, &upper)?;
+        let truss = k_truss_unchecked(&self.graphblas_operator_applier_collection, neighbours, k)?;
+
+        let mut surviving_edges =
+            SparseMatrix::<T>::new(truss.context_ref().to_owned(), truss.size()?)?;
+        self.graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_matrix(
+                &Identity::<T>::new(),
+                self.public_edge_store
+                    .adjacency_matrix_ref_unchecked(edge_type),
+                &Assignment::new(),
+                &mut surviving_edges,
+                &truss,
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )?;
+
+        let truss_edge_type = NewEdgeType::<T>::apply(self)?;
+        self.graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_matrix(
+                &Identity::<T>::new(),
+                &surviving_edges,
+                &Assignment::new(),
+                self.public_edge_store
+                    .adjacency_matrix_mut_ref_unchecked(&truss_edge_type)?,
+                self.graphblas_operator_applier_collection
+                    .entire_matrix_selector(),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(&truss_edge_type))?;
+        Ok(truss_edge_type)
+    }
+}
+
+/// Peels the vertices of lowest degree, updating the degrees of their neighbours by a masked multiplication.
+pub(crate) fn coreness_unchecked(
+    vertex_store: &impl GetVertexElementIndexer,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    neighbours: &SparseMatrix<i64>,
+) -> Result<SparseVector<i64>, GraphComputingError> {
+    let entire_vector = graphblas_operator_applier_collection.entire_vector_selector();
+
+    // The degrees of the vertices that have not been peeled
+    let mut degrees =
+        zero_for_each_vertex::<i64>(vertex_store, graphblas_operator_applier_collection)?;
+    MonoidReducer::new().to_column_vector(
+        &PlusMonoid::<i64>::new(),
+        neighbours,
+        &Plus::<i64>::new(),
+        &mut degrees,
+        entire_vector,
+        &OptionsForOperatorWithMatrixArgument::new_default(),
+    )?;
+
+    let graphblas_context = degrees.context_ref().to_owned();
+    let vertex_capacity = degrees.length()?;
+    let mut coreness = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut peeled = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut peeled_ones = SparseVector::<i64>::new(graphblas_context, vertex_capacity)?;
+
+    let mut k = 0;
+    while degrees.number_of_stored_elements()? > 0 {
+        graphblas_operator_applier_collection
+            .vector_selector()
+            .apply(
+                &IsValueLessThanOrEqualTo::<i64>::new(),
+                k,
+                &degrees,
+                &Assignment::new(),
+                &mut peeled,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+        if peeled.number_of_stored_elements()? == 0 {
+            k += 1;
+            continue;
+        }
+
+        graphblas_operator_applier_collection
+            .binary_operator_applier()
+            .apply_with_vector_as_left_argument(
+                &peeled,
+                &Second::<i64>::new(),
+                k,
+                &Plus::<i64>::new(),
+                &mut coreness,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &One::<i64>::new(),
+                &peeled,
+                &Assignment::new(),
+                &mut peeled_ones,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+        // Masking by the complement of the coreness, with replacement, also drops the degrees of the peeled vertices
+        graphblas_operator_applier_collection
+            .matrix_vector_multiplication_operator()
+            .apply(
+                neighbours,
+                &PlusTimes::<i64>::new(),
+                &peeled_ones,
+                &Minus::<i64>::new(),
+                &mut degrees,
+                &coreness,
+                &OptionsForMultiplicationWithStructuralComplementMask::new(),
+            )?;
+    }
+    Ok(coreness)
+}
+
+/// Repeatedly counts the triangles of each edge under a structural mask, and selects the edges with enough support, until no edge is removed.
+/// Returns the symmetric structure of the k-truss, storing ones.
+pub(crate) fn k_truss_unchecked(
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    neighbours: SparseMatrix<i64>,
+    k: u32,
+) -> Result<SparseMatrix<i64>, GraphComputingError> {
+    let mut truss = neighbours;
+    if k <= 2 {
+        return Ok(truss);
+    }
+    let minimum_support = i64::from(k) - 2;
+
+    let graphblas_context = truss.context_ref().to_owned();
+    let size = truss.size()?;
+    let entire_matrix = graphblas_operator_applier_collection.entire_matrix_selector();
+    let mut support = SparseMatrix::<i64>::new(graphblas_context.clone(), size)?;
+    let mut supported = SparseMatrix::<i64>::new(graphblas_context, size)?;
+
+    loop {
+        let number_of_edges = truss.number_of_stored_elements()?;
+
+        graphblas_operator_applier_collection
+            .matrix_multiplication_operator()
+            .apply(
+                &truss,
+                &PlusTimes::<i64>::new(),
+                &truss,
+                &Assignment::new(),
+                &mut support,
+                &truss,
+                &OptionsForMatrixMultiplicationWithStructuralMask::new(),
+            )?;
+        graphblas_operator_applier_collection
+            .matrix_selector()
+            .apply(
+                &IsValueGreaterThan::<i64>::new(),
+                minimum_support - 1,
+                &support,
+                &Assignment::new(),
+                &mut supported,
+                entire_matrix,
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )?;
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_matrix(
+                &One::<i64>::new(),
+                &supported,
+                &Assignment::new(),
+                &mut truss,
+                entire_matrix,
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )?;
+
+        if truss.number_of_stored_elements()? == number_of_edges {
+            return Ok(truss);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::VertexIndex;
+    use crate::operators::operators::new::{NewEdge, NewVertexIndex, NewVertexType};
+    use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+
+    // A clique of vertices 0 to 3, vertex 4 attached to vertex 0, and an isolated vertex 5
+    fn clique_with_pendant(graph: &mut Graph) -> (EdgeTypeIndex, Vec<VertexIndex>) {
+        let edge_type = NewEdgeType::<u8>::apply(graph).unwrap();
+        let vertices: Vec<VertexIndex> =
+            (0..6).map(|_| graph.new_vertex_index().unwrap()).collect();
+        for (tail, head) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (0, 4)] {
+            graph
+                .new_edge(&edge_type, &vertices[tail], &vertices[head], 7)
+                .unwrap();
+        }
+        (edge_type, vertices)
+    }
+
+    #[test]
+    fn coreness_of_clique_pendant_and_isolated_vertex() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let (edge_type, vertices) = clique_with_pendant(&mut graph);
+        let coreness = NewVertexType::<u32>::apply(&mut graph).unwrap();
+
+        graph.k_core_decomposition(&edge_type, &coreness).unwrap();
+
+        for (vertex, expected) in vertices.iter().zip([3, 3, 3, 3, 1, 0]) {
+            assert_eq!(
+                GetVertexValue::<u32>::vertex_value(&graph, &coreness, vertex).unwrap(),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn k_truss_keeps_edges_in_enough_triangles() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let (edge_type, vertices) = clique_with_pendant(&mut graph);
+
+        let four_truss = graph.k_truss::<u16>(&edge_type, 4).unwrap();
+        let two_truss = graph.k_truss::<u16>(&edge_type, 2).unwrap();
+
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(&graph, &four_truss, &vertices[1], &vertices[3])
+                .unwrap(),
+            Some(7)
+        );
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(&graph, &four_truss, &vertices[3], &vertices[1])
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(&graph, &four_truss, &vertices[0], &vertices[4])
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(&graph, &two_truss, &vertices[0], &vertices[4])
+                .unwrap(),
+            Some(7)
+        );
+
+        let five_truss = graph.k_truss::<u16>(&edge_type, 5).unwrap();
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(&graph, &five_truss, &vertices[0], &vertices[1])
+                .unwrap(),
+            None
+        );
+    }
+}
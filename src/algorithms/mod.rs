@@ -1,3 +1,20 @@
+mod betweenness_centrality;
+mod breadth_first_search;
+mod connected_components;
 pub mod connected_vertices;
+pub(crate) mod graphblas_extensions;
+mod k_core;
+mod page_rank;
+pub(crate) mod results;
+mod single_source_shortest_paths;
+mod triangle_count;
 
-// https://tinkerpop.apache.org/docs/3.5.1/reference/
\ No newline at end of file
+pub use betweenness_centrality::*;
+pub use breadth_first_search::*;
+pub use connected_components::*;
+pub use k_core::*;
+pub use page_rank::*;
+pub use single_source_shortest_paths::*;
+pub use triangle_count::*;
+
+// https://tinkerpop.apache.org/docs/3.5.1/reference/
@@ -0,0 +1,561 @@
+use std::mem;
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorLength;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::{
+    GetGraphblasSparseVector, SparseVector,
+};
+use graphblas_sparse_linear_algebra::collections::Collection;
+use graphblas_sparse_linear_algebra::context::GetContext;
+use graphblas_sparse_linear_algebra::operators::apply::{ApplyBinaryOperator, ApplyUnaryOperator};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    Assignment, Minus, Plus, ReverseDivide, Times,
+};
+use graphblas_sparse_linear_algebra::operators::element_wise_addition::ApplyElementWiseVectorAdditionBinaryOperator;
+use graphblas_sparse_linear_algebra::operators::element_wise_multiplication::ApplyElementWiseVectorMultiplicationBinaryOperator;
+use graphblas_sparse_linear_algebra::operators::index_unary_operator::IsValueLessThan;
+use graphblas_sparse_linear_algebra::operators::monoid::Plus as PlusMonoid;
+use graphblas_sparse_linear_algebra::operators::multiplication::{
+    MultiplyMatrixByVector, MultiplyVectorByMatrix,
+};
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixAsFirstArgument,
+    OptionsForOperatorWithMatrixAsSecondArgument,
+};
+use graphblas_sparse_linear_algebra::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+use graphblas_sparse_linear_algebra::operators::select::SelectFromVector;
+use graphblas_sparse_linear_algebra::operators::unary_operator::{Identity, One};
+
+use crate::error::{GraphComputingError, UserError, UserErrorType};
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{
+    GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection,
+};
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexTypeIndex};
+use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::graph::vertex_store::GetVertexElementIndexer;
+use crate::operators::change_data_capture::GraphChange;
+
+use super::graphblas_extensions::{AbsoluteValue, PlusFirst, PlusSecond};
+use super::results::write_to_vertex_vector;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageRankParameters {
+    damping_factor: f64,
+    tolerance: f64,
+    max_iterations: usize,
+}
+
+impl PageRankParameters {
+    /// The damping factor must be in [0, 1), and the tolerance must not be negative.
+    pub fn new(
+        damping_factor: f64,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<Self, GraphComputingError> {
+        if !(0.0..1.0).contains(&damping_factor) {
+            return Err(UserError::new(
+                UserErrorType::Other,
+                format!(
+                    "The damping factor must be in [0, 1), but is {}",
+                    damping_factor
+                ),
+                None,
+            )
+            .into());
+        }
+        if tolerance.is_nan() || tolerance < 0.0 {
+            return Err(UserError::new(
+                UserErrorType::Other,
+                format!("The tolerance must not be negative, but is {}", tolerance),
+                None,
+            )
+            .into());
+        }
+        Ok(Self {
+            damping_factor,
+            tolerance,
+            max_iterations,
+        })
+    }
+
+    pub fn damping_factor(&self) -> f64 {
+        self.damping_factor
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    pub fn max_iterations(&self) -> usize {
+        self.max_iterations
+    }
+}
+
+impl Default for PageRankParameters {
+    /// A damping factor of 0.85, a tolerance of 1e-6, and at most 100 iterations.
+    fn default() -> Self {
+        Self {
+            damping_factor: 0.85,
+            tolerance: 1e-6,
+            max_iterations: 100,
+        }
+    }
+}
+
+/// Writes the rank of each vertex into the rank vertex type, and returns the number of iterations.
+/// The iteration stops once the ranks change by less than the tolerance in L1 norm, or after the maximum number of iterations.
+/// Edge weights are ignored. The rank of a dangling vertex, without outgoing edges, is redistributed like the teleportation.
+pub trait PageRank {
+    /// Teleports uniformly to all vertices.
+    fn page_rank(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        rank: &impl GetVertexTypeIndex,
+        parameters: &PageRankParameters,
+    ) -> Result<usize, GraphComputingError>;
+
+    /// Teleports to vertices in proportion to their value in the personalization vertex type.
+    /// The values must not be negative, and must have a positive sum.
+    fn personalized_page_rank(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        personalization: &impl GetVertexTypeIndex,
+        rank: &impl GetVertexTypeIndex,
+        parameters: &PageRankParameters,
+    ) -> Result<usize, GraphComputingError>;
+}
+
+impl PageRank for Graph {
+    fn page_rank(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        rank: &impl GetVertexTypeIndex,
+        parameters: &PageRankParameters,
+    ) -> Result<usize, GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(rank)?;
+
+        let vertices = vertices(
+            &self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+        )?;
+        let teleportation =
+            uniform_teleportation(&self.graphblas_operator_applier_collection, &vertices)?;
+        let number_of_iterations = page_rank(
+            &mut self.public_vertex_store,
+            &self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            &vertices,
+            &teleportation,
+            rank,
+            parameters,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(rank))?;
+        Ok(number_of_iterations)
+    }
+
+    fn personalized_page_rank(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        personalization: &impl GetVertexTypeIndex,
+        rank: &impl GetVertexTypeIndex,
+        parameters: &PageRankParameters,
+    ) -> Result<usize, GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(personalization)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(rank)?;
+
+        let vertices = vertices(
+            &self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+        )?;
+        let teleportation = personalized_teleportation(
+            &self.graphblas_operator_applier_collection,
+            &vertices,
+            self.public_vertex_store
+                .vertex_vector_ref_unchecked(personalization),
+        )?;
+        let number_of_iterations = page_rank(
+            &mut self.public_vertex_store,
+            &self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            &vertices,
+            &teleportation,
+            rank,
+            parameters,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(rank))?;
+        Ok(number_of_iterations)
+    }
+}
+
+fn page_rank(
+    vertex_store: &mut impl GetVertexVector,
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    vertices: &SparseVector<f64>,
+    teleportation: &SparseVector<f64>,
+    rank: &impl GetVertexTypeIndex,
+    parameters: &PageRankParameters,
+) -> Result<usize, GraphComputingError> {
+    let (ranks, number_of_iterations) = page_rank_unchecked(
+        edge_store,
+        graphblas_operator_applier_collection,
+        edge_type,
+        vertices,
+        teleportation,
+        parameters,
+    )?;
+    write_to_vertex_vector(
+        vertex_store,
+        graphblas_operator_applier_collection,
+        &ranks,
+        rank,
+    )?;
+    Ok(number_of_iterations)
+}
+
+/// Returns a one for each valid vertex index.
+fn vertices(
+    vertex_store: &impl GetVertexElementIndexer,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+) -> Result<SparseVector<f64>, GraphComputingError> {
+    let valid_vertices = vertex_store
+        .element_indexer_ref()
+        .mask_with_valid_indices_ref();
+    let mut vertices = SparseVector::<f64>::new(
+        valid_vertices.context_ref().to_owned(),
+        valid_vertices.length()?,
+    )?;
+    graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_vector(
+            &One::<f64>::new(),
+            valid_vertices,
+            &Assignment::new(),
+            &mut vertices,
+            graphblas_operator_applier_collection.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+    Ok(vertices)
+}
+
+fn uniform_teleportation(
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    vertices: &SparseVector<f64>,
+) -> Result<SparseVector<f64>, GraphComputingError> {
+    let number_of_vertices = vertices.number_of_stored_elements()?;
+    let mut teleportation =
+        SparseVector::<f64>::new(vertices.context_ref().to_owned(), vertices.length()?)?;
+    if number_of_vertices > 0 {
+        graphblas_operator_applier_collection
+            .binary_operator_applier()
+            .apply_with_vector_as_left_argument(
+                vertices,
+                &Times::<f64>::new(),
+                1.0 / number_of_vertices as f64,
+                &Assignment::new(),
+                &mut teleportation,
+                graphblas_operator_applier_collection.entire_vector_selector(),
+                &OperatorOptions::new_default(),
+            )?;
+    }
+    Ok(teleportation)
+}
+
+fn personalized_teleportation(
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    vertices: &SparseVector<f64>,
+    personalization: &impl GetGraphblasSparseVector,
+) -> Result<SparseVector<f64>, GraphComputingError> {
+    let graphblas_context = vertices.context_ref();
+    let length = vertices.length()?;
+    let mut weights = SparseVector::<f64>::new(graphblas_context.clone(), length)?;
+    graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_vector(
+            &Identity::<f64>::new(),
+            personalization,
+            &Assignment::new(),
+            &mut weights,
+            graphblas_operator_applier_collection.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+
+    let mut negative_weights = SparseVector::<f64>::new(graphblas_context.clone(), length)?;
+    graphblas_operator_applier_collection
+        .vector_selector()
+        .apply(
+            &IsValueLessThan::<f64>::new(),
+            0.0,
+            &weights,
+            &Assignment::new(),
+            &mut negative_weights,
+            graphblas_operator_applier_collection.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+    let total_weight = sum(&weights)?;
+    if negative_weights.number_of_stored_elements()? > 0 || total_weight <= 0.0 {
+        return Err(UserError::new(
+            UserErrorType::Other,
+            String::from("The personalization must not be negative, and must have a positive sum"),
+            None,
+        )
+        .into());
+    }
+
+    let mut teleportation = SparseVector::<f64>::new(graphblas_context.clone(), length)?;
+    graphblas_operator_applier_collection
+        .binary_operator_applier()
+        .apply_with_vector_as_left_argument(
+            &weights,
+            &Times::<f64>::new(),
+            1.0 / total_weight,
+            &Assignment::new(),
+            &mut teleportation,
+            graphblas_operator_applier_collection.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+    Ok(teleportation)
+}
+
+/// Returns the ranks and the number of iterations.
+/// The teleportation vector sums to one, and stores values only for valid vertices.
+pub(crate) fn page_rank_unchecked(
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    vertices: &SparseVector<f64>,
+    teleportation: &SparseVector<f64>,
+    parameters: &PageRankParameters,
+) -> Result<(SparseVector<f64>, usize), GraphComputingError> {
+    let graphblas_context = vertices.context_ref();
+    let vertex_capacity = *edge_store.adjacency_matrix_size_ref();
+    let adjacency_matrix = edge_store.adjacency_matrix_ref_unchecked(edge_type);
+    let damping_factor = parameters.damping_factor();
+    let entire_vector = graphblas_operator_applier_collection.entire_vector_selector();
+
+    let new_vector = || SparseVector::<f64>::new(graphblas_context.to_owned(), vertex_capacity);
+    let mut out_degrees = new_vector()?;
+    let mut damping_by_out_degree = new_vector()?;
+    let mut ranks = new_vector()?;
+    let mut next_ranks = new_vector()?;
+    let mut linked_ranks = new_vector()?;
+    let mut contributions = new_vector()?;
+    let mut differences = new_vector()?;
+
+    graphblas_operator_applier_collection
+        .matrix_vector_multiplication_operator()
+        .apply(
+            adjacency_matrix,
+            &PlusSecond::new(),
+            vertices,
+            &Assignment::new(),
+            &mut out_degrees,
+            entire_vector,
+            &OptionsForOperatorWithMatrixAsFirstArgument::new_default(),
+        )?;
+    graphblas_operator_applier_collection
+        .binary_operator_applier()
+        .apply_with_vector_as_left_argument(
+            &out_degrees,
+            &ReverseDivide::<f64>::new(),
+            damping_factor,
+            &Assignment::new(),
+            &mut damping_by_out_degree,
+            entire_vector,
+            &OperatorOptions::new_default(),
+        )?;
+
+    graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_vector(
+            &Identity::<f64>::new(),
+            teleportation,
+            &Assignment::new(),
+            &mut ranks,
+            entire_vector,
+            &OperatorOptions::new_default(),
+        )?;
+
+    for iteration in 1..=parameters.max_iterations() {
+        // Ranks of dangling vertices are not linked to other vertices
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<f64>::new(),
+                &ranks,
+                &Assignment::new(),
+                &mut linked_ranks,
+                &out_degrees,
+                &OperatorOptions::new(true, true, false),
+            )?;
+        let total_rank = sum(&ranks)?;
+        let teleported_rank = total_rank - damping_factor * sum(&linked_ranks)?;
+
+        graphblas_operator_applier_collection
+            .element_wise_vector_multiplication_binary_operator()
+            .apply(
+                &ranks,
+                &Times::<f64>::new(),
+                &damping_by_out_degree,
+                &Assignment::new(),
+                &mut contributions,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+        graphblas_operator_applier_collection
+            .vector_matrix_multiplication_operator()
+            .apply(
+                &contributions,
+                &PlusFirst::new(),
+                adjacency_matrix,
+                &Assignment::new(),
+                &mut next_ranks,
+                entire_vector,
+                &OptionsForOperatorWithMatrixAsSecondArgument::new(true, false, false, false),
+            )?;
+        graphblas_operator_applier_collection
+            .binary_operator_applier()
+            .apply_with_vector_as_left_argument(
+                teleportation,
+                &Times::<f64>::new(),
+                teleported_rank,
+                &Plus::<f64>::new(),
+                &mut next_ranks,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+
+        // The ranks only gain stored values, so the union with Minus is the difference
+        graphblas_operator_applier_collection
+            .element_wise_vector_addition_binary_operator()
+            .apply(
+                &next_ranks,
+                &Minus::<f64>::new(),
+                &ranks,
+                &Assignment::new(),
+                &mut contributions,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &AbsoluteValue::new(),
+                &contributions,
+                &Assignment::new(),
+                &mut differences,
+                entire_vector,
+                &OperatorOptions::new_default(),
+            )?;
+
+        mem::swap(&mut ranks, &mut next_ranks);
+        if sum(&differences)? < parameters.tolerance() {
+            return Ok((ranks, iteration));
+        }
+    }
+
+    Ok((ranks, parameters.max_iterations()))
+}
+
+fn sum(vector: &SparseVector<f64>) -> Result<f64, GraphComputingError> {
+    let mut sum = 0.0;
+    MonoidReducer::new().vector_to_scalar(
+        &PlusMonoid::<f64>::new(),
+        vector,
+        &Assignment::new(),
+        &mut sum,
+        &OperatorOptions::new_default(),
+    )?;
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::VertexIndex;
+    use crate::operators::operators::new::{
+        NewEdge, NewEdgeType, NewVertex, NewVertexIndex, NewVertexType,
+    };
+    use crate::operators::operators::read::GetVertexValue;
+
+    fn assert_rank(
+        graph: &Graph,
+        rank: &impl GetVertexTypeIndex,
+        vertex: &VertexIndex,
+        expected: f64,
+    ) {
+        let value = GetVertexValue::<f64>::vertex_value(graph, rank, vertex)
+            .unwrap()
+            .unwrap();
+        assert!((value - expected).abs() < 1e-6, "{} != {}", value, expected);
+    }
+
+    #[test]
+    fn page_rank_of_cycle_is_uniform() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<bool>::apply(&mut graph).unwrap();
+        let rank = NewVertexType::<f64>::apply(&mut graph).unwrap();
+
+        let cycle: Vec<VertexIndex> = (0..3).map(|_| graph.new_vertex_index().unwrap()).collect();
+        for index in 0..3 {
+            graph
+                .new_edge(&edge_type, &cycle[index], &cycle[(index + 1) % 3], true)
+                .unwrap();
+        }
+
+        let number_of_iterations = graph
+            .page_rank(&edge_type, &rank, &PageRankParameters::default())
+            .unwrap();
+
+        assert_eq!(number_of_iterations, 1);
+        for vertex in cycle.iter() {
+            assert_rank(&graph, &rank, vertex, 1.0 / 3.0);
+        }
+    }
+
+    #[test]
+    fn page_rank_redistributes_rank_of_dangling_vertex() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let rank = NewVertexType::<f64>::apply(&mut graph).unwrap();
+        let personalization = NewVertexType::<u8>::apply(&mut graph).unwrap();
+
+        let tail = graph.new_vertex(&personalization, 1u8).unwrap();
+        let dangling = graph.new_vertex_index().unwrap();
+        graph.new_edge(&edge_type, &tail, &dangling, 1u8).unwrap();
+
+        let parameters = PageRankParameters::new(0.85, 1e-10, 1000).unwrap();
+
+        let number_of_iterations = graph.page_rank(&edge_type, &rank, &parameters).unwrap();
+        assert!(number_of_iterations > 1 && number_of_iterations < 1000);
+        assert_rank(&graph, &rank, &tail, 0.5 / 1.425);
+        assert_rank(&graph, &rank, &dangling, 0.925 / 1.425);
+
+        graph
+            .personalized_page_rank(&edge_type, &personalization, &rank, &parameters)
+            .unwrap();
+        assert_rank(&graph, &rank, &tail, 0.15 / 0.2775);
+        assert_rank(&graph, &rank, &dangling, 0.1275 / 0.2775);
+    }
+
+    #[test]
+    fn reject_invalid_damping_factor() {
+        assert!(PageRankParameters::new(1.0, 1e-6, 100).is_err());
+        assert!(PageRankParameters::new(0.85, -1.0, 100).is_err());
+    }
+}
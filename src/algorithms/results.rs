@@ -0,0 +1,122 @@
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::SparseMatrix;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorLength;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::context::GetContext;
+use graphblas_sparse_linear_algebra::operators::apply::{ApplyBinaryOperator, ApplyUnaryOperator};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    Assignment, BinaryOperator, Second,
+};
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixArgument,
+};
+use graphblas_sparse_linear_algebra::operators::unary_operator::{Identity, UnaryOperator};
+
+use crate::error::{GraphComputingError, LogicError, LogicErrorType};
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::graph::{GetGraphblasOperatorAppliers, GraphblasOperatorApplierCollection};
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{GetEdgeTypeIndex, GetIndex, GetVertexTypeIndex};
+use crate::graph::value_type::{GetValueTypeIdentifier, GetValueTypeIdentifierRef, ValueType};
+use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::graph::vertex_store::GetVertexElementIndexer;
+
+/// Returns an error if the vertex type does not have value type T, i.e. if writing a result of type T to it would cast.
+pub(crate) fn try_vertex_type_value_type<T: GetValueTypeIdentifier>(
+    vertex_store: &impl GetVertexVector,
+    vertex_type: &impl GetVertexTypeIndex,
+) -> Result<(), GraphComputingError> {
+    let value_type = vertex_store
+        .vertex_vector_ref_unchecked(vertex_type)
+        .value_type_identifier_ref();
+    if *value_type == T::value_type_identifier() {
+        Ok(())
+    } else {
+        Err(LogicError::new(
+            LogicErrorType::UnsafeTypeConversion,
+            format!(
+                "The vertex type {} has value type {:?}, expected {:?}",
+                vertex_type.index(),
+                value_type,
+                T::value_type_identifier()
+            ),
+            None,
+        )
+        .into())
+    }
+}
+
+/// Replaces the vertex vector by the result, casting to the value type of the vertex vector.
+pub(crate) fn write_to_vertex_vector<T>(
+    vertex_store: &mut impl GetVertexVector,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    result: &SparseVector<T>,
+    vertex_type: &impl GetVertexTypeIndex,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType,
+    Identity<T>: UnaryOperator<T>,
+{
+    Ok(graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_vector(
+            &Identity::<T>::new(),
+            result,
+            &Assignment::new(),
+            vertex_store.vertex_vector_mut_ref_unchecked(vertex_type)?,
+            graphblas_operator_applier_collection.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?)
+}
+
+/// Replaces the adjacency matrix by the result, casting to the value type of the adjacency matrix.
+pub(crate) fn write_to_adjacency_matrix<T>(
+    edge_store: &mut impl GetAdjacencyMatrix,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    result: &SparseMatrix<T>,
+    edge_type: &impl GetEdgeTypeIndex,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType,
+    Identity<T>: UnaryOperator<T>,
+{
+    Ok(graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_matrix(
+            &Identity::<T>::new(),
+            result,
+            &Assignment::new(),
+            edge_store.adjacency_matrix_mut_ref_unchecked(edge_type)?,
+            graphblas_operator_applier_collection.entire_matrix_selector(),
+            &OptionsForOperatorWithMatrixArgument::new_default(),
+        )?)
+}
+
+/// Returns a vector with a default value for each valid vertex.
+pub(crate) fn zero_for_each_vertex<T>(
+    vertex_store: &impl GetVertexElementIndexer,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+) -> Result<SparseVector<T>, GraphComputingError>
+where
+    T: ValueType + Default,
+    Second<T>: BinaryOperator<T>,
+{
+    let valid_vertices = vertex_store
+        .element_indexer_ref()
+        .mask_with_valid_indices_ref();
+    let mut zeros = SparseVector::<T>::new(
+        valid_vertices.context_ref().to_owned(),
+        valid_vertices.length()?,
+    )?;
+    graphblas_operator_applier_collection
+        .binary_operator_applier()
+        .apply_with_vector_as_left_argument(
+            valid_vertices,
+            &Second::<T>::new(),
+            T::default(),
+            &Assignment::new(),
+            &mut zeros,
+            graphblas_operator_applier_collection.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+    Ok(zeros)
+}
@@ -0,0 +1,987 @@
+use std::cmp::Ordering;
+use std::mem;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::SetSparseVectorElement;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::collections::Collection;
+use graphblas_sparse_linear_algebra::context::Context as GraphBLASContext;
+use graphblas_sparse_linear_algebra::operators::apply::ApplyUnaryOperator;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    Assignment, BinaryOperator, IsEqual, IsLessThan,
+};
+use graphblas_sparse_linear_algebra::operators::element_wise_multiplication::{
+    ApplyElementWiseMatrixMultiplicationBinaryOperator,
+    ApplyElementWiseVectorMultiplicationBinaryOperator,
+};
+use graphblas_sparse_linear_algebra::operators::index_unary_operator::{
+    IndexUnaryOperator, IsValueGreaterThan, IsValueLessThan, IsValueLessThanOrEqualTo,
+};
+use graphblas_sparse_linear_algebra::operators::monoid::{Min as MinMonoid, Monoid};
+use graphblas_sparse_linear_algebra::operators::multiplication::{
+    MultiplyMatrices, MultiplyVectorByMatrix,
+};
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixArgument, OptionsForOperatorWithMatrixArguments,
+    OptionsForOperatorWithMatrixAsSecondArgument,
+};
+use graphblas_sparse_linear_algebra::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+use graphblas_sparse_linear_algebra::operators::select::{
+    MatrixSelector, SelectFromMatrix, SelectFromVector, VectorSelector,
+};
+use graphblas_sparse_linear_algebra::operators::semiring::{MinPlus, MinSecond, Semiring};
+use graphblas_sparse_linear_algebra::operators::unary_operator::{Identity, One, UnaryOperator};
+
+use crate::error::{GraphComputingError, UserError, UserErrorType};
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{
+    GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection,
+};
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex};
+use crate::graph::value_type::ValueType;
+use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::operators::change_data_capture::GraphChange;
+
+use super::graphblas_extensions::{
+    diagonal_matrix, MinSecondIndex, OptionsForMultiplicationWithStructuralComplementMask,
+};
+use super::results::write_to_vertex_vector;
+
+/// Computes shortest paths in the EvaluationDomain, using min-plus vector-matrix multiplication.
+/// Both methods write the distance and the predecessor of each vertex reachable from the source into the distance and predecessor vertex types.
+/// The source has distance zero and is its own predecessor. Vertices that are not reachable have no distance or predecessor.
+pub trait SingleSourceShortestPaths<EvaluationDomain: ValueType> {
+    /// Supports negative edge weights.
+    /// Returns a NegativeCycle error, without writing any results, if a negative cycle is reachable from the source.
+    fn bellman_ford(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        source: &impl GetVertexIndexIndex,
+        distance: &impl GetVertexTypeIndex,
+        predecessor: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+
+    /// Requires non-negative edge weights, and a positive delta.
+    /// Edges with a weight up to delta are relaxed repeatedly within a bucket of width delta, heavier edges once per bucket.
+    fn delta_stepping(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        source: &impl GetVertexIndexIndex,
+        delta: EvaluationDomain,
+        distance: &impl GetVertexTypeIndex,
+        predecessor: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+}
+
+/// Adds distances without overflowing.
+pub trait CheckedDistanceAddition: Sized {
+    /// None if the sum overflows, or is not finite for floating point distances.
+    fn checked_distance_addition(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! implement_integer_checked_distance_addition {
+    ($value_type:ty) => {
+        impl CheckedDistanceAddition for $value_type {
+            fn checked_distance_addition(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+        }
+    };
+}
+implement_integer_checked_distance_addition!(i8);
+implement_integer_checked_distance_addition!(i16);
+implement_integer_checked_distance_addition!(i32);
+implement_integer_checked_distance_addition!(i64);
+implement_integer_checked_distance_addition!(u8);
+implement_integer_checked_distance_addition!(u16);
+implement_integer_checked_distance_addition!(u32);
+implement_integer_checked_distance_addition!(u64);
+implement_integer_checked_distance_addition!(isize);
+implement_integer_checked_distance_addition!(usize);
+
+macro_rules! implement_float_checked_distance_addition {
+    ($value_type:ty) => {
+        impl CheckedDistanceAddition for $value_type {
+            fn checked_distance_addition(self, other: Self) -> Option<Self> {
+                let sum = self + other;
+                if sum.is_finite() {
+                    Some(sum)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+implement_float_checked_distance_addition!(f32);
+implement_float_checked_distance_addition!(f64);
+
+impl<T> SingleSourceShortestPaths<T> for Graph
+where
+    T: ValueType + Copy + Default + PartialOrd + CheckedDistanceAddition,
+    MinPlus<T>: Semiring<T>,
+    MinSecond<T>: Semiring<T>,
+    IsEqual<T>: BinaryOperator<T>,
+    IsLessThan<T>: BinaryOperator<T>,
+    IsValueLessThan<T>: IndexUnaryOperator<T>,
+    IsValueLessThanOrEqualTo<T>: IndexUnaryOperator<T>,
+    IsValueGreaterThan<T>: IndexUnaryOperator<T>,
+    Identity<T>: UnaryOperator<T>,
+    MinMonoid<T>: Monoid<T>,
+    MonoidReducer: MonoidScalarReducer<T>,
+    VectorSelector: SelectFromVector<T>,
+    MatrixSelector: SelectFromMatrix<T>,
+    SparseVector<T>: SetSparseVectorElement<T>,
+{
+    fn bellman_ford(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        source: &impl GetVertexIndexIndex,
+        distance: &impl GetVertexTypeIndex,
+        predecessor: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        try_shortest_path_arguments(
+            &self.public_vertex_store,
+            &self.public_edge_store,
+            edge_type,
+            source,
+            distance,
+            predecessor,
+        )?;
+        let distances = bellman_ford_unchecked::<T>(
+            &self.public_edge_store,
+            &self.graphblas_context,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            source,
+        )?;
+        write_shortest_paths(
+            &mut self.public_vertex_store,
+            &self.public_edge_store,
+            &self.graphblas_context,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            source,
+            &distances,
+            distance,
+            predecessor,
+        )?;
+        self.publish_changes(vec![
+            GraphChange::vertex_vector_overwritten(distance),
+            GraphChange::vertex_vector_overwritten(predecessor),
+        ])
+    }
+
+    fn delta_stepping(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        source: &impl GetVertexIndexIndex,
+        delta: T,
+        distance: &impl GetVertexTypeIndex,
+        predecessor: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        try_shortest_path_arguments(
+            &self.public_vertex_store,
+            &self.public_edge_store,
+            edge_type,
+            source,
+            distance,
+            predecessor,
+        )?;
+        let distances = delta_stepping_unchecked::<T>(
+            &self.public_edge_store,
+            &self.graphblas_context,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            source,
+            delta,
+        )?;
+        write_shortest_paths(
+            &mut self.public_vertex_store,
+            &self.public_edge_store,
+            &self.graphblas_context,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+            source,
+            &distances,
+            distance,
+            predecessor,
+        )?;
+        self.publish_changes(vec![
+            GraphChange::vertex_vector_overwritten(distance),
+            GraphChange::vertex_vector_overwritten(predecessor),
+        ])
+    }
+}
+
+pub(crate) fn try_shortest_path_arguments(
+    vertex_store: &(impl CheckVertexTypeIndex + CheckVertexIndex),
+    edge_store: &impl EdgeTypeIndexing,
+    edge_type: &impl GetEdgeTypeIndex,
+    source: &impl GetVertexIndexIndex,
+    distance: &impl GetVertexTypeIndex,
+    predecessor: &impl GetVertexTypeIndex,
+) -> Result<(), GraphComputingError> {
+    edge_store.try_edge_type_index_validity(edge_type)?;
+    vertex_store.try_vertex_index_validity(source)?;
+    vertex_store.try_vertex_type_index_validity(distance)?;
+    vertex_store.try_vertex_type_index_validity(predecessor)
+}
+
+/// Returns the distance vector.
+pub(crate) fn bellman_ford_unchecked<T>(
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_context: &Arc<GraphBLASContext>,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    source: &impl GetVertexIndexIndex,
+) -> Result<SparseVector<T>, GraphComputingError>
+where
+    T: ValueType + Default,
+    MinPlus<T>: Semiring<T>,
+    IsLessThan<T>: BinaryOperator<T>,
+    Identity<T>: UnaryOperator<T>,
+    SparseVector<T>: SetSparseVectorElement<T>,
+{
+    let vertex_capacity = *edge_store.adjacency_matrix_size_ref();
+    let adjacency_matrix = edge_store.adjacency_matrix_ref_unchecked(edge_type);
+
+    let mut distances = SparseVector::<T>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut frontier = SparseVector::<T>::new(graphblas_context.clone(), vertex_capacity)?;
+    distances.set_value(source.index(), T::default())?;
+    frontier.set_value(source.index(), T::default())?;
+
+    let mut relaxation = Relaxation::new(graphblas_context, vertex_capacity)?;
+
+    // Without a negative cycle, a shortest path has fewer edges than there are vertices.
+    // Relaxing vertices that improved in the previous round thus ends in at most vertex_capacity rounds.
+    for _round in 0..vertex_capacity {
+        relaxation.relax(
+            graphblas_operator_applier_collection,
+            &frontier,
+            adjacency_matrix,
+            &mut distances,
+        )?;
+        relaxation.select_improved(graphblas_operator_applier_collection, &mut frontier, None)?;
+
+        if frontier.number_of_stored_elements()? == 0 {
+            return Ok(distances);
+        }
+    }
+
+    Err(UserError::new(
+        UserErrorType::NegativeCycle,
+        format!(
+            "A negative cycle is reachable from vertex {}",
+            source.index()
+        ),
+        None,
+    )
+    .into())
+}
+
+/// Returns the distance vector.
+pub(crate) fn delta_stepping_unchecked<T>(
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_context: &Arc<GraphBLASContext>,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    source: &impl GetVertexIndexIndex,
+    delta: T,
+) -> Result<SparseVector<T>, GraphComputingError>
+where
+    T: ValueType + Copy + Default + PartialOrd + CheckedDistanceAddition,
+    MinPlus<T>: Semiring<T>,
+    IsLessThan<T>: BinaryOperator<T>,
+    IsValueLessThan<T>: IndexUnaryOperator<T>,
+    IsValueLessThanOrEqualTo<T>: IndexUnaryOperator<T>,
+    IsValueGreaterThan<T>: IndexUnaryOperator<T>,
+    Identity<T>: UnaryOperator<T>,
+    MinMonoid<T>: Monoid<T>,
+    MonoidReducer: MonoidScalarReducer<T>,
+    VectorSelector: SelectFromVector<T>,
+    MatrixSelector: SelectFromMatrix<T>,
+    SparseVector<T>: SetSparseVectorElement<T>,
+{
+    if delta.partial_cmp(&T::default()) != Some(Ordering::Greater) {
+        return Err(UserError::new(
+            UserErrorType::Other,
+            format!("Delta must be positive, but is {:?}", delta),
+            None,
+        )
+        .into());
+    }
+
+    let vertex_capacity = *edge_store.adjacency_matrix_size_ref();
+    let adjacency_matrix = edge_store.adjacency_matrix_ref_unchecked(edge_type);
+    let matrix_size = Size::new(vertex_capacity, vertex_capacity);
+
+    let mut negative_edges = SparseMatrix::<T>::new(graphblas_context.clone(), matrix_size)?;
+    select_edges(
+        graphblas_operator_applier_collection,
+        &IsValueLessThan::<T>::new(),
+        T::default(),
+        adjacency_matrix,
+        &mut negative_edges,
+    )?;
+    if negative_edges.number_of_stored_elements()? > 0 {
+        return Err(UserError::new(
+            UserErrorType::NegativeEdgeWeight,
+            String::from("Delta-stepping requires non-negative edge weights"),
+            None,
+        )
+        .into());
+    }
+
+    let mut light_edges = SparseMatrix::<T>::new(graphblas_context.clone(), matrix_size)?;
+    let mut heavy_edges = SparseMatrix::<T>::new(graphblas_context.clone(), matrix_size)?;
+    select_edges(
+        graphblas_operator_applier_collection,
+        &IsValueLessThanOrEqualTo::<T>::new(),
+        delta,
+        adjacency_matrix,
+        &mut light_edges,
+    )?;
+    select_edges(
+        graphblas_operator_applier_collection,
+        &IsValueGreaterThan::<T>::new(),
+        delta,
+        adjacency_matrix,
+        &mut heavy_edges,
+    )?;
+
+    let mut distances = SparseVector::<T>::new(graphblas_context.clone(), vertex_capacity)?;
+    distances.set_value(source.index(), T::default())?;
+
+    let mut settled = SparseVector::<T>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut unsettled = SparseVector::<T>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut bucket = SparseVector::<T>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut reached = SparseVector::<T>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut relaxation = Relaxation::new(graphblas_context, vertex_capacity)?;
+
+    let options_to_select_with_structural_complement_mask = OperatorOptions::new(true, true, true);
+    let options_to_update_with_structural_mask = OperatorOptions::new(false, true, false);
+    let options_to_replace_with_structural_mask = OperatorOptions::new(true, true, false);
+
+    loop {
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<T>::new(),
+                &distances,
+                &Assignment::new(),
+                &mut unsettled,
+                &settled,
+                &options_to_select_with_structural_complement_mask,
+            )?;
+        if unsettled.number_of_stored_elements()? == 0 {
+            return Ok(distances);
+        }
+
+        // The bucket starts at the smallest unsettled distance, skipping empty buckets
+        let mut lower_bound = T::default();
+        MonoidReducer::new().vector_to_scalar(
+            &MinMonoid::<T>::new(),
+            &unsettled,
+            &Assignment::new(),
+            &mut lower_bound,
+            &OperatorOptions::new_default(),
+        )?;
+        let upper_bound = lower_bound.checked_distance_addition(delta);
+
+        match upper_bound {
+            Some(upper_bound) => graphblas_operator_applier_collection
+                .vector_selector()
+                .apply(
+                    &IsValueLessThan::<T>::new(),
+                    upper_bound,
+                    &unsettled,
+                    &Assignment::new(),
+                    &mut bucket,
+                    graphblas_operator_applier_collection.entire_vector_selector(),
+                    &OperatorOptions::new_default(),
+                )?,
+            // Without an upper bound, the bucket holds all unsettled vertices
+            None => graphblas_operator_applier_collection
+                .unary_operator_applier()
+                .apply_to_vector(
+                    &Identity::<T>::new(),
+                    &unsettled,
+                    &Assignment::new(),
+                    &mut bucket,
+                    graphblas_operator_applier_collection.entire_vector_selector(),
+                    &OperatorOptions::new_default(),
+                )?,
+        }
+        reached.clear()?;
+
+        // Light edges may reach vertices in the same bucket
+        while bucket.number_of_stored_elements()? > 0 {
+            graphblas_operator_applier_collection
+                .unary_operator_applier()
+                .apply_to_vector(
+                    &Identity::<T>::new(),
+                    &bucket,
+                    &Assignment::new(),
+                    &mut reached,
+                    &bucket,
+                    &options_to_update_with_structural_mask,
+                )?;
+            relaxation.relax(
+                graphblas_operator_applier_collection,
+                &bucket,
+                &light_edges,
+                &mut distances,
+            )?;
+            relaxation.select_improved(
+                graphblas_operator_applier_collection,
+                &mut bucket,
+                upper_bound,
+            )?;
+        }
+
+        // Heavy edges always lead out of the bucket, and are relaxed once
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<T>::new(),
+                &distances,
+                &Assignment::new(),
+                &mut bucket,
+                &reached,
+                &options_to_replace_with_structural_mask,
+            )?;
+        relaxation.relax(
+            graphblas_operator_applier_collection,
+            &bucket,
+            &heavy_edges,
+            &mut distances,
+        )?;
+
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<T>::new(),
+                &reached,
+                &Assignment::new(),
+                &mut settled,
+                &reached,
+                &options_to_update_with_structural_mask,
+            )?;
+    }
+}
+
+fn select_edges<T>(
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    selector: &impl IndexUnaryOperator<T>,
+    selector_argument: T,
+    adjacency_matrix: &impl GetGraphblasSparseMatrix,
+    product: &mut SparseMatrix<T>,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType,
+    MatrixSelector: SelectFromMatrix<T>,
+{
+    Ok(graphblas_operator_applier_collection
+        .matrix_selector()
+        .apply(
+            selector,
+            selector_argument,
+            adjacency_matrix,
+            &Assignment::new(),
+            product,
+            graphblas_operator_applier_collection.entire_matrix_selector(),
+            &OptionsForOperatorWithMatrixArgument::new_default(),
+        )?)
+}
+
+/// Relaxes the edges from a set of vertices, and tracks which distances improved.
+struct Relaxation<T: ValueType> {
+    requested_distances: SparseVector<T>,
+    improved: SparseVector<bool>,
+}
+
+impl<T> Relaxation<T>
+where
+    T: ValueType,
+    MinPlus<T>: Semiring<T>,
+    IsLessThan<T>: BinaryOperator<T>,
+    Identity<T>: UnaryOperator<T>,
+{
+    fn new(
+        graphblas_context: &Arc<GraphBLASContext>,
+        vertex_capacity: usize,
+    ) -> Result<Self, GraphComputingError> {
+        Ok(Self {
+            requested_distances: SparseVector::new(graphblas_context.clone(), vertex_capacity)?,
+            improved: SparseVector::new(graphblas_context.clone(), vertex_capacity)?,
+        })
+    }
+
+    fn relax(
+        &mut self,
+        graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+        vertices: &SparseVector<T>,
+        edges: &impl GetGraphblasSparseMatrix,
+        distances: &mut SparseVector<T>,
+    ) -> Result<(), GraphComputingError> {
+        graphblas_operator_applier_collection
+            .vector_matrix_multiplication_operator()
+            .apply(
+                vertices,
+                &MinPlus::<T>::new(),
+                edges,
+                &Assignment::new(),
+                &mut self.requested_distances,
+                graphblas_operator_applier_collection.entire_vector_selector(),
+                &OptionsForOperatorWithMatrixAsSecondArgument::new(true, false, false, false),
+            )?;
+
+        // A requested distance improves on a missing distance, or on a larger distance
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &One::<bool>::new(),
+                &self.requested_distances,
+                &Assignment::new(),
+                &mut self.improved,
+                distances,
+                &OperatorOptions::new(true, true, true),
+            )?;
+        graphblas_operator_applier_collection
+            .element_wise_vector_multiplication_binary_operator()
+            .apply(
+                &self.requested_distances,
+                &IsLessThan::<T>::new(),
+                distances,
+                &Assignment::new(),
+                &mut self.improved,
+                distances,
+                &OperatorOptions::new(false, true, false),
+            )?;
+
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<T>::new(),
+                &self.requested_distances,
+                &Assignment::new(),
+                distances,
+                &self.improved,
+                &OperatorOptions::new_default(),
+            )?;
+        Ok(())
+    }
+
+    /// Replaces the vertices by those that improved in the last relaxation, optionally up to an exclusive upper bound.
+    fn select_improved(
+        &self,
+        graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+        vertices: &mut SparseVector<T>,
+        upper_bound: Option<T>,
+    ) -> Result<(), GraphComputingError>
+    where
+        VectorSelector: SelectFromVector<T>,
+        IsValueLessThan<T>: IndexUnaryOperator<T>,
+    {
+        match upper_bound {
+            Some(upper_bound) => graphblas_operator_applier_collection
+                .vector_selector()
+                .apply(
+                    &IsValueLessThan::<T>::new(),
+                    upper_bound,
+                    &self.requested_distances,
+                    &Assignment::new(),
+                    vertices,
+                    &self.improved,
+                    &OperatorOptions::new(true, false, false),
+                )?,
+            None => graphblas_operator_applier_collection
+                .unary_operator_applier()
+                .apply_to_vector(
+                    &Identity::<T>::new(),
+                    &self.requested_distances,
+                    &Assignment::new(),
+                    vertices,
+                    &self.improved,
+                    &OperatorOptions::new(true, false, false),
+                )?,
+        }
+        Ok(())
+    }
+}
+
+/// Writes the distances, and the predecessors along edges on a shortest path.
+fn write_shortest_paths<T>(
+    vertex_store: &mut impl GetVertexVector,
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_context: &Arc<GraphBLASContext>,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    source: &impl GetVertexIndexIndex,
+    distances: &SparseVector<T>,
+    distance: &impl GetVertexTypeIndex,
+    predecessor: &impl GetVertexTypeIndex,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType,
+    Identity<T>: UnaryOperator<T>,
+    MinPlus<T>: Semiring<T>,
+    MinSecond<T>: Semiring<T>,
+    IsEqual<T>: BinaryOperator<T>,
+{
+    let predecessors = predecessors(
+        edge_store,
+        graphblas_context,
+        graphblas_operator_applier_collection,
+        edge_type,
+        source,
+        distances,
+    )?;
+    write_to_vertex_vector(
+        vertex_store,
+        graphblas_operator_applier_collection,
+        distances,
+        distance,
+    )?;
+    write_to_vertex_vector(
+        vertex_store,
+        graphblas_operator_applier_collection,
+        &predecessors,
+        predecessor,
+    )
+}
+
+/// An edge is tight if it extends a shortest path to its tail into a shortest path to its head.
+/// A breadth-first traversal of tight edges from the source assigns each reachable vertex a single predecessor,
+/// also in the presence of zero-weight cycles.
+/// The predecessor is the tight tail with the smallest vertex index in the previous level of the traversal.
+fn predecessors<T>(
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_context: &Arc<GraphBLASContext>,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    source: &impl GetVertexIndexIndex,
+    distances: &SparseVector<T>,
+) -> Result<SparseVector<i64>, GraphComputingError>
+where
+    T: ValueType,
+    MinPlus<T>: Semiring<T>,
+    MinSecond<T>: Semiring<T>,
+    IsEqual<T>: BinaryOperator<T>,
+{
+    let vertex_capacity = *edge_store.adjacency_matrix_size_ref();
+    let matrix_size = Size::new(vertex_capacity, vertex_capacity);
+    let adjacency_matrix = edge_store.adjacency_matrix_ref_unchecked(edge_type);
+    let distance_matrix = diagonal_matrix(graphblas_context, distances, vertex_capacity)?;
+
+    // Each edge between reached vertices, valued by the distance to its tail plus its weight
+    let mut extended_distances = SparseMatrix::<T>::new(graphblas_context.clone(), matrix_size)?;
+    graphblas_operator_applier_collection
+        .matrix_multiplication_operator()
+        .apply(
+            &distance_matrix,
+            &MinPlus::<T>::new(),
+            adjacency_matrix,
+            &Assignment::new(),
+            &mut extended_distances,
+            graphblas_operator_applier_collection.entire_matrix_selector(),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )?;
+
+    // Each edge between reached vertices, valued by the distance to its head
+    let mut head_distances = SparseMatrix::<T>::new(graphblas_context.clone(), matrix_size)?;
+    graphblas_operator_applier_collection
+        .matrix_multiplication_operator()
+        .apply(
+            adjacency_matrix,
+            &MinSecond::<T>::new(),
+            &distance_matrix,
+            &Assignment::new(),
+            &mut head_distances,
+            graphblas_operator_applier_collection.entire_matrix_selector(),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )?;
+
+    let mut is_tight = SparseMatrix::<bool>::new(graphblas_context.clone(), matrix_size)?;
+    graphblas_operator_applier_collection
+        .element_wise_matrix_multiplication_binary_operator()
+        .apply(
+            &extended_distances,
+            &IsEqual::<T>::new(),
+            &head_distances,
+            &Assignment::new(),
+            &mut is_tight,
+            graphblas_operator_applier_collection.entire_matrix_selector(),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )?;
+
+    // The values of is_tight mask out the edges that are not tight
+    let mut tight_edges = SparseMatrix::<bool>::new(graphblas_context.clone(), matrix_size)?;
+    graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_matrix(
+            &One::<bool>::new(),
+            &is_tight,
+            &Assignment::new(),
+            &mut tight_edges,
+            &is_tight,
+            &OptionsForOperatorWithMatrixArgument::new(true, false, false, false),
+        )?;
+
+    let mut predecessors = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut frontier = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+    let mut next_frontier = SparseVector::<i64>::new(graphblas_context.clone(), vertex_capacity)?;
+    predecessors.set_value(source.index(), source.index() as i64)?;
+    frontier.set_value(source.index(), source.index() as i64)?;
+
+    let semiring = MinSecondIndex::new();
+    let options_for_step = OptionsForMultiplicationWithStructuralComplementMask::new();
+    let options_for_update = OperatorOptions::new(false, true, false);
+
+    // The predecessors vector is the set of visited vertices.
+    // A vertex in the next frontier stores the index of its predecessor in the frontier.
+    loop {
+        graphblas_operator_applier_collection
+            .vector_matrix_multiplication_operator()
+            .apply(
+                &frontier,
+                &semiring,
+                &tight_edges,
+                &Assignment::new(),
+                &mut next_frontier,
+                &predecessors,
+                &options_for_step,
+            )?;
+        mem::swap(&mut frontier, &mut next_frontier);
+
+        if frontier.number_of_stored_elements()? == 0 {
+            return Ok(predecessors);
+        }
+
+        graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<i64>::new(),
+                &frontier,
+                &Assignment::new(),
+                &mut predecessors,
+                &frontier,
+                &options_for_update,
+            )?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::GraphComputingErrorType;
+    use crate::graph::indexing::{EdgeTypeIndex, VertexIndex, VertexTypeIndex};
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertexIndex, NewVertexType};
+    use crate::operators::operators::read::GetVertexValue;
+
+    fn weighted_graph() -> (
+        Graph,
+        EdgeTypeIndex,
+        Vec<VertexIndex>,
+        VertexTypeIndex,
+        VertexTypeIndex,
+    ) {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let road = NewEdgeType::<f64>::apply(&mut graph).unwrap();
+        let distance = NewVertexType::<f64>::apply(&mut graph).unwrap();
+        let predecessor = NewVertexType::<u64>::apply(&mut graph).unwrap();
+
+        let vertices: Vec<VertexIndex> =
+            (0..6).map(|_| graph.new_vertex_index().unwrap()).collect();
+        for (tail, head, weight) in [
+            (0, 1, 4.0),
+            (0, 2, 1.0),
+            (2, 1, 2.0),
+            (1, 3, 1.0),
+            (2, 3, 5.0),
+            (3, 4, 3.0),
+            (5, 0, 1.0),
+        ] {
+            graph
+                .new_edge(&road, &vertices[tail], &vertices[head], weight)
+                .unwrap();
+        }
+
+        (graph, road, vertices, distance, predecessor)
+    }
+
+    fn assert_shortest_paths(
+        graph: &Graph,
+        vertices: &[VertexIndex],
+        distance: &VertexTypeIndex,
+        predecessor: &VertexTypeIndex,
+    ) {
+        for (vertex, expected_distance, expected_predecessor) in [
+            (0, Some(0.0), Some(0)),
+            (1, Some(3.0), Some(2)),
+            (2, Some(1.0), Some(0)),
+            (3, Some(4.0), Some(1)),
+            (4, Some(7.0), Some(3)),
+            (5, None, None),
+        ] {
+            assert_eq!(
+                GetVertexValue::<f64>::vertex_value(graph, distance, &vertices[vertex]).unwrap(),
+                expected_distance
+            );
+            assert_eq!(
+                GetVertexValue::<u64>::vertex_value(graph, predecessor, &vertices[vertex]).unwrap(),
+                expected_predecessor.map(|index: usize| vertices[index].index() as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn bellman_ford_shortest_paths() {
+        let (mut graph, road, vertices, distance, predecessor) = weighted_graph();
+
+        SingleSourceShortestPaths::<f64>::bellman_ford(
+            &mut graph,
+            &road,
+            &vertices[0],
+            &distance,
+            &predecessor,
+        )
+        .unwrap();
+
+        assert_shortest_paths(&graph, &vertices, &distance, &predecessor);
+    }
+
+    #[test]
+    fn delta_stepping_shortest_paths() {
+        let (mut graph, road, vertices, distance, predecessor) = weighted_graph();
+
+        SingleSourceShortestPaths::<f64>::delta_stepping(
+            &mut graph,
+            &road,
+            &vertices[0],
+            2.0,
+            &distance,
+            &predecessor,
+        )
+        .unwrap();
+
+        assert_shortest_paths(&graph, &vertices, &distance, &predecessor);
+    }
+
+    #[test]
+    fn delta_stepping_near_the_maximum_distance() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let distance = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let predecessor = NewVertexType::<u64>::apply(&mut graph).unwrap();
+
+        let vertices: Vec<VertexIndex> =
+            (0..3).map(|_| graph.new_vertex_index().unwrap()).collect();
+        graph
+            .new_edge(&edge_type, &vertices[0], &vertices[1], 200)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertices[1], &vertices[2], 50)
+            .unwrap();
+
+        SingleSourceShortestPaths::<u8>::delta_stepping(
+            &mut graph,
+            &edge_type,
+            &vertices[0],
+            100,
+            &distance,
+            &predecessor,
+        )
+        .unwrap();
+
+        for (vertex, expected_distance, expected_predecessor) in
+            [(0, 0, 0), (1, 200, 0), (2, 250, 1)]
+        {
+            assert_eq!(
+                GetVertexValue::<u8>::vertex_value(&graph, &distance, &vertices[vertex]).unwrap(),
+                Some(expected_distance)
+            );
+            assert_eq!(
+                GetVertexValue::<u64>::vertex_value(&graph, &predecessor, &vertices[vertex])
+                    .unwrap(),
+                Some(vertices[expected_predecessor].index() as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn bellman_ford_detects_negative_cycle() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<i32>::apply(&mut graph).unwrap();
+        let distance = NewVertexType::<i32>::apply(&mut graph).unwrap();
+        let predecessor = NewVertexType::<u64>::apply(&mut graph).unwrap();
+
+        let vertices: Vec<VertexIndex> =
+            (0..3).map(|_| graph.new_vertex_index().unwrap()).collect();
+        graph
+            .new_edge(&edge_type, &vertices[0], &vertices[1], 1)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertices[1], &vertices[2], -3)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertices[2], &vertices[1], 1)
+            .unwrap();
+
+        let error = SingleSourceShortestPaths::<i32>::bellman_ford(
+            &mut graph,
+            &edge_type,
+            &vertices[0],
+            &distance,
+            &predecessor,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.error_type(),
+            GraphComputingErrorType::UserErrorType(UserErrorType::NegativeCycle)
+        );
+        assert_eq!(
+            GetVertexValue::<i32>::vertex_value(&graph, &distance, &vertices[0]).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn delta_stepping_rejects_negative_edge_weight() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<i32>::apply(&mut graph).unwrap();
+        let distance = NewVertexType::<i32>::apply(&mut graph).unwrap();
+        let predecessor = NewVertexType::<u64>::apply(&mut graph).unwrap();
+
+        let tail = graph.new_vertex_index().unwrap();
+        let head = graph.new_vertex_index().unwrap();
+        graph.new_edge(&edge_type, &tail, &head, -1).unwrap();
+
+        let error = SingleSourceShortestPaths::<i32>::delta_stepping(
+            &mut graph,
+            &edge_type,
+            &tail,
+            1,
+            &distance,
+            &predecessor,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.error_type(),
+            GraphComputingErrorType::UserErrorType(UserErrorType::NegativeEdgeWeight)
+        );
+    }
+}
@@ -0,0 +1,458 @@
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    GetMatrixDimensions, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorLength;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::context::GetContext;
+use graphblas_sparse_linear_algebra::operators::apply::{ApplyBinaryOperator, ApplyUnaryOperator};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    Assignment, Divide, First, Minus, Plus, Times,
+};
+use graphblas_sparse_linear_algebra::operators::element_wise_addition::ApplyElementWiseMatrixAdditionBinaryOperator;
+use graphblas_sparse_linear_algebra::operators::element_wise_multiplication::ApplyElementWiseVectorMultiplicationBinaryOperator;
+use graphblas_sparse_linear_algebra::operators::index_unary_operator::{
+    IsOnOrAboveDiagonal, IsOnOrBelowDiagonal,
+};
+use graphblas_sparse_linear_algebra::operators::monoid::Plus as PlusMonoid;
+use graphblas_sparse_linear_algebra::operators::multiplication::MultiplyMatrices;
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixArgument, OptionsForOperatorWithMatrixArguments,
+};
+use graphblas_sparse_linear_algebra::operators::reduce::{
+    MonoidReducer, MonoidScalarReducer, MonoidVectorReducer,
+};
+use graphblas_sparse_linear_algebra::operators::select::SelectFromMatrix;
+use graphblas_sparse_linear_algebra::operators::semiring::PlusTimes;
+use graphblas_sparse_linear_algebra::operators::unary_operator::One;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix_cached_attributes::GetAdjacencyMatrixCachedAttributes;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection};
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexTypeIndex};
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::operators::change_data_capture::GraphChange;
+
+use super::graphblas_extensions::OptionsForMatrixMultiplicationWithStructuralMask;
+use super::results::{write_to_vertex_vector, zero_for_each_vertex};
+
+/// Counts triangles in the undirected structure of an edge type, whatever its value type.
+/// An edge in either direction connects two vertices, and self-loops are ignored.
+pub trait TriangleCount {
+    /// Returns the total number of triangles.
+    fn triangle_count(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Result<u64, GraphComputingError>;
+
+    /// Writes the number of triangles of each vertex into the triangle count vertex type, and returns the total number of triangles.
+    fn triangle_count_per_vertex(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        triangle_count: &impl GetVertexTypeIndex,
+    ) -> Result<u64, GraphComputingError>;
+}
+
+/// Uses the same undirected structure as TriangleCount.
+pub trait LocalClusteringCoefficient {
+    /// Writes the fraction of pairs of neighbours that are connected into the coefficient vertex type.
+    /// Vertices with fewer than two neighbours have a coefficient of zero.
+    fn local_clustering_coefficient(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        coefficient: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+}
+
+impl TriangleCount for Graph {
+    fn triangle_count(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Result<u64, GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+
+        let (lower, upper) = undirected_structure(
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+        )?;
+        triangle_count_unchecked(&self.graphblas_operator_applier_collection, &lower, &upper)
+    }
+
+    fn triangle_count_per_vertex(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        triangle_count: &impl GetVertexTypeIndex,
+    ) -> Result<u64, GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(triangle_count)?;
+
+        let (lower, upper) = undirected_structure(
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+        )?;
+        let neighbours = neighbours(&self.graphblas_operator_applier_collection, &lower, &upper)?;
+        let twice_the_triangles = twice_the_triangles_per_vertex(
+            &self.graphblas_operator_applier_collection,
+            &neighbours,
+        )?;
+
+        let mut triangles = zero_for_each_vertex::<i64>(
+            &self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+        )?;
+        self.graphblas_operator_applier_collection
+            .binary_operator_applier()
+            .apply_with_vector_as_left_argument(
+                &twice_the_triangles,
+                &Divide::<i64>::new(),
+                2,
+                &Assignment::new(),
+                &mut triangles,
+                &twice_the_triangles,
+                &OperatorOptions::new(false, true, false),
+            )?;
+        let total = sum(&triangles)? / 3;
+
+        write_to_vertex_vector(
+            &mut self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+            &triangles,
+            triangle_count,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(triangle_count))?;
+        Ok(total as u64)
+    }
+}
+
+impl LocalClusteringCoefficient for Graph {
+    fn local_clustering_coefficient(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        coefficient: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        self.public_vertex_store
+            .try_vertex_type_index_validity(coefficient)?;
+
+        let (lower, upper) = undirected_structure(
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            edge_type,
+        )?;
+        let neighbours = neighbours(&self.graphblas_operator_applier_collection, &lower, &upper)?;
+        let twice_the_triangles = twice_the_triangles_per_vertex(
+            &self.graphblas_operator_applier_collection,
+            &neighbours,
+        )?;
+
+        let graphblas_context = neighbours.context_ref();
+        let vertex_capacity = twice_the_triangles.length()?;
+        let mut degrees = SparseVector::<i64>::new(graphblas_context.to_owned(), vertex_capacity)?;
+        let mut degrees_minus_one =
+            SparseVector::<i64>::new(graphblas_context.to_owned(), vertex_capacity)?;
+        let mut ordered_pairs_of_neighbours =
+            SparseVector::<i64>::new(graphblas_context.to_owned(), vertex_capacity)?;
+
+        MonoidReducer::new().to_column_vector(
+            &PlusMonoid::<i64>::new(),
+            &neighbours,
+            &Assignment::new(),
+            &mut degrees,
+            self.graphblas_operator_applier_collection
+                .entire_vector_selector(),
+            &OptionsForOperatorWithMatrixArgument::new_default(),
+        )?;
+        self.graphblas_operator_applier_collection
+            .binary_operator_applier()
+            .apply_with_vector_as_left_argument(
+                &degrees,
+                &Minus::<i64>::new(),
+                1,
+                &Assignment::new(),
+                &mut degrees_minus_one,
+                self.graphblas_operator_applier_collection
+                    .entire_vector_selector(),
+                &OperatorOptions::new_default(),
+            )?;
+        self.graphblas_operator_applier_collection
+            .element_wise_vector_multiplication_binary_operator()
+            .apply(
+                &degrees,
+                &Times::<i64>::new(),
+                &degrees_minus_one,
+                &Assignment::new(),
+                &mut ordered_pairs_of_neighbours,
+                self.graphblas_operator_applier_collection
+                    .entire_vector_selector(),
+                &OperatorOptions::new_default(),
+            )?;
+
+        // A vertex in a triangle has at least two neighbours
+        let mut coefficients = zero_for_each_vertex::<f64>(
+            &self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+        )?;
+        self.graphblas_operator_applier_collection
+            .element_wise_vector_multiplication_binary_operator()
+            .apply(
+                &twice_the_triangles,
+                &Divide::<f64>::new(),
+                &ordered_pairs_of_neighbours,
+                &Assignment::new(),
+                &mut coefficients,
+                &twice_the_triangles,
+                &OperatorOptions::new(false, true, false),
+            )?;
+
+        write_to_vertex_vector(
+            &mut self.public_vertex_store,
+            &self.graphblas_operator_applier_collection,
+            &coefficients,
+            coefficient,
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(coefficient))
+    }
+}
+
+/// Returns the strictly lower and strictly upper triangular parts of the symmetric structure of the edge type, storing ones.
+pub(crate) fn undirected_structure(
+    edge_store: &mut (impl GetAdjacencyMatrix + GetAdjacencyMatrixCachedAttributes),
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+) -> Result<(SparseMatrix<i64>, SparseMatrix<i64>), GraphComputingError> {
+    let vertex_capacity = *edge_store.adjacency_matrix_size_ref();
+    let graphblas_context = edge_store
+        .adjacency_matrix_ref_unchecked(edge_type)
+        .context_ref()
+        .to_owned();
+    let size = Size::new(vertex_capacity, vertex_capacity);
+    let entire_matrix = graphblas_operator_applier_collection.entire_matrix_selector();
+    let options = OptionsForOperatorWithMatrixArgument::new_default();
+
+    let mut structure = SparseMatrix::<i64>::new(graphblas_context.clone(), size)?;
+    graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_matrix(
+            &One::<i64>::new(),
+            edge_store.transposed_adjacency_matrix_ref_unchecked(edge_type),
+            &Assignment::new(),
+            &mut structure,
+            entire_matrix,
+            &options,
+        )?;
+    graphblas_operator_applier_collection
+        .unary_operator_applier()
+        .apply_to_matrix(
+            &One::<i64>::new(),
+            edge_store.adjacency_matrix_ref_unchecked(edge_type),
+            &First::<i64>::new(),
+            &mut structure,
+            entire_matrix,
+            &options,
+        )?;
+
+    let mut lower = SparseMatrix::<i64>::new(graphblas_context.clone(), size)?;
+    let mut upper = SparseMatrix::<i64>::new(graphblas_context, size)?;
+    graphblas_operator_applier_collection
+        .matrix_selector()
+        .apply(
+            &IsOnOrBelowDiagonal::<i64>::new(),
+            -1,
+            &structure,
+            &Assignment::new(),
+            &mut lower,
+            entire_matrix,
+            &options,
+        )?;
+    graphblas_operator_applier_collection
+        .matrix_selector()
+        .apply(
+            &IsOnOrAboveDiagonal::<i64>::new(),
+            1,
+            &structure,
+            &Assignment::new(),
+            &mut upper,
+            entire_matrix,
+            &options,
+        )?;
+    Ok((lower, upper))
+}
+
+/// Each triangle contributes to a single element of L*U, masked by L.
+pub(crate) fn triangle_count_unchecked(
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    lower: &SparseMatrix<i64>,
+    upper: &SparseMatrix<i64>,
+) -> Result<u64, GraphComputingError> {
+    let mut triangles = SparseMatrix::<i64>::new(lower.context_ref().to_owned(), lower.size()?)?;
+    graphblas_operator_applier_collection
+        .matrix_multiplication_operator()
+        .apply(
+            lower,
+            &PlusTimes::<i64>::new(),
+            upper,
+            &Assignment::new(),
+            &mut triangles,
+            lower,
+            &OptionsForMatrixMultiplicationWithStructuralMask::new(),
+        )?;
+
+    let mut total = 0;
+    MonoidReducer::new().matrix_to_scalar(
+        &PlusMonoid::<i64>::new(),
+        &triangles,
+        &Assignment::new(),
+        &mut total,
+        &OptionsForOperatorWithMatrixArgument::new_default(),
+    )?;
+    Ok(total as u64)
+}
+
+/// Returns the symmetric structure without self-loops.
+pub(crate) fn neighbours(
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    lower: &SparseMatrix<i64>,
+    upper: &SparseMatrix<i64>,
+) -> Result<SparseMatrix<i64>, GraphComputingError> {
+    let mut neighbours = SparseMatrix::<i64>::new(lower.context_ref().to_owned(), lower.size()?)?;
+    graphblas_operator_applier_collection
+        .element_wise_matrix_addition_binary_operator()
+        .apply(
+            lower,
+            &Plus::<i64>::new(),
+            upper,
+            &Assignment::new(),
+            &mut neighbours,
+            graphblas_operator_applier_collection.entire_matrix_selector(),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )?;
+    Ok(neighbours)
+}
+
+/// Counts the common neighbours of each pair of neighbours, and sums them per vertex.
+/// Vertices without triangles have no stored value.
+fn twice_the_triangles_per_vertex(
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    neighbours: &SparseMatrix<i64>,
+) -> Result<SparseVector<i64>, GraphComputingError> {
+    let graphblas_context = neighbours.context_ref();
+    let size = neighbours.size()?;
+
+    let mut common_neighbours = SparseMatrix::<i64>::new(graphblas_context.to_owned(), size)?;
+    graphblas_operator_applier_collection
+        .matrix_multiplication_operator()
+        .apply(
+            neighbours,
+            &PlusTimes::<i64>::new(),
+            neighbours,
+            &Assignment::new(),
+            &mut common_neighbours,
+            neighbours,
+            &OptionsForMatrixMultiplicationWithStructuralMask::new(),
+        )?;
+
+    let mut twice_the_triangles =
+        SparseVector::<i64>::new(graphblas_context.to_owned(), size.row_height())?;
+    MonoidReducer::new().to_column_vector(
+        &PlusMonoid::<i64>::new(),
+        &common_neighbours,
+        &Assignment::new(),
+        &mut twice_the_triangles,
+        graphblas_operator_applier_collection.entire_vector_selector(),
+        &OptionsForOperatorWithMatrixArgument::new_default(),
+    )?;
+    Ok(twice_the_triangles)
+}
+
+fn sum(vector: &SparseVector<i64>) -> Result<i64, GraphComputingError> {
+    let mut sum = 0;
+    MonoidReducer::new().vector_to_scalar(
+        &PlusMonoid::<i64>::new(),
+        vector,
+        &Assignment::new(),
+        &mut sum,
+        &OperatorOptions::new_default(),
+    )?;
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::{EdgeTypeIndex, VertexIndex};
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertexIndex, NewVertexType};
+    use crate::operators::operators::read::GetVertexValue;
+
+    // Triangles {0, 1, 2} and {1, 2, 3}, with a reciprocal edge, a pendant vertex and a self-loop
+    fn graph_with_two_triangles() -> (Graph, EdgeTypeIndex, Vec<VertexIndex>) {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<f32>::apply(&mut graph).unwrap();
+        let vertices: Vec<VertexIndex> =
+            (0..5).map(|_| graph.new_vertex_index().unwrap()).collect();
+        for (tail, head) in [
+            (0, 1),
+            (1, 0),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 4),
+        ] {
+            graph
+                .new_edge(&edge_type, &vertices[tail], &vertices[head], 0.0)
+                .unwrap();
+        }
+        (graph, edge_type, vertices)
+    }
+
+    #[test]
+    fn count_triangles() {
+        let (mut graph, edge_type, vertices) = graph_with_two_triangles();
+        let triangle_count = NewVertexType::<u32>::apply(&mut graph).unwrap();
+
+        assert_eq!(graph.triangle_count(&edge_type).unwrap(), 2);
+        assert_eq!(
+            graph
+                .triangle_count_per_vertex(&edge_type, &triangle_count)
+                .unwrap(),
+            2
+        );
+        for (vertex, expected) in vertices.iter().zip([1, 2, 2, 1, 0]) {
+            assert_eq!(
+                GetVertexValue::<u32>::vertex_value(&graph, &triangle_count, vertex).unwrap(),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn local_clustering_coefficient() {
+        let (mut graph, edge_type, vertices) = graph_with_two_triangles();
+        let coefficient = NewVertexType::<f64>::apply(&mut graph).unwrap();
+
+        graph
+            .local_clustering_coefficient(&edge_type, &coefficient)
+            .unwrap();
+
+        for (vertex, expected) in vertices
+            .iter()
+            .zip([1.0, 2.0 / 3.0, 2.0 / 3.0, 1.0 / 3.0, 0.0])
+        {
+            let value = GetVertexValue::<f64>::vertex_value(&graph, &coefficient, vertex)
+                .unwrap()
+                .unwrap();
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+}
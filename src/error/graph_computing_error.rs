@@ -11,6 +11,7 @@ use graphblas_sparse_linear_algebra::error::{
     SparseLinearAlgebraError, SparseLinearAlgebraErrorType,
 };
 use std::collections::TryReserveError;
+use std::sync::PoisonError;
 
 #[derive(Debug)]
 pub enum GraphComputingError {
@@ -110,3 +111,15 @@ impl From<TryReserveError> for GraphComputingError {
         GraphComputingError::SystemError(error.into())
     }
 }
+
+impl From<std::io::Error> for GraphComputingError {
+    fn from(error: std::io::Error) -> Self {
+        GraphComputingError::SystemError(error.into())
+    }
+}
+
+impl<T> From<PoisonError<T>> for GraphComputingError {
+    fn from(error: PoisonError<T>) -> Self {
+        GraphComputingError::SystemError(error.into())
+    }
+}
@@ -35,13 +35,17 @@ pub enum LogicErrorType {
     InvalidKey,
     KeyAlreadyExists,
     ProductCannotEqualArgument,
+    StaleIndex,
     UnsafeTypeConversion,
+    UnsupportedValueType,
     VertexAlreadyExists,
     VertexTypeDoesNotExist,
     VertexTypeAlreadyExsists,
     VertexTypeMustExist,
     VertexMustExist,
     VertexElementNotEmpty,
+    VertexValueIndexMustExist,
+    VertexValueIndexMustBeUpToDate,
     Other,
 }
 
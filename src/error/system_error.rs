@@ -6,6 +6,7 @@ use graphblas_sparse_linear_algebra::error::{
     SparseLinearAlgebraError, SparseLinearAlgebraErrorType,
 };
 use std::collections::TryReserveError;
+use std::sync::PoisonError;
 
 #[derive(Debug)]
 pub struct SystemError {
@@ -18,6 +19,7 @@ pub struct SystemError {
 pub enum SystemErrorSource {
     SparseLinearAlgebra(SparseLinearAlgebraError),
     TryReserveError(TryReserveError),
+    Io(std::io::Error),
     PoisonedData,
 }
 
@@ -30,6 +32,7 @@ pub enum SystemErrorType {
     UninitialisedContext,
     ContextAlreadyInitialized,
     CannotReserveMemory,
+    Io,
     PoisonedData,
     IndexOutOfBounds,
     Other,
@@ -62,6 +65,7 @@ impl error::Error for SystemError {
             Some(ref error) => match error {
                 SystemErrorSource::SparseLinearAlgebra(error) => Some(error),
                 SystemErrorSource::TryReserveError(error) => Some(error),
+                SystemErrorSource::Io(error) => Some(error),
                 SystemErrorSource::PoisonedData => None,
             },
             None => None,
@@ -102,3 +106,23 @@ impl From<TryReserveError> for SystemError {
         }
     }
 }
+
+impl From<std::io::Error> for SystemError {
+    fn from(error: std::io::Error) -> Self {
+        Self {
+            error_type: SystemErrorType::Io,
+            explanation: String::new(),
+            source: Some(SystemErrorSource::Io(error)),
+        }
+    }
+}
+
+impl<T> From<PoisonError<T>> for SystemError {
+    fn from(error: PoisonError<T>) -> Self {
+        Self {
+            error_type: SystemErrorType::PoisonedData,
+            explanation: error.to_string(),
+            source: Some(SystemErrorSource::PoisonedData),
+        }
+    }
+}
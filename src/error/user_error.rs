@@ -24,6 +24,9 @@ pub enum UserErrorType {
     EdgeTypeAlreadyExists,
     EdgeTypeDoesNotExist,
     IndexOutOfBounds,
+    InvalidFileFormat,
+    NegativeCycle,
+    NegativeEdgeWeight,
     VertexAlreadyExists,
     VertexTypeDoesNotExist,
     Other,
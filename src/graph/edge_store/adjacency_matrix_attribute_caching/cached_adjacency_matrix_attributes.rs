@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use once_cell::sync::OnceCell;
+
 use graphblas_sparse_linear_algebra::operators::mask::SelectEntireMatrix;
 use graphblas_sparse_linear_algebra::{
     collections::sparse_matrix::GetGraphblasSparseMatrix, context::GetContext,
@@ -19,20 +21,23 @@ use crate::graph::edge_store::adjacency_matrix_attribute_caching::transpose_adja
 use crate::graph::edge_store::adjacency_matrix_attribute_caching::transpose_adjacency_matrix_u64;
 use crate::graph::edge_store::adjacency_matrix_attribute_caching::transpose_adjacency_matrix_u8;
 use crate::graph::edge_store::adjacency_matrix_attribute_caching::transpose_adjacency_matrix_usize;
+use crate::graph::edge_store::adjacency_matrix_attribute_caching::transpose_user_defined_adjacency_matrix;
 use crate::graph::edge_store::weighted_adjacency_matrix::WeightedAdjacencyMatrix;
-use crate::graph::graph::GraphblasContext;
+use crate::graph::graph::{complete_pending_work_of_matrix, GraphblasContext};
 use crate::graph::value_type::{GetValueTypeIdentifierRef, ValueTypeIdentifier};
 
+// The transpose is computed on first use through a shared reference, such that readers of a shared
+// adjacency matrix do not copy it. Copies of the attributes share the transpose until either is invalidated.
 #[derive(Clone, Debug)]
 pub(crate) struct CachedAdjacencyMatrixAttributes {
-    transpose: Option<WeightedAdjacencyMatrix>,
+    transpose: Arc<OnceCell<WeightedAdjacencyMatrix>>,
     select_entire_adjacency_matrix: SelectEntireMatrix,
 }
 
 impl CachedAdjacencyMatrixAttributes {
     pub(crate) fn new(context: Arc<GraphblasContext>) -> Self {
         CachedAdjacencyMatrixAttributes {
-            transpose: None,
+            transpose: Arc::new(OnceCell::new()),
             select_entire_adjacency_matrix: SelectEntireMatrix::new(context),
         }
     }
@@ -44,35 +49,32 @@ pub(crate) trait InvalidateChachedAdjacencyMatrixAttributes {
 
 impl InvalidateChachedAdjacencyMatrixAttributes for CachedAdjacencyMatrixAttributes {
     fn invalidate_all_attributes(&mut self) -> () {
-        self.transpose = None
+        self.transpose = Arc::new(OnceCell::new())
     }
 }
 
 pub(crate) trait GetAdjacencyMatrixTranspose {
     fn transpose_ref(
-        &mut self,
+        &self,
         adjacency_matrix: &(impl GetValueTypeIdentifierRef + GetGraphblasSparseMatrix + GetContext),
     ) -> Result<&WeightedAdjacencyMatrix, GraphComputingError>;
 }
 
 impl GetAdjacencyMatrixTranspose for CachedAdjacencyMatrixAttributes {
     fn transpose_ref(
-        &mut self,
+        &self,
         adjacency_matrix: &(impl GetValueTypeIdentifierRef + GetGraphblasSparseMatrix + GetContext),
     ) -> Result<&WeightedAdjacencyMatrix, GraphComputingError> {
-        self.compute_transpose(adjacency_matrix)?;
-        if self.transpose.is_none() {
-            self.compute_transpose(adjacency_matrix)?;
-        }
-        return Ok(self.transpose.as_ref().unwrap());
+        self.transpose
+            .get_or_try_init(|| self.compute_transpose(adjacency_matrix))
     }
 }
 
 impl CachedAdjacencyMatrixAttributes {
     fn compute_transpose(
-        &mut self,
+        &self,
         adjacency_matrix: &(impl GetValueTypeIdentifierRef + GetGraphblasSparseMatrix + GetContext),
-    ) -> Result<(), GraphComputingError> {
+    ) -> Result<WeightedAdjacencyMatrix, GraphComputingError> {
         let transpose = match adjacency_matrix.value_type_identifier_ref() {
             &ValueTypeIdentifier::Bool => transpose_adjacency_matrix_bool(
                 adjacency_matrix,
@@ -126,8 +128,16 @@ impl CachedAdjacencyMatrixAttributes {
                 adjacency_matrix,
                 &self.select_entire_adjacency_matrix,
             )?,
+            &ValueTypeIdentifier::UserDefined(user_defined_value_type) => {
+                transpose_user_defined_adjacency_matrix(
+                    &user_defined_value_type,
+                    adjacency_matrix,
+                    &self.select_entire_adjacency_matrix,
+                )?
+            }
         };
-        self.transpose = Some(transpose);
-        return Ok(());
+        // Readers of a shared adjacency matrix may read the transpose from multiple threads
+        complete_pending_work_of_matrix(&transpose)?;
+        return Ok(transpose);
     }
 }
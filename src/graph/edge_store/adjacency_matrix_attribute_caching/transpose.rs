@@ -1,8 +1,14 @@
+use std::ptr;
+
 use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::sparse_matrix_size;
-use graphblas_sparse_linear_algebra::collections::sparse_matrix::GetMatrixDimensions;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{GetMatrixDimensions, Size};
+use graphblas_sparse_linear_algebra::context::CallGraphBlasContext;
+use graphblas_sparse_linear_algebra::graphblas_bindings::GrB_transpose;
 use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
 use graphblas_sparse_linear_algebra::operators::mask::MatrixMask;
-use graphblas_sparse_linear_algebra::operators::options::OptionsForOperatorWithMatrixArgument;
+use graphblas_sparse_linear_algebra::operators::options::{
+    GetGraphblasDescriptor, OptionsForOperatorWithMatrixArgument,
+};
 use graphblas_sparse_linear_algebra::operators::transpose::TransposeMatrix;
 use graphblas_sparse_linear_algebra::{
     collections::sparse_matrix::GetGraphblasSparseMatrix, context::GetContext,
@@ -14,7 +20,10 @@ use crate::error::GraphComputingError;
 use crate::graph::edge_store::weighted_adjacency_matrix::{
     CreateWeightedAdjacencyMatrix, WeightedAdjacencyMatrix,
 };
-use crate::graph::value_type::implement_macro_for_all_native_value_types_with_capitalized_value_type;
+use crate::graph::value_type::{
+    implement_macro_for_all_native_value_types_with_capitalized_value_type,
+    UserDefinedValueTypeIdentifier,
+};
 
 static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixArgument> =
     Lazy::new(|| OptionsForOperatorWithMatrixArgument::new_default());
@@ -75,6 +84,37 @@ implement_macro_for_all_native_value_types_with_capitalized_value_type!(
     create_transpose_adjacency_matrix_function
 );
 
+pub(crate) fn transpose_user_defined_adjacency_matrix(
+    value_type: &UserDefinedValueTypeIdentifier,
+    adjacency_matrix: &(impl GetGraphblasSparseMatrix + GetContext),
+    mask: &(impl MatrixMask + GetContext),
+) -> Result<WeightedAdjacencyMatrix, GraphComputingError> {
+    let sparse_matrix_size = sparse_matrix_size(adjacency_matrix)?;
+    let transposed_adjacency_matrix = WeightedAdjacencyMatrix::with_user_defined_value_type(
+        adjacency_matrix.context(),
+        *value_type,
+        Size::new(
+            sparse_matrix_size.column_width(),
+            sparse_matrix_size.column_width(),
+        ),
+    )?;
+
+    adjacency_matrix.context_ref().call(
+        || unsafe {
+            GrB_transpose(
+                *transposed_adjacency_matrix.graphblas_matrix_ref(),
+                mask.graphblas_matrix(),
+                ptr::null_mut(),
+                adjacency_matrix.graphblas_matrix(),
+                DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+            )
+        },
+        unsafe { transposed_adjacency_matrix.graphblas_matrix_ref() },
+    )?;
+
+    Ok(transposed_adjacency_matrix)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
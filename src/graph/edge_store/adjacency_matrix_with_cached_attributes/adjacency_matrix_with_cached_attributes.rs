@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::Size;
 use graphblas_sparse_linear_algebra::context::Context as GraphBLASContext;
 
 use crate::error::GraphComputingError;
@@ -12,7 +13,8 @@ use crate::graph::edge_store::weighted_adjacency_matrix::{
 };
 use crate::graph::indexing::ElementCount;
 use crate::graph::value_type::{
-    GetValueTypeIdentifier, GetValueTypeIdentifierRef, ValueType, ValueTypeIdentifier,
+    GetValueTypeIdentifier, GetValueTypeIdentifierRef, UserDefinedValueTypeIdentifier, ValueType,
+    ValueTypeIdentifier,
 };
 
 #[derive(Clone, Debug)]
@@ -48,6 +50,26 @@ impl<T: ValueType + GetValueTypeIdentifier> CreateWeightedAdjacencyMatrixWithCac
     }
 }
 
+impl WeightedAdjacencyMatrixWithCachedAttributes {
+    pub(crate) fn with_user_defined_value_type(
+        graphblas_context: Arc<GraphBLASContext>,
+        value_type: UserDefinedValueTypeIdentifier,
+        initial_vertex_capacity: ElementCount,
+    ) -> Result<WeightedAdjacencyMatrixWithCachedAttributes, GraphComputingError> {
+        let adjacency_matrix = WeightedAdjacencyMatrix::with_user_defined_value_type(
+            graphblas_context.clone(),
+            value_type,
+            Size::new(initial_vertex_capacity, initial_vertex_capacity),
+        )?;
+        let cached_attributes = CachedAdjacencyMatrixAttributes::new(graphblas_context);
+
+        Ok(WeightedAdjacencyMatrixWithCachedAttributes {
+            adjacency_matrix,
+            cached_attributes,
+        })
+    }
+}
+
 pub(crate) trait GetWeightedAdjacencyMatrix {
     fn weighted_adjacency_matrix_ref(&self) -> &WeightedAdjacencyMatrix;
     fn weighted_adjacency_matrix_mut_ref(&mut self) -> &mut WeightedAdjacencyMatrix;
@@ -56,7 +78,7 @@ pub(crate) trait GetWeightedAdjacencyMatrix {
 pub(crate) trait GetCachedAttributesOfAdjacencyMatrix {
     fn weighted_adjacency_matrix_cached_attributes_ref(&self) -> &CachedAdjacencyMatrixAttributes;
     fn transposed_weighted_adjacency_matrix_ref(
-        &mut self,
+        &self,
     ) -> Result<&WeightedAdjacencyMatrix, GraphComputingError>;
 }
 
@@ -77,7 +99,7 @@ impl GetCachedAttributesOfAdjacencyMatrix for WeightedAdjacencyMatrixWithCachedA
     }
 
     fn transposed_weighted_adjacency_matrix_ref(
-        &mut self,
+        &self,
     ) -> Result<&WeightedAdjacencyMatrix, GraphComputingError> {
         self.cached_attributes.transpose_ref(&self.adjacency_matrix)
     }
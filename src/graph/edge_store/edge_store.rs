@@ -12,10 +12,11 @@ use crate::graph::graph::GetGraphblasContext;
 use crate::graph::indexing::operations::GetValidIndices;
 use crate::graph::indexing::{EdgeTypeIndex, ElementCount, Index, Indexer as EdgeTypeIndexer};
 
+// Clones share adjacency matrices until either clone changes them.
 #[derive(Clone, Debug)]
 pub(crate) struct EdgeStore {
     graphblas_context: Arc<GraphblasContext>,
-    adjacency_matrices: Vec<WeightedAdjacencyMatrixWithCachedAttributes>,
+    adjacency_matrices: Vec<Arc<WeightedAdjacencyMatrixWithCachedAttributes>>,
     edge_type_indexer: EdgeTypeIndexer,
     adjacency_matrix_size: ElementCount,
     mask_to_select_entire_adjacency_matrix: SelectEntireMatrix,
@@ -33,9 +34,7 @@ impl EdgeStore {
                 graphblas_context.clone(),
                 initial_edge_type_capacity,
             )?,
-            adjacency_matrices: Vec::<WeightedAdjacencyMatrixWithCachedAttributes>::with_capacity(
-                initial_edge_type_capacity.clone(),
-            ),
+            adjacency_matrices: Vec::with_capacity(initial_edge_type_capacity.clone()),
             adjacency_matrix_size: initial_vertex_capacity,
             mask_to_select_entire_adjacency_matrix: SelectEntireMatrix::new(graphblas_context),
         })
@@ -43,9 +42,14 @@ impl EdgeStore {
 }
 
 pub(crate) trait GetAdjacencyMatrices {
-    fn adjacency_matrices_ref(&self) -> &[WeightedAdjacencyMatrixWithCachedAttributes];
-    fn adjacency_matrices_mut_ref(&mut self) -> &mut [WeightedAdjacencyMatrixWithCachedAttributes];
-    fn adjacency_matrices_mut(&mut self) -> &mut Vec<WeightedAdjacencyMatrixWithCachedAttributes>;
+    fn adjacency_matrices_ref(&self) -> &[Arc<WeightedAdjacencyMatrixWithCachedAttributes>];
+    /// Use Arc::make_mut to change an adjacency matrix, such that it is copied if it is shared.
+    fn adjacency_matrices_mut_ref(
+        &mut self,
+    ) -> &mut [Arc<WeightedAdjacencyMatrixWithCachedAttributes>];
+    fn adjacency_matrices_mut(
+        &mut self,
+    ) -> &mut Vec<Arc<WeightedAdjacencyMatrixWithCachedAttributes>>;
 
     fn adjacency_matrix_size(&self) -> ElementCount;
     fn adjacency_matrix_size_ref(&self) -> &ElementCount;
@@ -70,15 +74,19 @@ impl GetGraphblasContext for EdgeStore {
 }
 
 impl GetAdjacencyMatrices for EdgeStore {
-    fn adjacency_matrices_ref(&self) -> &[WeightedAdjacencyMatrixWithCachedAttributes] {
+    fn adjacency_matrices_ref(&self) -> &[Arc<WeightedAdjacencyMatrixWithCachedAttributes>] {
         self.adjacency_matrices.as_slice()
     }
 
-    fn adjacency_matrices_mut_ref(&mut self) -> &mut [WeightedAdjacencyMatrixWithCachedAttributes] {
+    fn adjacency_matrices_mut_ref(
+        &mut self,
+    ) -> &mut [Arc<WeightedAdjacencyMatrixWithCachedAttributes>] {
         self.adjacency_matrices.as_mut_slice()
     }
 
-    fn adjacency_matrices_mut(&mut self) -> &mut Vec<WeightedAdjacencyMatrixWithCachedAttributes> {
+    fn adjacency_matrices_mut(
+        &mut self,
+    ) -> &mut Vec<Arc<WeightedAdjacencyMatrixWithCachedAttributes>> {
         &mut self.adjacency_matrices
     }
 
@@ -123,7 +131,9 @@ impl EdgeStore {
         // TODO: would par_iter() give better performance?
         self.edge_type_indexer
             .iter_valid_indices()?
-            .try_for_each(|i: Index| function_to_apply(&mut self.adjacency_matrices[i]))?;
+            .try_for_each(|i: Index| {
+                function_to_apply(Arc::make_mut(&mut self.adjacency_matrices[i]))
+            })?;
         Ok(())
     }
 
@@ -144,7 +154,10 @@ impl EdgeStore {
             .iter_mut()
             .enumerate()
             .try_for_each(|(edge_type_index, adjacency_matrix)| {
-                function_to_apply(&EdgeTypeIndex::new(edge_type_index), adjacency_matrix)
+                function_to_apply(
+                    &EdgeTypeIndex::new(edge_type_index),
+                    Arc::make_mut(adjacency_matrix),
+                )
             })?;
         Ok(())
     }
@@ -165,7 +178,10 @@ impl EdgeStore {
         self.edge_type_indexer
             .iter_valid_indices()?
             .try_for_each(|i: Index| {
-                function_to_apply(&EdgeTypeIndex::new(i), &mut self.adjacency_matrices[i])
+                function_to_apply(
+                    &EdgeTypeIndex::new(i),
+                    Arc::make_mut(&mut self.adjacency_matrices[i]),
+                )
             })?;
         Ok(())
     }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::{
     CreateWeightedAdjacencyMatrixWithCachedAttributes, WeightedAdjacencyMatrixWithCachedAttributes,
 };
@@ -33,10 +35,12 @@ impl EdgeStore {
                 self.adjacency_matrix_size(),
             )?;
         if *edge_type_index.index_ref() >= self.adjacency_matrices_ref().len() {
-            self.adjacency_matrices_mut().push(new_adjacency_matrix);
+            self.adjacency_matrices_mut()
+                .push(Arc::new(new_adjacency_matrix));
         } else {
-            self.adjacency_matrices_mut_ref()[*edge_type_index.index_ref()] = new_adjacency_matrix;
+            self.adjacency_matrices_mut_ref()[*edge_type_index.index_ref()] =
+                Arc::new(new_adjacency_matrix);
         }
-        Ok(EdgeTypeIndex::new(edge_type_index.index()))
+        Ok(EdgeTypeIndex::from(edge_type_index))
     }
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use graphblas_sparse_linear_algebra::operators::mask::SelectEntireMatrix;
 
 use crate::error::GraphComputingError;
@@ -43,7 +45,7 @@ impl GetAdjacencyMatrix for EdgeStore {
         edge_type_index: &impl GetEdgeTypeIndex,
     ) -> Result<&mut WeightedAdjacencyMatrix, GraphComputingError> {
         Ok(
-            self.adjacency_matrices_mut_ref()[*edge_type_index.index_ref()]
+            Arc::make_mut(&mut self.adjacency_matrices_mut_ref()[*edge_type_index.index_ref()])
                 .weighted_adjacency_matrix_mut_ref(),
         )
     }
@@ -87,6 +89,8 @@ impl GetAdjacencyMatrixWithCachedAttributes for EdgeStore {
         &mut self,
         edge_type_index: &impl GetEdgeTypeIndex,
     ) -> Result<&mut WeightedAdjacencyMatrixWithCachedAttributes, GraphComputingError> {
-        Ok(&mut self.adjacency_matrices_mut_ref()[*edge_type_index.index_ref()])
+        Ok(Arc::make_mut(
+            &mut self.adjacency_matrices_mut_ref()[*edge_type_index.index_ref()],
+        ))
     }
 }
@@ -9,12 +9,13 @@ use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_m
 use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::GetCachedAttributesOfAdjacencyMatrix;
 use crate::graph::indexing::GetEdgeTypeIndex;
 
+// The transpose is cached behind a shared reference, such that reading it does not copy an adjacency matrix that is shared with a snapshot.
 impl GetAdjacencyMatrixCachedAttributes for EdgeStore {
     fn transposed_adjacency_matrix_ref_unchecked(
         &mut self,
         edge_type_index: &impl GetEdgeTypeIndex,
     ) -> &WeightedAdjacencyMatrix {
-        self.adjacency_matrices_mut_ref()[*edge_type_index.index_ref()]
+        self.adjacency_matrices_ref()[*edge_type_index.index_ref()]
             .transposed_weighted_adjacency_matrix_ref()
             .unwrap()
     }
@@ -24,8 +25,8 @@ impl GetAdjacencyMatrixCachedAttributes for EdgeStore {
         edge_type_index: &(impl GetEdgeTypeIndex + Debug),
     ) -> Result<&WeightedAdjacencyMatrix, GraphComputingError> {
         match self
-            .adjacency_matrices_mut_ref()
-            .get_mut(*edge_type_index.index_ref())
+            .adjacency_matrices_ref()
+            .get(*edge_type_index.index_ref())
         {
             Some(adjacency_matrix) => {
                 Ok(adjacency_matrix.transposed_weighted_adjacency_matrix_ref()?)
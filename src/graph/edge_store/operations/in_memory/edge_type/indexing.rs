@@ -10,16 +10,19 @@ impl Indexing for EdgeStore {
         &self,
         edge_type_index: &impl GetEdgeTypeIndex,
     ) -> Result<bool, GraphComputingError> {
-        self.edge_type_indexer_ref()
-            .is_valid_index(edge_type_index.index())
+        let indexer = self.edge_type_indexer_ref();
+        Ok(indexer.is_valid_index(edge_type_index.index())?
+            && indexer
+                .is_current_generation(edge_type_index.index(), edge_type_index.generation())?)
     }
 
     fn try_edge_type_index_validity(
         &self,
         edge_type_index: &impl GetEdgeTypeIndex,
     ) -> Result<(), GraphComputingError> {
-        self.edge_type_indexer_ref()
-            .try_index_validity(edge_type_index.index())
+        let indexer = self.edge_type_indexer_ref();
+        indexer.try_index_validity(edge_type_index.index())?;
+        indexer.try_index_generation(edge_type_index.index(), edge_type_index.generation())
     }
 
     fn try_optional_edge_type_index_validity(
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::error::GraphComputingError;
@@ -52,7 +54,7 @@ where
     ) -> Result<(), GraphComputingError> {
         self.adjacency_matrices_mut_ref()
             .into_par_iter()
-            .try_for_each(|adjacency_matrix| function_to_apply(adjacency_matrix))?;
+            .try_for_each(|adjacency_matrix| function_to_apply(Arc::make_mut(adjacency_matrix)))?;
         Ok(())
     }
 
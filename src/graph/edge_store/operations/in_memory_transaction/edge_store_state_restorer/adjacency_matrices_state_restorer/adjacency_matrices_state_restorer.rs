@@ -2,12 +2,17 @@ use std::collections::HashMap;
 
 use graphblas_sparse_linear_algebra::collections::sparse_matrix::Size;
 
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::weighted_adjacency_matrix::WeightedAdjacencyMatrix;
 use crate::graph::indexing::{
     BuildIndexHasher, ElementCount, ElementIndex, ElementIndexMap, GetEdgeTypeIndex,
 };
 use crate::graph::value_type::{
     implement_1_type_macro_with_typed_indentifier_for_all_value_types, ValueType,
 };
+use crate::operators::history::{
+    approximate_size_in_bytes_of_all, sparse_matrix_size_in_bytes, GetApproximateSizeInBytes,
+};
 
 use super::state_restorer_for_adjacency_matrix_with_cached_attributes::{
     // CreateStateReverterForAdjacencyMatrixWithCachedAttributes,
@@ -20,6 +25,8 @@ pub(crate) struct AdjacencyMatricesWithCachedAttributesStateRestorer {
     pub(super) adjacency_matrix_vector_length_to_restore: ElementCount,
     pub(super) adjacency_matrix_state_reverters:
         TypedAdjacencyMatrixWithCachedAttributesStateReverters,
+    // Adjacency matrices with a user-defined value type are restored from a copy
+    pub(super) user_defined_adjacency_matrices_to_restore: ElementIndexMap<WeightedAdjacencyMatrix>,
 }
 
 #[derive(Debug)]
@@ -145,6 +152,7 @@ impl AdjacencyMatricesWithCachedAttributesStateRestorer {
             adjacency_matrix_vector_length_to_restore,
             adjacency_matrix_state_reverters:
                 TypedAdjacencyMatrixWithCachedAttributesStateReverters::new(),
+            user_defined_adjacency_matrices_to_restore: ElementIndexMap::default(),
         }
     }
 
@@ -157,6 +165,7 @@ impl AdjacencyMatricesWithCachedAttributesStateRestorer {
             adjacency_matrix_vector_length_to_restore,
             adjacency_matrix_state_reverters:
                 TypedAdjacencyMatrixWithCachedAttributesStateReverters::new(),
+            user_defined_adjacency_matrices_to_restore: ElementIndexMap::default(),
         }
     }
 }
@@ -272,3 +281,48 @@ impl TypedAdjacencyMatrixWithCachedAttributesStateReverters {
         }
     }
 }
+
+impl GetApproximateSizeInBytes for AdjacencyMatricesWithCachedAttributesStateRestorer {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        let mut size_in_bytes = self
+            .adjacency_matrix_state_reverters
+            .approximate_size_in_bytes()?;
+        for adjacency_matrix in self.user_defined_adjacency_matrices_to_restore.values() {
+            size_in_bytes += sparse_matrix_size_in_bytes(adjacency_matrix)?;
+        }
+        Ok(size_in_bytes)
+    }
+}
+
+impl GetApproximateSizeInBytes for TypedAdjacencyMatrixWithCachedAttributesStateReverters {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        let mut size_in_bytes = 0;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_bool.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_i8.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_i16.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_i32.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_i64.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_u8.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_u16.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_u32.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_u64.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_f32.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_f64.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_isize.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.adjacency_matrix_state_reverters_usize.values())?;
+        Ok(size_in_bytes)
+    }
+}
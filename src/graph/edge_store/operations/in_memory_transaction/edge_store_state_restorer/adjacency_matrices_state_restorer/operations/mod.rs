@@ -2,10 +2,12 @@ mod register_adjacency_matrix_to_restore;
 mod register_edge_weight_to_restore;
 mod register_empty_edge_to_restore;
 mod register_size_to_restore;
+mod register_user_defined_adjacency_matrix_to_restore;
 mod restore_state;
 
 pub(crate) use register_adjacency_matrix_to_restore::*;
 pub(crate) use register_edge_weight_to_restore::*;
 pub(crate) use register_empty_edge_to_restore::*;
 pub(crate) use register_size_to_restore::*;
+pub(crate) use register_user_defined_adjacency_matrix_to_restore::*;
 pub(crate) use restore_state::*;
@@ -0,0 +1,42 @@
+use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::adjacency_matrices_state_restorer::AdjacencyMatricesWithCachedAttributesStateRestorer;
+use crate::graph::edge_store::weighted_adjacency_matrix::WeightedAdjacencyMatrix;
+use crate::graph::indexing::GetEdgeTypeIndex;
+
+pub(crate) trait RegisterUserDefinedAdjacencyMatrixToRestore {
+    fn register_user_defined_adjacency_matrix_to_restore(
+        &mut self,
+        edge_type_index: &impl GetEdgeTypeIndex,
+        adjacency_matrix: &WeightedAdjacencyMatrix,
+    );
+
+    fn register_deleted_user_defined_adjacency_matrix_to_restore(
+        &mut self,
+        edge_type_index: &impl GetEdgeTypeIndex,
+        adjacency_matrix: WeightedAdjacencyMatrix,
+    );
+}
+
+// The first registration holds the state to restore
+impl RegisterUserDefinedAdjacencyMatrixToRestore
+    for AdjacencyMatricesWithCachedAttributesStateRestorer
+{
+    fn register_user_defined_adjacency_matrix_to_restore(
+        &mut self,
+        edge_type_index: &impl GetEdgeTypeIndex,
+        adjacency_matrix: &WeightedAdjacencyMatrix,
+    ) {
+        self.user_defined_adjacency_matrices_to_restore
+            .entry(edge_type_index.index())
+            .or_insert_with(|| adjacency_matrix.clone());
+    }
+
+    fn register_deleted_user_defined_adjacency_matrix_to_restore(
+        &mut self,
+        edge_type_index: &impl GetEdgeTypeIndex,
+        adjacency_matrix: WeightedAdjacencyMatrix,
+    ) {
+        self.user_defined_adjacency_matrices_to_restore
+            .entry(edge_type_index.index())
+            .or_insert(adjacency_matrix);
+    }
+}
@@ -1,5 +1,8 @@
+use std::mem;
+use std::sync::Arc;
+
 use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::resize_sparse_matrix;
-use graphblas_sparse_linear_algebra::collections::sparse_matrix::Size;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{GetGraphblasSparseMatrix, Size};
 
 use crate::error::GraphComputingError;
 use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::{GetWeightedAdjacencyMatrix, WeightedAdjacencyMatrixWithCachedAttributes};
@@ -7,16 +10,17 @@ use crate::graph::edge_store::operations::in_memory_transaction::adjacency_matri
 use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::adjacency_matrices_state_restorer::GetAdjacencyMatrixSizeToRestore;
 use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::adjacency_matrices_state_restorer::GetAdjacencyMatrixVectorLengthToRestore;
 use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::adjacency_matrices_state_restorer::AdjacencyMatricesWithCachedAttributesStateRestorer;
+use crate::graph::edge_store::weighted_adjacency_matrix::WeightedAdjacencyMatrix;
 use crate::graph::indexing::ElementIndexMap;
 use crate::graph::value_type::ValueType;
 use crate::operators::transaction::RestoreState;
 
-impl RestoreState<Vec<WeightedAdjacencyMatrixWithCachedAttributes>>
+impl RestoreState<Vec<Arc<WeightedAdjacencyMatrixWithCachedAttributes>>>
     for AdjacencyMatricesWithCachedAttributesStateRestorer
 {
     fn restore(
         self,
-        adjacency_matrices_to_restore: &mut Vec<WeightedAdjacencyMatrixWithCachedAttributes>,
+        adjacency_matrices_to_restore: &mut Vec<Arc<WeightedAdjacencyMatrixWithCachedAttributes>>,
     ) -> Result<(), crate::error::GraphComputingError> {
         restore_weighted_adjacency_matrices_state(self, adjacency_matrices_to_restore)
     }
@@ -31,7 +35,7 @@ impl RestoreState<Vec<WeightedAdjacencyMatrixWithCachedAttributes>>
 
 fn restore_weighted_adjacency_matrices_state(
     adjacency_matrix_with_cached_attributes_state_restorer: AdjacencyMatricesWithCachedAttributesStateRestorer,
-    adjacency_matrices_to_restore: &mut Vec<WeightedAdjacencyMatrixWithCachedAttributes>,
+    adjacency_matrices_to_restore: &mut Vec<Arc<WeightedAdjacencyMatrixWithCachedAttributes>>,
 ) -> Result<(), crate::error::GraphComputingError> {
     let adjacency_matrix_vector_length_to_restore =
         adjacency_matrix_with_cached_attributes_state_restorer
@@ -45,6 +49,10 @@ fn restore_weighted_adjacency_matrices_state(
     let adjacency_matrix_state_reverters =
         adjacency_matrix_with_cached_attributes_state_restorer.adjacency_matrix_state_reverters;
 
+    let user_defined_adjacency_matrices_to_restore =
+        adjacency_matrix_with_cached_attributes_state_restorer
+            .user_defined_adjacency_matrices_to_restore;
+
     restore_weighted_adjacency_matrices(
         adjacency_matrix_state_reverters.adjacency_matrix_state_reverters_bool,
         adjacency_matrices_to_restore,
@@ -98,6 +106,11 @@ fn restore_weighted_adjacency_matrices_state(
         adjacency_matrices_to_restore,
     )?;
 
+    restore_user_defined_adjacency_matrices(
+        user_defined_adjacency_matrices_to_restore,
+        adjacency_matrices_to_restore,
+    );
+
     adjacency_matrices_to_restore.truncate(adjacency_matrix_vector_length_to_restore);
 
     restore_adjacency_matrix_size(
@@ -112,7 +125,9 @@ fn restore_weighted_adjacency_matrices<T>(
     typed_adjacency_matrix_state_reverters_for_edge_type: ElementIndexMap<
         StateRestorerForAdjacencyMatrixWithCachedAttributes<T>,
     >,
-    weighted_adjacency_matrices_to_restore: &mut Vec<WeightedAdjacencyMatrixWithCachedAttributes>,
+    weighted_adjacency_matrices_to_restore: &mut Vec<
+        Arc<WeightedAdjacencyMatrixWithCachedAttributes>,
+    >,
 ) -> Result<(), GraphComputingError>
 where
     T: ValueType,
@@ -122,22 +137,42 @@ where
     for (edge_type_index, adjacency_matrix_state_reverter) in
         typed_adjacency_matrix_state_reverters_for_edge_type.into_iter()
     {
-        adjacency_matrix_state_reverter
-            .restore(&mut weighted_adjacency_matrices_to_restore[edge_type_index])?;
+        adjacency_matrix_state_reverter.restore(Arc::make_mut(
+            &mut weighted_adjacency_matrices_to_restore[edge_type_index],
+        ))?;
     }
     Ok(())
 }
 
+// Swapping the restored adjacency matrix in invalidates its cached attributes
+fn restore_user_defined_adjacency_matrices(
+    user_defined_adjacency_matrices_to_restore: ElementIndexMap<WeightedAdjacencyMatrix>,
+    adjacency_matrices_to_restore: &mut Vec<Arc<WeightedAdjacencyMatrixWithCachedAttributes>>,
+) {
+    for (edge_type_index, mut adjacency_matrix_to_restore) in
+        user_defined_adjacency_matrices_to_restore.into_iter()
+    {
+        unsafe {
+            mem::swap(
+                Arc::make_mut(&mut adjacency_matrices_to_restore[edge_type_index])
+                    .weighted_adjacency_matrix_mut_ref()
+                    .graphblas_matrix_mut_ref(),
+                adjacency_matrix_to_restore.graphblas_matrix_mut_ref(),
+            )
+        };
+    }
+}
+
 fn restore_adjacency_matrix_size(
     adjacency_matrix_size_to_restore: Option<Size>,
-    adjacency_matrices_to_restore: &mut Vec<WeightedAdjacencyMatrixWithCachedAttributes>,
+    adjacency_matrices_to_restore: &mut Vec<Arc<WeightedAdjacencyMatrixWithCachedAttributes>>,
 ) -> Result<(), GraphComputingError> {
     Ok(match adjacency_matrix_size_to_restore {
         Some(size_to_restore) => {
             // TODO: consider resizing in parallel
             for adjacency_matrix in adjacency_matrices_to_restore.iter_mut() {
                 resize_sparse_matrix(
-                    adjacency_matrix.weighted_adjacency_matrix_mut_ref(),
+                    Arc::make_mut(adjacency_matrix).weighted_adjacency_matrix_mut_ref(),
                     size_to_restore,
                 )?;
             }
@@ -11,6 +11,7 @@ use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::{
     GetWeightedAdjacencyMatrix, WeightedAdjacencyMatrixWithCachedAttributes,
 };
 use crate::graph::value_type::ValueType;
+use crate::operators::history::GetApproximateSizeInBytes;
 use crate::operators::in_memory_transaction::transaction::{
     restore_sparse_matrix_state, GetSparseMatrixSizeToRestore, RegisterSparseMatrixChangeToRevert,
     SparseMatrixStateReverter, SparseMatrixStateToRestore,
@@ -166,6 +167,15 @@ impl<T: ValueType + SetSparseMatrixElementTyped<T>>
     }
 }
 
+impl<T: ValueType> GetApproximateSizeInBytes
+    for StateRestorerForAdjacencyMatrixWithCachedAttributes<T>
+{
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        self.sparse_matrix_state_reverter
+            .approximate_size_in_bytes()
+    }
+}
+
 // pub(crate) fn restore_sparse_matrix_state<T: ValueType + SetSparseMatrixElementTyped<T>>(
 //     state_reverter: SparseMatrixStateReverter<T>,
 //     instance_to_restore: &mut WeightedAdjacencyMatrixWithCachedAttributes,
@@ -2,6 +2,7 @@ use crate::error::GraphComputingError;
 use crate::graph::edge_store::{EdgeStore, GetAdjacencyMatrices, GetEdgeTypeIndicer};
 use crate::graph::indexing::operations::in_memory_transaction::IndexerStateRestorer;
 use crate::graph::indexing::{GetIndexCapacity, Indexer};
+use crate::operators::history::GetApproximateSizeInBytes;
 use crate::operators::transaction::RestoreState;
 
 use super::adjacency_matrices_state_restorer::adjacency_matrices_state_restorer::AdjacencyMatricesWithCachedAttributesStateRestorer;
@@ -111,3 +112,14 @@ impl EdgeStoreStateRestorer {
         })
     }
 }
+
+impl GetApproximateSizeInBytes for EdgeStoreStateRestorer {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        Ok(self
+            .edge_type_indexer_state_restorer
+            .approximate_size_in_bytes()?
+            + self
+                .adjacency_matrices_state_restorer
+                .approximate_size_in_bytes()?)
+    }
+}
@@ -1,19 +1,22 @@
+use std::mem;
+
 use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::{
     GetSparseMatrixElementValueTyped, SetSparseMatrixElementTyped,
 };
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::GetGraphblasSparseMatrix;
 
 use crate::error::GraphComputingError;
 use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::adjacency_matrices_state_restorer::GetAdjacencyMatrixStateRevertersByEdgeTypeMap;
-use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::operations::RegisterTypedAdjacencyMatrixToRestore;
+use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::operations::{RegisterTypedAdjacencyMatrixToRestore, RegisterUserDefinedAdjacencyMatrixToRestore};
 use crate::graph::edge_store::operations::in_memory_transaction::{
     EdgeStoreStateRestorer, GetEdgeStoreStateReverters, InMemoryEdgeStoreTransaction
 };
 use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::weighted_adjacency_matrix::operations::GetMatrixSize;
+use crate::graph::graph::GetGraphblasContext;
 use crate::graph::indexing::operations::in_memory_transaction::RegisterFreedIndexToRestore;
 use crate::graph::indexing::GetEdgeTypeIndex;
-use crate::graph::value_type::{
-    GetValueTypeIdentifierRef, ValueType, ValueTypeIdentifier
-};
+use crate::graph::value_type::{GetValueTypeIdentifierRef, ValueType, ValueTypeIdentifier};
 use crate::graph::edge_store::weighted_adjacency_matrix::IntoSparseMatrixAndClearValuesForValueType;
 use crate::graph::edge_store::weighted_adjacency_matrix::ToSparseMatrix;
 use crate::graph::weighted_adjacency_matrix::{ToSparseMatrixForValueType, WeightedAdjacencyMatrix};
@@ -102,6 +105,13 @@ impl<'t> RegisterAdjacencyMatrixToRestore<'t> for EdgeStoreStateRestorer {
                     edge_type_index,
                 )?;
             }
+            ValueTypeIdentifier::UserDefined(_) => {
+                self.adjacency_matrices_state_restorer_mut_ref()
+                    .register_user_defined_adjacency_matrix_to_restore(
+                        edge_type_index,
+                        adjacency_matrix,
+                    );
+            }
         }
         Ok(())
     }
@@ -205,6 +215,25 @@ impl EdgeStoreStateRestorer {
                     edge_type_index,
                 )?;
             }
+            ValueTypeIdentifier::UserDefined(user_defined_value_type) => {
+                let mut deleted_adjacency_matrix =
+                    WeightedAdjacencyMatrix::with_user_defined_value_type(
+                        adjacency_matrix.graphblas_context(),
+                        *user_defined_value_type,
+                        adjacency_matrix.size()?,
+                    )?;
+                unsafe {
+                    mem::swap(
+                        adjacency_matrix.graphblas_matrix_mut_ref(),
+                        deleted_adjacency_matrix.graphblas_matrix_mut_ref(),
+                    )
+                };
+                self.adjacency_matrices_state_restorer_mut_ref()
+                    .register_deleted_user_defined_adjacency_matrix_to_restore(
+                        edge_type_index,
+                        deleted_adjacency_matrix,
+                    );
+            }
         })
     }
 }
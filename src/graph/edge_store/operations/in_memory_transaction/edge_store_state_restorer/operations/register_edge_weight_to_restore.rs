@@ -4,11 +4,9 @@ use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::Get
 use crate::error::GraphComputingError;
 use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::WeightedAdjacencyMatrixWithCachedAttributes;
 use crate::graph::edge_store::operations::in_memory_transaction::EdgeStoreStateRestorer;
-use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::operations::RegisterTypedEdgeWeightToRestore;
+use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::adjacency_matrices_state_restorer::operations::{RegisterTypedEdgeWeightToRestore, RegisterUserDefinedAdjacencyMatrixToRestore};
 use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex};
-use crate::graph::value_type::{
-    implement_macro_for_all_native_value_types, GetValueTypeIdentifierRef, ValueTypeIdentifier,
-};
+use crate::graph::value_type::{implement_macro_for_all_native_value_types, GetValueTypeIdentifierRef, ValueTypeIdentifier};
 use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::GetWeightedAdjacencyMatrix;
 use crate::graph::edge_store::operations::in_memory_transaction::edge_store_state_restorer::edge_store_state_restorer::GetEdgeStoreStateReverters;
 use crate::graph::edge_store::operations::in_memory_transaction::RegisterEmptyEdgeToRestore;
@@ -157,6 +155,14 @@ impl RegisterEdgeWeightToRestore for EdgeStoreStateRestorer {
                     head,
                 )?;
             }
+            // Weights of a user-defined value type are restored with their adjacency matrix
+            ValueTypeIdentifier::UserDefined(_) => {
+                self.adjacency_matrices_state_restorer_mut_ref()
+                    .register_user_defined_adjacency_matrix_to_restore(
+                        edge_type_index,
+                        adjacency_matrix_with_value_to_restore.weighted_adjacency_matrix_ref(),
+                    );
+            }
         }
         Ok(())
     }
@@ -286,6 +292,14 @@ impl RegisterEdgeWeightToRestore for EdgeStoreStateRestorer {
                     head,
                 )?;
             }
+            // Weights of a user-defined value type are restored with their adjacency matrix
+            ValueTypeIdentifier::UserDefined(_) => {
+                self.adjacency_matrices_state_restorer_mut_ref()
+                    .register_user_defined_adjacency_matrix_to_restore(
+                        edge_type_index,
+                        adjacency_matrix_with_value_to_restore.weighted_adjacency_matrix_ref(),
+                    );
+            }
         }
         Ok(())
     }
@@ -11,6 +11,7 @@ use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_m
 use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex};
 use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
 use crate::graph::weighted_adjacency_matrix::GetAdjacencyMatrixCoordinateIndices;
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s> DeleteEdge for InMemoryEdgeStoreTransaction<'s> {
     fn delete_weight_at_edge_coordinate(
@@ -73,6 +74,8 @@ impl<'s> DeleteEdge for InMemoryEdgeStoreTransaction<'s> {
             tail.index(),
             head.index(),
         )?;
+        self.change_recorder
+            .record(GraphChange::edge_deleted(edge_type_index, tail, head));
         Ok(())
     }
 }
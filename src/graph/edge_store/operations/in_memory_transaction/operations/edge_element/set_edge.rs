@@ -2,6 +2,7 @@ use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::Get
 use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::SetSparseMatrixElementTyped;
 
 use crate::error::GraphComputingError;
+use crate::operators::change_data_capture::GraphChange;
 
 use crate::graph::edge::GetDirectedEdgeCoordinateIndex;
 use crate::graph::edge::GetEdgeWeight;
@@ -82,7 +83,10 @@ where
         )?;
 
         self.edge_store_mut_ref()
-            .set_edge_unchecked(edge_type_index, tail, head, weight)
+            .set_edge_unchecked(edge_type_index, tail, head, weight)?;
+        self.change_recorder
+            .record(GraphChange::edge_weight_set(edge_type_index, tail, head));
+        Ok(())
     }
 }
 
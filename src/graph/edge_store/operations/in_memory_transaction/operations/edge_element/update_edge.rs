@@ -2,6 +2,7 @@ use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::Get
 use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::SetSparseMatrixElementTyped;
 
 use crate::error::GraphComputingError;
+use crate::operators::change_data_capture::GraphChange;
 
 use crate::graph::edge::GetDirectedEdgeCoordinateIndex;
 use crate::graph::edge::GetEdgeWeight;
@@ -87,6 +88,9 @@ where
         )?;
 
         self.edge_store_mut_ref()
-            .update_edge_unchecked(edge_type_index, tail, head, weight)
+            .update_edge_unchecked(edge_type_index, tail, head, weight)?;
+        self.change_recorder
+            .record(GraphChange::edge_weight_set(edge_type_index, tail, head));
+        Ok(())
     }
 }
@@ -8,6 +8,7 @@ use crate::graph::edge_store::GetEdgeTypeIndicer;
 use crate::graph::indexing::operations::GenerateIndex;
 use crate::graph::indexing::EdgeTypeIndex;
 use crate::graph::value_type::{GetValueTypeIdentifier, ValueType};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s, T: ValueType + GetValueTypeIdentifier> AddEdgeType<T>
     for InMemoryEdgeStoreTransaction<'s>
@@ -22,6 +23,9 @@ impl<'s, T: ValueType + GetValueTypeIdentifier> AddEdgeType<T>
         let edge_type_index = self
             .edge_store_mut_ref()
             .add_edge_type_at_assigned_index::<T>(new_type_index)?;
+        self.change_recorder.record(GraphChange::EdgeTypeAdded {
+            edge_type: edge_type_index,
+        });
         Ok(edge_type_index)
     }
 }
@@ -5,6 +5,7 @@ use crate::graph::edge_store::operations::operations::edge_type::delete_edge_typ
 use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
 use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing;
 use crate::graph::indexing::GetEdgeTypeIndex;
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s> DropEdgeType for InMemoryEdgeStoreTransaction<'s> {
     fn drop_edge_type(
@@ -29,6 +30,9 @@ impl<'s> DropEdgeType for InMemoryEdgeStoreTransaction<'s> {
             .register_deleted_adjacency_matrix_to_restore(
                 edge_type_index,
                 adjacency_matrix_to_restore,
-            )
+            )?;
+        self.change_recorder
+            .record(GraphChange::edge_type_dropped(edge_type_index));
+        Ok(())
     }
 }
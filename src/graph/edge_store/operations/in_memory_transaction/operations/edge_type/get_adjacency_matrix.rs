@@ -12,6 +12,7 @@ use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_m
 };
 use crate::graph::edge_store::weighted_adjacency_matrix::WeightedAdjacencyMatrix;
 use crate::graph::indexing::{ElementCount, GetEdgeTypeIndex};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s> GetAdjacencyMatrix for InMemoryEdgeStoreTransaction<'s> {
     fn adjacency_matrix_ref(
@@ -29,6 +30,8 @@ impl<'s> GetAdjacencyMatrix for InMemoryEdgeStoreTransaction<'s> {
 
         self.edge_store_state_restorer
             .register_updated_adjacency_matrix_to_restore(edge_type_index, adjacency_matrix)?;
+        self.change_recorder
+            .record(GraphChange::adjacency_matrix_overwritten(edge_type_index));
 
         Ok(adjacency_matrix)
     }
@@ -51,6 +54,8 @@ impl<'s> GetAdjacencyMatrix for InMemoryEdgeStoreTransaction<'s> {
 
         self.edge_store_state_restorer
             .register_updated_adjacency_matrix_to_restore(edge_type_index, adjacency_matrix)?;
+        self.change_recorder
+            .record(GraphChange::adjacency_matrix_overwritten(edge_type_index));
 
         Ok(adjacency_matrix)
     }
@@ -86,6 +91,8 @@ impl<'s> GetAdjacencyMatrixWithCachedAttributes for InMemoryEdgeStoreTransaction
                 edge_type_index,
                 adjacency_matrix.weighted_adjacency_matrix_ref(),
             )?;
+        self.change_recorder
+            .record(GraphChange::adjacency_matrix_overwritten(edge_type_index));
 
         Ok(adjacency_matrix)
     }
@@ -111,6 +118,8 @@ impl<'s> GetAdjacencyMatrixWithCachedAttributes for InMemoryEdgeStoreTransaction
                 edge_type_index,
                 adjacency_matrix.weighted_adjacency_matrix_ref(),
             )?;
+        self.change_recorder
+            .record(GraphChange::adjacency_matrix_overwritten(edge_type_index));
 
         Ok(adjacency_matrix)
     }
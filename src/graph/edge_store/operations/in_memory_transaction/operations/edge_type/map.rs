@@ -9,6 +9,7 @@ use crate::graph::edge_store::operations::operations::edge_type::map::{
     MapAdjacencyMatricesWithCachedAttributes, MapMutableAdjacencyMatrices,
 };
 use crate::graph::indexing::EdgeTypeIndex;
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s, MappingFunction> MapAdjacencyMatricesWithCachedAttributes<MappingFunction>
     for InMemoryEdgeStoreTransaction<'s>
@@ -56,7 +57,10 @@ where
                         &adjacency_matrix.weighted_adjacency_matrix_ref(),
                     )?;
 
-                function_to_apply(adjacency_matrix)
+                function_to_apply(adjacency_matrix)?;
+                self.change_recorder
+                    .record(GraphChange::adjacency_matrix_overwritten(edge_type_index));
+                Ok(())
             };
 
         self.edge_store.indexed_map_mut_all_adjacency_matrices(
@@ -78,7 +82,10 @@ where
                         &adjacency_matrix.weighted_adjacency_matrix_ref(),
                     )?;
 
-                function_to_apply(adjacency_matrix)
+                function_to_apply(adjacency_matrix)?;
+                self.change_recorder
+                    .record(GraphChange::adjacency_matrix_overwritten(edge_type_index));
+                Ok(())
             };
 
         self.edge_store
@@ -135,7 +142,11 @@ where
                     &adjacency_matrix.weighted_adjacency_matrix_ref(),
                 )?;
 
-            function_to_apply(edge_type_index, adjacency_matrix)
+            function_to_apply(edge_type_index, adjacency_matrix)?;
+            edge_store_transaction
+                .change_recorder
+                .record(GraphChange::adjacency_matrix_overwritten(edge_type_index));
+            Ok(())
         };
 
     edge_store_transaction
@@ -2,6 +2,7 @@ use std::mem;
 
 use crate::error::GraphComputingError;
 use crate::graph::edge_store::{EdgeStore, GetEdgeTypeIndicer};
+use crate::operators::change_data_capture::{GraphChange, GraphChangeRecorder};
 use crate::operators::transaction::{RestoreState, UseTransaction};
 
 use super::EdgeStoreStateRestorer;
@@ -22,6 +23,11 @@ pub(crate) struct InMemoryEdgeStoreTransaction<'s> {
         &'s mut EdgeStore,
     pub(in crate::graph::edge_store::operations::in_memory_transaction) edge_store_state_restorer:
         EdgeStoreStateRestorer,
+    // One state restorer per savepoint, for the changes made before it.
+    // edge_store_state_restorer records the changes after the last savepoint.
+    state_restorers_before_savepoints: Vec<EdgeStoreStateRestorer>,
+    pub(in crate::graph::edge_store::operations::in_memory_transaction) change_recorder:
+        GraphChangeRecorder,
 }
 
 impl<'s> InMemoryEdgeStoreTransaction<'s> {
@@ -32,8 +38,68 @@ impl<'s> InMemoryEdgeStoreTransaction<'s> {
         Ok(Self {
             edge_store,
             edge_store_state_restorer,
+            state_restorers_before_savepoints: Vec::new(),
+            change_recorder: GraphChangeRecorder::new(),
         })
     }
+
+    /// Records the changes after the savepoint separately.
+    /// Returns the number of state restorers to retain when rolling back to the savepoint.
+    pub(crate) fn begin_savepoint(&mut self) -> Result<usize, GraphComputingError> {
+        let edge_store_state_restorer = mem::replace(
+            &mut self.edge_store_state_restorer,
+            EdgeStoreStateRestorer::new_for_edge_store(self.edge_store)?,
+        );
+        self.state_restorers_before_savepoints
+            .push(edge_store_state_restorer);
+        self.change_recorder.begin_savepoint();
+        Ok(self.state_restorers_before_savepoints.len())
+    }
+
+    /// Restores the changes after the savepoint, latest first.
+    pub(crate) fn rollback_to_savepoint(
+        &mut self,
+        number_of_state_restorers_to_retain: usize,
+    ) -> Result<(), GraphComputingError> {
+        self.change_recorder
+            .rollback_to_savepoint(number_of_state_restorers_to_retain);
+
+        let state_restorers_after_savepoint = self
+            .state_restorers_before_savepoints
+            .split_off(number_of_state_restorers_to_retain);
+        for state_restorer in state_restorers_after_savepoint.into_iter().rev() {
+            mem::replace(&mut self.edge_store_state_restorer, state_restorer)
+                .restore(&mut self.edge_store)?;
+        }
+
+        let reset_edge_store_state_restorer =
+            self.edge_store_state_restorer.with_reset_state_to_restore();
+        let edge_store_state_restorer = mem::replace(
+            &mut self.edge_store_state_restorer,
+            reset_edge_store_state_restorer,
+        );
+
+        edge_store_state_restorer.restore(&mut self.edge_store)
+    }
+
+    /// Returns the state restorers for the changes since the transaction began or last committed, oldest first.
+    /// The transaction records the following changes with a new state restorer.
+    pub(crate) fn take_state_restorers(
+        &mut self,
+    ) -> Result<Vec<EdgeStoreStateRestorer>, GraphComputingError> {
+        let edge_store_state_restorer = mem::replace(
+            &mut self.edge_store_state_restorer,
+            EdgeStoreStateRestorer::new_for_edge_store(self.edge_store)?,
+        );
+        let mut state_restorers = mem::take(&mut self.state_restorers_before_savepoints);
+        state_restorers.push(edge_store_state_restorer);
+        Ok(state_restorers)
+    }
+
+    /// Returns the changes since the transaction began or last committed, and stops tracking them.
+    pub(crate) fn take_changes(&mut self) -> Vec<GraphChange> {
+        self.change_recorder.take_changes()
+    }
 }
 
 pub(crate) trait GetEdgeStore {
@@ -68,19 +134,14 @@ impl<'t> GetEdgeStoreStateRestorer for InMemoryEdgeStoreTransaction<'t> {
 
 impl<'s> UseTransaction for InMemoryEdgeStoreTransaction<'s> {
     fn revert(&mut self) -> Result<(), GraphComputingError> {
-        let reset_edge_store_state_restorer =
-            self.edge_store_state_restorer.with_reset_state_to_restore();
-        let edge_store_state_restorer = mem::replace(
-            &mut self.edge_store_state_restorer,
-            reset_edge_store_state_restorer,
-        );
-
-        edge_store_state_restorer.restore(&mut self.edge_store)
+        self.rollback_to_savepoint(0)
     }
 
     fn commit(&mut self) -> Result<(), GraphComputingError> {
         self.edge_store_state_restorer =
             EdgeStoreStateRestorer::new_for_edge_store(self.edge_store)?;
+        self.state_restorers_before_savepoints.clear();
+        self.change_recorder.clear();
         Ok(())
     }
 }
@@ -13,7 +13,8 @@ use crate::graph::edge_store::weighted_adjacency_matrix::{
 
 use crate::graph::indexing::GetVertexIndexIndex;
 use crate::graph::value_type::{
-    GetValueTypeIdentifierRef, IntoValueType, ValueType, ValueTypeIdentifier,
+    unsupported_user_defined_value_type_error, GetValueTypeIdentifierRef, IntoValueType, ValueType,
+    ValueTypeIdentifier,
 };
 
 pub(crate) trait GetEdgeWeight<T> {
@@ -114,6 +115,9 @@ where
             &ValueTypeIdentifier::USize => unsafe {
                 get_matrix_element_value::<usize, T>(self, tail, head)
             },
+            &ValueTypeIdentifier::UserDefined(user_defined_value_type) => Err(
+                unsupported_user_defined_value_type_error(&user_defined_value_type),
+            ),
         }
     }
 
@@ -23,8 +23,9 @@ use crate::graph::graph::GetGraphblasContext;
 use crate::graph::indexing::ElementCount;
 use crate::graph::value_type::{
     implement_1_type_macro_with_enum_type_indentifier_for_all_value_types,
-    implement_macro_for_all_native_value_types, GetValueTypeIdentifier, GetValueTypeIdentifierRef,
-    ValueType, ValueTypeIdentifier,
+    implement_macro_for_all_native_value_types, user_defined_graphblas_type,
+    GetValueTypeIdentifier, GetValueTypeIdentifierRef, UserDefinedValueTypeIdentifier, ValueType,
+    ValueTypeIdentifier,
 };
 use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArgument;
 
@@ -77,6 +78,26 @@ impl<T: ValueType + GetValueTypeIdentifier> CreateWeightedAdjacencyMatrix<T>
     }
 }
 
+impl WeightedAdjacencyMatrix {
+    pub(crate) fn with_user_defined_value_type(
+        graphblas_context: Arc<GraphBLASContext>,
+        value_type: UserDefinedValueTypeIdentifier,
+        size: Size,
+    ) -> Result<WeightedAdjacencyMatrix, GraphComputingError> {
+        Ok(WeightedAdjacencyMatrix {
+            graphblas_context: graphblas_context.clone(),
+            sparse_matrix: unsafe {
+                new_graphblas_matrix(
+                    &graphblas_context,
+                    size,
+                    user_defined_graphblas_type(&value_type)?,
+                )?
+            },
+            value_type: ValueTypeIdentifier::UserDefined(value_type),
+        })
+    }
+}
+
 impl Drop for WeightedAdjacencyMatrix {
     fn drop(&mut self) -> () {
         let _ = self
@@ -8,6 +8,10 @@ use graphblas_sparse_linear_algebra::context::{
 
 use crate::graph::indexing::{ElementCount, MINIMUM_INDEXER_CAPACITY};
 use crate::graph::vertex_store::VertexStore;
+use crate::operators::change_data_capture::GraphChangeSubscribers;
+use crate::operators::history::GraphHistory;
+use crate::operators::properties::PropertyStore;
+use crate::operators::vertex_value_index::VertexValueIndexes;
 use crate::{error::GraphComputingError, graph::edge_store::EdgeStore};
 
 use super::{GetGraphblasOperatorApplierCollection, GraphblasOperatorApplierCollection};
@@ -105,15 +109,15 @@ impl GetEdgeStore for Graph {
 
 impl GetPrivateEdgeStore for Graph {
     fn private_edge_store_ref(&self) -> &EdgeStore {
-        &self.public_edge_store
+        &self.private_edge_store
     }
 
     fn private_edge_store_mut_ref(&mut self) -> &mut EdgeStore {
-        &mut self.public_edge_store
+        &mut self.private_edge_store
     }
 
     fn private_edge_store_mut_ref_unsafe(&mut self) -> *mut EdgeStore {
-        &mut self.public_edge_store
+        &mut self.private_edge_store
     }
 }
 
@@ -143,6 +147,11 @@ pub struct Graph {
 
     pub(crate) private_vertex_store: VertexStore,
     pub(crate) private_edge_store: EdgeStore,
+
+    pub(crate) change_subscribers: GraphChangeSubscribers,
+    pub(crate) history: GraphHistory,
+    pub(crate) vertex_value_indexes: VertexValueIndexes,
+    pub(crate) property_store: PropertyStore,
 }
 
 impl Graph {
@@ -189,6 +198,11 @@ impl Graph {
 
             private_vertex_store,
             private_edge_store,
+
+            change_subscribers: GraphChangeSubscribers::new(),
+            history: GraphHistory::new(),
+            vertex_value_indexes: VertexValueIndexes::new(),
+            property_store: PropertyStore::new(),
         };
 
         Ok(graph)
@@ -0,0 +1,469 @@
+use std::cmp::max;
+use std::mem;
+use std::ptr;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::{
+    GetGraphblasSparseVector, SparseVector,
+};
+use graphblas_sparse_linear_algebra::context::CallGraphBlasContext;
+use graphblas_sparse_linear_algebra::graphblas_bindings::{
+    GrB_Index, GrB_Matrix_extract, GrB_Vector_extract,
+};
+use graphblas_sparse_linear_algebra::index::ElementIndexSelector;
+use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+use graphblas_sparse_linear_algebra::operators::extract::{
+    ExtractSubMatrix, ExtractSubVector, SubMatrixExtractor, SubVectorExtractor,
+};
+use graphblas_sparse_linear_algebra::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use graphblas_sparse_linear_algebra::operators::options::OperatorOptions;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::GetWeightedAdjacencyMatrix;
+use crate::graph::edge_store::weighted_adjacency_matrix::WeightedAdjacencyMatrix;
+use crate::graph::edge_store::{GetAdjacencyMatrices, GetEdgeTypeIndicer};
+use crate::graph::graph::{
+    GetEdgeStore, GetGraphblasContext, GetVertexStore, Graph, GraphblasContext, SetVertexCapacity,
+};
+use crate::graph::indexing::operations::{claim_all_indices_below, GetValidIndices};
+use crate::graph::indexing::{
+    EdgeTypeIndex, ElementCount, ElementIndexMap, GetIndex, GetIndexGenerations,
+    GetVertexIndexIndex, Index, Indexer, VertexIndex, VertexTypeIndex, MINIMUM_INDEXER_CAPACITY,
+};
+use crate::graph::value_type::{
+    call_generic_function_for_value_type_identifier, graphblas_indices, GetValueTypeIdentifierRef,
+    UserDefinedValueTypeIdentifier, ValueType,
+};
+use crate::graph::vertex_store::{
+    GetVertexElementIndexer, GetVertexTypeIndexer, GetVertexVectors, VertexVector,
+};
+use crate::operators::change_data_capture::GraphChange;
+use crate::operators::history::HistoryStep;
+use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArgument;
+use crate::operators::vertex_value_index::rebuild_vertex_value_indexes;
+
+pub trait Compact {
+    /// Renumbers the valid vertex indices densely, and shrinks the vertex vectors and adjacency matrices to fit.
+    /// Vertex indices from before the compaction must be replaced using the returned remapping.
+    fn compact(&mut self) -> Result<VertexIndexRemapping, GraphComputingError>;
+}
+
+/// Maps the vertex indices from before a compaction to the vertex indices after the compaction.
+#[derive(Clone, Debug)]
+pub struct VertexIndexRemapping {
+    // Pairs of old and new vertex indices, ordered by new index
+    vertex_indices: Vec<(VertexIndex, VertexIndex)>,
+    new_indices: ElementIndexMap<Index>,
+}
+
+impl VertexIndexRemapping {
+    /// None if the vertex index was not valid, or stale, before the compaction.
+    pub fn new_vertex_index(
+        &self,
+        old_vertex_index: &impl GetVertexIndexIndex,
+    ) -> Option<VertexIndex> {
+        let new_index = *self.new_indices.get(&old_vertex_index.index())?;
+        let (old_vertex_index_at_compaction, new_vertex_index) = self.vertex_indices[new_index];
+        match old_vertex_index.generation() {
+            Some(generation) if Some(generation) != old_vertex_index_at_compaction.generation() => {
+                None
+            }
+            _ => Some(new_vertex_index),
+        }
+    }
+
+    /// Iterates over pairs of old and new vertex indices.
+    pub fn iter(&self) -> impl Iterator<Item = &(VertexIndex, VertexIndex)> {
+        self.vertex_indices.iter()
+    }
+
+    pub fn number_of_vertex_indices(&self) -> ElementCount {
+        self.vertex_indices.len()
+    }
+}
+
+impl Compact for Graph {
+    fn compact(&mut self) -> Result<VertexIndexRemapping, GraphComputingError> {
+        if self.history.is_recording() {
+            let step = HistoryStep::snapshot(&self.public_vertex_store, &self.public_edge_store);
+            self.history.record(step)?;
+        }
+
+        let element_indexer = self.vertex_store_ref().element_indexer_ref();
+        let valid_indices = element_indexer.valid_indices()?;
+        let new_indices: ElementIndexMap<Index> = valid_indices
+            .iter()
+            .enumerate()
+            .map(|(new_index, old_index)| (*old_index, new_index))
+            .collect();
+        let old_vertex_indices: Vec<VertexIndex> = valid_indices
+            .iter()
+            .map(|old_index| {
+                VertexIndex::with_generation(
+                    *old_index,
+                    Some(element_indexer.generation(*old_index)),
+                )
+            })
+            .collect();
+
+        let graphblas_context = self.graphblas_context();
+        for vertex_vector in self
+            .vertex_store_mut_ref()
+            .vertex_vector_for_all_vertex_types_mut_ref()
+            .iter_mut()
+        {
+            let vertex_vector = Arc::make_mut(vertex_vector);
+            let value_type = vertex_vector.value_type_identifier_ref().clone();
+            call_generic_function_for_value_type_identifier!(
+                value_type,
+                renumber_vertex_vector_elements,
+                renumber_user_defined_vertex_vector_elements,
+                (vertex_vector, &graphblas_context, &valid_indices)
+            )?;
+        }
+        for adjacency_matrix in self.edge_store_mut_ref().adjacency_matrices_mut_ref() {
+            let adjacency_matrix =
+                Arc::make_mut(adjacency_matrix).weighted_adjacency_matrix_mut_ref();
+            let value_type = adjacency_matrix.value_type_identifier_ref().clone();
+            call_generic_function_for_value_type_identifier!(
+                value_type,
+                renumber_adjacency_matrix_elements,
+                renumber_user_defined_adjacency_matrix_elements,
+                (adjacency_matrix, &graphblas_context, &valid_indices)
+            )?;
+        }
+
+        let vertex_capacity = max(valid_indices.len(), MINIMUM_INDEXER_CAPACITY);
+        self.set_vertex_capacity_of_vertex_vectors_and_adjacency_matrices(vertex_capacity)?;
+
+        let compacted_element_indexer = compacted_indexer(
+            self.vertex_store_ref().element_indexer_ref(),
+            graphblas_context,
+            &valid_indices,
+            vertex_capacity,
+        )?;
+        let vertex_indices = old_vertex_indices
+            .into_iter()
+            .enumerate()
+            .map(|(new_index, old_vertex_index)| {
+                (
+                    old_vertex_index,
+                    VertexIndex::with_generation(
+                        new_index,
+                        Some(compacted_element_indexer.generation(new_index)),
+                    ),
+                )
+            })
+            .collect();
+        *self.vertex_store_mut_ref().element_indexer_mut_ref() = compacted_element_indexer;
+        rebuild_vertex_value_indexes(&mut self.vertex_value_indexes, &self.public_vertex_store)?;
+        self.property_store.renumber_vertex_indices(&new_indices);
+
+        let vertex_index_remapping = VertexIndexRemapping {
+            vertex_indices,
+            new_indices,
+        };
+        self.publish_changes_that_reassign_indices(changes_of_compaction(
+            self,
+            &vertex_index_remapping,
+        )?)?;
+        Ok(vertex_index_remapping)
+    }
+}
+
+// A renumbered vertex is dropped at its old index and added at its new index,
+// and all vertex vectors and adjacency matrices are rewritten.
+fn changes_of_compaction(
+    graph: &Graph,
+    vertex_index_remapping: &VertexIndexRemapping,
+) -> Result<Vec<GraphChange>, GraphComputingError> {
+    let mut changes = Vec::new();
+    for (old_vertex_index, new_vertex_index) in vertex_index_remapping.iter() {
+        if old_vertex_index.index() != new_vertex_index.index() {
+            changes.push(GraphChange::vertex_dropped(old_vertex_index));
+            changes.push(GraphChange::VertexAdded {
+                vertex: *new_vertex_index,
+            });
+        }
+    }
+
+    let vertex_type_indexer = graph.vertex_store_ref().vertex_type_indexer_ref();
+    for index in vertex_type_indexer.valid_indices()? {
+        changes.push(GraphChange::VertexVectorOverwritten {
+            vertex_type: VertexTypeIndex::with_generation(
+                index,
+                Some(vertex_type_indexer.generation(index)),
+            ),
+        });
+    }
+    let edge_type_indexer = graph.edge_store_ref().edge_type_indexer_ref();
+    for index in edge_type_indexer.valid_indices()? {
+        changes.push(GraphChange::AdjacencyMatrixOverwritten {
+            edge_type: EdgeTypeIndex::with_generation(
+                index,
+                Some(edge_type_indexer.generation(index)),
+            ),
+        });
+    }
+    Ok(changes)
+}
+
+// Handed out indices that do not keep their vertex get a new generation,
+// such that vertex indices from before the compaction are detected as stale.
+fn compacted_indexer(
+    indexer: &Indexer,
+    graphblas_context: Arc<GraphblasContext>,
+    valid_indices: &[Index],
+    capacity: ElementCount,
+) -> Result<Indexer, GraphComputingError> {
+    let number_of_handed_out_indices = indexer.get_number_of_stored_and_reusable_elements()?;
+    let mut compacted_indexer = Indexer::with_initial_capacity(graphblas_context, capacity)?;
+    claim_all_indices_below(&mut compacted_indexer, valid_indices.len())?;
+
+    for index in 0..number_of_handed_out_indices {
+        let is_unchanged = valid_indices.get(index) == Some(&index);
+        let generation = if is_unchanged {
+            indexer.generation(index)
+        } else {
+            indexer.generation(index).wrapping_add(1)
+        };
+        if generation != 0 {
+            compacted_indexer
+                .generations_mut_ref()
+                .insert(index, generation);
+        }
+    }
+    Ok(compacted_indexer)
+}
+
+// The elements at the valid indices are extracted in the order of the valid indices.
+fn renumber_vertex_vector_elements<T>(
+    vertex_vector: &mut VertexVector,
+    graphblas_context: &Arc<GraphblasContext>,
+    valid_indices: &Vec<Index>,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType,
+{
+    let mut elements_to_renumber =
+        SparseVector::<T>::new(graphblas_context.clone(), valid_indices.len())?;
+    unsafe {
+        mem::swap(
+            vertex_vector.graphblas_vector_mut_ref(),
+            elements_to_renumber.graphblas_vector_mut_ref(),
+        )
+    };
+    SubVectorExtractor::new().apply(
+        &elements_to_renumber,
+        &ElementIndexSelector::Index(valid_indices),
+        &Assignment::<T>::new(),
+        vertex_vector,
+        &SelectEntireVector::new(graphblas_context.clone()),
+        &OperatorOptions::new_default(),
+    )?;
+    Ok(())
+}
+
+fn renumber_adjacency_matrix_elements<T>(
+    adjacency_matrix: &mut WeightedAdjacencyMatrix,
+    graphblas_context: &Arc<GraphblasContext>,
+    valid_indices: &Vec<Index>,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType,
+{
+    let mut elements_to_renumber = SparseMatrix::<T>::new(
+        graphblas_context.clone(),
+        Size::new(valid_indices.len(), valid_indices.len()),
+    )?;
+    unsafe {
+        mem::swap(
+            adjacency_matrix.graphblas_matrix_mut_ref(),
+            elements_to_renumber.graphblas_matrix_mut_ref(),
+        )
+    };
+    SubMatrixExtractor::new().apply(
+        &elements_to_renumber,
+        &ElementIndexSelector::Index(valid_indices),
+        &ElementIndexSelector::Index(valid_indices),
+        &Assignment::<T>::new(),
+        adjacency_matrix,
+        &SelectEntireMatrix::new(graphblas_context.clone()),
+        &OptionsForOperatorWithAdjacencyMatrixArgument::new_default(),
+    )?;
+    Ok(())
+}
+
+fn renumber_user_defined_vertex_vector_elements(
+    value_type: &UserDefinedValueTypeIdentifier,
+    vertex_vector: &mut VertexVector,
+    graphblas_context: &Arc<GraphblasContext>,
+    valid_indices: &Vec<Index>,
+) -> Result<(), GraphComputingError> {
+    let mut elements_to_renumber = VertexVector::with_user_defined_value_type(
+        graphblas_context.clone(),
+        *value_type,
+        valid_indices.len(),
+    )?;
+    unsafe {
+        mem::swap(
+            vertex_vector.graphblas_vector_mut_ref(),
+            elements_to_renumber.graphblas_vector_mut_ref(),
+        )
+    };
+    let indices_to_extract = graphblas_indices(valid_indices)?;
+    graphblas_context.call(
+        || unsafe {
+            GrB_Vector_extract(
+                vertex_vector.graphblas_vector(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                elements_to_renumber.graphblas_vector(),
+                indices_to_extract.as_ptr(),
+                indices_to_extract.len() as GrB_Index,
+                ptr::null_mut(),
+            )
+        },
+        unsafe { vertex_vector.graphblas_vector_ref() },
+    )?;
+    Ok(())
+}
+
+fn renumber_user_defined_adjacency_matrix_elements(
+    value_type: &UserDefinedValueTypeIdentifier,
+    adjacency_matrix: &mut WeightedAdjacencyMatrix,
+    graphblas_context: &Arc<GraphblasContext>,
+    valid_indices: &Vec<Index>,
+) -> Result<(), GraphComputingError> {
+    let mut elements_to_renumber = WeightedAdjacencyMatrix::with_user_defined_value_type(
+        graphblas_context.clone(),
+        *value_type,
+        Size::new(valid_indices.len(), valid_indices.len()),
+    )?;
+    unsafe {
+        mem::swap(
+            adjacency_matrix.graphblas_matrix_mut_ref(),
+            elements_to_renumber.graphblas_matrix_mut_ref(),
+        )
+    };
+    let indices_to_extract = graphblas_indices(valid_indices)?;
+    graphblas_context.call(
+        || unsafe {
+            GrB_Matrix_extract(
+                adjacency_matrix.graphblas_matrix(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                elements_to_renumber.graphblas_matrix(),
+                indices_to_extract.as_ptr(),
+                indices_to_extract.len() as GrB_Index,
+                indices_to_extract.as_ptr(),
+                indices_to_extract.len() as GrB_Index,
+                ptr::null_mut(),
+            )
+        },
+        unsafe { adjacency_matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::{GraphComputingErrorType, LogicErrorType};
+    use crate::graph::indexing::GetIndexCapacity;
+    use crate::operators::operators::delete::DropVertexIndex;
+    use crate::operators::operators::indexing::CheckIndex;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+    use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+
+    #[test]
+    fn compact_renumbers_vertices_and_shrinks_capacity() {
+        let mut graph = Graph::with_initial_capacity(1, 10, 1).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<u16>::apply(&mut graph).unwrap();
+
+        let vertices: Vec<VertexIndex> = (0..6)
+            .map(|value| graph.new_vertex(&vertex_type, value as u8).unwrap())
+            .collect();
+        graph
+            .new_edge(&edge_type, &vertices[4], &vertices[5], 45)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertices[1], &vertices[4], 14)
+            .unwrap();
+        graph
+            .drop_vertex_index_and_connected_edges(&vertices[0])
+            .unwrap();
+        graph
+            .drop_vertex_index_and_connected_edges(&vertices[2])
+            .unwrap();
+
+        let remapping = graph.compact().unwrap();
+
+        assert_eq!(remapping.number_of_vertex_indices(), 4);
+        assert_eq!(
+            graph
+                .vertex_store_ref()
+                .element_indexer_ref()
+                .capacity()
+                .unwrap(),
+            4
+        );
+        assert_eq!(graph.edge_store_ref().adjacency_matrix_size(), 4);
+        assert!(remapping.new_vertex_index(&vertices[0]).is_none());
+
+        let new_vertices: Vec<VertexIndex> = [1, 3, 4, 5]
+            .iter()
+            .map(|index| remapping.new_vertex_index(&vertices[*index]).unwrap())
+            .collect();
+        assert_eq!(
+            new_vertices
+                .iter()
+                .map(|vertex| vertex.index())
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        for (vertex, value) in new_vertices.iter().zip([1u8, 3, 4, 5]) {
+            assert_eq!(
+                GetVertexValue::<u8>::vertex_value(&graph, &vertex_type, vertex).unwrap(),
+                Some(value)
+            );
+        }
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(
+                &graph,
+                &edge_type,
+                &new_vertices[2],
+                &new_vertices[3]
+            )
+            .unwrap(),
+            Some(45)
+        );
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(
+                &graph,
+                &edge_type,
+                &new_vertices[0],
+                &new_vertices[2]
+            )
+            .unwrap(),
+            Some(14)
+        );
+
+        assert_eq!(
+            graph
+                .try_vertex_index_validity(&vertices[1])
+                .unwrap_err()
+                .error_type(),
+            GraphComputingErrorType::LogicErrorType(LogicErrorType::StaleIndex)
+        );
+        assert!(graph.is_valid_vertex_index(&new_vertices[0]).unwrap());
+
+        let new_vertex = graph.new_vertex(&vertex_type, 6u8).unwrap();
+        assert_eq!(new_vertex.index(), 4);
+    }
+}
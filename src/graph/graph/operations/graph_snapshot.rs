@@ -0,0 +1,224 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::GetGraphblasSparseMatrix;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector;
+use graphblas_sparse_linear_algebra::context::{CallGraphBlasContext, GetContext};
+use graphblas_sparse_linear_algebra::graphblas_bindings::{
+    GrB_Matrix_wait, GrB_Vector_wait, GrB_WaitMode_GrB_MATERIALIZE,
+};
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::GetWeightedAdjacencyMatrix;
+use crate::graph::edge_store::{EdgeStore, GetAdjacencyMatrices, GetEdgeTypeIndicer};
+use crate::graph::graph::Graph;
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::vertex_store::{
+    GetVertexElementIndexer, GetVertexTypeIndexer, GetVertexVectors, VertexStore,
+};
+
+/// A read-only version of a graph, which is not affected by later changes to the graph.
+///
+/// Vertex vectors and adjacency matrices are shared with the graph, until the graph changes them.
+/// A snapshot can be cloned cheaply, and can be read from other threads while the graph is being changed.
+#[derive(Clone, Debug)]
+pub struct GraphSnapshot {
+    graph: Arc<Graph>,
+}
+
+impl Deref for GraphSnapshot {
+    type Target = Graph;
+
+    fn deref(&self) -> &Graph {
+        &self.graph
+    }
+}
+
+impl Graph {
+    /// Finishes pending GraphBLAS work, and copies the indexers.
+    /// Vertex vectors and adjacency matrices are copied on the next write only.
+    pub fn snapshot(&self) -> Result<GraphSnapshot, GraphComputingError> {
+        self.complete_pending_work()?;
+        Ok(GraphSnapshot {
+            graph: Arc::new(self.clone()),
+        })
+    }
+
+    /// GraphBLAS may finish pending work when a collection is read,
+    /// so collections, including the masks of the indexers, must be complete before they are shared between threads.
+    pub(crate) fn complete_pending_work(&self) -> Result<(), GraphComputingError> {
+        for vertex_store in [&self.public_vertex_store, &self.private_vertex_store] {
+            complete_pending_work_of_vertex_store(vertex_store)?;
+        }
+        for edge_store in [&self.public_edge_store, &self.private_edge_store] {
+            complete_pending_work_of_edge_store(edge_store)?;
+        }
+        Ok(())
+    }
+}
+
+fn complete_pending_work_of_vertex_store(
+    vertex_store: &VertexStore,
+) -> Result<(), GraphComputingError> {
+    complete_pending_work_of_vector(
+        vertex_store
+            .vertex_type_indexer_ref()
+            .mask_with_valid_indices_ref(),
+    )?;
+    complete_pending_work_of_vector(
+        vertex_store
+            .element_indexer_ref()
+            .mask_with_valid_indices_ref(),
+    )?;
+    for vertex_vector in vertex_store.vertex_vector_for_all_vertex_types_ref() {
+        complete_pending_work_of_vector(&**vertex_vector)?;
+    }
+    Ok(())
+}
+
+fn complete_pending_work_of_edge_store(edge_store: &EdgeStore) -> Result<(), GraphComputingError> {
+    complete_pending_work_of_vector(
+        edge_store
+            .edge_type_indexer_ref()
+            .mask_with_valid_indices_ref(),
+    )?;
+    for adjacency_matrix in edge_store.adjacency_matrices_ref() {
+        complete_pending_work_of_matrix(adjacency_matrix.weighted_adjacency_matrix_ref())?;
+    }
+    Ok(())
+}
+
+pub(crate) fn complete_pending_work_of_vector(
+    vector: &impl GetGraphblasSparseVector,
+) -> Result<(), GraphComputingError> {
+    vector.context_ref().call(
+        || unsafe { GrB_Vector_wait(vector.graphblas_vector(), GrB_WaitMode_GrB_MATERIALIZE) },
+        unsafe { vector.graphblas_vector_ref() },
+    )?;
+    Ok(())
+}
+
+pub(crate) fn complete_pending_work_of_matrix(
+    matrix: &impl GetGraphblasSparseMatrix,
+) -> Result<(), GraphComputingError> {
+    matrix.context_ref().call(
+        || unsafe { GrB_Matrix_wait(matrix.graphblas_matrix(), GrB_WaitMode_GrB_MATERIALIZE) },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    use crate::graph::edge_store::GetAdjacencyMatrices;
+    use crate::graph::graph::{GetEdgeStore, GetVertexStore};
+    use crate::graph::indexing::GetIndex;
+    use crate::graph::vertex_store::GetVertexVectors;
+    use crate::operators::operators::delete::DeleteEdge;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertexIndex, NewVertexType};
+    use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+    use crate::operators::operators::set::SetVertexValue;
+
+    #[test]
+    fn snapshot_is_not_affected_by_later_changes() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let other_vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let tail = graph.new_vertex_index().unwrap();
+        let head = graph.new_vertex_index().unwrap();
+        graph.set_vertex_value(&vertex_type, &tail, 1).unwrap();
+
+        let snapshot = graph.snapshot().unwrap();
+
+        graph.set_vertex_value(&vertex_type, &tail, 2).unwrap();
+        graph.new_edge(&edge_type, &tail, &head, 3).unwrap();
+
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&*snapshot, &vertex_type, &tail).unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            GetEdgeWeight::<u8>::edge_weight(&*snapshot, &edge_type, &tail, &head).unwrap(),
+            None
+        );
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&graph, &vertex_type, &tail).unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            GetEdgeWeight::<u8>::edge_weight(&graph, &edge_type, &tail, &head).unwrap(),
+            Some(3)
+        );
+
+        // Only the changed vertex vector and adjacency matrix are copied
+        let vertex_vectors = graph
+            .vertex_store_ref()
+            .vertex_vector_for_all_vertex_types_ref();
+        let snapshot_vertex_vectors = snapshot
+            .vertex_store_ref()
+            .vertex_vector_for_all_vertex_types_ref();
+        assert!(!Arc::ptr_eq(
+            &vertex_vectors[vertex_type.index()],
+            &snapshot_vertex_vectors[vertex_type.index()]
+        ));
+        assert!(Arc::ptr_eq(
+            &vertex_vectors[other_vertex_type.index()],
+            &snapshot_vertex_vectors[other_vertex_type.index()]
+        ));
+        assert!(!Arc::ptr_eq(
+            &graph.edge_store_ref().adjacency_matrices_ref()[edge_type.index()],
+            &snapshot.edge_store_ref().adjacency_matrices_ref()[edge_type.index()]
+        ));
+    }
+
+    #[test]
+    fn snapshot_can_be_read_from_other_threads_while_the_graph_changes() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let tail = graph.new_vertex_index().unwrap();
+        let head = graph.new_vertex_index().unwrap();
+        graph.set_vertex_value(&vertex_type, &tail, 1).unwrap();
+        graph.new_edge(&edge_type, &tail, &head, 1).unwrap();
+
+        let snapshot = graph.snapshot().unwrap();
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let snapshot = snapshot.clone();
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        assert_eq!(
+                            GetVertexValue::<u8>::vertex_value(&*snapshot, &vertex_type, &tail)
+                                .unwrap(),
+                            Some(1)
+                        );
+                        assert_eq!(
+                            GetEdgeWeight::<u8>::edge_weight(&*snapshot, &edge_type, &tail, &head)
+                                .unwrap(),
+                            Some(1)
+                        );
+                    }
+                })
+            })
+            .collect();
+
+        for value in 2..100u8 {
+            graph.set_vertex_value(&vertex_type, &tail, value).unwrap();
+            graph.delete_edge(&edge_type, &tail, &head).unwrap();
+            graph.new_edge(&edge_type, &tail, &head, value).unwrap();
+        }
+        for reader in readers {
+            reader.join().unwrap();
+        }
+
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&graph, &vertex_type, &tail).unwrap(),
+            Some(99)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::GetSparseMatrixElementList;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    GetMatrixDimensions, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorElementList;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::weighted_adjacency_matrix::operations::GetMatrixSize;
+use crate::graph::edge_store::weighted_adjacency_matrix::{
+    ToSparseMatrix, WeightedAdjacencyMatrix,
+};
+use crate::graph::graph::{GetEdgeStore, GetVertexStore, Graph};
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexTypeIndex};
+use crate::graph::value_type::{
+    call_generic_function_for_value_type_identifier, GetValueTypeIdentifierRef, ValueType,
+};
+use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::graph::vertex_store::{GetVectorLength, ToSparseVector, VertexVector};
+
+use super::{write_matrix_market, MatrixMarketValue};
+
+pub trait ExportMatrixMarket {
+    /// Writes the adjacency matrix as a coordinate Matrix Market file.
+    fn export_adjacency_matrix_to_matrix_market(
+        &self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+    ) -> Result<(), GraphComputingError>;
+
+    /// Writes the vertex vector as a coordinate Matrix Market file with a single column.
+    fn export_vertex_vector_to_matrix_market(
+        &self,
+        vertex_type: &impl GetVertexTypeIndex,
+        path: impl AsRef<Path>,
+    ) -> Result<(), GraphComputingError>;
+}
+
+impl ExportMatrixMarket for Graph {
+    fn export_adjacency_matrix_to_matrix_market(
+        &self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+    ) -> Result<(), GraphComputingError> {
+        let adjacency_matrix = self.edge_store_ref().adjacency_matrix_ref(edge_type)?;
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        call_generic_function_for_value_type_identifier!(
+            adjacency_matrix.value_type_identifier_ref(),
+            write_adjacency_matrix,
+            (&mut writer, adjacency_matrix)
+        )?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn export_vertex_vector_to_matrix_market(
+        &self,
+        vertex_type: &impl GetVertexTypeIndex,
+        path: impl AsRef<Path>,
+    ) -> Result<(), GraphComputingError> {
+        let vertex_vector = self.vertex_store_ref().vertex_vector_ref(vertex_type)?;
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        call_generic_function_for_value_type_identifier!(
+            vertex_vector.value_type_identifier_ref(),
+            write_vertex_vector,
+            (&mut writer, vertex_vector)
+        )?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+fn write_adjacency_matrix<T>(
+    writer: &mut impl Write,
+    adjacency_matrix: &WeightedAdjacencyMatrix,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + MatrixMarketValue,
+    WeightedAdjacencyMatrix: ToSparseMatrix<T>,
+    SparseMatrix<T>: GetSparseMatrixElementList<T>,
+{
+    let size = adjacency_matrix.size()?;
+    let element_list = ToSparseMatrix::<T>::to_sparse_matrix(adjacency_matrix)?.element_list()?;
+    write_matrix_market(
+        writer,
+        size.row_height(),
+        size.column_width(),
+        element_list.row_indices_ref(),
+        element_list.column_indices_ref(),
+        element_list.values_ref(),
+    )
+}
+
+fn write_vertex_vector<T>(
+    writer: &mut impl Write,
+    vertex_vector: &VertexVector,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + MatrixMarketValue,
+    VertexVector: ToSparseVector<T>,
+    SparseVector<T>: GetSparseVectorElementList<T>,
+{
+    let element_list = ToSparseVector::<T>::to_sparse_vector(vertex_vector)?.element_list()?;
+    write_matrix_market(
+        writer,
+        vertex_vector.length()?,
+        1,
+        element_list.indices_ref(),
+        vec![0; element_list.indices_ref().len()].as_slice(),
+        element_list.values_ref(),
+    )
+}
@@ -0,0 +1,126 @@
+use std::path::Path;
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::Graph;
+use crate::graph::indexing::{EdgeTypeIndex, VertexTypeIndex};
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::transaction::UseTransaction;
+
+// The indices in a Matrix Market file are taken as vertex indices.
+// Importing makes all vertex indices below the size of the imported matrix or vector valid,
+// and grows the vertex capacity if required.
+
+pub trait ImportMatrixMarket {
+    /// Creates a new edge type, with the value type of the Matrix Market file.
+    fn import_adjacency_matrix_from_matrix_market(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<EdgeTypeIndex, GraphComputingError>;
+
+    /// Creates a new vertex type, with the value type of the Matrix Market file.
+    /// The file must hold a matrix with a single row or column.
+    fn import_vertex_vector_from_matrix_market(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<VertexTypeIndex, GraphComputingError>;
+}
+
+// An import that fails leaves no claimed vertex indices behind.
+impl ImportMatrixMarket for Graph {
+    fn import_adjacency_matrix_from_matrix_market(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<EdgeTypeIndex, GraphComputingError> {
+        let mut transaction = InMemoryGraphTransaction::new(self)?;
+        let edge_type = transaction.import_adjacency_matrix_from_matrix_market(path)?;
+        transaction.commit()?;
+        Ok(edge_type)
+    }
+
+    fn import_vertex_vector_from_matrix_market(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<VertexTypeIndex, GraphComputingError> {
+        let mut transaction = InMemoryGraphTransaction::new(self)?;
+        let vertex_type = transaction.import_vertex_vector_from_matrix_market(path)?;
+        transaction.commit()?;
+        Ok(vertex_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::graph::ExportMatrixMarket;
+    use crate::graph::indexing::VertexIndex;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+    use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+
+    #[test]
+    fn export_and_import_matrix_market() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let vertex_type = NewVertexType::<u16>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<f32>::apply(&mut graph).unwrap();
+
+        let vertex_1 = graph.new_vertex(&vertex_type, 1u16).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type, 2u16).unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_2, &vertex_1, 0.5f32)
+            .unwrap();
+
+        let directory = std::env::temp_dir();
+        let adjacency_matrix_path = directory.join(format!(
+            "stacked_linear_algebra_graph_adjacency_matrix_{}.mtx",
+            std::process::id()
+        ));
+        let vertex_vector_path = directory.join(format!(
+            "stacked_linear_algebra_graph_vertex_vector_{}.mtx",
+            std::process::id()
+        ));
+        graph
+            .export_adjacency_matrix_to_matrix_market(&edge_type, &adjacency_matrix_path)
+            .unwrap();
+        graph
+            .export_vertex_vector_to_matrix_market(&vertex_type, &vertex_vector_path)
+            .unwrap();
+
+        let mut imported_graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        let imported_edge_type = imported_graph
+            .import_adjacency_matrix_from_matrix_market(&adjacency_matrix_path)
+            .unwrap();
+        let imported_vertex_type = imported_graph
+            .import_vertex_vector_from_matrix_market(&vertex_vector_path)
+            .unwrap();
+        std::fs::remove_file(&adjacency_matrix_path).unwrap();
+        std::fs::remove_file(&vertex_vector_path).unwrap();
+
+        assert_eq!(
+            GetEdgeWeight::<f32>::edge_weight(
+                &imported_graph,
+                &imported_edge_type,
+                &vertex_2,
+                &vertex_1
+            )
+            .unwrap(),
+            Some(0.5)
+        );
+        assert_eq!(
+            GetVertexValue::<u16>::vertex_value(&imported_graph, &imported_vertex_type, &vertex_2)
+                .unwrap(),
+            Some(2)
+        );
+
+        // All vertex indices below the exported vertex capacity are valid
+        assert_eq!(
+            GetVertexValue::<u16>::vertex_value(
+                &imported_graph,
+                &imported_vertex_type,
+                &VertexIndex::new(4)
+            )
+            .unwrap(),
+            None
+        );
+    }
+}
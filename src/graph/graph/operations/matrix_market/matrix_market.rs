@@ -0,0 +1,375 @@
+use std::io::Write;
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::invalid_file_format_error;
+use crate::graph::indexing::ElementIndex;
+use crate::graph::value_type::ValueTypeIdentifier;
+
+use super::{value_type_identifier_for_matrix_market, MatrixMarketValue};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum MatrixMarketFormat {
+    Coordinate,
+    Array,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum MatrixMarketSymmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct MatrixMarketHeader {
+    format: MatrixMarketFormat,
+    is_pattern: bool,
+    symmetry: MatrixMarketSymmetry,
+    value_type: ValueTypeIdentifier,
+    row_height: ElementIndex,
+    column_width: ElementIndex,
+    number_of_entries: usize,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct MatrixMarketElements<T> {
+    pub(crate) row_indices: Vec<ElementIndex>,
+    pub(crate) column_indices: Vec<ElementIndex>,
+    pub(crate) values: Vec<T>,
+}
+
+impl MatrixMarketHeader {
+    pub(crate) fn value_type_ref(&self) -> &ValueTypeIdentifier {
+        &self.value_type
+    }
+
+    pub(crate) fn row_height(&self) -> ElementIndex {
+        self.row_height
+    }
+
+    pub(crate) fn column_width(&self) -> ElementIndex {
+        self.column_width
+    }
+}
+
+/// Reads the banner, comments and size line.
+pub(crate) fn read_matrix_market_header(
+    lines: &mut impl Iterator<Item = std::io::Result<String>>,
+) -> Result<MatrixMarketHeader, GraphComputingError> {
+    let banner = next_line(lines)?.to_lowercase();
+    let banner: Vec<&str> = banner.split_whitespace().collect();
+    if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" {
+        return Err(invalid_file_format_error(String::from(
+            "The file is not a Matrix Market matrix",
+        )));
+    }
+
+    let format = match banner[2] {
+        "coordinate" => MatrixMarketFormat::Coordinate,
+        "array" => MatrixMarketFormat::Array,
+        format => {
+            return Err(invalid_file_format_error(format!(
+                "Unsupported Matrix Market format: {}",
+                format
+            )))
+        }
+    };
+    let field = banner[3];
+    // A real hermitian matrix is symmetric
+    let symmetry = match banner[4] {
+        "general" => MatrixMarketSymmetry::General,
+        "symmetric" | "hermitian" => MatrixMarketSymmetry::Symmetric,
+        "skew-symmetric" => MatrixMarketSymmetry::SkewSymmetric,
+        symmetry => {
+            return Err(invalid_file_format_error(format!(
+                "Unsupported Matrix Market symmetry: {}",
+                symmetry
+            )))
+        }
+    };
+    if format == MatrixMarketFormat::Array && field == "pattern" {
+        return Err(invalid_file_format_error(String::from(
+            "A Matrix Market array cannot have a pattern field",
+        )));
+    }
+
+    let mut graphblas_type_name = None;
+    let size_line = loop {
+        let line = next_line(lines)?;
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix('%') {
+            let comment: Vec<&str> = comment.split_whitespace().collect();
+            if comment.len() == 3 && comment[0] == "%GraphBLAS" && comment[1] == "type" {
+                graphblas_type_name = Some(comment[2].to_owned());
+            }
+        } else if !line.is_empty() {
+            break line.to_owned();
+        }
+    };
+
+    let value_type =
+        value_type_identifier_for_matrix_market(field, graphblas_type_name.as_deref())?;
+
+    let size: Vec<usize> = parse_indices(size_line.as_str())?;
+    let (row_height, column_width, number_of_entries) = match (format, size.as_slice()) {
+        (MatrixMarketFormat::Coordinate, [row_height, column_width, number_of_entries]) => {
+            (*row_height, *column_width, *number_of_entries)
+        }
+        (MatrixMarketFormat::Array, [row_height, column_width]) => {
+            let number_of_entries = match symmetry {
+                MatrixMarketSymmetry::General => row_height.checked_mul(*column_width),
+                MatrixMarketSymmetry::Symmetric => column_width
+                    .checked_add(1)
+                    .and_then(|column_width_plus_one| {
+                        column_width.checked_mul(column_width_plus_one)
+                    })
+                    .map(|product| product / 2),
+                MatrixMarketSymmetry::SkewSymmetric => column_width
+                    .checked_mul(column_width.saturating_sub(1))
+                    .map(|product| product / 2),
+            }
+            .ok_or_else(|| {
+                invalid_file_format_error(format!(
+                    "Matrix Market array size is too large: {}",
+                    size_line
+                ))
+            })?;
+            (*row_height, *column_width, number_of_entries)
+        }
+        _ => {
+            return Err(invalid_file_format_error(format!(
+                "Invalid Matrix Market size line: {}",
+                size_line
+            )))
+        }
+    };
+    if symmetry != MatrixMarketSymmetry::General && row_height != column_width {
+        return Err(invalid_file_format_error(String::from(
+            "A symmetric Matrix Market matrix must be square",
+        )));
+    }
+
+    Ok(MatrixMarketHeader {
+        format,
+        is_pattern: field == "pattern",
+        symmetry,
+        value_type,
+        row_height,
+        column_width,
+        number_of_entries,
+    })
+}
+
+/// Reads all entries after the header, and expands symmetric entries to a general matrix.
+pub(crate) fn read_matrix_market_elements<T: MatrixMarketValue>(
+    lines: &mut impl Iterator<Item = std::io::Result<String>>,
+    header: &MatrixMarketHeader,
+) -> Result<MatrixMarketElements<T>, GraphComputingError> {
+    let mut elements = MatrixMarketElements {
+        row_indices: Vec::new(),
+        column_indices: Vec::new(),
+        values: Vec::new(),
+    };
+
+    // Array entries are listed column by column, for symmetric arrays only the lower triangle
+    let mut array_positions = (0..header.column_width).flat_map(|column_index| {
+        let first_row_index = match header.symmetry {
+            MatrixMarketSymmetry::General => 0,
+            MatrixMarketSymmetry::Symmetric => column_index,
+            MatrixMarketSymmetry::SkewSymmetric => column_index + 1,
+        };
+        (first_row_index..header.row_height).map(move |row_index| (row_index, column_index))
+    });
+
+    for _ in 0..header.number_of_entries {
+        let line = next_non_empty_line(lines)?;
+        let mut fields = line.split_whitespace();
+
+        let (row_index, column_index) = match header.format {
+            MatrixMarketFormat::Coordinate => {
+                let row_index = parse_one_based_index(fields.next(), header.row_height)?;
+                let column_index = parse_one_based_index(fields.next(), header.column_width)?;
+                (row_index, column_index)
+            }
+            MatrixMarketFormat::Array => array_positions.next().unwrap(),
+        };
+
+        let value = if header.is_pattern {
+            T::parse_matrix_market_value("1")?
+        } else {
+            match fields.next() {
+                Some(value) => T::parse_matrix_market_value(value)?,
+                None => {
+                    return Err(invalid_file_format_error(format!(
+                        "Missing value in Matrix Market entry: {}",
+                        line
+                    )))
+                }
+            }
+        };
+
+        elements.row_indices.push(row_index);
+        elements.column_indices.push(column_index);
+        elements.values.push(value);
+
+        if row_index != column_index {
+            let mirrored_value = match header.symmetry {
+                MatrixMarketSymmetry::General => None,
+                MatrixMarketSymmetry::Symmetric => Some(value),
+                MatrixMarketSymmetry::SkewSymmetric => match value.negated() {
+                    Some(negated_value) => Some(negated_value),
+                    None => {
+                        return Err(invalid_file_format_error(format!(
+                            "Cannot negate skew-symmetric value of type {}",
+                            T::graphblas_type_name()
+                        )))
+                    }
+                },
+            };
+            if let Some(mirrored_value) = mirrored_value {
+                elements.row_indices.push(column_index);
+                elements.column_indices.push(row_index);
+                elements.values.push(mirrored_value);
+            }
+        }
+    }
+
+    Ok(elements)
+}
+
+/// Writes a general coordinate matrix.
+pub(crate) fn write_matrix_market<T: MatrixMarketValue>(
+    writer: &mut impl Write,
+    row_height: ElementIndex,
+    column_width: ElementIndex,
+    row_indices: &[ElementIndex],
+    column_indices: &[ElementIndex],
+    values: &[T],
+) -> Result<(), GraphComputingError> {
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix coordinate {} general",
+        T::matrix_market_field()
+    )?;
+    writeln!(writer, "%%GraphBLAS type {}", T::graphblas_type_name())?;
+    writeln!(writer, "{} {} {}", row_height, column_width, values.len())?;
+
+    for ((row_index, column_index), value) in row_indices.iter().zip(column_indices).zip(values) {
+        write!(writer, "{} {} ", row_index + 1, column_index + 1)?;
+        value.write_matrix_market_value(writer)?;
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+fn next_line(
+    lines: &mut impl Iterator<Item = std::io::Result<String>>,
+) -> Result<String, GraphComputingError> {
+    match lines.next() {
+        Some(line) => Ok(line?),
+        None => Err(invalid_file_format_error(String::from(
+            "Unexpected end of Matrix Market file",
+        ))),
+    }
+}
+
+fn next_non_empty_line(
+    lines: &mut impl Iterator<Item = std::io::Result<String>>,
+) -> Result<String, GraphComputingError> {
+    loop {
+        let line = next_line(lines)?;
+        if !line.trim().is_empty() {
+            return Ok(line);
+        }
+    }
+}
+
+fn parse_indices(line: &str) -> Result<Vec<usize>, GraphComputingError> {
+    line.split_whitespace()
+        .map(|value| {
+            value.parse::<usize>().map_err(|_| {
+                invalid_file_format_error(format!("Invalid Matrix Market size line: {}", line))
+            })
+        })
+        .collect()
+}
+
+fn parse_one_based_index(
+    value: Option<&str>,
+    length: ElementIndex,
+) -> Result<ElementIndex, GraphComputingError> {
+    match value.and_then(|value| value.parse::<ElementIndex>().ok()) {
+        Some(index) if index >= 1 && index <= length => Ok(index - 1),
+        _ => Err(invalid_file_format_error(format!(
+            "Invalid Matrix Market index: {:?}, expected a value in 1..={}",
+            value, length
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufRead;
+
+    use super::*;
+
+    #[test]
+    fn read_symmetric_pattern_matrix() {
+        let file = "%%MatrixMarket matrix coordinate pattern symmetric\n\
+                    % a comment\n\
+                    3 3 2\n\
+                    2 1\n\
+                    3 3\n";
+        let mut lines = file.as_bytes().lines();
+
+        let header = read_matrix_market_header(&mut lines).unwrap();
+        assert!(matches!(header.value_type_ref(), ValueTypeIdentifier::Bool));
+
+        let elements = read_matrix_market_elements::<bool>(&mut lines, &header).unwrap();
+        assert_eq!(elements.row_indices, vec![1, 0, 2]);
+        assert_eq!(elements.column_indices, vec![0, 1, 2]);
+        assert_eq!(elements.values, vec![true, true, true]);
+    }
+
+    #[test]
+    fn read_skew_symmetric_array() {
+        let file = "%%MatrixMarket matrix array integer skew-symmetric\n\
+                    %%GraphBLAS type int8_t\n\
+                    3 3\n\
+                    1\n\
+                    2\n\
+                    3\n";
+        let mut lines = file.as_bytes().lines();
+
+        let header = read_matrix_market_header(&mut lines).unwrap();
+        assert!(matches!(header.value_type_ref(), ValueTypeIdentifier::Int8));
+
+        let elements = read_matrix_market_elements::<i8>(&mut lines, &header).unwrap();
+        assert_eq!(elements.row_indices, vec![1, 0, 2, 0, 2, 1]);
+        assert_eq!(elements.column_indices, vec![0, 1, 0, 2, 1, 2]);
+        assert_eq!(elements.values, vec![1, -1, 2, -2, 3, -3]);
+    }
+
+    #[test]
+    fn reject_index_out_of_bounds() {
+        let file = "%%MatrixMarket matrix coordinate real general\n\
+                    2 2 1\n\
+                    3 1 1.5\n";
+        let mut lines = file.as_bytes().lines();
+
+        let header = read_matrix_market_header(&mut lines).unwrap();
+        assert!(read_matrix_market_elements::<f64>(&mut lines, &header).is_err());
+    }
+
+    #[test]
+    fn reject_array_size_that_overflows() {
+        let file = format!(
+            "%%MatrixMarket matrix array real general\n{} {}\n",
+            usize::MAX,
+            2
+        );
+        let mut lines = file.as_bytes().lines();
+
+        assert!(read_matrix_market_header(&mut lines).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use std::io::Write;
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::invalid_file_format_error;
+use crate::graph::value_type::ValueTypeIdentifier;
+
+// The "%%GraphBLAS type" comment, as written by LAGraph, preserves the exact value type.
+// isize and usize are written as 64-bit integers.
+
+pub(crate) trait MatrixMarketValue: Sized + Copy {
+    fn matrix_market_field() -> &'static str;
+    fn graphblas_type_name() -> &'static str;
+
+    fn parse_matrix_market_value(value: &str) -> Result<Self, GraphComputingError>;
+    fn write_matrix_market_value(&self, writer: &mut impl Write)
+        -> Result<(), GraphComputingError>;
+
+    /// Returns None if the value has no negation in its value type.
+    fn negated(&self) -> Option<Self>;
+}
+
+macro_rules! implement_matrix_market_value_for_integer {
+    ($value_type:ty, $graphblas_type_name:literal) => {
+        impl MatrixMarketValue for $value_type {
+            fn matrix_market_field() -> &'static str {
+                "integer"
+            }
+
+            fn graphblas_type_name() -> &'static str {
+                $graphblas_type_name
+            }
+
+            fn parse_matrix_market_value(value: &str) -> Result<Self, GraphComputingError> {
+                value
+                    .parse::<$value_type>()
+                    .map_err(|_| invalid_value_error(value, stringify!($value_type)))
+            }
+
+            fn write_matrix_market_value(
+                &self,
+                writer: &mut impl Write,
+            ) -> Result<(), GraphComputingError> {
+                write!(writer, "{}", self)?;
+                Ok(())
+            }
+
+            fn negated(&self) -> Option<Self> {
+                self.checked_neg()
+            }
+        }
+    };
+}
+
+macro_rules! implement_matrix_market_value_for_float {
+    ($value_type:ty, $graphblas_type_name:literal) => {
+        impl MatrixMarketValue for $value_type {
+            fn matrix_market_field() -> &'static str {
+                "real"
+            }
+
+            fn graphblas_type_name() -> &'static str {
+                $graphblas_type_name
+            }
+
+            fn parse_matrix_market_value(value: &str) -> Result<Self, GraphComputingError> {
+                value
+                    .parse::<$value_type>()
+                    .map_err(|_| invalid_value_error(value, stringify!($value_type)))
+            }
+
+            fn write_matrix_market_value(
+                &self,
+                writer: &mut impl Write,
+            ) -> Result<(), GraphComputingError> {
+                write!(writer, "{}", self)?;
+                Ok(())
+            }
+
+            fn negated(&self) -> Option<Self> {
+                Some(-self)
+            }
+        }
+    };
+}
+
+implement_matrix_market_value_for_integer!(i8, "int8_t");
+implement_matrix_market_value_for_integer!(i16, "int16_t");
+implement_matrix_market_value_for_integer!(i32, "int32_t");
+implement_matrix_market_value_for_integer!(i64, "int64_t");
+implement_matrix_market_value_for_integer!(u8, "uint8_t");
+implement_matrix_market_value_for_integer!(u16, "uint16_t");
+implement_matrix_market_value_for_integer!(u32, "uint32_t");
+implement_matrix_market_value_for_integer!(u64, "uint64_t");
+implement_matrix_market_value_for_integer!(isize, "int64_t");
+implement_matrix_market_value_for_integer!(usize, "uint64_t");
+implement_matrix_market_value_for_float!(f32, "float");
+implement_matrix_market_value_for_float!(f64, "double");
+
+impl MatrixMarketValue for bool {
+    fn matrix_market_field() -> &'static str {
+        "integer"
+    }
+
+    fn graphblas_type_name() -> &'static str {
+        "bool"
+    }
+
+    fn parse_matrix_market_value(value: &str) -> Result<Self, GraphComputingError> {
+        match value {
+            "0" => Ok(false),
+            "1" => Ok(true),
+            _ => Err(invalid_value_error(value, "bool")),
+        }
+    }
+
+    fn write_matrix_market_value(
+        &self,
+        writer: &mut impl Write,
+    ) -> Result<(), GraphComputingError> {
+        write!(writer, "{}", *self as u8)?;
+        Ok(())
+    }
+
+    fn negated(&self) -> Option<Self> {
+        None
+    }
+}
+
+/// Without a "%%GraphBLAS type" comment, the value type follows from the Matrix Market field.
+pub(crate) fn value_type_identifier_for_matrix_market(
+    field: &str,
+    graphblas_type_name: Option<&str>,
+) -> Result<ValueTypeIdentifier, GraphComputingError> {
+    if let Some(graphblas_type_name) = graphblas_type_name {
+        return match graphblas_type_name {
+            "bool" => Ok(ValueTypeIdentifier::Bool),
+            "int8_t" => Ok(ValueTypeIdentifier::Int8),
+            "int16_t" => Ok(ValueTypeIdentifier::Int16),
+            "int32_t" => Ok(ValueTypeIdentifier::Int32),
+            "int64_t" => Ok(ValueTypeIdentifier::Int64),
+            "uint8_t" => Ok(ValueTypeIdentifier::UInt8),
+            "uint16_t" => Ok(ValueTypeIdentifier::UInt16),
+            "uint32_t" => Ok(ValueTypeIdentifier::UInt32),
+            "uint64_t" => Ok(ValueTypeIdentifier::UInt64),
+            "float" => Ok(ValueTypeIdentifier::Float32),
+            "double" => Ok(ValueTypeIdentifier::Float64),
+            _ => Err(invalid_file_format_error(format!(
+                "Unsupported GraphBLAS type: {}",
+                graphblas_type_name
+            ))),
+        };
+    }
+
+    match field {
+        "pattern" => Ok(ValueTypeIdentifier::Bool),
+        "integer" => Ok(ValueTypeIdentifier::Int64),
+        "real" => Ok(ValueTypeIdentifier::Float64),
+        _ => Err(invalid_file_format_error(format!(
+            "Unsupported Matrix Market field: {}",
+            field
+        ))),
+    }
+}
+
+fn invalid_value_error(value: &str, value_type: &str) -> GraphComputingError {
+    invalid_file_format_error(format!(
+        "Invalid Matrix Market value for type {}: {}",
+        value_type, value
+    ))
+}
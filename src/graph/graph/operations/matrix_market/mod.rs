@@ -0,0 +1,9 @@
+mod export_matrix_market;
+mod import_matrix_market;
+mod matrix_market;
+mod matrix_market_value;
+
+pub use export_matrix_market::*;
+pub use import_matrix_market::*;
+pub(crate) use matrix_market::*;
+pub(crate) use matrix_market_value::*;
@@ -1,3 +1,11 @@
+mod compact;
+mod graph_snapshot;
+mod matrix_market;
 mod set_vertex_capacity;
+mod snapshot;
 
+pub use compact::*;
+pub use graph_snapshot::*;
+pub use matrix_market::*;
 pub use set_vertex_capacity::*;
+pub use snapshot::*;
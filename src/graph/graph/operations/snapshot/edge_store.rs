@@ -0,0 +1,206 @@
+use std::io::{Read, Write};
+use std::mem;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::{
+    FromMatrixElementList, GetSparseMatrixElementList,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, GetMatrixDimensions, MatrixElementList, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::context::Context as GraphblasContext;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{BinaryOperator, First};
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::{
+    CreateWeightedAdjacencyMatrixWithCachedAttributes, GetWeightedAdjacencyMatrix,
+    WeightedAdjacencyMatrixWithCachedAttributes,
+};
+use crate::graph::edge_store::weighted_adjacency_matrix::operations::GetMatrixSize;
+use crate::graph::edge_store::weighted_adjacency_matrix::{
+    ToSparseMatrix, WeightedAdjacencyMatrix,
+};
+use crate::graph::edge_store::{EdgeStore, GetAdjacencyMatrices, GetEdgeTypeIndicer};
+use crate::graph::indexing::MINIMUM_INDEXER_CAPACITY;
+use crate::graph::value_type::{
+    build_user_defined_matrix, call_generic_function_for_value_type_identifier,
+    user_defined_matrix_elements, GetValueTypeIdentifier, GetValueTypeIdentifierRef,
+    UserDefinedValueTypeIdentifier, ValueType,
+};
+
+use super::{
+    decode_bytes, decode_indexer, decode_indices, decode_value_type_identifier, decode_values,
+    encode_bytes, encode_indexer, encode_indices, encode_value_type_identifier, encode_values,
+    EncodeValue,
+};
+
+pub(crate) fn encode_edge_store(
+    writer: &mut impl Write,
+    edge_store: &EdgeStore,
+) -> Result<(), GraphComputingError> {
+    edge_store.adjacency_matrix_size().encode(writer)?;
+    encode_indexer(writer, edge_store.edge_type_indexer_ref())?;
+
+    let adjacency_matrices = edge_store.adjacency_matrices_ref();
+    adjacency_matrices.len().encode(writer)?;
+    for adjacency_matrix in adjacency_matrices {
+        encode_adjacency_matrix(writer, adjacency_matrix.weighted_adjacency_matrix_ref())?;
+    }
+    Ok(())
+}
+
+pub(crate) fn decode_edge_store(
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+) -> Result<EdgeStore, GraphComputingError> {
+    let adjacency_matrix_size = usize::decode(reader)?;
+    let edge_type_indexer = decode_indexer(reader, graphblas_context.clone())?;
+
+    let number_of_adjacency_matrices = usize::decode(reader)?;
+    let mut adjacency_matrices = Vec::new();
+    adjacency_matrices.try_reserve_exact(number_of_adjacency_matrices)?;
+    for _ in 0..number_of_adjacency_matrices {
+        adjacency_matrices.push(Arc::new(decode_adjacency_matrix(
+            reader,
+            graphblas_context.clone(),
+        )?));
+    }
+
+    let mut edge_store = EdgeStore::with_initial_capacity(
+        graphblas_context,
+        adjacency_matrix_size,
+        MINIMUM_INDEXER_CAPACITY,
+    )?;
+    *edge_store.edge_type_indexer_mut_ref() = edge_type_indexer;
+    *edge_store.adjacency_matrices_mut() = adjacency_matrices;
+
+    Ok(edge_store)
+}
+
+pub(crate) fn encode_adjacency_matrix(
+    writer: &mut impl Write,
+    adjacency_matrix: &WeightedAdjacencyMatrix,
+) -> Result<(), GraphComputingError> {
+    let size = adjacency_matrix.size()?;
+
+    encode_value_type_identifier(writer, adjacency_matrix.value_type_identifier_ref())?;
+    size.row_height().encode(writer)?;
+    size.column_width().encode(writer)?;
+    call_generic_function_for_value_type_identifier!(
+        adjacency_matrix.value_type_identifier_ref(),
+        encode_adjacency_matrix_elements,
+        encode_user_defined_adjacency_matrix_elements,
+        (writer, adjacency_matrix)
+    )
+}
+
+pub(crate) fn decode_adjacency_matrix(
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+) -> Result<WeightedAdjacencyMatrixWithCachedAttributes, GraphComputingError> {
+    let value_type = decode_value_type_identifier(reader)?;
+    let row_height = usize::decode(reader)?;
+    let column_width = usize::decode(reader)?;
+    call_generic_function_for_value_type_identifier!(
+        value_type,
+        decode_adjacency_matrix_elements,
+        decode_user_defined_adjacency_matrix_elements,
+        (
+            reader,
+            graphblas_context,
+            Size::new(row_height, column_width)
+        )
+    )
+}
+
+fn encode_adjacency_matrix_elements<T>(
+    writer: &mut impl Write,
+    adjacency_matrix: &WeightedAdjacencyMatrix,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + EncodeValue + Copy,
+    WeightedAdjacencyMatrix: ToSparseMatrix<T>,
+    SparseMatrix<T>: GetSparseMatrixElementList<T>,
+{
+    let element_list = ToSparseMatrix::<T>::to_sparse_matrix(adjacency_matrix)?.element_list()?;
+    encode_indices(writer, element_list.row_indices_ref())?;
+    encode_indices(writer, element_list.column_indices_ref())?;
+    encode_values(writer, element_list.values_ref())
+}
+
+fn decode_adjacency_matrix_elements<T>(
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+    size: Size,
+) -> Result<WeightedAdjacencyMatrixWithCachedAttributes, GraphComputingError>
+where
+    T: ValueType + GetValueTypeIdentifier + EncodeValue + Copy,
+    WeightedAdjacencyMatrixWithCachedAttributes:
+        CreateWeightedAdjacencyMatrixWithCachedAttributes<T>,
+    SparseMatrix<T>: FromMatrixElementList<T>,
+    First<T>: BinaryOperator<T>,
+{
+    let row_indices = decode_indices(reader)?;
+    let column_indices = decode_indices(reader)?;
+    let values = decode_values::<T>(reader)?;
+
+    let mut sparse_matrix = SparseMatrix::<T>::from_element_list(
+        graphblas_context.clone(),
+        size,
+        MatrixElementList::from_vectors(row_indices, column_indices, values)?,
+        &First::<T>::new(),
+    )?;
+
+    // Adjacency matrices are square, the vertex capacity is restored from the snapshotted size.
+    let mut adjacency_matrix = <WeightedAdjacencyMatrixWithCachedAttributes as CreateWeightedAdjacencyMatrixWithCachedAttributes<T>>::new(
+        graphblas_context,
+        size.row_height(),
+    )?;
+    unsafe {
+        mem::swap(
+            adjacency_matrix
+                .weighted_adjacency_matrix_mut_ref()
+                .graphblas_matrix_mut_ref(),
+            sparse_matrix.graphblas_matrix_mut_ref(),
+        )
+    };
+    Ok(adjacency_matrix)
+}
+
+fn encode_user_defined_adjacency_matrix_elements(
+    value_type: &UserDefinedValueTypeIdentifier,
+    writer: &mut impl Write,
+    adjacency_matrix: &WeightedAdjacencyMatrix,
+) -> Result<(), GraphComputingError> {
+    let (row_indices, column_indices, values) =
+        user_defined_matrix_elements(adjacency_matrix, value_type)?;
+    encode_indices(writer, &row_indices)?;
+    encode_indices(writer, &column_indices)?;
+    encode_bytes(writer, &values)
+}
+
+fn decode_user_defined_adjacency_matrix_elements(
+    value_type: &UserDefinedValueTypeIdentifier,
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+    size: Size,
+) -> Result<WeightedAdjacencyMatrixWithCachedAttributes, GraphComputingError> {
+    let row_indices = decode_indices(reader)?;
+    let column_indices = decode_indices(reader)?;
+    let values = decode_bytes(reader)?;
+
+    let mut adjacency_matrix =
+        WeightedAdjacencyMatrixWithCachedAttributes::with_user_defined_value_type(
+            graphblas_context,
+            *value_type,
+            size.row_height(),
+        )?;
+    build_user_defined_matrix(
+        adjacency_matrix.weighted_adjacency_matrix_mut_ref(),
+        value_type,
+        &row_indices,
+        &column_indices,
+        &values,
+    )?;
+    Ok(adjacency_matrix)
+}
@@ -0,0 +1,239 @@
+use std::io::{Read, Write};
+
+use crate::error::{GraphComputingError, UserError, UserErrorType};
+use crate::graph::indexing::ElementIndex;
+use crate::graph::value_type::{
+    implement_macro_for_all_native_value_types, registered_user_defined_value_type,
+    ValueTypeIdentifier,
+};
+
+// All values are stored little-endian. isize and usize are stored as 64-bit integers,
+// such that a snapshot does not depend on the pointer width of the platform that wrote it.
+
+/// Binary encoding of values in snapshots and write-ahead logs.
+pub trait EncodeValue: Sized {
+    fn encode(&self, writer: &mut impl Write) -> Result<(), GraphComputingError>;
+    fn decode(reader: &mut impl Read) -> Result<Self, GraphComputingError>;
+}
+
+macro_rules! implement_encode_value_for_number {
+    ($value_type:ty) => {
+        impl EncodeValue for $value_type {
+            fn encode(&self, writer: &mut impl Write) -> Result<(), GraphComputingError> {
+                writer.write_all(&self.to_le_bytes())?;
+                Ok(())
+            }
+
+            fn decode(reader: &mut impl Read) -> Result<Self, GraphComputingError> {
+                let mut bytes = [0u8; std::mem::size_of::<$value_type>()];
+                reader.read_exact(&mut bytes)?;
+                Ok(<$value_type>::from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+macro_rules! implement_encode_value_for_pointer_sized_number {
+    ($value_type:ty, $encoded_type:ty) => {
+        impl EncodeValue for $value_type {
+            fn encode(&self, writer: &mut impl Write) -> Result<(), GraphComputingError> {
+                (*self as $encoded_type).encode(writer)
+            }
+
+            fn decode(reader: &mut impl Read) -> Result<Self, GraphComputingError> {
+                Ok(<$encoded_type>::decode(reader)? as $value_type)
+            }
+        }
+    };
+}
+
+macro_rules! implement_encode_value_for_native_number {
+    (bool) => {};
+    (isize) => {
+        implement_encode_value_for_pointer_sized_number!(isize, i64);
+    };
+    (usize) => {
+        implement_encode_value_for_pointer_sized_number!(usize, u64);
+    };
+    ($value_type:ty) => {
+        implement_encode_value_for_number!($value_type);
+    };
+}
+implement_macro_for_all_native_value_types!(implement_encode_value_for_native_number);
+
+impl EncodeValue for bool {
+    fn encode(&self, writer: &mut impl Write) -> Result<(), GraphComputingError> {
+        (*self as u8).encode(writer)
+    }
+
+    fn decode(reader: &mut impl Read) -> Result<Self, GraphComputingError> {
+        match u8::decode(reader)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(invalid_file_format_error(format!(
+                "Expected an encoded boolean, found: {}",
+                value
+            ))),
+        }
+    }
+}
+
+pub(crate) fn encode_indices(
+    writer: &mut impl Write,
+    indices: &[ElementIndex],
+) -> Result<(), GraphComputingError> {
+    indices.len().encode(writer)?;
+    for index in indices {
+        index.encode(writer)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn decode_indices(
+    reader: &mut impl Read,
+) -> Result<Vec<ElementIndex>, GraphComputingError> {
+    let length = ElementIndex::decode(reader)?;
+    let mut indices = Vec::new();
+    indices.try_reserve_exact(length)?;
+    for _ in 0..length {
+        indices.push(ElementIndex::decode(reader)?);
+    }
+    Ok(indices)
+}
+
+pub(crate) fn encode_values<T: EncodeValue>(
+    writer: &mut impl Write,
+    values: &[T],
+) -> Result<(), GraphComputingError> {
+    values.len().encode(writer)?;
+    for value in values {
+        value.encode(writer)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn decode_values<T: EncodeValue>(
+    reader: &mut impl Read,
+) -> Result<Vec<T>, GraphComputingError> {
+    let length = usize::decode(reader)?;
+    let mut values = Vec::new();
+    values.try_reserve_exact(length)?;
+    for _ in 0..length {
+        values.push(T::decode(reader)?);
+    }
+    Ok(values)
+}
+
+pub(crate) fn encode_bytes(
+    writer: &mut impl Write,
+    bytes: &[u8],
+) -> Result<(), GraphComputingError> {
+    bytes.len().encode(writer)?;
+    writer.write_all(bytes)?;
+    Ok(())
+}
+
+pub(crate) fn decode_bytes(reader: &mut impl Read) -> Result<Vec<u8>, GraphComputingError> {
+    let length = usize::decode(reader)?;
+    let mut bytes = Vec::new();
+    bytes.try_reserve_exact(length)?;
+    bytes.resize(length, 0);
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+pub(crate) fn encode_value_type_identifier(
+    writer: &mut impl Write,
+    value_type_identifier: &ValueTypeIdentifier,
+) -> Result<(), GraphComputingError> {
+    let tag: u8 = match value_type_identifier {
+        ValueTypeIdentifier::Bool => 0,
+        ValueTypeIdentifier::Int8 => 1,
+        ValueTypeIdentifier::Int16 => 2,
+        ValueTypeIdentifier::Int32 => 3,
+        ValueTypeIdentifier::Int64 => 4,
+        ValueTypeIdentifier::UInt8 => 5,
+        ValueTypeIdentifier::UInt16 => 6,
+        ValueTypeIdentifier::UInt32 => 7,
+        ValueTypeIdentifier::UInt64 => 8,
+        ValueTypeIdentifier::Float32 => 9,
+        ValueTypeIdentifier::Float64 => 10,
+        ValueTypeIdentifier::ISize => 11,
+        ValueTypeIdentifier::USize => 12,
+        ValueTypeIdentifier::UserDefined(user_defined_value_type) => {
+            // User-defined value types are identified by their type name and size
+            13u8.encode(writer)?;
+            encode_bytes(writer, user_defined_value_type.type_name().as_bytes())?;
+            return user_defined_value_type.size().encode(writer);
+        }
+    };
+    tag.encode(writer)
+}
+
+pub(crate) fn decode_value_type_identifier(
+    reader: &mut impl Read,
+) -> Result<ValueTypeIdentifier, GraphComputingError> {
+    match u8::decode(reader)? {
+        0 => Ok(ValueTypeIdentifier::Bool),
+        1 => Ok(ValueTypeIdentifier::Int8),
+        2 => Ok(ValueTypeIdentifier::Int16),
+        3 => Ok(ValueTypeIdentifier::Int32),
+        4 => Ok(ValueTypeIdentifier::Int64),
+        5 => Ok(ValueTypeIdentifier::UInt8),
+        6 => Ok(ValueTypeIdentifier::UInt16),
+        7 => Ok(ValueTypeIdentifier::UInt32),
+        8 => Ok(ValueTypeIdentifier::UInt64),
+        9 => Ok(ValueTypeIdentifier::Float32),
+        10 => Ok(ValueTypeIdentifier::Float64),
+        11 => Ok(ValueTypeIdentifier::ISize),
+        12 => Ok(ValueTypeIdentifier::USize),
+        13 => {
+            let type_name = String::from_utf8(decode_bytes(reader)?).map_err(|error| {
+                invalid_file_format_error(format!(
+                    "Expected the type name of a user-defined value type, found: {}",
+                    error
+                ))
+            })?;
+            let size = usize::decode(reader)?;
+            Ok(ValueTypeIdentifier::UserDefined(
+                registered_user_defined_value_type(&type_name, size)?,
+            ))
+        }
+        tag => Err(invalid_file_format_error(format!(
+            "Unknown value type identifier: {}",
+            tag
+        ))),
+    }
+}
+
+pub(crate) fn invalid_file_format_error(explanation: String) -> GraphComputingError {
+    UserError::new(UserErrorType::InvalidFileFormat, explanation, None).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_and_decode_values() {
+        let mut buffer = Vec::new();
+
+        encode_values(&mut buffer, &[true, false]).unwrap();
+        encode_values(&mut buffer, &[-1isize, 2]).unwrap();
+        encode_values(&mut buffer, &[1.5f32]).unwrap();
+        encode_value_type_identifier(&mut buffer, &ValueTypeIdentifier::USize).unwrap();
+
+        let mut reader = buffer.as_slice();
+
+        assert_eq!(
+            decode_values::<bool>(&mut reader).unwrap(),
+            vec![true, false]
+        );
+        assert_eq!(decode_values::<isize>(&mut reader).unwrap(), vec![-1, 2]);
+        assert_eq!(decode_values::<f32>(&mut reader).unwrap(), vec![1.5]);
+        assert!(matches!(
+            decode_value_type_identifier(&mut reader).unwrap(),
+            ValueTypeIdentifier::USize
+        ));
+    }
+}
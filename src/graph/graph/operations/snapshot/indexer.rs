@@ -0,0 +1,83 @@
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::FromVectorElementList;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::{
+    SparseVector, VectorElementList,
+};
+use graphblas_sparse_linear_algebra::context::Context as GraphblasContext;
+use graphblas_sparse_linear_algebra::operators::binary_operator::First;
+
+use crate::error::GraphComputingError;
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{
+    Generation, GetIndexCapacity, GetIndexGenerations, GetIndexMask, GetIndicesAvailableForReuse,
+    GetQueueWithIndicesForReuse, Indexer, Queue,
+};
+
+use super::{
+    decode_indices, decode_values, encode_indices, encode_values, invalid_file_format_error,
+    EncodeValue,
+};
+
+pub(crate) fn encode_indexer(
+    writer: &mut impl Write,
+    indexer: &Indexer,
+) -> Result<(), GraphComputingError> {
+    indexer.capacity()?.encode(writer)?;
+    encode_indices(writer, indexer.valid_indices()?.as_slice())?;
+
+    let indices_available_for_reuse: Vec<_> = indexer
+        .queue_with_indices_for_reuse_ref()
+        .iter()
+        .copied()
+        .collect();
+    encode_indices(writer, indices_available_for_reuse.as_slice())?;
+
+    let mut generations: Vec<_> = indexer
+        .generations_ref()
+        .iter()
+        .map(|(index, generation)| (*index, *generation))
+        .collect();
+    generations.sort_unstable();
+    let (indices_with_generation, generations): (Vec<_>, Vec<_>) = generations.into_iter().unzip();
+    encode_indices(writer, indices_with_generation.as_slice())?;
+    encode_values(writer, generations.as_slice())
+}
+
+pub(crate) fn decode_indexer(
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+) -> Result<Indexer, GraphComputingError> {
+    let capacity = usize::decode(reader)?;
+    let valid_indices = decode_indices(reader)?;
+    let indices_available_for_reuse = decode_indices(reader)?;
+    let indices_with_generation = decode_indices(reader)?;
+    let generations = decode_values::<Generation>(reader)?;
+    if indices_with_generation.len() != generations.len() {
+        return Err(invalid_file_format_error(String::from(
+            "The number of index generations does not match the number of indices",
+        )));
+    }
+
+    let mut indexer = Indexer::with_initial_capacity(graphblas_context.clone(), capacity)?;
+
+    let number_of_valid_indices = valid_indices.len();
+    *indexer.mask_with_valid_indices_mut_ref() = SparseVector::<bool>::from_element_list(
+        graphblas_context,
+        indexer.capacity()?,
+        VectorElementList::from_vectors(valid_indices, vec![true; number_of_valid_indices])?,
+        &First::<bool>::new(),
+    )?;
+
+    let queue = indexer.indices_available_for_reuse_mut_ref();
+    for index in indices_available_for_reuse {
+        queue.push_back(index);
+    }
+
+    indexer
+        .generations_mut_ref()
+        .extend(indices_with_generation.into_iter().zip(generations));
+
+    Ok(indexer)
+}
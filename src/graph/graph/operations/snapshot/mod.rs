@@ -0,0 +1,12 @@
+mod edge_store;
+mod encoding;
+mod indexer;
+mod snapshot;
+mod vertex_store;
+
+pub(crate) use edge_store::*;
+pub(crate) use encoding::*;
+pub use encoding::EncodeValue;
+pub(crate) use indexer::*;
+pub use snapshot::*;
+pub(crate) use vertex_store::*;
@@ -0,0 +1,312 @@
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::{GraphComputingError, UserError, UserErrorType};
+use crate::graph::graph::{GetEdgeStore, GetGraphblasContext, GetVertexStore, Graph};
+use crate::graph::indexing::MINIMUM_INDEXER_CAPACITY;
+
+use super::{
+    decode_edge_store, decode_vertex_store, encode_edge_store, encode_vertex_store,
+    invalid_file_format_error, EncodeValue,
+};
+
+const SNAPSHOT_MAGIC_BYTES: &[u8; 8] = b"SLAGSNAP";
+const SNAPSHOT_FORMAT_VERSION: u32 = 2;
+
+pub trait SaveSnapshot {
+    /// Writes the public vertex and edge stores, including the state of their indexers.
+    /// The snapshot is written to a temporary file first, and then moved to path.
+    /// Returns an error if the graph holds properties, which a snapshot does not hold.
+    fn save_snapshot(&self, path: impl AsRef<Path>) -> Result<(), GraphComputingError>;
+}
+
+pub trait LoadSnapshot: Sized {
+    fn load_snapshot(path: impl AsRef<Path>) -> Result<Self, GraphComputingError>;
+}
+
+impl SaveSnapshot for Graph {
+    fn save_snapshot(&self, path: impl AsRef<Path>) -> Result<(), GraphComputingError> {
+        let path = path.as_ref();
+        let mut temporary_path = path.as_os_str().to_owned();
+        temporary_path.push(".partial");
+        let temporary_path = PathBuf::from(temporary_path);
+
+        if let Err(error) = write_snapshot_file(&temporary_path, self) {
+            // The temporary file is incomplete, and a failure to remove it does not matter more than the error
+            let _ = fs::remove_file(&temporary_path);
+            return Err(error);
+        }
+
+        fs::rename(&temporary_path, path)?;
+        match path.parent() {
+            Some(directory) if !directory.as_os_str().is_empty() => sync_directory(directory),
+            _ => sync_directory(Path::new(".")),
+        }
+    }
+}
+
+impl LoadSnapshot for Graph {
+    fn load_snapshot(path: impl AsRef<Path>) -> Result<Self, GraphComputingError> {
+        let mut reader = BufReader::new(File::open(path)?);
+        read_snapshot(&mut reader)
+    }
+}
+
+fn write_snapshot_file(path: &Path, graph: &Graph) -> Result<(), GraphComputingError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_snapshot(&mut writer, graph)?;
+    let file = writer.into_inner().map_err(|error| error.into_error())?;
+    file.sync_all()?;
+    Ok(())
+}
+
+pub(crate) fn write_snapshot(
+    writer: &mut impl Write,
+    graph: &Graph,
+) -> Result<(), GraphComputingError> {
+    if !graph.property_store.is_empty() {
+        return Err(UserError::new(
+            UserErrorType::Other,
+            String::from("A snapshot cannot hold vertex or edge properties"),
+            None,
+        )
+        .into());
+    }
+
+    writer.write_all(SNAPSHOT_MAGIC_BYTES)?;
+    SNAPSHOT_FORMAT_VERSION.encode(writer)?;
+
+    encode_vertex_store(writer, graph.vertex_store_ref())?;
+    encode_edge_store(writer, graph.edge_store_ref())?;
+    Ok(())
+}
+
+// Makes created and renamed files in the directory durable
+#[cfg(unix)]
+pub(crate) fn sync_directory(directory: &Path) -> Result<(), GraphComputingError> {
+    File::open(directory)?.sync_all()?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub(crate) fn sync_directory(_directory: &Path) -> Result<(), GraphComputingError> {
+    Ok(())
+}
+
+// The private stores only hold intermediate results of operators, and are not part of a snapshot.
+pub(crate) fn read_snapshot(reader: &mut impl Read) -> Result<Graph, GraphComputingError> {
+    let mut magic_bytes = [0u8; 8];
+    reader.read_exact(&mut magic_bytes)?;
+    if &magic_bytes != SNAPSHOT_MAGIC_BYTES {
+        return Err(invalid_file_format_error(String::from(
+            "The file is not a graph snapshot",
+        )));
+    }
+
+    let format_version = u32::decode(reader)?;
+    if format_version != SNAPSHOT_FORMAT_VERSION {
+        return Err(invalid_file_format_error(format!(
+            "Unsupported snapshot format version: {}, expected: {}",
+            format_version, SNAPSHOT_FORMAT_VERSION
+        )));
+    }
+
+    let mut graph = Graph::with_initial_capacity(
+        MINIMUM_INDEXER_CAPACITY,
+        MINIMUM_INDEXER_CAPACITY,
+        MINIMUM_INDEXER_CAPACITY,
+    )?;
+    *graph.vertex_store_mut_ref() = decode_vertex_store(reader, graph.graphblas_context())?;
+    *graph.edge_store_mut_ref() = decode_edge_store(reader, graph.graphblas_context())?;
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::{EdgeTypeIndex, VertexIndex};
+    use crate::implement_user_defined_value_type;
+    use crate::operators::operators::delete::DropVertexIndex;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+    use crate::operators::operators::property::VertexProperties;
+    use crate::operators::operators::read::{
+        GetEdgeWeight, GetUserDefinedEdgeWeight, GetUserDefinedVertexValue, GetVertexValue,
+    };
+
+    #[test]
+    fn save_and_load_snapshot() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let vertex_type_1 = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex_type_2 = NewVertexType::<f64>::apply(&mut graph).unwrap();
+        let edge_type_1 = NewEdgeType::<i32>::apply(&mut graph).unwrap();
+        let edge_type_2 = NewEdgeType::<bool>::apply(&mut graph).unwrap();
+
+        let mut vertices: Vec<VertexIndex> = Vec::new();
+        for value in 0..10u8 {
+            vertices.push(graph.new_vertex(&vertex_type_1, value).unwrap());
+        }
+        graph.new_vertex(&vertex_type_2, 1.5f64).unwrap();
+
+        graph
+            .new_edge(&edge_type_1, &vertices[1], &vertices[2], -3i32)
+            .unwrap();
+        graph
+            .new_edge(&edge_type_2, &vertices[2], &vertices[8], true)
+            .unwrap();
+
+        graph
+            .drop_vertex_index_and_connected_edges(&vertices[4])
+            .unwrap();
+        graph
+            .drop_vertex_index_and_connected_edges(&vertices[3])
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_snapshot_{}.slag",
+            std::process::id()
+        ));
+        graph.save_snapshot(&path).unwrap();
+        let mut loaded_graph = Graph::load_snapshot(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&loaded_graph, &vertex_type_1, &vertices[9])
+                .unwrap(),
+            Some(9)
+        );
+        assert_eq!(
+            GetVertexValue::<f64>::vertex_value(&loaded_graph, &vertex_type_2, &vertices[9])
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            GetEdgeWeight::<i32>::edge_weight(
+                &loaded_graph,
+                &edge_type_1,
+                &vertices[1],
+                &vertices[2]
+            )
+            .unwrap(),
+            Some(-3)
+        );
+        assert_eq!(
+            GetEdgeWeight::<bool>::edge_weight(
+                &loaded_graph,
+                &edge_type_2,
+                &vertices[2],
+                &vertices[8]
+            )
+            .unwrap(),
+            Some(true)
+        );
+        assert!(
+            GetVertexValue::<u8>::vertex_value(&loaded_graph, &vertex_type_1, &vertices[3])
+                .is_err()
+        );
+
+        // Freed indices are reused in the same order as before saving
+        let reused_index = graph.new_vertex(&vertex_type_1, 1u8).unwrap();
+        let reused_index_after_loading = loaded_graph.new_vertex(&vertex_type_1, 1u8).unwrap();
+        assert_eq!(reused_index, vertices[4]);
+        assert_eq!(reused_index_after_loading, vertices[4]);
+
+        assert_eq!(
+            NewEdgeType::<u8>::apply(&mut loaded_graph).unwrap(),
+            EdgeTypeIndex::new(2)
+        );
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Measurement {
+        timestamp: u64,
+        score: f32,
+    }
+    implement_user_defined_value_type!(unsafe Measurement);
+
+    #[test]
+    fn save_and_load_snapshot_with_user_defined_value_type() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let vertex_type = NewVertexType::<Measurement>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<Measurement>::apply(&mut graph).unwrap();
+
+        let measurement = Measurement {
+            timestamp: 1,
+            score: 0.5,
+        };
+        let weight = Measurement {
+            timestamp: 2,
+            score: -1.5,
+        };
+        let vertex_1 = graph.new_vertex(&vertex_type, measurement).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type, measurement).unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_2, &vertex_1, weight)
+            .unwrap();
+
+        let mut snapshot = Vec::new();
+        write_snapshot(&mut snapshot, &graph).unwrap();
+        let loaded_graph = read_snapshot(&mut snapshot.as_slice()).unwrap();
+
+        assert_eq!(
+            GetUserDefinedVertexValue::<Measurement>::user_defined_vertex_value(
+                &loaded_graph,
+                &vertex_type,
+                &vertex_2
+            )
+            .unwrap(),
+            Some(measurement)
+        );
+        assert_eq!(
+            GetUserDefinedEdgeWeight::<Measurement>::user_defined_edge_weight(
+                &loaded_graph,
+                &edge_type,
+                &vertex_2,
+                &vertex_1
+            )
+            .unwrap(),
+            Some(weight)
+        );
+        assert_eq!(
+            GetUserDefinedEdgeWeight::<Measurement>::user_defined_edge_weight(
+                &loaded_graph,
+                &edge_type,
+                &vertex_1,
+                &vertex_2
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn reject_snapshot_of_graph_with_properties() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex(&vertex_type, 1u8).unwrap();
+        graph
+            .set_vertex_property(&vertex_type, &vertex, "name", "Alice")
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_reject_snapshot_of_graph_with_properties_{}.slag",
+            std::process::id()
+        ));
+        assert!(graph.save_snapshot(&path).is_err());
+        assert!(!path.exists());
+        let mut temporary_path = path.into_os_string();
+        temporary_path.push(".partial");
+        assert!(!PathBuf::from(temporary_path).exists());
+    }
+
+    #[test]
+    fn reject_invalid_snapshot() {
+        let mut reader: &[u8] = b"NOTASNAPSHOT";
+        assert!(read_snapshot(&mut reader).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use std::io::{Read, Write};
+use std::mem;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::{
+    FromVectorElementList, GetSparseVectorElementList,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::{
+    GetGraphblasSparseVector, SparseVector, VectorElementList,
+};
+use graphblas_sparse_linear_algebra::context::Context as GraphblasContext;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{BinaryOperator, First};
+
+use crate::error::GraphComputingError;
+use crate::graph::indexing::MINIMUM_INDEXER_CAPACITY;
+use crate::graph::value_type::{
+    build_user_defined_vector, call_generic_function_for_value_type_identifier,
+    user_defined_vector_elements, GetValueTypeIdentifier, GetValueTypeIdentifierRef,
+    UserDefinedValueTypeIdentifier, ValueType,
+};
+use crate::graph::vertex_store::{
+    CreateVertexVector, GetVectorLength, GetVertexElementIndexer, GetVertexTypeIndexer,
+    GetVertexVectors, ToSparseVector, VertexStore, VertexVector,
+};
+
+use super::{
+    decode_bytes, decode_indexer, decode_indices, decode_value_type_identifier, decode_values,
+    encode_bytes, encode_indexer, encode_indices, encode_value_type_identifier, encode_values,
+    EncodeValue,
+};
+
+pub(crate) fn encode_vertex_store(
+    writer: &mut impl Write,
+    vertex_store: &VertexStore,
+) -> Result<(), GraphComputingError> {
+    encode_indexer(writer, vertex_store.vertex_type_indexer_ref())?;
+    encode_indexer(writer, vertex_store.element_indexer_ref())?;
+
+    // Vertex vectors of freed vertex types are kept as well, such that positions in the vector
+    // keep matching vertex type indices.
+    let vertex_vectors = vertex_store.vertex_vector_for_all_vertex_types_ref();
+    vertex_vectors.len().encode(writer)?;
+    for vertex_vector in vertex_vectors {
+        encode_vertex_vector(writer, vertex_vector)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn decode_vertex_store(
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+) -> Result<VertexStore, GraphComputingError> {
+    let vertex_type_indexer = decode_indexer(reader, graphblas_context.clone())?;
+    let element_indexer = decode_indexer(reader, graphblas_context.clone())?;
+
+    let number_of_vertex_vectors = usize::decode(reader)?;
+    let mut vertex_vectors = Vec::new();
+    vertex_vectors.try_reserve_exact(number_of_vertex_vectors)?;
+    for _ in 0..number_of_vertex_vectors {
+        vertex_vectors.push(Arc::new(decode_vertex_vector(
+            reader,
+            graphblas_context.clone(),
+        )?));
+    }
+
+    let mut vertex_store = VertexStore::with_initial_capacity(
+        graphblas_context,
+        MINIMUM_INDEXER_CAPACITY,
+        MINIMUM_INDEXER_CAPACITY,
+    )?;
+    *vertex_store.vertex_type_indexer_mut_ref() = vertex_type_indexer;
+    *vertex_store.element_indexer_mut_ref() = element_indexer;
+    *vertex_store.vertex_vector_for_all_vertex_types_mut() = vertex_vectors;
+
+    Ok(vertex_store)
+}
+
+pub(crate) fn encode_vertex_vector(
+    writer: &mut impl Write,
+    vertex_vector: &VertexVector,
+) -> Result<(), GraphComputingError> {
+    encode_value_type_identifier(writer, vertex_vector.value_type_identifier_ref())?;
+    vertex_vector.length()?.encode(writer)?;
+    call_generic_function_for_value_type_identifier!(
+        vertex_vector.value_type_identifier_ref(),
+        encode_vertex_vector_elements,
+        encode_user_defined_vertex_vector_elements,
+        (writer, vertex_vector)
+    )
+}
+
+pub(crate) fn decode_vertex_vector(
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+) -> Result<VertexVector, GraphComputingError> {
+    let value_type = decode_value_type_identifier(reader)?;
+    let length = usize::decode(reader)?;
+    call_generic_function_for_value_type_identifier!(
+        value_type,
+        decode_vertex_vector_elements,
+        decode_user_defined_vertex_vector_elements,
+        (reader, graphblas_context, length)
+    )
+}
+
+fn encode_vertex_vector_elements<T>(
+    writer: &mut impl Write,
+    vertex_vector: &VertexVector,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + EncodeValue + Copy,
+    VertexVector: ToSparseVector<T>,
+    SparseVector<T>: GetSparseVectorElementList<T>,
+{
+    let element_list = ToSparseVector::<T>::to_sparse_vector(vertex_vector)?.element_list()?;
+    encode_indices(writer, element_list.indices_ref())?;
+    encode_values(writer, element_list.values_ref())
+}
+
+fn decode_vertex_vector_elements<T>(
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+    length: usize,
+) -> Result<VertexVector, GraphComputingError>
+where
+    T: ValueType + GetValueTypeIdentifier + EncodeValue + Copy,
+    VertexVector: CreateVertexVector<T>,
+    SparseVector<T>: FromVectorElementList<T>,
+    First<T>: BinaryOperator<T>,
+{
+    let indices = decode_indices(reader)?;
+    let values = decode_values::<T>(reader)?;
+
+    let mut sparse_vector = SparseVector::<T>::from_element_list(
+        graphblas_context.clone(),
+        length,
+        VectorElementList::from_vectors(indices, values)?,
+        &First::<T>::new(),
+    )?;
+
+    let mut vertex_vector =
+        <VertexVector as CreateVertexVector<T>>::new(graphblas_context, length)?;
+    unsafe {
+        mem::swap(
+            vertex_vector.graphblas_vector_mut_ref(),
+            sparse_vector.graphblas_vector_mut_ref(),
+        )
+    };
+    Ok(vertex_vector)
+}
+
+fn encode_user_defined_vertex_vector_elements(
+    value_type: &UserDefinedValueTypeIdentifier,
+    writer: &mut impl Write,
+    vertex_vector: &VertexVector,
+) -> Result<(), GraphComputingError> {
+    let (indices, values) = user_defined_vector_elements(vertex_vector, value_type)?;
+    encode_indices(writer, &indices)?;
+    encode_bytes(writer, &values)
+}
+
+fn decode_user_defined_vertex_vector_elements(
+    value_type: &UserDefinedValueTypeIdentifier,
+    reader: &mut impl Read,
+    graphblas_context: Arc<GraphblasContext>,
+    length: usize,
+) -> Result<VertexVector, GraphComputingError> {
+    let indices = decode_indices(reader)?;
+    let values = decode_bytes(reader)?;
+
+    let mut vertex_vector =
+        VertexVector::with_user_defined_value_type(graphblas_context, *value_type, length)?;
+    build_user_defined_vector(&mut vertex_vector, value_type, &indices, &values)?;
+    Ok(vertex_vector)
+}
@@ -1,10 +1,11 @@
-use super::index::{ElementCount, Index};
+use super::index::{ElementCount, Generation, Index};
 
 #[derive(Clone, Debug)]
 pub(crate) struct AssignedIndex {
     index: Index,
     new_index_capacity: Option<ElementCount>,
     is_reused: bool,
+    generation: Generation,
 }
 
 impl AssignedIndex {
@@ -12,11 +13,13 @@ impl AssignedIndex {
         index: Index,
         new_index_capacity: Option<ElementCount>,
         is_reused: bool,
+        generation: Generation,
     ) -> Self {
         Self {
             index,
             new_index_capacity,
             is_reused,
+            generation,
         }
     }
 }
@@ -26,6 +29,7 @@ pub(crate) trait GetAssignedIndexData {
     fn index_ref(&self) -> &Index;
     fn new_index_capacity(&self) -> Option<ElementCount>;
     fn is_reused(&self) -> bool;
+    fn generation(&self) -> Generation;
 }
 
 impl GetAssignedIndexData for AssignedIndex {
@@ -44,4 +48,8 @@ impl GetAssignedIndexData for AssignedIndex {
     fn is_reused(&self) -> bool {
         self.is_reused
     }
+
+    fn generation(&self) -> Generation {
+        self.generation
+    }
 }
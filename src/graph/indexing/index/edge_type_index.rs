@@ -1,15 +1,37 @@
+use std::cmp::Ordering;
 use std::fmt::Debug;
 
-use super::Index;
+use super::{AssignedIndex, Generation, GetAssignedIndexData, Index};
 
 pub trait GetEdgeTypeIndex: Debug {
     fn index_ref(&self) -> &Index;
     fn index(&self) -> Index;
+
+    /// The generation of the index when it was assigned. None skips the check for stale indices.
+    fn generation(&self) -> Option<Generation> {
+        None
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug)]
 pub struct EdgeTypeIndex {
     index: Index,
+    generation: Option<Generation>,
+}
+
+// Handles are compared by index only, the generation is only used to detect stale handles.
+impl PartialEq for EdgeTypeIndex {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Eq for EdgeTypeIndex {}
+
+impl PartialOrd for EdgeTypeIndex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.index.partial_cmp(&other.index)
+    }
 }
 
 impl GetEdgeTypeIndex for EdgeTypeIndex {
@@ -20,10 +42,28 @@ impl GetEdgeTypeIndex for EdgeTypeIndex {
     fn index(&self) -> Index {
         self.index.to_owned()
     }
+
+    fn generation(&self) -> Option<Generation> {
+        self.generation
+    }
 }
 
 impl EdgeTypeIndex {
+    /// The index is not checked for staleness.
     pub fn new(index: Index) -> Self {
-        Self { index }
+        Self {
+            index,
+            generation: None,
+        }
+    }
+
+    pub(crate) fn with_generation(index: Index, generation: Option<Generation>) -> Self {
+        Self { index, generation }
+    }
+}
+
+impl From<AssignedIndex> for EdgeTypeIndex {
+    fn from(assigned_index: AssignedIndex) -> Self {
+        EdgeTypeIndex::with_generation(assigned_index.index(), Some(assigned_index.generation()))
     }
 }
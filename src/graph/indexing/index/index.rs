@@ -5,6 +5,8 @@ use std::fmt::Debug;
 pub(crate) type ElementIndex = GraphblasElementIndex;
 pub type ElementCount = ElementIndex;
 pub(crate) type Index = ElementIndex;
+/// Counts how often an index was reused, such that a handle to a freed index can be detected.
+pub type Generation = u32;
 
 // pub type VertexIndex = Index;
 // pub type VertexTypeIndex = Index;
@@ -13,4 +15,9 @@ pub(crate) type Index = ElementIndex;
 pub trait GetIndex: Debug {
     fn index_ref(&self) -> &Index;
     fn index(&self) -> Index;
+
+    /// The generation of the index when it was assigned. None skips the check for stale indices.
+    fn generation(&self) -> Option<Generation> {
+        None
+    }
 }
@@ -1,11 +1,13 @@
 mod assigned_index;
 mod edge_type_index;
 mod index;
+mod scratch_type_index;
 mod vertex_index;
 mod vertex_type_index;
 
 pub(crate) use assigned_index::*;
 pub use edge_type_index::*;
 pub use index::*;
+pub use scratch_type_index::*;
 pub use vertex_index::*;
 pub use vertex_type_index::*;
@@ -0,0 +1,89 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::{EdgeTypeIndex, GetIndex, Index, VertexTypeIndex};
+
+// Scratch generations are unique across transactions, so that a handle from a committed or
+// reverted transaction never matches a scratch type created later at the same index.
+static NEXT_SCRATCH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+pub(crate) type ScratchGeneration = u64;
+
+fn next_scratch_generation() -> ScratchGeneration {
+    NEXT_SCRATCH_GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A vertex type in the private vertex store of a transaction.
+/// Its vertex vector uses the same vertex indices as the public vertex types,
+/// but the index is not valid as a public VertexTypeIndex.
+/// The handle is rejected once the scratch vertex type has been dropped.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq)]
+pub struct ScratchVertexTypeIndex {
+    index: Index,
+    scratch_generation: ScratchGeneration,
+}
+
+impl GetIndex for ScratchVertexTypeIndex {
+    fn index_ref(&self) -> &Index {
+        &self.index
+    }
+
+    fn index(&self) -> Index {
+        self.index.to_owned()
+    }
+}
+
+impl ScratchVertexTypeIndex {
+    pub(crate) fn new(index: Index) -> Self {
+        Self {
+            index,
+            scratch_generation: next_scratch_generation(),
+        }
+    }
+
+    pub(crate) fn scratch_generation(&self) -> ScratchGeneration {
+        self.scratch_generation
+    }
+
+    /// The index in the private vertex store
+    pub(crate) fn private_vertex_type_index(&self) -> VertexTypeIndex {
+        VertexTypeIndex::new(self.index)
+    }
+}
+
+/// An edge type in the private edge store of a transaction.
+/// Its adjacency matrix uses the same vertex indices as the public edge types,
+/// but the index is not valid as a public EdgeTypeIndex.
+/// The handle is rejected once the scratch edge type has been dropped.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq)]
+pub struct ScratchEdgeTypeIndex {
+    index: Index,
+    scratch_generation: ScratchGeneration,
+}
+
+impl GetIndex for ScratchEdgeTypeIndex {
+    fn index_ref(&self) -> &Index {
+        &self.index
+    }
+
+    fn index(&self) -> Index {
+        self.index.to_owned()
+    }
+}
+
+impl ScratchEdgeTypeIndex {
+    pub(crate) fn new(index: Index) -> Self {
+        Self {
+            index,
+            scratch_generation: next_scratch_generation(),
+        }
+    }
+
+    pub(crate) fn scratch_generation(&self) -> ScratchGeneration {
+        self.scratch_generation
+    }
+
+    /// The index in the private edge store
+    pub(crate) fn private_edge_type_index(&self) -> EdgeTypeIndex {
+        EdgeTypeIndex::new(self.index)
+    }
+}
@@ -1,12 +1,29 @@
+use std::cmp::Ordering;
 use std::fmt::Debug;
 
-use super::{AssignedIndex, GetAssignedIndexData, GetIndex, Index};
+use super::{AssignedIndex, Generation, GetAssignedIndexData, GetIndex, Index};
 
 pub trait GetVertexIndexIndex: GetIndex {}
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug)]
 pub struct VertexIndex {
     index: Index,
+    generation: Option<Generation>,
+}
+
+// Handles are compared by index only, the generation is only used to detect stale handles.
+impl PartialEq for VertexIndex {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Eq for VertexIndex {}
+
+impl PartialOrd for VertexIndex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.index.partial_cmp(&other.index)
+    }
 }
 
 impl GetIndex for VertexIndex {
@@ -17,18 +34,30 @@ impl GetIndex for VertexIndex {
     fn index(&self) -> Index {
         self.index.to_owned()
     }
+
+    fn generation(&self) -> Option<Generation> {
+        self.generation
+    }
 }
 
 impl GetVertexIndexIndex for VertexIndex {}
 
 impl VertexIndex {
+    /// The index is not checked for staleness.
     pub fn new(index: Index) -> Self {
-        Self { index }
+        Self {
+            index,
+            generation: None,
+        }
+    }
+
+    pub(crate) fn with_generation(index: Index, generation: Option<Generation>) -> Self {
+        Self { index, generation }
     }
 }
 
 impl From<AssignedIndex> for VertexIndex {
     fn from(assigned_index: AssignedIndex) -> Self {
-        VertexIndex::new(assigned_index.index())
+        VertexIndex::with_generation(assigned_index.index(), Some(assigned_index.generation()))
     }
 }
@@ -1,12 +1,29 @@
+use std::cmp::Ordering;
 use std::fmt::Debug;
 
-use super::{AssignedIndex, GetAssignedIndexData, GetIndex, Index};
+use super::{AssignedIndex, Generation, GetAssignedIndexData, GetIndex, Index};
 
 pub trait GetVertexTypeIndex: GetIndex {}
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Default)]
+#[derive(Clone, Copy, Debug, Default)]
 pub struct VertexTypeIndex {
     index: Index,
+    generation: Option<Generation>,
+}
+
+// Handles are compared by index only, the generation is only used to detect stale handles.
+impl PartialEq for VertexTypeIndex {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Eq for VertexTypeIndex {}
+
+impl PartialOrd for VertexTypeIndex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.index.partial_cmp(&other.index)
+    }
 }
 
 impl GetIndex for VertexTypeIndex {
@@ -17,18 +34,30 @@ impl GetIndex for VertexTypeIndex {
     fn index(&self) -> Index {
         self.index.to_owned()
     }
+
+    fn generation(&self) -> Option<Generation> {
+        self.generation
+    }
 }
 
 impl GetVertexTypeIndex for VertexTypeIndex {}
 
 impl VertexTypeIndex {
+    /// The index is not checked for staleness.
     pub fn new(index: Index) -> Self {
-        Self { index }
+        Self {
+            index,
+            generation: None,
+        }
+    }
+
+    pub(crate) fn with_generation(index: Index, generation: Option<Generation>) -> Self {
+        Self { index, generation }
     }
 }
 
 impl From<AssignedIndex> for VertexTypeIndex {
     fn from(assigned_index: AssignedIndex) -> Self {
-        VertexTypeIndex::new(assigned_index.index())
+        VertexTypeIndex::with_generation(assigned_index.index(), Some(assigned_index.generation()))
     }
 }
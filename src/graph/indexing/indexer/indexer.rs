@@ -9,7 +9,7 @@ use graphblas_sparse_linear_algebra::context::Context as GraphBLASContext;
 use graphblas_sparse_linear_algebra::operators::mask::SelectEntireVector;
 
 use crate::error::GraphComputingError;
-use crate::graph::indexing::{ElementCount, Index};
+use crate::graph::indexing::{ElementCount, ElementIndexMap, Generation, Index};
 
 use super::{Queue, VecDequeQueue};
 
@@ -23,6 +23,9 @@ pub(crate) struct Indexer {
     indices_available_for_reuse: VecDequeQueue<Index>,
 
     mask_with_valid_indices: SparseVector<bool>,
+
+    // Only reused indices are stored, all other indices are of generation 0.
+    generations: ElementIndexMap<Generation>,
 }
 
 pub(crate) trait GetIndicesAvailableForReuse {
@@ -55,6 +58,26 @@ impl GetIndexMask for Indexer {
     }
 }
 
+pub(crate) trait GetIndexGenerations {
+    fn generation(&self, index: Index) -> Generation;
+    fn generations_ref(&self) -> &ElementIndexMap<Generation>;
+    fn generations_mut_ref(&mut self) -> &mut ElementIndexMap<Generation>;
+}
+
+impl GetIndexGenerations for Indexer {
+    fn generation(&self, index: Index) -> Generation {
+        self.generations.get(&index).copied().unwrap_or_default()
+    }
+
+    fn generations_ref(&self) -> &ElementIndexMap<Generation> {
+        &self.generations
+    }
+
+    fn generations_mut_ref(&mut self) -> &mut ElementIndexMap<Generation> {
+        &mut self.generations
+    }
+}
+
 pub(crate) trait GetQueueWithIndicesForReuse {
     fn queue_with_indices_for_reuse_ref(&self) -> &VecDequeQueue<Index>;
 }
@@ -102,6 +125,7 @@ impl Indexer {
             select_entire_vector: SelectEntireVector::new(graphblas_context.clone()),
             indices_available_for_reuse: VecDequeQueue::new(),
             mask_with_valid_indices: empty_bool_vector,
+            generations: ElementIndexMap::default(),
         })
     }
 
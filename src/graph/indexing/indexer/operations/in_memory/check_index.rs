@@ -1,7 +1,9 @@
 use crate::error::GraphComputingError;
-use crate::graph::indexing::operations::{is_valid_index, try_index_validity, CheckIndex};
-use crate::graph::indexing::Index;
+use crate::graph::indexing::operations::{
+    is_current_generation, is_valid_index, try_index_generation, try_index_validity, CheckIndex,
+};
 use crate::graph::indexing::Indexer;
+use crate::graph::indexing::{Generation, Index};
 
 impl CheckIndex for Indexer {
     fn is_valid_index(&self, index: Index) -> Result<bool, GraphComputingError> {
@@ -11,6 +13,22 @@ impl CheckIndex for Indexer {
     fn try_index_validity(&self, index: Index) -> Result<(), GraphComputingError> {
         try_index_validity(self, index)
     }
+
+    fn is_current_generation(
+        &self,
+        index: Index,
+        generation: Option<Generation>,
+    ) -> Result<bool, GraphComputingError> {
+        is_current_generation(self, index, generation)
+    }
+
+    fn try_index_generation(
+        &self,
+        index: Index,
+        generation: Option<Generation>,
+    ) -> Result<(), GraphComputingError> {
+        try_index_generation(self, index, generation)
+    }
 }
 
 #[cfg(test)]
@@ -18,8 +36,8 @@ mod tests {
     use graphblas_sparse_linear_algebra::context::Context as GraphBLASContext;
 
     use crate::graph::indexing::{
-        operations::{CheckIndex, FreeIndex, GenerateIndex},
-        Indexer,
+        operations::{claim_all_indices_below, CheckIndex, FreeIndex, GenerateIndex},
+        GetAssignedIndexData, Indexer,
     };
 
     #[test]
@@ -44,4 +62,52 @@ mod tests {
         assert_eq!(indexer.is_valid_index(3).unwrap(), false);
         assert_eq!(indexer.is_valid_index(5).unwrap(), true);
     }
+
+    #[test]
+    fn test_reused_index_generation() {
+        let mut indexer =
+            Indexer::with_initial_capacity(GraphBLASContext::init_default().unwrap(), 0).unwrap();
+
+        let index = indexer.new_index().unwrap();
+        indexer.new_index().unwrap();
+        assert_eq!(index.generation(), 0);
+
+        indexer.free_valid_index(index.index()).unwrap();
+        let reused_index = indexer.new_index().unwrap();
+
+        assert_eq!(reused_index.index(), index.index());
+        assert_eq!(reused_index.generation(), 1);
+        assert!(!indexer
+            .is_current_generation(index.index(), Some(index.generation()))
+            .unwrap());
+        assert!(indexer
+            .is_current_generation(reused_index.index(), Some(reused_index.generation()))
+            .unwrap());
+        assert!(indexer.is_current_generation(index.index(), None).unwrap());
+        assert!(indexer
+            .try_index_generation(index.index(), Some(index.generation()))
+            .is_err());
+    }
+
+    #[test]
+    fn test_reclaimed_index_generation() {
+        let mut indexer =
+            Indexer::with_initial_capacity(GraphBLASContext::init_default().unwrap(), 4).unwrap();
+
+        let index = indexer.new_index().unwrap();
+        let kept_index = indexer.new_index().unwrap();
+        indexer.free_valid_index(index.index()).unwrap();
+
+        claim_all_indices_below(&mut indexer, 3).unwrap();
+
+        assert!(indexer.is_valid_index(index.index()).unwrap());
+        assert!(!indexer
+            .is_current_generation(index.index(), Some(index.generation()))
+            .unwrap());
+        assert!(indexer
+            .is_current_generation(kept_index.index(), Some(kept_index.generation()))
+            .unwrap());
+        assert!(indexer.is_valid_index(2).unwrap());
+        assert!(indexer.is_current_generation(2, Some(0)).unwrap());
+    }
 }
@@ -3,7 +3,7 @@ use crate::graph::indexing::operations::in_memory_transaction::{
     GetIndexerUnderTransaction, InMemoryIndexerTransaction,
 };
 use crate::graph::indexing::operations::CheckIndex;
-use crate::graph::indexing::Index;
+use crate::graph::indexing::{Generation, Index};
 
 impl<'t> CheckIndex for InMemoryIndexerTransaction<'t> {
     fn is_valid_index(&self, index: Index) -> Result<bool, GraphComputingError> {
@@ -13,6 +13,22 @@ impl<'t> CheckIndex for InMemoryIndexerTransaction<'t> {
     fn try_index_validity(&self, index: Index) -> Result<(), GraphComputingError> {
         self.indexer_ref().try_index_validity(index)
     }
+
+    fn is_current_generation(
+        &self,
+        index: Index,
+        generation: Option<Generation>,
+    ) -> Result<bool, GraphComputingError> {
+        self.indexer_ref().is_current_generation(index, generation)
+    }
+
+    fn try_index_generation(
+        &self,
+        index: Index,
+        generation: Option<Generation>,
+    ) -> Result<(), GraphComputingError> {
+        self.indexer_ref().try_index_generation(index, generation)
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
+use std::mem;
+
 use crate::graph::indexing::indexer::indexer::GetQueueWithIndicesForReuse;
 use crate::graph::indexing::indexer::GetIndexMask;
-use crate::graph::indexing::GetIndexCapacity;
+use crate::graph::indexing::{ElementIndexMap, Generation, GetIndexCapacity};
+use crate::operators::history::GetApproximateSizeInBytes;
 use crate::{
     error::GraphComputingError,
     graph::indexing::{ElementCount, Index, Indexer},
@@ -14,6 +17,8 @@ pub(crate) struct IndexerStateRestorer {
     index_capacity_to_restore: ElementCount,
     indices_available_for_reuse_restorer: QueueStateReverter<Index>,
     mask_with_valid_indices_restorer: SparseVectorStateReverter<bool>,
+    // The generation of each reused index before it was reused
+    generations_to_restore: ElementIndexMap<Generation>,
 }
 
 pub(super) trait GetIndexerStateReverters {
@@ -26,6 +31,9 @@ pub(super) trait GetIndexerStateReverters {
 
     fn mask_with_valid_indices_restorer_ref(&self) -> &SparseVectorStateReverter<bool>;
     fn mask_with_valid_indices_restorer_mut_ref(&mut self) -> &mut SparseVectorStateReverter<bool>;
+
+    fn generations_to_restore_ref(&self) -> &ElementIndexMap<Generation>;
+    fn generations_to_restore_mut_ref(&mut self) -> &mut ElementIndexMap<Generation>;
 }
 
 impl GetIndexerStateReverters for IndexerStateRestorer {
@@ -52,6 +60,14 @@ impl GetIndexerStateReverters for IndexerStateRestorer {
     fn mask_with_valid_indices_restorer_mut_ref(&mut self) -> &mut SparseVectorStateReverter<bool> {
         &mut self.mask_with_valid_indices_restorer
     }
+
+    fn generations_to_restore_ref(&self) -> &ElementIndexMap<Generation> {
+        &self.generations_to_restore
+    }
+
+    fn generations_to_restore_mut_ref(&mut self) -> &mut ElementIndexMap<Generation> {
+        &mut self.generations_to_restore
+    }
 }
 
 impl IndexerStateRestorer {
@@ -64,6 +80,7 @@ impl IndexerStateRestorer {
             index_capacity_to_restore,
             indices_available_for_reuse_restorer,
             mask_with_valid_indices_restorer,
+            generations_to_restore: ElementIndexMap::default(),
         }
     }
 
@@ -84,6 +101,19 @@ impl IndexerStateRestorer {
             index_capacity_to_restore,
             indices_available_for_reuse_restorer,
             mask_with_valid_indices_restorer,
+            generations_to_restore: ElementIndexMap::default(),
         })
     }
 }
+
+impl GetApproximateSizeInBytes for IndexerStateRestorer {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        Ok(self
+            .indices_available_for_reuse_restorer
+            .approximate_size_in_bytes()?
+            + self
+                .mask_with_valid_indices_restorer
+                .approximate_size_in_bytes()?
+            + self.generations_to_restore.len() * mem::size_of::<(Index, Generation)>())
+    }
+}
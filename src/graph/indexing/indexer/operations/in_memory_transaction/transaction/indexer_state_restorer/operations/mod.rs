@@ -1,8 +1,10 @@
+mod register_claimed_indices_to_revert;
 mod register_freed_index_to_restore;
 mod register_index_capacity_to_restore;
 mod register_new_index_to_revert;
 mod restore_state;
 
+pub(crate) use register_claimed_indices_to_revert::*;
 pub(crate) use register_freed_index_to_restore::*;
 pub(crate) use register_index_capacity_to_restore::*;
 pub(crate) use register_new_index_to_revert::*;
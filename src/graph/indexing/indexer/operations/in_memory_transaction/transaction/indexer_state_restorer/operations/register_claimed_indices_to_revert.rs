@@ -0,0 +1,47 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::indexer::operations::in_memory_transaction::transaction::indexer_state_restorer::indexer_state_restorer::GetIndexerStateReverters;
+use crate::graph::indexing::indexer::{GetIndexGenerations, GetIndicesAvailableForReuse};
+use crate::graph::indexing::Index;
+use crate::graph::indexing::operations::in_memory_transaction::transaction::indexer_state_restorer::IndexerStateRestorer;
+use crate::operators::in_memory_transaction::transaction::{RegisterQueueChangeToRevert, RegisterSparseVectorChangeToRevert};
+
+pub(crate) trait RegisterClaimedIndicesToRevert {
+    /// Must be called before claiming the indices, with the indexer in its state before the claim.
+    fn register_claimed_indices_to_revert(
+        &mut self,
+        indexer: &(impl GetIndicesAvailableForReuse + GetIndexGenerations),
+        claimed_indices: &Vec<Index>,
+    ) -> Result<(), GraphComputingError>;
+}
+
+impl RegisterClaimedIndicesToRevert for IndexerStateRestorer {
+    fn register_claimed_indices_to_revert(
+        &mut self,
+        indexer: &(impl GetIndicesAvailableForReuse + GetIndexGenerations),
+        claimed_indices: &Vec<Index>,
+    ) -> Result<(), GraphComputingError> {
+        let indices_available_for_reuse = indexer.indices_available_for_reuse_ref();
+        if indices_available_for_reuse
+            .iter()
+            .any(|index| claimed_indices.binary_search(index).is_ok())
+        {
+            // Claiming removes indices from anywhere in the queue.
+            // Restoring the entire queue as popped values reverts that.
+            for index in indices_available_for_reuse.iter() {
+                self.indices_available_for_reuse_restorer_mut_ref()
+                    .front_popped_value_to_restore(*index);
+                if claimed_indices.binary_search(index).is_ok() {
+                    self.generations_to_restore_mut_ref()
+                        .entry(*index)
+                        .or_insert(indexer.generation(*index));
+                }
+            }
+        }
+
+        for index in claimed_indices {
+            self.mask_with_valid_indices_restorer_mut_ref()
+                .register_empty_element_to_restore(*index);
+        }
+        Ok(())
+    }
+}
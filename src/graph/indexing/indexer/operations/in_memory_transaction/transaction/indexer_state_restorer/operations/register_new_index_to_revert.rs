@@ -19,6 +19,10 @@ impl RegisterNewIndexToRevert for IndexerStateRestorer {
         if index.is_reused() {
             self.indices_available_for_reuse_restorer_mut_ref()
                 .front_popped_value_to_restore(index.index());
+            // The first registration holds the generation to restore
+            self.generations_to_restore_mut_ref()
+                .entry(index.index())
+                .or_insert(index.generation().wrapping_sub(1));
         }
         self.mask_with_valid_indices_restorer_mut_ref()
             .register_empty_element_to_restore(index.index());
@@ -2,7 +2,7 @@ use crate::error::GraphComputingError;
 use crate::graph::indexing::operations::in_memory_transaction::transaction::indexer_state_restorer::GetIndexerStateReverters;
 use crate::graph::vertex_store::VertexVector;
 use crate::operators::transaction::RestoreState;
-use crate::graph::indexing::indexer::GetIndexMask;
+use crate::graph::indexing::indexer::{GetIndexGenerations, GetIndexMask};
 use crate::graph::indexing::indexer::GetIndicesAvailableForReuse;
 use crate::graph::indexing::Indexer;
 use crate::graph::indexing::operations::in_memory_transaction::transaction::indexer_state_restorer::IndexerStateRestorer;
@@ -16,6 +16,12 @@ impl RestoreState<Indexer> for IndexerStateRestorer {
         self.mask_with_valid_indices_restorer_ref()
             .to_owned()
             .restore(instance_to_restore.mask_with_valid_indices_mut_ref())?;
+
+        for (index, generation) in self.generations_to_restore_ref() {
+            instance_to_restore
+                .generations_mut_ref()
+                .insert(*index, *generation);
+        }
         Ok(())
     }
 
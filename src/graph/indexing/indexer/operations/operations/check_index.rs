@@ -1,12 +1,24 @@
 use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::IsSparseVectorElement;
 
 use crate::error::{GraphComputingError, LogicError, LogicErrorType};
-use crate::graph::indexing::indexer::indexer::GetIndexMask;
-use crate::graph::indexing::Index;
+use crate::graph::indexing::indexer::indexer::{GetIndexGenerations, GetIndexMask};
+use crate::graph::indexing::{Generation, Index};
 
 pub(crate) trait CheckIndex {
     fn is_valid_index(&self, index: Index) -> Result<bool, GraphComputingError>;
     fn try_index_validity(&self, index: Index) -> Result<(), GraphComputingError>;
+
+    /// A generation of None matches any generation.
+    fn is_current_generation(
+        &self,
+        index: Index,
+        generation: Option<Generation>,
+    ) -> Result<bool, GraphComputingError>;
+    fn try_index_generation(
+        &self,
+        index: Index,
+        generation: Option<Generation>,
+    ) -> Result<(), GraphComputingError>;
 }
 
 pub(crate) fn is_valid_index(
@@ -32,5 +44,38 @@ pub(crate) fn try_index_validity(
     }
 }
 
+pub(crate) fn is_current_generation(
+    indexer: &impl GetIndexGenerations,
+    index: Index,
+    generation: Option<Generation>,
+) -> Result<bool, GraphComputingError> {
+    Ok(match generation {
+        Some(generation) => indexer.generation(index) == generation,
+        None => true,
+    })
+}
+
+pub(crate) fn try_index_generation(
+    indexer: &impl GetIndexGenerations,
+    index: Index,
+    generation: Option<Generation>,
+) -> Result<(), GraphComputingError> {
+    if is_current_generation(indexer, index, generation)? {
+        return Ok(());
+    } else {
+        return Err(LogicError::new(
+            LogicErrorType::StaleIndex,
+            format!(
+                "Index [{}] of generation {:?} is stale, the index has been reused as generation {}.",
+                index,
+                generation,
+                indexer.generation(index)
+            ),
+            None,
+        )
+        .into());
+    }
+}
+
 #[cfg(test)]
 mod tests {}
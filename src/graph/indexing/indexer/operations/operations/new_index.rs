@@ -1,9 +1,19 @@
-use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::SetSparseVectorElement;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::{
+    GetSparseVectorElementIndices, SetSparseVectorElement,
+};
 use graphblas_sparse_linear_algebra::collections::Collection;
+use graphblas_sparse_linear_algebra::context::GetContext;
+use graphblas_sparse_linear_algebra::index::ElementIndexSelector;
+use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+use graphblas_sparse_linear_algebra::operators::insert::{
+    InsertScalarIntoVector, InsertScalarIntoVectorOperator,
+};
+use graphblas_sparse_linear_algebra::operators::mask::SelectEntireVector;
+use graphblas_sparse_linear_algebra::operators::options::OperatorOptions;
 
 use crate::error::GraphComputingError;
 use crate::graph::indexing::indexer::indexer::GetIndexMask;
-use crate::graph::indexing::indexer::GetIndicesAvailableForReuse;
+use crate::graph::indexing::indexer::{GetIndexGenerations, GetIndicesAvailableForReuse};
 use crate::graph::indexing::{AssignedIndex, GetAssignedIndexData};
 use crate::graph::indexing::{GetIndexCapacity, Index, Queue};
 
@@ -17,7 +27,8 @@ pub(crate) fn new_index(
     indexer: &mut (impl GetIndexMask
               + GetIndexCapacity
               + SetIndexCapacity
-              + GetIndicesAvailableForReuse),
+              + GetIndicesAvailableForReuse
+              + GetIndexGenerations),
 ) -> Result<AssignedIndex, GraphComputingError> {
     let index = claim_available_index(indexer)?;
     indexer
@@ -30,7 +41,8 @@ pub(crate) fn claim_available_index(
     indexer: &mut (impl GetIndexMask
               + GetIndexCapacity
               + SetIndexCapacity
-              + GetIndicesAvailableForReuse),
+              + GetIndicesAvailableForReuse
+              + GetIndexGenerations),
 ) -> Result<AssignedIndex, GraphComputingError> {
     let is_index_reused: bool;
     let available_index = match indexer.indices_available_for_reuse_mut_ref().pop_front() {
@@ -46,12 +58,27 @@ pub(crate) fn claim_available_index(
         }
     };
 
+    let generation = if is_index_reused {
+        let generation = indexer.generation(available_index).wrapping_add(1);
+        indexer
+            .generations_mut_ref()
+            .insert(available_index, generation);
+        generation
+    } else {
+        indexer.generation(available_index)
+    };
+
     let new_index;
     if (!is_index_reused) && (available_index >= indexer.capacity()?) {
         let new_capacity = expand_capacity(indexer)?;
-        new_index = AssignedIndex::new(available_index, Some(new_capacity), is_index_reused);
+        new_index = AssignedIndex::new(
+            available_index,
+            Some(new_capacity),
+            is_index_reused,
+            generation,
+        );
     } else {
-        new_index = AssignedIndex::new(available_index, None, is_index_reused);
+        new_index = AssignedIndex::new(available_index, None, is_index_reused, generation);
     }
 
     indexer
@@ -61,6 +88,71 @@ pub(crate) fn claim_available_index(
     Ok(new_index)
 }
 
+/// Makes all indices below end valid, such that they will not be handed out for reuse.
+/// Reclaimed indices get a new generation, like reused indices do.
+/// The capacity of the indexer must be at least end. Returns the indices that were not valid before.
+pub(crate) fn claim_all_indices_below(
+    indexer: &mut (impl GetIndexMask + GetIndicesAvailableForReuse + GetIndexGenerations),
+    end: Index,
+) -> Result<Vec<Index>, GraphComputingError> {
+    let indices_to_claim = invalid_indices_below(indexer, end)?;
+    claim_indices(indexer, &indices_to_claim)?;
+    Ok(indices_to_claim)
+}
+
+/// In ascending order
+pub(crate) fn invalid_indices_below(
+    indexer: &impl GetIndexMask,
+    end: Index,
+) -> Result<Vec<Index>, GraphComputingError> {
+    let mut valid_indices = indexer
+        .mask_with_valid_indices_ref()
+        .element_indices()?
+        .into_iter()
+        .peekable();
+    let mut invalid_indices = Vec::new();
+    for index in 0..end {
+        if valid_indices.next_if_eq(&index).is_none() {
+            invalid_indices.push(index);
+        }
+    }
+    Ok(invalid_indices)
+}
+
+/// Makes the invalid indices_to_claim valid. indices_to_claim must be in ascending order.
+pub(crate) fn claim_indices(
+    indexer: &mut (impl GetIndexMask + GetIndicesAvailableForReuse + GetIndexGenerations),
+    indices_to_claim: &Vec<Index>,
+) -> Result<(), GraphComputingError> {
+    let mut reclaimed_indices = Vec::new();
+    indexer
+        .indices_available_for_reuse_mut_ref()
+        .retain(|index| {
+            if indices_to_claim.binary_search(index).is_ok() {
+                reclaimed_indices.push(*index);
+                false
+            } else {
+                true
+            }
+        });
+    for index in reclaimed_indices {
+        let generation = indexer.generation(index).wrapping_add(1);
+        indexer.generations_mut_ref().insert(index, generation);
+    }
+
+    let mask_with_valid_indices = indexer.mask_with_valid_indices_mut_ref();
+    let context = mask_with_valid_indices.context();
+    InsertScalarIntoVectorOperator::new().apply(
+        mask_with_valid_indices,
+        &ElementIndexSelector::Index(indices_to_claim),
+        true,
+        &Assignment::new(),
+        &SelectEntireVector::new(context),
+        &OperatorOptions::new_default(),
+    )?;
+    Ok(())
+}
+
 pub(crate) fn expand_capacity(
     indexer: &mut (impl GetIndexCapacity + SetIndexCapacity),
 ) -> Result<Index, GraphComputingError> {
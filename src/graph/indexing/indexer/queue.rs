@@ -55,4 +55,12 @@ impl<T> VecDequeQueue<T> {
             queue: VecDeque::new(),
         }
     }
+
+    pub(crate) fn iter(&self) -> std::collections::vec_deque::Iter<'_, T> {
+        self.queue.iter()
+    }
+
+    pub(crate) fn retain(&mut self, keep: impl FnMut(&T) -> bool) {
+        self.queue.retain(keep)
+    }
 }
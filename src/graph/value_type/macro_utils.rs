@@ -102,6 +102,51 @@ macro_rules! implement_1_type_macro_with_enum_type_indentifier_for_all_value_typ
 }
 pub(crate) use implement_1_type_macro_with_enum_type_indentifier_for_all_value_types;
 
+macro_rules! call_generic_function_for_value_type_identifier {
+    ($value_type_identifier:expr, $function:ident, ($($argument:expr),*)) => {
+        match $value_type_identifier {
+            $crate::graph::value_type::ValueTypeIdentifier::Bool => $function::<bool>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Int8 => $function::<i8>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Int16 => $function::<i16>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Int32 => $function::<i32>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Int64 => $function::<i64>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UInt8 => $function::<u8>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UInt16 => $function::<u16>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UInt32 => $function::<u32>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UInt64 => $function::<u64>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Float32 => $function::<f32>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Float64 => $function::<f64>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::ISize => $function::<isize>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::USize => $function::<usize>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UserDefined(user_defined_value_type) => Err(
+                $crate::graph::value_type::unsupported_user_defined_value_type_error(&user_defined_value_type),
+            ),
+        }
+    };
+    // The user-defined function takes the user-defined value type identifier as its first argument
+    ($value_type_identifier:expr, $function:ident, $user_defined_function:ident, ($($argument:expr),*)) => {
+        match $value_type_identifier {
+            $crate::graph::value_type::ValueTypeIdentifier::Bool => $function::<bool>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Int8 => $function::<i8>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Int16 => $function::<i16>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Int32 => $function::<i32>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Int64 => $function::<i64>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UInt8 => $function::<u8>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UInt16 => $function::<u16>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UInt32 => $function::<u32>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UInt64 => $function::<u64>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Float32 => $function::<f32>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::Float64 => $function::<f64>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::ISize => $function::<isize>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::USize => $function::<usize>($($argument),*),
+            $crate::graph::value_type::ValueTypeIdentifier::UserDefined(user_defined_value_type) => {
+                $user_defined_function(&user_defined_value_type, $($argument),*)
+            }
+        }
+    };
+}
+pub(crate) use call_generic_function_for_value_type_identifier;
+
 macro_rules! implement_1_type_macro_with_2_typed_indentifiers_for_all_value_types {
     ($macro_identifier:ident, $untyped_ident_1:ident, $untyped_ident_2:ident) => {
         paste::paste! {
@@ -1,8 +1,12 @@
 mod macro_utils;
+mod user_defined_operator;
+mod user_defined_value_type;
 mod value_type;
 mod value_type_identifier;
 
 // TODO: remove unused modules and code
 pub(crate) use macro_utils::*;
+pub use user_defined_operator::*;
+pub use user_defined_value_type::*;
 pub use value_type::*;
 pub use value_type_identifier::*;
@@ -0,0 +1,124 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use graphblas_sparse_linear_algebra::graphblas_bindings::{
+    GrB_BinaryOp, GrB_BinaryOp_free, GrB_BinaryOp_new, GrB_IndexUnaryOp, GrB_IndexUnaryOp_free,
+    GrB_IndexUnaryOp_new, GxB_binary_function, GxB_index_unary_function,
+};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    AccumulatorBinaryOperator, BinaryOperator,
+};
+use graphblas_sparse_linear_algebra::operators::index_unary_operator::IndexUnaryOperator;
+use graphblas_sparse_linear_algebra::value_type::ValueType as GraphblasValueType;
+
+use crate::error::GraphComputingError;
+
+use super::{try_graphblas_success, UserDefinedValueType};
+
+/// Binary operator z = f(x, y) on a user-defined value type.
+/// The function receives pointers to values of T.
+#[derive(Debug)]
+pub struct UserDefinedBinaryOperator<T: UserDefinedValueType> {
+    graphblas_operator: GrB_BinaryOp,
+    value_type: PhantomData<T>,
+}
+
+// The GraphBLAS operator is not mutated after creation
+unsafe impl<T: UserDefinedValueType> Send for UserDefinedBinaryOperator<T> {}
+unsafe impl<T: UserDefinedValueType> Sync for UserDefinedBinaryOperator<T> {}
+
+impl<T: UserDefinedValueType> UserDefinedBinaryOperator<T> {
+    pub fn new(function: GxB_binary_function) -> Result<Self, GraphComputingError> {
+        let mut graphblas_operator = MaybeUninit::uninit();
+        try_graphblas_success(
+            unsafe {
+                GrB_BinaryOp_new(
+                    graphblas_operator.as_mut_ptr(),
+                    function,
+                    T::to_graphblas_type(),
+                    T::to_graphblas_type(),
+                    T::to_graphblas_type(),
+                )
+            },
+            || {
+                format!(
+                    "Unable to create binary operator for {}",
+                    std::any::type_name::<T>()
+                )
+            },
+        )?;
+        Ok(Self {
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            value_type: PhantomData,
+        })
+    }
+}
+
+impl<T: UserDefinedValueType> AccumulatorBinaryOperator<T> for UserDefinedBinaryOperator<T> {
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<T: UserDefinedValueType> BinaryOperator<T> for UserDefinedBinaryOperator<T> {
+    fn graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<T: UserDefinedValueType> Drop for UserDefinedBinaryOperator<T> {
+    fn drop(&mut self) {
+        unsafe { GrB_BinaryOp_free(&mut self.graphblas_operator) };
+    }
+}
+
+/// Index unary operator z = f(x, row_index, column_index, y) on a user-defined value type, for select.
+/// The function receives pointers to values of T and writes a bool to z.
+#[derive(Debug)]
+pub struct UserDefinedIndexUnaryOperator<T: UserDefinedValueType> {
+    graphblas_operator: GrB_IndexUnaryOp,
+    value_type: PhantomData<T>,
+}
+
+// The GraphBLAS operator is not mutated after creation
+unsafe impl<T: UserDefinedValueType> Send for UserDefinedIndexUnaryOperator<T> {}
+unsafe impl<T: UserDefinedValueType> Sync for UserDefinedIndexUnaryOperator<T> {}
+
+impl<T: UserDefinedValueType> UserDefinedIndexUnaryOperator<T> {
+    pub fn new(function: GxB_index_unary_function) -> Result<Self, GraphComputingError> {
+        let mut graphblas_operator = MaybeUninit::uninit();
+        try_graphblas_success(
+            unsafe {
+                GrB_IndexUnaryOp_new(
+                    graphblas_operator.as_mut_ptr(),
+                    function,
+                    bool::to_graphblas_type(),
+                    T::to_graphblas_type(),
+                    T::to_graphblas_type(),
+                )
+            },
+            || {
+                format!(
+                    "Unable to create index unary operator for {}",
+                    std::any::type_name::<T>()
+                )
+            },
+        )?;
+        Ok(Self {
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            value_type: PhantomData,
+        })
+    }
+}
+
+impl<T: UserDefinedValueType> IndexUnaryOperator<T> for UserDefinedIndexUnaryOperator<T> {
+    fn graphblas_type(&self) -> GrB_IndexUnaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<T: UserDefinedValueType> Drop for UserDefinedIndexUnaryOperator<T> {
+    fn drop(&mut self) {
+        unsafe { GrB_IndexUnaryOp_free(&mut self.graphblas_operator) };
+    }
+}
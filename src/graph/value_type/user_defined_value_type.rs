@@ -0,0 +1,713 @@
+use std::any::{type_name, TypeId};
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::mem::{size_of, MaybeUninit};
+use std::ptr::{self, addr_of_mut};
+use std::sync::Mutex;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    ColumnIndex, GetGraphblasSparseMatrix, RowIndex,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector;
+use graphblas_sparse_linear_algebra::context::{CallGraphBlasContext, Context};
+use graphblas_sparse_linear_algebra::error::{
+    GraphblasErrorType, LogicError as GraphblasLogicError,
+    LogicErrorType as GraphblasLogicErrorType, SparseLinearAlgebraError,
+    SparseLinearAlgebraErrorType,
+};
+use graphblas_sparse_linear_algebra::graphblas_bindings::{
+    GrB_Index, GrB_Info, GrB_Info_GrB_SUCCESS, GrB_Matrix_build_UDT, GrB_Matrix_extractElement_UDT,
+    GrB_Matrix_extractTuples_UDT, GrB_Matrix_nvals, GrB_Matrix_select_Scalar,
+    GrB_Matrix_setElement_UDT, GrB_Scalar, GrB_Scalar_free, GrB_Scalar_new,
+    GrB_Scalar_setElement_UDT, GrB_Type, GrB_Type_new, GrB_Vector_build_UDT,
+    GrB_Vector_extractElement_UDT, GrB_Vector_extractTuples_UDT, GrB_Vector_nvals,
+    GrB_Vector_select_Scalar, GrB_Vector_setElement_UDT, GxB_Matrix_type, GxB_Vector_type,
+};
+use graphblas_sparse_linear_algebra::index::{ElementIndex, IndexConversion};
+use graphblas_sparse_linear_algebra::operators::binary_operator::AccumulatorBinaryOperator;
+use graphblas_sparse_linear_algebra::operators::index_unary_operator::IndexUnaryOperator;
+use graphblas_sparse_linear_algebra::operators::mask::{MatrixMask, VectorMask};
+use graphblas_sparse_linear_algebra::operators::options::{
+    GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument,
+};
+use once_cell::sync::Lazy;
+
+use crate::error::{GraphComputingError, LogicError, LogicErrorType};
+
+use super::{GetValueTypeIdentifier, UserDefinedValueTypeIdentifier, ValueType};
+
+/// A value type that is stored in GraphBLAS as an opaque user-defined type.
+/// Implement it with implement_user_defined_value_type!(unsafe MyType)
+///
+/// # Safety
+/// Values are copied bytewise into and out of GraphBLAS.
+/// The type must therefore not own memory through pointers or references.
+pub unsafe trait UserDefinedValueType:
+    ValueType + GetValueTypeIdentifier + Copy + Default + Send + Sync + 'static
+{
+}
+
+/// Implements all traits required to store values of a plain-data type in a graph.
+/// The type must implement Copy, Debug and Default, and should be #[repr(C)].
+/// Operators on values of the type must be supplied by the user,
+/// for example as UserDefinedBinaryOperator and UserDefinedIndexUnaryOperator.
+///
+/// # Safety
+/// The macro implements the unsafe trait UserDefinedValueType, so the invocation must start with unsafe,
+/// as in implement_user_defined_value_type!(unsafe MyType).
+/// Writing unsafe asserts that the type does not own memory through pointers or references.
+#[macro_export]
+macro_rules! implement_user_defined_value_type {
+    (unsafe $value_type:ty) => {
+        impl $crate::graphblas_sparse_linear_algebra::value_type::ValueType for $value_type {
+            fn to_graphblas_type(
+            ) -> $crate::graphblas_sparse_linear_algebra::graphblas_bindings::GrB_Type {
+                // GraphBLAS rejects the null type if the registration fails
+                $crate::graph::value_type::registered_graphblas_type::<$value_type>()
+                    .unwrap_or(::std::ptr::null_mut())
+            }
+        }
+
+        impl $crate::graph::value_type::ValueType for $value_type {}
+
+        impl $crate::graph::value_type::GetValueTypeIdentifier for $value_type {
+            fn value_type_identifier() -> $crate::graph::value_type::ValueTypeIdentifier {
+                $crate::graph::value_type::ValueTypeIdentifier::UserDefined(
+                    $crate::graph::value_type::UserDefinedValueTypeIdentifier::of::<$value_type>(),
+                )
+            }
+        }
+
+        unsafe impl $crate::graph::value_type::UserDefinedValueType for $value_type {}
+
+        impl
+            $crate::graphblas_sparse_linear_algebra::collections::sparse_vector::operations::SetSparseVectorElementTyped<$value_type>
+            for $value_type
+        {
+            fn set_graphblas_vector_element(
+                vector: &mut impl $crate::graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector,
+                element: impl $crate::graphblas_sparse_linear_algebra::collections::sparse_vector::GetVectorElementIndex
+                    + $crate::graphblas_sparse_linear_algebra::collections::sparse_vector::GetVectorElementValue<$value_type>,
+            ) -> Result<(), $crate::graphblas_sparse_linear_algebra::error::SparseLinearAlgebraError> {
+                $crate::graph::value_type::set_user_defined_vector_value(
+                    vector,
+                    element.index(),
+                    element.value(),
+                )
+            }
+
+            fn set_graphblas_vector_value(
+                vector: &mut impl $crate::graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector,
+                index: $crate::graphblas_sparse_linear_algebra::index::ElementIndex,
+                value: $value_type,
+            ) -> Result<(), $crate::graphblas_sparse_linear_algebra::error::SparseLinearAlgebraError> {
+                $crate::graph::value_type::set_user_defined_vector_value(vector, index, value)
+            }
+        }
+
+        impl
+            $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::SetSparseMatrixElementTyped<$value_type>
+            for $value_type
+        {
+            fn set_graphblas_matrix_value(
+                matrix: &mut impl $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::GetGraphblasSparseMatrix,
+                row_index: $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::RowIndex,
+                column_index: $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::ColumnIndex,
+                value: $value_type,
+            ) -> Result<(), $crate::graphblas_sparse_linear_algebra::error::SparseLinearAlgebraError> {
+                $crate::graph::value_type::set_user_defined_matrix_value(
+                    matrix,
+                    row_index,
+                    column_index,
+                    value,
+                )
+            }
+
+            fn set_graphblas_matrix_element(
+                matrix: &mut impl $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::GetGraphblasSparseMatrix,
+                element: impl $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::GetMatrixElementCoordinate
+                    + $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::GetMatrixElementValue<$value_type>,
+            ) -> Result<(), $crate::graphblas_sparse_linear_algebra::error::SparseLinearAlgebraError> {
+                $crate::graph::value_type::set_user_defined_matrix_value(
+                    matrix,
+                    element.row_index(),
+                    element.column_index(),
+                    element.value(),
+                )
+            }
+        }
+
+        impl
+            $crate::graphblas_sparse_linear_algebra::operators::select::SelectFromVector<$value_type>
+            for $crate::graphblas_sparse_linear_algebra::operators::select::VectorSelector
+        {
+            fn apply(
+                &self,
+                selector: &impl $crate::graphblas_sparse_linear_algebra::operators::index_unary_operator::IndexUnaryOperator<$value_type>,
+                selector_argument: $value_type,
+                argument: &impl $crate::graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector,
+                accumulator: &impl $crate::graphblas_sparse_linear_algebra::operators::binary_operator::AccumulatorBinaryOperator<$value_type>,
+                product: &mut impl $crate::graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector,
+                mask: &impl $crate::graphblas_sparse_linear_algebra::operators::mask::VectorMask,
+                options: &impl $crate::graphblas_sparse_linear_algebra::operators::options::GetOperatorOptions,
+            ) -> Result<(), $crate::graphblas_sparse_linear_algebra::error::SparseLinearAlgebraError> {
+                $crate::graph::value_type::select_from_vector_with_user_defined_argument(
+                    selector,
+                    selector_argument,
+                    argument,
+                    accumulator,
+                    product,
+                    mask,
+                    options,
+                )
+            }
+        }
+
+        impl
+            $crate::graphblas_sparse_linear_algebra::operators::select::SelectFromMatrix<$value_type>
+            for $crate::graphblas_sparse_linear_algebra::operators::select::MatrixSelector
+        {
+            fn apply(
+                &self,
+                selector: &impl $crate::graphblas_sparse_linear_algebra::operators::index_unary_operator::IndexUnaryOperator<$value_type>,
+                selector_argument: $value_type,
+                argument: &impl $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::GetGraphblasSparseMatrix,
+                accumulator: &impl $crate::graphblas_sparse_linear_algebra::operators::binary_operator::AccumulatorBinaryOperator<$value_type>,
+                product: &mut impl $crate::graphblas_sparse_linear_algebra::collections::sparse_matrix::GetGraphblasSparseMatrix,
+                mask: &impl $crate::graphblas_sparse_linear_algebra::operators::mask::MatrixMask,
+                options: &impl $crate::graphblas_sparse_linear_algebra::operators::options::GetOptionsForOperatorWithMatrixArgument,
+            ) -> Result<(), $crate::graphblas_sparse_linear_algebra::error::SparseLinearAlgebraError> {
+                $crate::graph::value_type::select_from_matrix_with_user_defined_argument(
+                    selector,
+                    selector_argument,
+                    argument,
+                    accumulator,
+                    product,
+                    mask,
+                    options,
+                )
+            }
+        }
+    };
+}
+
+struct RegisteredGraphblasType {
+    graphblas_type: GrB_Type,
+    value_type: UserDefinedValueTypeIdentifier,
+}
+
+// GraphBLAS types are immutable after creation
+unsafe impl Send for RegisteredGraphblasType {}
+
+static REGISTERED_GRAPHBLAS_TYPES: Lazy<Mutex<HashMap<TypeId, RegisteredGraphblasType>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Registers T with GraphBLAS on first use.
+/// Fails if GraphBLAS cannot create the type, e.g. because no graph has initialized GraphBLAS yet.
+pub fn registered_graphblas_type<T: 'static>() -> Result<GrB_Type, GraphComputingError> {
+    let mut registered_types = REGISTERED_GRAPHBLAS_TYPES.lock()?;
+    if let Some(registered_type) = registered_types.get(&TypeId::of::<T>()) {
+        return Ok(registered_type.graphblas_type);
+    }
+
+    let mut graphblas_type = MaybeUninit::uninit();
+    try_graphblas_success(
+        unsafe { GrB_Type_new(graphblas_type.as_mut_ptr(), size_of::<T>()) },
+        || {
+            format!(
+                "Unable to register user-defined value type {} with GraphBLAS",
+                type_name::<T>()
+            )
+        },
+    )?;
+    let graphblas_type = unsafe { graphblas_type.assume_init() };
+    registered_types.insert(
+        TypeId::of::<T>(),
+        RegisteredGraphblasType {
+            graphblas_type,
+            value_type: UserDefinedValueTypeIdentifier::of::<T>(),
+        },
+    );
+    Ok(graphblas_type)
+}
+
+pub(crate) fn user_defined_graphblas_type(
+    value_type: &UserDefinedValueTypeIdentifier,
+) -> Result<GrB_Type, GraphComputingError> {
+    match REGISTERED_GRAPHBLAS_TYPES
+        .lock()?
+        .get(&value_type.type_id())
+    {
+        Some(registered_type) => Ok(registered_type.graphblas_type),
+        None => Err(unregistered_user_defined_value_type_error(
+            value_type.type_name(),
+        )),
+    }
+}
+
+/// Finds a registered user-defined value type by its type name, e.g. to decode a snapshot.
+/// The type must have been registered by this process, and have the given size.
+pub(crate) fn registered_user_defined_value_type(
+    type_name: &str,
+    size: usize,
+) -> Result<UserDefinedValueTypeIdentifier, GraphComputingError> {
+    let registered_types = REGISTERED_GRAPHBLAS_TYPES.lock()?;
+    let value_type = registered_types
+        .values()
+        .map(|registered_type| registered_type.value_type)
+        .find(|value_type| value_type.type_name() == type_name)
+        .ok_or_else(|| unregistered_user_defined_value_type_error(type_name))?;
+    if value_type.size() == size {
+        Ok(value_type)
+    } else {
+        Err(LogicError::new(
+            LogicErrorType::UnsupportedValueType,
+            format!(
+                "The user-defined value type {} has a size of {} bytes, not {} bytes",
+                type_name,
+                value_type.size(),
+                size
+            ),
+            None,
+        )
+        .into())
+    }
+}
+
+fn unregistered_user_defined_value_type_error(type_name: &str) -> GraphComputingError {
+    LogicError::new(
+        LogicErrorType::UnsupportedValueType,
+        format!(
+            "The user-defined value type {} is not registered with GraphBLAS",
+            type_name
+        ),
+        None,
+    )
+    .into()
+}
+
+pub fn set_user_defined_vector_value<T: UserDefinedValueType>(
+    vector: &mut impl GetGraphblasSparseVector,
+    index: ElementIndex,
+    mut value: T,
+) -> Result<(), SparseLinearAlgebraError> {
+    try_vector_value_type::<T>(vector)?;
+    let index_to_set = index.as_graphblas_index()?;
+    vector.context_ref().call(
+        || unsafe {
+            GrB_Vector_setElement_UDT(
+                vector.graphblas_vector(),
+                addr_of_mut!(value).cast::<c_void>(),
+                index_to_set,
+            )
+        },
+        unsafe { &vector.graphblas_vector() },
+    )?;
+    Ok(())
+}
+
+pub fn user_defined_vector_element_value<T: UserDefinedValueType>(
+    vector: &impl GetGraphblasSparseVector,
+    index: ElementIndex,
+) -> Result<Option<T>, SparseLinearAlgebraError> {
+    try_vector_value_type::<T>(vector)?;
+    let index_to_get = index.as_graphblas_index()?;
+    let mut value = MaybeUninit::<T>::uninit();
+    let result = vector.context_ref().call(
+        || unsafe {
+            GrB_Vector_extractElement_UDT(
+                value.as_mut_ptr().cast::<c_void>(),
+                vector.graphblas_vector(),
+                index_to_get,
+            )
+        },
+        unsafe { &vector.graphblas_vector() },
+    );
+    optional_element_value(result, value)
+}
+
+pub fn set_user_defined_matrix_value<T: UserDefinedValueType>(
+    matrix: &mut impl GetGraphblasSparseMatrix,
+    row_index: RowIndex,
+    column_index: ColumnIndex,
+    mut value: T,
+) -> Result<(), SparseLinearAlgebraError> {
+    try_matrix_value_type::<T>(matrix)?;
+    let row_index_to_set = row_index.as_graphblas_index()?;
+    let column_index_to_set = column_index.as_graphblas_index()?;
+    matrix.context_ref().call(
+        || unsafe {
+            GrB_Matrix_setElement_UDT(
+                matrix.graphblas_matrix(),
+                addr_of_mut!(value).cast::<c_void>(),
+                row_index_to_set,
+                column_index_to_set,
+            )
+        },
+        unsafe { &matrix.graphblas_matrix() },
+    )?;
+    Ok(())
+}
+
+pub fn user_defined_matrix_element_value<T: UserDefinedValueType>(
+    matrix: &impl GetGraphblasSparseMatrix,
+    row_index: RowIndex,
+    column_index: ColumnIndex,
+) -> Result<Option<T>, SparseLinearAlgebraError> {
+    try_matrix_value_type::<T>(matrix)?;
+    let row_index_to_get = row_index.as_graphblas_index()?;
+    let column_index_to_get = column_index.as_graphblas_index()?;
+    let mut value = MaybeUninit::<T>::uninit();
+    let result = matrix.context_ref().call(
+        || unsafe {
+            GrB_Matrix_extractElement_UDT(
+                value.as_mut_ptr().cast::<c_void>(),
+                matrix.graphblas_matrix(),
+                row_index_to_get,
+                column_index_to_get,
+            )
+        },
+        unsafe { &matrix.graphblas_matrix() },
+    );
+    optional_element_value(result, value)
+}
+
+pub fn select_from_vector_with_user_defined_argument<T: UserDefinedValueType>(
+    selector: &impl IndexUnaryOperator<T>,
+    selector_argument: T,
+    argument: &impl GetGraphblasSparseVector,
+    accumulator: &impl AccumulatorBinaryOperator<T>,
+    product: &mut impl GetGraphblasSparseVector,
+    mask: &impl VectorMask,
+    options: &impl GetOperatorOptions,
+) -> Result<(), SparseLinearAlgebraError> {
+    let selector_argument = UserDefinedScalar::new(argument.context_ref(), selector_argument)?;
+    argument.context_ref().call(
+        || unsafe {
+            GrB_Vector_select_Scalar(
+                product.graphblas_vector(),
+                mask.graphblas_vector(),
+                accumulator.accumulator_graphblas_type(),
+                selector.graphblas_type(),
+                argument.graphblas_vector(),
+                selector_argument.graphblas_scalar,
+                options.graphblas_descriptor(),
+            )
+        },
+        unsafe { product.graphblas_vector_ref() },
+    )?;
+    Ok(())
+}
+
+pub fn select_from_matrix_with_user_defined_argument<T: UserDefinedValueType>(
+    selector: &impl IndexUnaryOperator<T>,
+    selector_argument: T,
+    argument: &impl GetGraphblasSparseMatrix,
+    accumulator: &impl AccumulatorBinaryOperator<T>,
+    product: &mut impl GetGraphblasSparseMatrix,
+    mask: &impl MatrixMask,
+    options: &impl GetOptionsForOperatorWithMatrixArgument,
+) -> Result<(), SparseLinearAlgebraError> {
+    let selector_argument = UserDefinedScalar::new(argument.context_ref(), selector_argument)?;
+    argument.context_ref().call(
+        || unsafe {
+            GrB_Matrix_select_Scalar(
+                product.graphblas_matrix(),
+                mask.graphblas_matrix(),
+                accumulator.accumulator_graphblas_type(),
+                selector.graphblas_type(),
+                argument.graphblas_matrix(),
+                selector_argument.graphblas_scalar,
+                options.graphblas_descriptor(),
+            )
+        },
+        unsafe { product.graphblas_matrix_ref() },
+    )?;
+    Ok(())
+}
+
+/// Returns the indices and the bytes of the values of all stored elements of a vector with a user-defined value type.
+pub(crate) fn user_defined_vector_elements(
+    vector: &impl GetGraphblasSparseVector,
+    value_type: &UserDefinedValueTypeIdentifier,
+) -> Result<(Vec<ElementIndex>, Vec<u8>), GraphComputingError> {
+    let mut number_of_values: GrB_Index = 0;
+    vector.context_ref().call(
+        || unsafe { GrB_Vector_nvals(&mut number_of_values, vector.graphblas_vector()) },
+        unsafe { vector.graphblas_vector_ref() },
+    )?;
+
+    let mut indices: Vec<GrB_Index> = vec![0; number_of_values as usize];
+    let mut values = vec![0u8; number_of_values as usize * value_type.size()];
+    vector.context_ref().call(
+        || unsafe {
+            GrB_Vector_extractTuples_UDT(
+                indices.as_mut_ptr(),
+                values.as_mut_ptr().cast::<c_void>(),
+                &mut number_of_values,
+                vector.graphblas_vector(),
+            )
+        },
+        unsafe { vector.graphblas_vector_ref() },
+    )?;
+
+    Ok((element_indices(indices)?, values))
+}
+
+/// Stores elements in an empty vector with a user-defined value type, from the bytes of their values.
+pub(crate) fn build_user_defined_vector(
+    vector: &mut impl GetGraphblasSparseVector,
+    value_type: &UserDefinedValueTypeIdentifier,
+    indices: &[ElementIndex],
+    values: &[u8],
+) -> Result<(), GraphComputingError> {
+    try_number_of_value_bytes(value_type, indices.len(), values.len())?;
+    let indices = graphblas_indices(indices)?;
+    vector.context_ref().call(
+        || unsafe {
+            GrB_Vector_build_UDT(
+                vector.graphblas_vector(),
+                indices.as_ptr(),
+                values.as_ptr().cast::<c_void>(),
+                indices.len() as GrB_Index,
+                ptr::null_mut(),
+            )
+        },
+        unsafe { vector.graphblas_vector_ref() },
+    )?;
+    Ok(())
+}
+
+/// Returns the row indices, the column indices and the bytes of the values of all stored elements of a matrix with a user-defined value type.
+pub(crate) fn user_defined_matrix_elements(
+    matrix: &impl GetGraphblasSparseMatrix,
+    value_type: &UserDefinedValueTypeIdentifier,
+) -> Result<(Vec<RowIndex>, Vec<ColumnIndex>, Vec<u8>), GraphComputingError> {
+    let mut number_of_values: GrB_Index = 0;
+    matrix.context_ref().call(
+        || unsafe { GrB_Matrix_nvals(&mut number_of_values, matrix.graphblas_matrix()) },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+
+    let mut row_indices: Vec<GrB_Index> = vec![0; number_of_values as usize];
+    let mut column_indices: Vec<GrB_Index> = vec![0; number_of_values as usize];
+    let mut values = vec![0u8; number_of_values as usize * value_type.size()];
+    matrix.context_ref().call(
+        || unsafe {
+            GrB_Matrix_extractTuples_UDT(
+                row_indices.as_mut_ptr(),
+                column_indices.as_mut_ptr(),
+                values.as_mut_ptr().cast::<c_void>(),
+                &mut number_of_values,
+                matrix.graphblas_matrix(),
+            )
+        },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+
+    Ok((
+        element_indices(row_indices)?,
+        element_indices(column_indices)?,
+        values,
+    ))
+}
+
+/// Stores elements in an empty matrix with a user-defined value type, from the bytes of their values.
+pub(crate) fn build_user_defined_matrix(
+    matrix: &mut impl GetGraphblasSparseMatrix,
+    value_type: &UserDefinedValueTypeIdentifier,
+    row_indices: &[RowIndex],
+    column_indices: &[ColumnIndex],
+    values: &[u8],
+) -> Result<(), GraphComputingError> {
+    if row_indices.len() != column_indices.len() {
+        return Err(LogicError::new(
+            LogicErrorType::Other,
+            format!(
+                "The number of row indices {} does not match the number of column indices {}",
+                row_indices.len(),
+                column_indices.len()
+            ),
+            None,
+        )
+        .into());
+    }
+    try_number_of_value_bytes(value_type, row_indices.len(), values.len())?;
+    let row_indices = graphblas_indices(row_indices)?;
+    let column_indices = graphblas_indices(column_indices)?;
+    matrix.context_ref().call(
+        || unsafe {
+            GrB_Matrix_build_UDT(
+                matrix.graphblas_matrix(),
+                row_indices.as_ptr(),
+                column_indices.as_ptr(),
+                values.as_ptr().cast::<c_void>(),
+                row_indices.len() as GrB_Index,
+                ptr::null_mut(),
+            )
+        },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(())
+}
+
+pub(crate) fn unsupported_user_defined_value_type_error(
+    value_type: &UserDefinedValueTypeIdentifier,
+) -> GraphComputingError {
+    LogicError::new(
+        LogicErrorType::UnsupportedValueType,
+        format!(
+            "This operation does not support the user-defined value type {}",
+            value_type.type_name()
+        ),
+        None,
+    )
+    .into()
+}
+
+pub(crate) fn try_graphblas_success(
+    status: GrB_Info,
+    explanation: impl FnOnce() -> String,
+) -> Result<(), GraphComputingError> {
+    if status == GrB_Info_GrB_SUCCESS {
+        Ok(())
+    } else {
+        Err(LogicError::new(
+            LogicErrorType::Other,
+            format!("{}, GraphBLAS status: {}", explanation(), status),
+            None,
+        )
+        .into())
+    }
+}
+
+fn try_number_of_value_bytes(
+    value_type: &UserDefinedValueTypeIdentifier,
+    number_of_values: usize,
+    number_of_bytes: usize,
+) -> Result<(), GraphComputingError> {
+    if number_of_values * value_type.size() == number_of_bytes {
+        Ok(())
+    } else {
+        Err(LogicError::new(
+            LogicErrorType::Other,
+            format!(
+                "{} bytes cannot hold {} values of the user-defined value type {}",
+                number_of_bytes,
+                number_of_values,
+                value_type.type_name()
+            ),
+            None,
+        )
+        .into())
+    }
+}
+
+fn element_indices(indices: Vec<GrB_Index>) -> Result<Vec<ElementIndex>, GraphComputingError> {
+    Ok(indices
+        .into_iter()
+        .map(ElementIndex::from_graphblas_index)
+        .collect::<Result<Vec<ElementIndex>, SparseLinearAlgebraError>>()?)
+}
+
+pub(crate) fn graphblas_indices(
+    indices: &[ElementIndex],
+) -> Result<Vec<GrB_Index>, GraphComputingError> {
+    Ok(indices
+        .iter()
+        .map(IndexConversion::to_graphblas_index)
+        .collect::<Result<Vec<GrB_Index>, SparseLinearAlgebraError>>()?)
+}
+
+struct UserDefinedScalar {
+    graphblas_scalar: GrB_Scalar,
+}
+
+impl UserDefinedScalar {
+    fn new<T: UserDefinedValueType>(
+        context: &Context,
+        mut value: T,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_scalar = MaybeUninit::uninit();
+        let uninitialized_scalar: GrB_Scalar = ptr::null_mut();
+        context.call(
+            || unsafe { GrB_Scalar_new(graphblas_scalar.as_mut_ptr(), T::to_graphblas_type()) },
+            &uninitialized_scalar,
+        )?;
+        let scalar = Self {
+            graphblas_scalar: unsafe { graphblas_scalar.assume_init() },
+        };
+        context.call(
+            || unsafe {
+                GrB_Scalar_setElement_UDT(
+                    scalar.graphblas_scalar,
+                    addr_of_mut!(value).cast::<c_void>(),
+                )
+            },
+            &scalar.graphblas_scalar,
+        )?;
+        Ok(scalar)
+    }
+}
+
+impl Drop for UserDefinedScalar {
+    fn drop(&mut self) {
+        unsafe { GrB_Scalar_free(&mut self.graphblas_scalar) };
+    }
+}
+
+fn try_vector_value_type<T: UserDefinedValueType>(
+    vector: &impl GetGraphblasSparseVector,
+) -> Result<(), SparseLinearAlgebraError> {
+    let mut graphblas_type = MaybeUninit::uninit();
+    vector.context_ref().call(
+        || unsafe { GxB_Vector_type(graphblas_type.as_mut_ptr(), vector.graphblas_vector()) },
+        unsafe { &vector.graphblas_vector() },
+    )?;
+    try_matching_graphblas_type::<T>(unsafe { graphblas_type.assume_init() })
+}
+
+fn try_matrix_value_type<T: UserDefinedValueType>(
+    matrix: &impl GetGraphblasSparseMatrix,
+) -> Result<(), SparseLinearAlgebraError> {
+    let mut graphblas_type = MaybeUninit::uninit();
+    matrix.context_ref().call(
+        || unsafe { GxB_Matrix_type(graphblas_type.as_mut_ptr(), matrix.graphblas_matrix()) },
+        unsafe { &matrix.graphblas_matrix() },
+    )?;
+    try_matching_graphblas_type::<T>(unsafe { graphblas_type.assume_init() })
+}
+
+// GraphBLAS does not distinguish between user-defined types when copying values
+fn try_matching_graphblas_type<T: UserDefinedValueType>(
+    graphblas_type: GrB_Type,
+) -> Result<(), SparseLinearAlgebraError> {
+    if graphblas_type == T::to_graphblas_type() {
+        Ok(())
+    } else {
+        Err(GraphblasLogicError::new(
+            GraphblasLogicErrorType::UnsafeTypeConversion,
+            format!(
+                "The value type of the GraphBLAS collection is not {}",
+                type_name::<T>()
+            ),
+            None,
+        )
+        .into())
+    }
+}
+
+fn optional_element_value<T, S>(
+    result: Result<S, SparseLinearAlgebraError>,
+    value: MaybeUninit<T>,
+) -> Result<Option<T>, SparseLinearAlgebraError> {
+    match result {
+        Ok(_) => Ok(Some(unsafe { value.assume_init() })),
+        Err(error) => match error.error_type() {
+            SparseLinearAlgebraErrorType::LogicErrorType(GraphblasLogicErrorType::GraphBlas(
+                GraphblasErrorType::NoValue,
+            )) => Ok(None),
+            _ => Err(error),
+        },
+    }
+}
@@ -1,8 +1,11 @@
+use std::any::{type_name, TypeId};
+use std::mem::size_of;
+
 use graphblas_sparse_linear_algebra::value_type::ValueType;
 
 use super::implement_1_type_macro_with_enum_type_indentifier_for_all_value_types;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum ValueTypeIdentifier {
     Bool,
@@ -18,6 +21,37 @@ pub enum ValueTypeIdentifier {
     Float64,
     ISize,
     USize,
+    UserDefined(UserDefinedValueTypeIdentifier),
+}
+
+/// Identifies a value type registered with implement_user_defined_value_type!
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UserDefinedValueTypeIdentifier {
+    type_id: TypeId,
+    type_name: &'static str,
+    size: usize,
+}
+
+impl UserDefinedValueTypeIdentifier {
+    pub fn of<T: 'static>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            size: size_of::<T>(),
+        }
+    }
+
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
 }
 
 pub trait GetValueTypeIdentifierRef {
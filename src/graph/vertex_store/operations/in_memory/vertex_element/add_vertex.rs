@@ -10,7 +10,7 @@ use crate::graph::value_type::ValueType;
 use crate::graph::vertex_store::operations::vertex_element::AddVertex;
 use crate::graph::vertex_store::operations::vertex_element::CreateVertexIndex;
 use crate::graph::vertex_store::operations::vertex_element::SetVertex;
-use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::graph::vertex_store::operations::vertex_type::vertex_vector_mut_ref_to_write_element_unchecked;
 use crate::graph::vertex_store::vertex_store::VertexStore;
 use crate::graph::vertex_store::GetVertexElementIndexer;
 use crate::graph::vertex_store::GetVertexTypeIndexer;
@@ -47,7 +47,8 @@ where
         value: T,
     ) -> Result<AssignedIndex, GraphComputingError> {
         let vertex_index = self.new_vertex_index()?;
-        let vertex_vector: &mut VertexVector = self.vertex_vector_mut_ref_unchecked(type_index)?;
+        let vertex_vector: &mut VertexVector =
+            vertex_vector_mut_ref_to_write_element_unchecked(self, type_index);
         T::set_graphblas_vector_value(vertex_vector, vertex_index.index(), value)?;
         Ok(vertex_index)
     }
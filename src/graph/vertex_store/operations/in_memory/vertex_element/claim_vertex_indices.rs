@@ -0,0 +1,22 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::operations::claim_all_indices_below;
+use crate::graph::indexing::{ElementCount, GetIndexGenerations, VertexIndex};
+use crate::graph::vertex_store::operations::vertex_element::ClaimVertexIndices;
+use crate::graph::vertex_store::{GetVertexElementIndexer, VertexStore};
+
+impl ClaimVertexIndices for VertexStore {
+    fn claim_vertex_indices_below(
+        &mut self,
+        end: ElementCount,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError> {
+        let claimed_indices = claim_all_indices_below(self.element_indexer_mut_ref(), end)?;
+
+        let element_indexer = self.element_indexer_ref();
+        Ok(claimed_indices
+            .into_iter()
+            .map(|index| {
+                VertexIndex::with_generation(index, Some(element_indexer.generation(index)))
+            })
+            .collect())
+    }
+}
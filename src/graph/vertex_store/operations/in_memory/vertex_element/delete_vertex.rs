@@ -6,7 +6,7 @@ use crate::graph::indexing::{operations::FreeIndex, GetVertexIndexIndex, GetVert
 use crate::graph::vertex_store::operations::vertex_element::{
     DeleteVertexForAllTypes, DeleteVertexValue,
 };
-use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::graph::vertex_store::operations::vertex_type::vertex_vector_mut_ref_to_write_element_unchecked;
 use crate::graph::vertex_store::{
     GetVertexElementIndexer, GetVertexTypeIndexer, VertexStore, VertexVector,
 };
@@ -27,7 +27,8 @@ impl DeleteVertexValue for VertexStore {
         vertex_type_index: &impl GetVertexTypeIndex,
         vertex_index: &impl GetVertexIndexIndex,
     ) -> Result<(), GraphComputingError> {
-        let vertex_vector = self.vertex_vector_mut_ref_unchecked(vertex_type_index)?;
+        let vertex_vector =
+            vertex_vector_mut_ref_to_write_element_unchecked(self, vertex_type_index);
         drop_sparse_vector_element(vertex_vector, *vertex_index.index_ref())?;
         Ok(())
     }
@@ -8,7 +8,8 @@ use crate::error::{LogicError, LogicErrorType};
 use crate::graph::indexing::operations::CheckIndex;
 use crate::graph::indexing::{GetVertexIndexIndex, GetVertexTypeIndex};
 use crate::graph::value_type::{
-    GetValueTypeIdentifierRef, IntoValueType, ValueType, ValueTypeIdentifier,
+    unsupported_user_defined_value_type_error, GetValueTypeIdentifierRef, IntoValueType, ValueType,
+    ValueTypeIdentifier,
 };
 
 use crate::graph::vertex_store::operations::vertex_element::GetVertexValue;
@@ -118,6 +119,9 @@ where
             &ValueTypeIdentifier::USize => unsafe {
                 get_vector_element_value::<usize, T>(sparse_vertex_vector, vertex_index)
             },
+            &ValueTypeIdentifier::UserDefined(user_defined_value_type) => Err(
+                unsupported_user_defined_value_type_error(&user_defined_value_type),
+            ),
         }
     }
 
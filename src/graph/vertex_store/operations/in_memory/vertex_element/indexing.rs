@@ -10,16 +10,18 @@ impl CheckVertexIndex for VertexStore {
         &self,
         vertex_index: &impl GetVertexIndexIndex,
     ) -> Result<bool, GraphComputingError> {
-        self.element_indexer_ref()
-            .is_valid_index(vertex_index.index())
+        let indexer = self.element_indexer_ref();
+        Ok(indexer.is_valid_index(vertex_index.index())?
+            && indexer.is_current_generation(vertex_index.index(), vertex_index.generation())?)
     }
 
     fn try_vertex_index_validity(
         &self,
         vertex_index: &impl GetVertexIndexIndex,
     ) -> Result<(), GraphComputingError> {
-        self.element_indexer_ref()
-            .try_index_validity(vertex_index.index())
+        let indexer = self.element_indexer_ref();
+        indexer.try_index_validity(vertex_index.index())?;
+        indexer.try_index_generation(vertex_index.index(), vertex_index.generation())
     }
 
     fn is_valid_vertex_element(
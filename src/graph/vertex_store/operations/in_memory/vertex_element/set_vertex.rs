@@ -7,8 +7,8 @@ use crate::graph::indexing::GetVertexTypeIndex;
 use crate::graph::value_type::ValueType;
 use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
 use crate::graph::vertex_store::operations::vertex_element::SetVertex;
+use crate::graph::vertex_store::operations::vertex_type::vertex_vector_mut_ref_to_write_element_unchecked;
 use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
-use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
 use crate::graph::vertex_store::vertex_store::VertexStore;
 
 impl<T> SetVertex<T> for VertexStore
@@ -45,7 +45,8 @@ where
         vertex_index: &impl GetVertexIndexIndex,
         value: T,
     ) -> Result<(), GraphComputingError> {
-        let vertex_vector = self.vertex_vector_mut_ref_unchecked(vertex_type_index)?;
+        let vertex_vector =
+            vertex_vector_mut_ref_to_write_element_unchecked(self, vertex_type_index);
         T::set_graphblas_vector_value(vertex_vector, vertex_index.index(), value)?;
         Ok(())
     }
@@ -8,16 +8,19 @@ impl CheckVertexTypeIndex for VertexStore {
         &self,
         vertex_type_index: &impl GetVertexTypeIndex,
     ) -> Result<bool, GraphComputingError> {
-        self.vertex_type_indexer_ref()
-            .is_valid_index(vertex_type_index.index())
+        let indexer = self.vertex_type_indexer_ref();
+        Ok(indexer.is_valid_index(vertex_type_index.index())?
+            && indexer
+                .is_current_generation(vertex_type_index.index(), vertex_type_index.generation())?)
     }
 
     fn try_vertex_type_index_validity(
         &self,
         vertex_type_index: &impl GetVertexTypeIndex,
     ) -> Result<(), GraphComputingError> {
-        self.vertex_type_indexer_ref()
-            .try_index_validity(vertex_type_index.index())
+        let indexer = self.vertex_type_indexer_ref();
+        indexer.try_index_validity(vertex_type_index.index())?;
+        indexer.try_index_generation(vertex_type_index.index(), vertex_type_index.generation())
     }
 
     fn try_optional_vertex_type_index_validity(
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::error::GraphComputingError;
 use crate::graph::indexing::ElementCount;
 use crate::graph::vertex_store::operations::vertex_type::ResizeVertexVectors;
@@ -11,7 +13,7 @@ impl ResizeVertexVectors for VertexStore {
         new_vertex_capacity: ElementCount,
     ) -> Result<(), GraphComputingError> {
         for vertex_vector in self.vertex_vector_for_all_vertex_types_mut_ref().iter_mut() {
-            vertex_vector.resize(new_vertex_capacity)?;
+            Arc::make_mut(vertex_vector).resize(new_vertex_capacity)?;
         }
         Ok(())
     }
@@ -5,6 +5,7 @@ use crate::error::GraphComputingError;
 use crate::graph::indexing::AssignedIndex;
 use crate::graph::indexing::GetVertexIndexIndex;
 use crate::graph::indexing::GetVertexTypeIndex;
+use crate::graph::indexing::VertexIndex;
 use crate::graph::value_type::ValueType;
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::GetSparseVectorStateRevertersByVertexTypeMap;
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::GetVertexStore;
@@ -15,6 +16,7 @@ use crate::graph::vertex_store::operations::vertex_element::AddVertex;
 use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
 use crate::graph::vertex_store::operations::vertex_element::SetVertex;
 use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s, T> AddVertex<T> for InMemoryVertexStoreTransaction<'s>
 where
@@ -61,6 +63,15 @@ where
             &vertex_index,
         )?;
 
+        let new_vertex_index = VertexIndex::from(vertex_index.clone());
+        self.change_recorder.record(GraphChange::VertexAdded {
+            vertex: new_vertex_index,
+        });
+        self.change_recorder.record(GraphChange::vertex_value_set(
+            vertex_type_index,
+            &new_vertex_index,
+        ));
+
         Ok(vertex_index)
     }
 
@@ -0,0 +1,41 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::operations::in_memory_transaction::RegisterClaimedIndicesToRevert;
+use crate::graph::indexing::operations::{claim_indices, invalid_indices_below};
+use crate::graph::indexing::{ElementCount, GetIndexGenerations, VertexIndex};
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::{
+    GetVertexStore, GetVertexStoreStateReverters, InMemoryVertexStoreTransaction,
+};
+use crate::graph::vertex_store::operations::vertex_element::ClaimVertexIndices;
+use crate::graph::vertex_store::GetVertexElementIndexer;
+use crate::operators::change_data_capture::GraphChange;
+
+impl<'s> ClaimVertexIndices for InMemoryVertexStoreTransaction<'s> {
+    fn claim_vertex_indices_below(
+        &mut self,
+        end: ElementCount,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError> {
+        let element_indexer = self.vertex_store.element_indexer_ref();
+        let indices_to_claim = invalid_indices_below(element_indexer, end)?;
+        self.vertex_store_state_restorer
+            .element_indexer_state_restorer_mut_ref()
+            .register_claimed_indices_to_revert(element_indexer, &indices_to_claim)?;
+
+        claim_indices(
+            self.vertex_store_mut_ref().element_indexer_mut_ref(),
+            &indices_to_claim,
+        )?;
+
+        let element_indexer = self.vertex_store.element_indexer_ref();
+        let claimed_vertices: Vec<VertexIndex> = indices_to_claim
+            .into_iter()
+            .map(|index| {
+                VertexIndex::with_generation(index, Some(element_indexer.generation(index)))
+            })
+            .collect();
+        for vertex in claimed_vertices.iter() {
+            self.change_recorder
+                .record(GraphChange::VertexAdded { vertex: *vertex });
+        }
+        Ok(claimed_vertices)
+    }
+}
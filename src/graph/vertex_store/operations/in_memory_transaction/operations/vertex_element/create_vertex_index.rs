@@ -1,16 +1,20 @@
 use crate::error::GraphComputingError;
-use crate::graph::indexing::AssignedIndex;
+use crate::graph::indexing::{AssignedIndex, VertexIndex};
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::{
     GetVertexStore, GetVertexStoreStateRestorer, InMemoryVertexStoreTransaction,
     RegisterNewVertexIndexToRevert,
 };
 use crate::graph::vertex_store::operations::vertex_element::CreateVertexIndex;
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s> CreateVertexIndex for InMemoryVertexStoreTransaction<'s> {
     fn new_vertex_index(&mut self) -> Result<AssignedIndex, GraphComputingError> {
         let vertex_index = self.vertex_store_mut_ref().new_vertex_index()?;
         self.vertex_store_state_restorer_mut_ref()
             .register_new_vertex_index_to_revert(&vertex_index)?;
+        self.change_recorder.record(GraphChange::VertexAdded {
+            vertex: VertexIndex::from(vertex_index.clone()),
+        });
         Ok(vertex_index)
     }
 }
@@ -1,14 +1,17 @@
 use crate::error::GraphComputingError;
+use crate::graph::indexing::operations::in_memory_transaction::RegisterFreedIndexToRestore;
 use crate::graph::indexing::operations::GetValidIndices;
 use crate::graph::indexing::{GetVertexIndexIndex, GetVertexTypeIndex, VertexTypeIndex};
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::{
-    GetVertexStore, InMemoryVertexStoreTransaction, RegisterVertexValueToRestore,
+    GetVertexStore, GetVertexStoreStateReverters, InMemoryVertexStoreTransaction,
+    RegisterVertexValueToRestore,
 };
 use crate::graph::vertex_store::operations::vertex_element::{
     CheckVertexIndex, DeleteVertexForAllTypes, DeleteVertexValue,
 };
 use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
 use crate::graph::vertex_store::GetVertexTypeIndexer;
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s> DeleteVertexValue for InMemoryVertexStoreTransaction<'s> {
     fn delete_vertex_element(
@@ -37,7 +40,13 @@ impl<'s> DeleteVertexValue for InMemoryVertexStoreTransaction<'s> {
             .register_vertex_value_to_restore(vertex_vector, vertex_type_index, vertex_index)?;
 
         self.vertex_store_mut_ref()
-            .delete_vertex_element_unchecked(vertex_type_index, vertex_index)
+            .delete_vertex_element_unchecked(vertex_type_index, vertex_index)?;
+        self.change_recorder
+            .record(GraphChange::vertex_value_deleted(
+                vertex_type_index,
+                vertex_index,
+            ));
+        Ok(())
     }
 }
 
@@ -61,16 +70,24 @@ impl<'s> DeleteVertexForAllTypes for InMemoryVertexStoreTransaction<'s> {
                 .vertex_store
                 .vertex_vector_ref_unchecked(&vertex_type_index);
 
+            // The vertex does not need to have a value for every vertex type
             self.vertex_store_state_restorer
-                .register_vertex_value_to_restore(
+                .register_optional_vertex_value_to_restore(
                     vertex_vector,
                     &vertex_type_index,
                     vertex_index,
                 )?;
         }
 
+        self.vertex_store_state_restorer
+            .element_indexer_state_restorer_mut_ref()
+            .register_freed_index_to_restore(vertex_index.index())?;
+
         self.vertex_store_mut_ref()
-            .delete_vertex_for_all_valid_vertex_types_and_value_types(vertex_index)
+            .delete_vertex_for_all_valid_vertex_types_and_value_types(vertex_index)?;
+        self.change_recorder
+            .record(GraphChange::vertex_dropped(vertex_index));
+        Ok(())
     }
 }
 
@@ -79,9 +96,11 @@ mod tests {
     use graphblas_sparse_linear_algebra::context::Context as GraphblasContext;
 
     use crate::graph::indexing::{GetAssignedIndexData, VertexIndex};
+    use crate::graph::value_type::user_defined_vector_element_value;
     use crate::graph::vertex_store::operations::vertex_element::{AddVertex, GetVertexValue};
     use crate::graph::vertex_store::operations::vertex_type::AddVertexType;
     use crate::graph::vertex_store::VertexStore;
+    use crate::implement_user_defined_value_type;
 
     use super::*;
 
@@ -143,6 +162,59 @@ mod tests {
         );
     }
 
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Measurement {
+        timestamp: u64,
+        score: f32,
+    }
+    implement_user_defined_value_type!(unsafe Measurement);
+
+    #[test]
+    fn roll_back_deleted_vertex_with_user_defined_value() {
+        let mut vertex_store = initialize_vertex_store();
+
+        let measurement = Measurement {
+            timestamp: 1,
+            score: 0.5,
+        };
+        let measurements = AddVertexType::<Measurement>::apply(&mut vertex_store).unwrap();
+        // The vertex has no value for the other vertex types
+        let vertex_index = VertexIndex::new(
+            vertex_store
+                .add_new_vertex(&measurements, measurement)
+                .unwrap()
+                .index(),
+        );
+
+        {
+            let mut transaction = InMemoryVertexStoreTransaction::new(&mut vertex_store).unwrap();
+
+            transaction
+                .delete_vertex_for_all_valid_vertex_types_and_value_types(&vertex_index)
+                .unwrap();
+
+            assert_eq!(
+                None,
+                user_defined_vector_element_value::<Measurement>(
+                    transaction.vertex_vector_ref(&measurements).unwrap(),
+                    vertex_index.index()
+                )
+                .unwrap()
+            );
+        }
+
+        assert_eq!(
+            Some(measurement),
+            user_defined_vector_element_value::<Measurement>(
+                vertex_store.vertex_vector_ref(&measurements).unwrap(),
+                vertex_index.index()
+            )
+            .unwrap()
+        );
+        assert!(vertex_store.is_valid_vertex_index(&vertex_index).unwrap());
+    }
+
     fn initialize_vertex_store() -> VertexStore {
         let context = GraphblasContext::init_default().unwrap();
 
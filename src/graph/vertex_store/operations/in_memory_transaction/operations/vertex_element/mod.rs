@@ -1,4 +1,5 @@
 mod add_vertex;
+mod claim_vertex_indices;
 mod create_vertex_index;
 mod delete_vertex;
 // mod get_length;
@@ -8,6 +9,7 @@ mod set_vertex;
 mod update_vertex;
 
 pub(crate) use add_vertex::*;
+pub(crate) use claim_vertex_indices::*;
 pub(crate) use create_vertex_index::*;
 pub(crate) use delete_vertex::*;
 // pub(crate) use get_length::*;
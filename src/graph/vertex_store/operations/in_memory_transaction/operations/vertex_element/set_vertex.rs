@@ -2,6 +2,7 @@ use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::Get
 use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::SetSparseVectorElementTyped;
 
 use crate::error::GraphComputingError;
+use crate::operators::change_data_capture::GraphChange;
 
 use crate::graph::indexing::GetVertexIndexIndex;
 use crate::graph::indexing::GetVertexTypeIndex;
@@ -41,7 +42,12 @@ where
                 vertex_index,
             )?;
         self.vertex_store_mut_ref()
-            .set_vertex_unchecked(vertex_type_index, vertex_index, value)
+            .set_vertex_unchecked(vertex_type_index, vertex_index, value)?;
+        self.change_recorder.record(GraphChange::vertex_value_set(
+            vertex_type_index,
+            vertex_index,
+        ));
+        Ok(())
     }
 
     fn set_new_vertex(
@@ -62,7 +68,12 @@ where
         );
 
         self.vertex_store_mut_ref()
-            .set_vertex_unchecked(vertex_type_index, vertex_index, value)
+            .set_vertex_unchecked(vertex_type_index, vertex_index, value)?;
+        self.change_recorder.record(GraphChange::vertex_value_set(
+            vertex_type_index,
+            vertex_index,
+        ));
+        Ok(())
     }
 
     fn set_vertex_unchecked(
@@ -79,7 +90,12 @@ where
                 vertex_index,
             )?;
         self.vertex_store_mut_ref()
-            .set_vertex_unchecked(vertex_type_index, vertex_index, value)
+            .set_vertex_unchecked(vertex_type_index, vertex_index, value)?;
+        self.change_recorder.record(GraphChange::vertex_value_set(
+            vertex_type_index,
+            vertex_index,
+        ));
+        Ok(())
     }
 }
 
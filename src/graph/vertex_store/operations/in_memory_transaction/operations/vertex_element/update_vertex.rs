@@ -1,6 +1,7 @@
 use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::SetSparseVectorElementTyped;
 
 use crate::error::GraphComputingError;
+use crate::operators::change_data_capture::GraphChange;
 
 use crate::graph::indexing::GetVertexIndexIndex;
 use crate::graph::indexing::GetVertexTypeIndex;
@@ -44,7 +45,15 @@ where
         self.vertex_store_state_restorer
             .register_vertex_value_to_restore(vertex_vector, vertex_type_index, vertex_index)?;
 
-        self.vertex_store_mut_ref()
-            .update_vertex_unchecked(vertex_type_index, vertex_index, value)
+        self.vertex_store_mut_ref().update_vertex_unchecked(
+            vertex_type_index,
+            vertex_index,
+            value,
+        )?;
+        self.change_recorder.record(GraphChange::vertex_value_set(
+            vertex_type_index,
+            vertex_index,
+        ));
+        Ok(())
     }
 }
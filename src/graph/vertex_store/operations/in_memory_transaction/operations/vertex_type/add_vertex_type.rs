@@ -6,6 +6,7 @@ use crate::graph::vertex_store::operations::in_memory_transaction::transaction::
     RegisterNewVertexTypeToRevert,
 };
 use crate::graph::vertex_store::operations::vertex_type::{add_vertex_type, AddVertexType};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'t, T: ValueType + GetValueTypeIdentifier> AddVertexType<T>
     for InMemoryVertexStoreTransaction<'t>
@@ -14,6 +15,10 @@ impl<'t, T: ValueType + GetValueTypeIdentifier> AddVertexType<T>
         let new_vertex_type_index = add_vertex_type::<T>(self.vertex_store_mut_ref())?;
         self.vertex_store_state_restorer_mut_ref()
             .register_new_vertex_type_to_revert(&new_vertex_type_index)?;
-        Ok(VertexTypeIndex::from(new_vertex_type_index))
+
+        let vertex_type = VertexTypeIndex::from(new_vertex_type_index);
+        self.change_recorder
+            .record(GraphChange::VertexTypeAdded { vertex_type });
+        Ok(vertex_type)
     }
 }
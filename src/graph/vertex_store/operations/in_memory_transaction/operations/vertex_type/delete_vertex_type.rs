@@ -4,8 +4,10 @@ use crate::graph::vertex_store::operations::in_memory_transaction::transaction::
     GetVertexStore, InMemoryVertexStoreTransaction, RegisterVertexVectorToRestore,
 };
 use crate::graph::vertex_store::operations::vertex_type::{
-    delete_vertex_type_unchecked, DeleteVertexType, GetVertexVector,
+    delete_vertex_type_unchecked, vertex_vector_mut_ref_to_write_element_unchecked,
+    CheckVertexTypeIndex, DeleteVertexType,
 };
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'t> DeleteVertexType for InMemoryVertexStoreTransaction<'t> {
     fn delete_vertex_type(
@@ -13,7 +15,10 @@ impl<'t> DeleteVertexType for InMemoryVertexStoreTransaction<'t> {
         index: &impl GetVertexTypeIndex,
     ) -> Result<(), GraphComputingError> {
         register_deleted_vertex_vector_to_restore(self, index)?;
-        delete_vertex_type_unchecked(self.vertex_store_mut_ref(), index)
+        delete_vertex_type_unchecked(self.vertex_store_mut_ref(), index)?;
+        self.change_recorder
+            .record(GraphChange::vertex_type_dropped(index));
+        Ok(())
     }
 
     fn delete_vertex_type_unchecked(
@@ -21,7 +26,10 @@ impl<'t> DeleteVertexType for InMemoryVertexStoreTransaction<'t> {
         index: &impl GetVertexTypeIndex,
     ) -> Result<(), GraphComputingError> {
         register_deleted_vertex_vector_to_restore_unchecked(self, index)?;
-        delete_vertex_type_unchecked(self.vertex_store_mut_ref(), index)
+        delete_vertex_type_unchecked(self.vertex_store_mut_ref(), index)?;
+        self.change_recorder
+            .record(GraphChange::vertex_type_dropped(index));
+        Ok(())
     }
 }
 
@@ -29,23 +37,21 @@ fn register_deleted_vertex_vector_to_restore<'s>(
     transaction: &mut InMemoryVertexStoreTransaction<'s>,
     vertex_type_index: &impl GetVertexTypeIndex,
 ) -> Result<(), GraphComputingError> {
-    let vertex_vector = transaction
-        .vertex_store
-        .vertex_vector_mut_ref(vertex_type_index)?;
-
     transaction
-        .vertex_store_state_restorer
-        .register_deleted_vertex_vector_to_restore(vertex_type_index, vertex_vector)?;
-    Ok(())
+        .vertex_store
+        .try_vertex_type_index_validity(vertex_type_index)?;
+    register_deleted_vertex_vector_to_restore_unchecked(transaction, vertex_type_index)
 }
 
 fn register_deleted_vertex_vector_to_restore_unchecked<'s>(
     transaction: &mut InMemoryVertexStoreTransaction<'s>,
     vertex_type_index: &impl GetVertexTypeIndex,
 ) -> Result<(), GraphComputingError> {
-    let vertex_vector = transaction
-        .vertex_store
-        .vertex_vector_mut_ref_unchecked(vertex_type_index)?;
+    // Not an overwrite, because the vertex value index is dropped and restored with the vertex type
+    let vertex_vector = vertex_vector_mut_ref_to_write_element_unchecked(
+        transaction.vertex_store,
+        vertex_type_index,
+    );
 
     transaction
         .vertex_store_state_restorer
@@ -10,6 +10,7 @@ use crate::graph::vertex_store::operations::vertex_type::{
     GetVertexVector, GetVertexVectorNativeValueType,
 };
 use crate::graph::vertex_store::VertexVector;
+use crate::operators::change_data_capture::GraphChange;
 
 impl<'s> GetVertexVector for InMemoryVertexStoreTransaction<'s> {
     fn vertex_vector_ref(
@@ -26,6 +27,8 @@ impl<'s> GetVertexVector for InMemoryVertexStoreTransaction<'s> {
         let vertex_vector = vertex_vector_mut_ref(self.vertex_store, vertex_type_index)?;
         self.vertex_store_state_restorer
             .register_updated_vertex_vector_to_restore(vertex_type_index, &vertex_vector)?;
+        self.change_recorder
+            .record(GraphChange::vertex_vector_overwritten(vertex_type_index));
         Ok(vertex_vector)
     }
 
@@ -63,6 +66,8 @@ impl<'s> GetVertexVector for InMemoryVertexStoreTransaction<'s> {
         let vertex_vector = vertex_vector_mut_ref_unchecked(self.vertex_store, vertex_type_index);
         self.vertex_store_state_restorer
             .register_updated_vertex_vector_to_restore(vertex_type_index, &vertex_vector)?;
+        self.change_recorder
+            .record(GraphChange::vertex_vector_overwritten(vertex_type_index));
         Ok(vertex_vector)
     }
 }
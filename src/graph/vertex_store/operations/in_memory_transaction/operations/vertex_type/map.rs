@@ -7,6 +7,7 @@ use crate::graph::vertex_store::operations::vertex_type::{
     indexed_map_mut_all_valid_vertex_vectors, MapValidVertexVectors,
 };
 use crate::graph::vertex_store::VertexVector;
+use crate::operators::change_data_capture::GraphChange;
 
 // impl<'s> MapAllVertexVectors for AtomicInMemoryVertexStoreTransaction<'s> {
 //     fn map_all_vertex_vectors<F>(&self, function_to_apply: F) -> Result<(), GraphComputingError>
@@ -63,7 +64,10 @@ impl<'s> MapValidVertexVectors for InMemoryVertexStoreTransaction<'s> {
                 self.vertex_store_state_restorer
                     .register_updated_vertex_vector_to_restore(vertex_type_index, &vertex_vector)?;
 
-                function_to_apply(vertex_vector)
+                function_to_apply(vertex_vector)?;
+                self.change_recorder
+                    .record(GraphChange::vertex_vector_overwritten(vertex_type_index));
+                Ok(())
             };
 
         indexed_map_mut_all_valid_vertex_vectors(
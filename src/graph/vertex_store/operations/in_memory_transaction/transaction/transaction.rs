@@ -2,6 +2,7 @@ use std::mem;
 
 use crate::error::GraphComputingError;
 use crate::graph::vertex_store::{GetVertexElementIndexer, GetVertexTypeIndexer, VertexStore};
+use crate::operators::change_data_capture::{GraphChange, GraphChangeRecorder};
 use crate::operators::transaction::{RestoreState, UseTransaction};
 
 use super::VertexStoreStateRestorer;
@@ -21,6 +22,11 @@ pub(crate) struct InMemoryVertexStoreTransaction<'s> {
         &'s mut VertexStore,
     pub(in crate::graph::vertex_store::operations::in_memory_transaction) vertex_store_state_restorer:
         VertexStoreStateRestorer,
+    // One state restorer per savepoint, for the changes made before it.
+    // vertex_store_state_restorer records the changes after the last savepoint.
+    state_restorers_before_savepoints: Vec<VertexStoreStateRestorer>,
+    pub(in crate::graph::vertex_store::operations::in_memory_transaction) change_recorder:
+        GraphChangeRecorder,
 }
 
 impl<'s> InMemoryVertexStoreTransaction<'s> {
@@ -33,8 +39,69 @@ impl<'s> InMemoryVertexStoreTransaction<'s> {
         Ok(Self {
             vertex_store,
             vertex_store_state_restorer,
+            state_restorers_before_savepoints: Vec::new(),
+            change_recorder: GraphChangeRecorder::new(),
         })
     }
+
+    /// Records the changes after the savepoint separately.
+    /// Returns the number of state restorers to retain when rolling back to the savepoint.
+    pub(crate) fn begin_savepoint(&mut self) -> Result<usize, GraphComputingError> {
+        let vertex_store_state_restorer = mem::replace(
+            &mut self.vertex_store_state_restorer,
+            VertexStoreStateRestorer::new_for_vertex_store(self.vertex_store)?,
+        );
+        self.state_restorers_before_savepoints
+            .push(vertex_store_state_restorer);
+        self.change_recorder.begin_savepoint();
+        Ok(self.state_restorers_before_savepoints.len())
+    }
+
+    /// Restores the changes after the savepoint, latest first.
+    pub(crate) fn rollback_to_savepoint(
+        &mut self,
+        number_of_state_restorers_to_retain: usize,
+    ) -> Result<(), GraphComputingError> {
+        self.change_recorder
+            .rollback_to_savepoint(number_of_state_restorers_to_retain);
+
+        let state_restorers_after_savepoint = self
+            .state_restorers_before_savepoints
+            .split_off(number_of_state_restorers_to_retain);
+        for state_restorer in state_restorers_after_savepoint.into_iter().rev() {
+            mem::replace(&mut self.vertex_store_state_restorer, state_restorer)
+                .restore(&mut self.vertex_store)?;
+        }
+
+        let reset_vertex_store_state_restorer = self
+            .vertex_store_state_restorer
+            .with_reset_state_to_restore();
+        let vertex_store_state_restorer = mem::replace(
+            &mut self.vertex_store_state_restorer,
+            reset_vertex_store_state_restorer,
+        );
+
+        vertex_store_state_restorer.restore(&mut self.vertex_store)
+    }
+
+    /// Returns the state restorers for the changes since the transaction began or last committed, oldest first.
+    /// The transaction records the following changes with a new state restorer.
+    pub(crate) fn take_state_restorers(
+        &mut self,
+    ) -> Result<Vec<VertexStoreStateRestorer>, GraphComputingError> {
+        let vertex_store_state_restorer = mem::replace(
+            &mut self.vertex_store_state_restorer,
+            VertexStoreStateRestorer::new_for_vertex_store(self.vertex_store)?,
+        );
+        let mut state_restorers = mem::take(&mut self.state_restorers_before_savepoints);
+        state_restorers.push(vertex_store_state_restorer);
+        Ok(state_restorers)
+    }
+
+    /// Returns the changes since the transaction began or last committed, and stops tracking them.
+    pub(crate) fn take_changes(&mut self) -> Vec<GraphChange> {
+        self.change_recorder.take_changes()
+    }
 }
 
 pub(crate) trait GetVertexStore {
@@ -69,22 +136,14 @@ impl<'t> GetVertexStoreStateRestorer for InMemoryVertexStoreTransaction<'t> {
 
 impl<'s> UseTransaction for InMemoryVertexStoreTransaction<'s> {
     fn revert(&mut self) -> Result<(), GraphComputingError> {
-        let reset_vertex_store_state_restorer =
-            VertexStoreStateRestorer::new_for_vertex_store(self.vertex_store)?;
-        let reset_vertex_store_state_restorer = self
-            .vertex_store_state_restorer
-            .with_reset_state_to_restore();
-        let vertex_store_state_restorer = mem::replace(
-            &mut self.vertex_store_state_restorer,
-            reset_vertex_store_state_restorer,
-        );
-
-        vertex_store_state_restorer.restore(&mut self.vertex_store)
+        self.rollback_to_savepoint(0)
     }
 
     fn commit(&mut self) -> Result<(), GraphComputingError> {
         self.vertex_store_state_restorer =
             VertexStoreStateRestorer::new_for_vertex_store(self.vertex_store)?;
+        self.state_restorers_before_savepoints.clear();
+        self.change_recorder.clear();
         Ok(())
     }
 }
@@ -1,4 +1,4 @@
-use crate::graph::vertex_store::operations::in_memory_transaction::transaction::vertex_store_state_restorer::vertex_vectors_state_restorer::RegisterTypedVertexValueToRestore;
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::vertex_store_state_restorer::vertex_vectors_state_restorer::{RegisterTypedVertexValueToRestore, RegisterUserDefinedVertexVectorToRestore};
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::VertexStoreStateRestorer;
 use crate::graph::vertex_store::VertexVector;
 use crate::graph::value_type::{implement_macro_for_all_native_value_types, GetValueTypeIdentifierRef, ValueTypeIdentifier};
@@ -137,6 +137,14 @@ impl RegisterVertexValueToRestore for VertexStoreStateRestorer {
                     vertex_index,
                 )?;
             }
+            // Values of a user-defined value type are restored with their vertex vector
+            ValueTypeIdentifier::UserDefined(_) => {
+                self.vertex_vectors_state_restorer_mut_ref()
+                    .register_user_defined_vertex_vector_to_restore(
+                        vertex_type_index,
+                        vertex_vector,
+                    );
+            }
         }
         Ok(())
     }
@@ -252,6 +260,14 @@ impl RegisterVertexValueToRestore for VertexStoreStateRestorer {
                     vertex_index,
                 )?;
             }
+            // Values of a user-defined value type are restored with their vertex vector
+            ValueTypeIdentifier::UserDefined(_) => {
+                self.vertex_vectors_state_restorer_mut_ref()
+                    .register_user_defined_vertex_vector_to_restore(
+                        vertex_type_index,
+                        vertex_vector,
+                    );
+            }
         }
         Ok(())
     }
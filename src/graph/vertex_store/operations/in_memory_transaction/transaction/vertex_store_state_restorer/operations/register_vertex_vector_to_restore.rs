@@ -1,3 +1,9 @@
+use std::mem;
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector;
+
+use crate::graph::graph::GetGraphblasContext;
+use crate::graph::vertex_store::GetVectorLength;
 use crate::graph::indexing::operations::in_memory_transaction::RegisterFreedIndexToRestore;
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::{InMemoryVertexStoreTransaction, VertexStoreStateRestorer};
 use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
@@ -6,7 +12,7 @@ use crate::graph::vertex_store::VertexVector;
 use crate::graph::value_type::{implement_macro_for_all_native_value_types, GetValueTypeIdentifierRef, ValueTypeIdentifier};
 use crate::graph::indexing::GetVertexTypeIndex;
 use crate::error::GraphComputingError;
-use crate::graph::vertex_store::operations::in_memory_transaction::transaction::vertex_store_state_restorer::vertex_vectors_state_restorer::RegisterTypedVertexVectorToRestore;
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::vertex_store_state_restorer::vertex_vectors_state_restorer::{RegisterTypedVertexVectorToRestore, RegisterUserDefinedVertexVectorToRestore};
 use crate::graph::vertex_store::vertex_vector::IntoSparseVectorAndClearValuesForValueType;
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::vertex_store_state_restorer::GetVertexStoreStateReverters;
 
@@ -82,6 +88,13 @@ impl<'t> RegisterVertexVectorToRestore<'t> for VertexStoreStateRestorer {
             ValueTypeIdentifier::USize => {
                 usize::register_vertex_vector_to_restore(self, vertex_vector, vertex_type_index)?;
             }
+            ValueTypeIdentifier::UserDefined(_) => {
+                self.vertex_vectors_state_restorer_mut_ref()
+                    .register_user_defined_vertex_vector_to_restore(
+                        vertex_type_index,
+                        vertex_vector,
+                    );
+            }
         }
         Ok(())
     }
@@ -185,6 +198,24 @@ impl VertexStoreStateRestorer {
                     vertex_type_index,
                 )?;
             }
+            ValueTypeIdentifier::UserDefined(user_defined_value_type) => {
+                let mut deleted_vertex_vector = VertexVector::with_user_defined_value_type(
+                    vertex_vector.graphblas_context(),
+                    *user_defined_value_type,
+                    vertex_vector.length()?,
+                )?;
+                unsafe {
+                    mem::swap(
+                        vertex_vector.graphblas_vector_mut_ref(),
+                        deleted_vertex_vector.graphblas_vector_mut_ref(),
+                    )
+                };
+                self.vertex_vectors_state_restorer_mut_ref()
+                    .register_deleted_user_defined_vertex_vector_to_restore(
+                        vertex_type_index,
+                        deleted_vertex_vector,
+                    );
+            }
         })
     }
 }
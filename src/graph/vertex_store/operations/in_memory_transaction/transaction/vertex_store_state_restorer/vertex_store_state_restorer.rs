@@ -4,6 +4,7 @@ use crate::graph::indexing::{GetIndexCapacity, Indexer};
 use crate::graph::vertex_store::{
     GetVertexElementIndexer, GetVertexTypeIndexer, GetVertexVectors, VertexStore,
 };
+use crate::operators::history::GetApproximateSizeInBytes;
 use crate::operators::transaction::RestoreState;
 
 use super::VertexVectorsStateRestorer;
@@ -129,3 +130,17 @@ impl VertexStoreStateRestorer {
         })
     }
 }
+
+impl GetApproximateSizeInBytes for VertexStoreStateRestorer {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        Ok(self
+            .vertex_type_indexer_state_restorer
+            .approximate_size_in_bytes()?
+            + self
+                .element_indexer_state_restorer
+                .approximate_size_in_bytes()?
+            + self
+                .vertex_vectors_state_restorer
+                .approximate_size_in_bytes()?)
+    }
+}
@@ -1,11 +1,13 @@
 mod register_capacity_to_restore;
 mod register_empty_vertex_to_restore;
+mod register_user_defined_vertex_vector_to_restore;
 mod register_vertex_value_to_restore;
 mod register_vertex_vector_to_restore;
 mod restore_state;
 
 pub(crate) use register_capacity_to_restore::*;
 pub(crate) use register_empty_vertex_to_restore::*;
+pub(crate) use register_user_defined_vertex_vector_to_restore::*;
 pub(crate) use register_vertex_value_to_restore::*;
 pub(crate) use register_vertex_vector_to_restore::*;
 pub(crate) use restore_state::*;
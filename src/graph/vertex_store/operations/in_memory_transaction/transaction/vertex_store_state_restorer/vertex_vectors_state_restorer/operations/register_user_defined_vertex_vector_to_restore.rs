@@ -0,0 +1,40 @@
+use crate::graph::indexing::GetVertexTypeIndex;
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::vertex_store_state_restorer::vertex_vectors_state_restorer::vertex_vectors_state_restorer::VertexVectorsStateRestorer;
+use crate::graph::vertex_store::VertexVector;
+
+pub(crate) trait RegisterUserDefinedVertexVectorToRestore {
+    fn register_user_defined_vertex_vector_to_restore(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_vector: &VertexVector,
+    );
+
+    fn register_deleted_user_defined_vertex_vector_to_restore(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_vector: VertexVector,
+    );
+}
+
+// The first registration holds the state to restore
+impl RegisterUserDefinedVertexVectorToRestore for VertexVectorsStateRestorer {
+    fn register_user_defined_vertex_vector_to_restore(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_vector: &VertexVector,
+    ) {
+        self.user_defined_vertex_vectors_to_restore
+            .entry(vertex_type_index.index())
+            .or_insert_with(|| vertex_vector.clone());
+    }
+
+    fn register_deleted_user_defined_vertex_vector_to_restore(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_vector: VertexVector,
+    ) {
+        self.user_defined_vertex_vectors_to_restore
+            .entry(vertex_type_index.index())
+            .or_insert(vertex_vector);
+    }
+}
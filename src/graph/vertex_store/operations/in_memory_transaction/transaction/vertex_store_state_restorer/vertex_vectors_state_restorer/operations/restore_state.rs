@@ -1,4 +1,8 @@
+use std::mem;
+use std::sync::Arc;
+
 use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::resize_sparse_vector;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector;
 use graphblas_sparse_linear_algebra::index::ElementCount;
 
 use crate::error::GraphComputingError;
@@ -9,10 +13,10 @@ use crate::operators::transaction::RestoreState;
 use crate::graph::vertex_store::VertexVector;
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::vertex_store_state_restorer::vertex_vectors_state_restorer::vertex_vectors_state_restorer::{GetVertexTypeVectorLengthToRestore, VertexVectorsStateRestorer};
 
-impl RestoreState<Vec<VertexVector>> for VertexVectorsStateRestorer {
+impl RestoreState<Vec<Arc<VertexVector>>> for VertexVectorsStateRestorer {
     fn restore(
         self,
-        vectors_to_restore: &mut Vec<VertexVector>,
+        vectors_to_restore: &mut Vec<Arc<VertexVector>>,
     ) -> Result<(), crate::error::GraphComputingError> {
         restore_vertex_vectors_state(self, vectors_to_restore)
     }
@@ -24,7 +28,7 @@ impl RestoreState<Vec<VertexVector>> for VertexVectorsStateRestorer {
 
 pub(crate) fn restore_vertex_vectors_state(
     vertex_vectors_state_restorer: VertexVectorsStateRestorer,
-    vectors_to_restore: &mut Vec<VertexVector>,
+    vectors_to_restore: &mut Vec<Arc<VertexVector>>,
 ) -> Result<(), crate::error::GraphComputingError> {
     let vertex_vector_state_reverters = vertex_vectors_state_restorer.vertex_vector_state_reverters;
 
@@ -84,6 +88,11 @@ pub(crate) fn restore_vertex_vectors_state(
         vectors_to_restore,
     )?;
 
+    restore_user_defined_vertex_vectors(
+        vertex_vectors_state_restorer.user_defined_vertex_vectors_to_restore,
+        vectors_to_restore,
+    );
+
     vectors_to_restore.truncate(vertex_vectors_state_restorer.vertex_type_vector_length_to_restore);
 
     restore_vertex_vector_length(vertex_vector_length_to_restore, vectors_to_restore)?;
@@ -95,7 +104,7 @@ fn restore_vertex_vectors<T>(
     typed_sparse_vector_state_reverters_for_vertex_type: ElementIndexMap<
         SparseVectorStateReverter<T>,
     >,
-    vectors_to_restore: &mut Vec<VertexVector>,
+    vectors_to_restore: &mut Vec<Arc<VertexVector>>,
 ) -> Result<(), GraphComputingError>
 where
     T: ValueType,
@@ -104,20 +113,39 @@ where
     for (vertex_type_index, sparse_vector_state_reverter) in
         typed_sparse_vector_state_reverters_for_vertex_type.into_iter()
     {
-        sparse_vector_state_reverter.restore(&mut vectors_to_restore[vertex_type_index])?;
+        sparse_vector_state_reverter
+            .restore(Arc::make_mut(&mut vectors_to_restore[vertex_type_index]))?;
     }
     Ok(())
 }
 
+// The restored vertex vector keeps counting overwrites
+fn restore_user_defined_vertex_vectors(
+    user_defined_vertex_vectors_to_restore: ElementIndexMap<VertexVector>,
+    vectors_to_restore: &mut Vec<Arc<VertexVector>>,
+) {
+    for (vertex_type_index, mut vertex_vector_to_restore) in
+        user_defined_vertex_vectors_to_restore.into_iter()
+    {
+        unsafe {
+            mem::swap(
+                Arc::make_mut(&mut vectors_to_restore[vertex_type_index])
+                    .graphblas_vector_mut_ref(),
+                vertex_vector_to_restore.graphblas_vector_mut_ref(),
+            )
+        };
+    }
+}
+
 fn restore_vertex_vector_length(
     vertex_vector_length_to_restore: Option<ElementCount>,
-    vectors_to_restore: &mut Vec<VertexVector>,
+    vectors_to_restore: &mut Vec<Arc<VertexVector>>,
 ) -> Result<(), GraphComputingError> {
     Ok(match vertex_vector_length_to_restore {
         Some(element_count) => {
             // TODO: consider resizing in parallel
             for vertex_vector in vectors_to_restore.iter_mut() {
-                resize_sparse_vector(vertex_vector, element_count)?;
+                resize_sparse_vector(Arc::make_mut(vertex_vector), element_count)?;
             }
         }
         None => (),
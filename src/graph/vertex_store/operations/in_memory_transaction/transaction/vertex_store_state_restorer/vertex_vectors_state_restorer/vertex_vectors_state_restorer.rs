@@ -1,17 +1,24 @@
 use std::collections::HashMap;
 
+use crate::error::GraphComputingError;
 use crate::graph::indexing::{
     BuildIndexHasher, ElementCount, ElementIndex, ElementIndexMap, GetVertexTypeIndex,
 };
 use crate::graph::value_type::{
     implement_1_type_macro_with_typed_indentifier_for_all_value_types, ValueType,
 };
+use crate::graph::vertex_store::VertexVector;
+use crate::operators::history::{
+    approximate_size_in_bytes_of_all, sparse_vector_size_in_bytes, GetApproximateSizeInBytes,
+};
 use crate::operators::in_memory_transaction::transaction::SparseVectorStateReverter;
 
 pub(crate) struct VertexVectorsStateRestorer {
     pub(super) vertex_vector_length_to_restore: Option<ElementCount>,
     pub(super) vertex_type_vector_length_to_restore: ElementCount,
     pub(super) vertex_vector_state_reverters: TypedSparseVectorStateReverters,
+    // Vertex vectors with a user-defined value type are restored from a copy
+    pub(super) user_defined_vertex_vectors_to_restore: ElementIndexMap<VertexVector>,
 }
 
 pub(super) struct TypedSparseVectorStateReverters {
@@ -123,6 +130,7 @@ impl VertexVectorsStateRestorer {
             vertex_vector_length_to_restore,
             vertex_type_vector_length_to_restore,
             vertex_vector_state_reverters: TypedSparseVectorStateReverters::new(),
+            user_defined_vertex_vectors_to_restore: ElementIndexMap::default(),
         }
     }
 
@@ -133,6 +141,7 @@ impl VertexVectorsStateRestorer {
             vertex_vector_length_to_restore: None,
             vertex_type_vector_length_to_restore,
             vertex_vector_state_reverters: TypedSparseVectorStateReverters::new(),
+            user_defined_vertex_vectors_to_restore: ElementIndexMap::default(),
         }
     }
 }
@@ -241,3 +250,48 @@ impl TypedSparseVectorStateReverters {
         }
     }
 }
+
+impl GetApproximateSizeInBytes for VertexVectorsStateRestorer {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        let mut size_in_bytes = self
+            .vertex_vector_state_reverters
+            .approximate_size_in_bytes()?;
+        for vertex_vector in self.user_defined_vertex_vectors_to_restore.values() {
+            size_in_bytes += sparse_vector_size_in_bytes(vertex_vector)?;
+        }
+        Ok(size_in_bytes)
+    }
+}
+
+impl GetApproximateSizeInBytes for TypedSparseVectorStateReverters {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        let mut size_in_bytes = 0;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_bool.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_i8.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_i16.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_i32.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_i64.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_u8.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_u16.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_u32.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_u64.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_f32.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_f64.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_isize.values())?;
+        size_in_bytes +=
+            approximate_size_in_bytes_of_all(self.sparse_vector_state_reverters_usize.values())?;
+        Ok(size_in_bytes)
+    }
+}
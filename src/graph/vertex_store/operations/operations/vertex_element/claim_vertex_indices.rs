@@ -0,0 +1,11 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::{ElementCount, VertexIndex};
+
+pub(crate) trait ClaimVertexIndices {
+    /// Makes all vertex indices below end valid. The vertex capacity must be at least end.
+    /// Returns the vertex indices that were not valid before.
+    fn claim_vertex_indices_below(
+        &mut self,
+        end: ElementCount,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError>;
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorLength;
 
 use crate::error::GraphComputingError;
@@ -59,10 +61,10 @@ impl VertexStore {
         Ok(
             if *new_type_index.index_ref() >= self.vertex_vector_for_all_vertex_types_ref().len() {
                 self.vertex_vector_for_all_vertex_types_mut()
-                    .push(new_vertex_vector);
+                    .push(Arc::new(new_vertex_vector));
             } else {
                 self.vertex_vector_for_all_vertex_types_mut_ref()[*new_type_index.index_ref()] =
-                    new_vertex_vector;
+                    Arc::new(new_vertex_vector);
             },
         )
     }
@@ -1,9 +1,13 @@
+use std::sync::Arc;
+
 use crate::error::GraphComputingError;
 use crate::graph::indexing::operations::CheckIndex;
 use crate::graph::indexing::GetVertexTypeIndex;
 use crate::graph::value_type::{GetValueTypeIdentifierRef, ValueTypeIdentifier};
 use crate::graph::vertex_store::vertex_store::GetVertexVectors as GetVertexVectorFromVertexStore;
-use crate::graph::vertex_store::{GetVertexTypeIndexer, VertexStore, VertexVector};
+use crate::graph::vertex_store::{
+    CountVertexVectorOverwrites, GetVertexTypeIndexer, VertexStore, VertexVector,
+};
 
 pub(crate) trait GetVertexVector {
     fn vertex_vector_ref(
@@ -56,11 +60,26 @@ pub(crate) fn vertex_vector_ref_unchecked<'s>(
     &vertex_store.vertex_vector_for_all_vertex_types_ref()[*vertex_type_index.index_ref()]
 }
 
+/// Counts as an overwrite of the vertex vector, which makes its vertex value index outdated.
 pub(crate) fn vertex_vector_mut_ref_unchecked<'s>(
     vertex_store: &'s mut VertexStore,
     vertex_type_index: &impl GetVertexTypeIndex,
 ) -> &'s mut VertexVector {
-    &mut vertex_store.vertex_vector_for_all_vertex_types_mut_ref()[*vertex_type_index.index_ref()]
+    let vertex_vector =
+        vertex_vector_mut_ref_to_write_element_unchecked(vertex_store, vertex_type_index);
+    vertex_vector.count_overwrite();
+    vertex_vector
+}
+
+/// For writes of single vertex elements, which the vertex value index follows.
+pub(crate) fn vertex_vector_mut_ref_to_write_element_unchecked<'s>(
+    vertex_store: &'s mut VertexStore,
+    vertex_type_index: &impl GetVertexTypeIndex,
+) -> &'s mut VertexVector {
+    Arc::make_mut(
+        &mut vertex_store.vertex_vector_for_all_vertex_types_mut_ref()
+            [*vertex_type_index.index_ref()],
+    )
 }
 
 pub(crate) trait GetVertexVectorNativeValueType {
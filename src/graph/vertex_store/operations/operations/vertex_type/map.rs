@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::error::GraphComputingError;
@@ -27,7 +29,7 @@ where
     vertex_store
         .vertex_vector_for_all_vertex_types_ref()
         .into_par_iter()
-        .try_for_each(function_to_apply)?;
+        .try_for_each(|vertex_vector| function_to_apply(vertex_vector))?;
     Ok(())
 }
 
@@ -41,7 +43,7 @@ where
     vertex_store
         .vertex_vector_for_all_vertex_types_mut_ref()
         .into_par_iter()
-        .try_for_each(function_to_apply)?;
+        .try_for_each(|vertex_vector| function_to_apply(Arc::make_mut(vertex_vector)))?;
     Ok(())
 }
 
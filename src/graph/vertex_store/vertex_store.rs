@@ -12,11 +12,12 @@ use super::VertexVector;
 pub(crate) type VertexTypeIndexer = Indexer;
 pub(crate) type VertexElementIndexer = Indexer;
 
+// Clones share vertex vectors until either clone changes them.
 #[derive(Clone, Debug)]
 pub(crate) struct VertexStore {
     graphblas_context: Arc<GraphblasContext>,
     vertex_type_indexer: VertexTypeIndexer,
-    vertex_vectors: Vec<VertexVector>,
+    vertex_vectors: Vec<Arc<VertexVector>>,
     element_indexer: VertexElementIndexer,
 }
 
@@ -55,9 +56,10 @@ pub(crate) trait GetVertexElementIndexer {
 }
 
 pub(crate) trait GetVertexVectors {
-    fn vertex_vector_for_all_vertex_types_ref(&self) -> &[VertexVector];
-    fn vertex_vector_for_all_vertex_types_mut_ref(&mut self) -> &mut [VertexVector];
-    fn vertex_vector_for_all_vertex_types_mut(&mut self) -> &mut Vec<VertexVector>;
+    fn vertex_vector_for_all_vertex_types_ref(&self) -> &[Arc<VertexVector>];
+    /// Use Arc::make_mut to change a vertex vector, such that it is copied if it is shared.
+    fn vertex_vector_for_all_vertex_types_mut_ref(&mut self) -> &mut [Arc<VertexVector>];
+    fn vertex_vector_for_all_vertex_types_mut(&mut self) -> &mut Vec<Arc<VertexVector>>;
 }
 
 impl GetGraphblasContext for VertexStore {
@@ -89,15 +91,15 @@ impl GetVertexElementIndexer for VertexStore {
 }
 
 impl GetVertexVectors for VertexStore {
-    fn vertex_vector_for_all_vertex_types_ref(&self) -> &[VertexVector] {
+    fn vertex_vector_for_all_vertex_types_ref(&self) -> &[Arc<VertexVector>] {
         self.vertex_vectors.as_slice()
     }
 
-    fn vertex_vector_for_all_vertex_types_mut_ref(&mut self) -> &mut [VertexVector] {
+    fn vertex_vector_for_all_vertex_types_mut_ref(&mut self) -> &mut [Arc<VertexVector>] {
         self.vertex_vectors.as_mut_slice()
     }
 
-    fn vertex_vector_for_all_vertex_types_mut(&mut self) -> &mut Vec<VertexVector> {
+    fn vertex_vector_for_all_vertex_types_mut(&mut self) -> &mut Vec<Arc<VertexVector>> {
         &mut self.vertex_vectors
     }
 }
@@ -114,7 +116,9 @@ impl VertexStore {
         // TODO: would par_iter() give better performance?
         self.vertex_type_indexer
             .iter_valid_indices()?
-            .try_for_each(|i: Index| function_to_apply(&mut self.vertex_vectors[i]))?;
+            .try_for_each(|i: Index| {
+                function_to_apply(Arc::make_mut(&mut self.vertex_vectors[i]))
+            })?;
         Ok(())
     }
 
@@ -131,7 +135,10 @@ impl VertexStore {
         self.vertex_type_indexer
             .iter_valid_indices()?
             .try_for_each(|i: Index| {
-                function_to_apply(&VertexTypeIndex::new(i), &mut self.vertex_vectors[i])
+                function_to_apply(
+                    &VertexTypeIndex::new(i),
+                    Arc::make_mut(&mut self.vertex_vectors[i]),
+                )
             })?;
         Ok(())
     }
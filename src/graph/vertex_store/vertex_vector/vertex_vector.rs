@@ -24,8 +24,10 @@ use crate::graph::graph::GetGraphblasContext;
 use crate::graph::indexing::ElementCount;
 use crate::graph::value_type::implement_1_type_macro_with_enum_type_indentifier_for_all_value_types;
 use crate::graph::value_type::implement_macro_for_all_native_value_types;
+use crate::graph::value_type::user_defined_graphblas_type;
 use crate::graph::value_type::GetValueTypeIdentifier;
 use crate::graph::value_type::GetValueTypeIdentifierRef;
+use crate::graph::value_type::UserDefinedValueTypeIdentifier;
 use crate::graph::value_type::ValueType;
 use crate::graph::value_type::ValueTypeIdentifier;
 
@@ -39,6 +41,7 @@ pub(crate) struct VertexVector {
     graphblas_context: Arc<GraphBLASContext>,
     value_type: ValueTypeIdentifier,
     sparse_vector: GrB_Vector,
+    overwrite_count: u64,
 }
 
 pub(crate) trait CreateVertexVector<T> {
@@ -63,6 +66,28 @@ impl<T: ValueType + GetValueTypeIdentifier> CreateVertexVector<T> for VertexVect
                 )?
             },
             value_type: T::value_type_identifier(),
+            overwrite_count: 0,
+        })
+    }
+}
+
+impl VertexVector {
+    pub(crate) fn with_user_defined_value_type(
+        graphblas_context: Arc<GraphBLASContext>,
+        value_type: UserDefinedValueTypeIdentifier,
+        initial_vertex_capacity: ElementCount,
+    ) -> Result<VertexVector, GraphComputingError> {
+        Ok(VertexVector {
+            graphblas_context: graphblas_context.clone(),
+            sparse_vector: unsafe {
+                new_graphblas_vector(
+                    &graphblas_context,
+                    initial_vertex_capacity,
+                    user_defined_graphblas_type(&value_type)?,
+                )?
+            },
+            value_type: ValueTypeIdentifier::UserDefined(value_type),
+            overwrite_count: 0,
         })
     }
 }
@@ -85,6 +110,7 @@ impl Clone for VertexVector {
             sparse_vector: unsafe {
                 clone_graphblas_vector(self.context_ref(), self.graphblas_vector_ref()).unwrap()
             },
+            overwrite_count: self.overwrite_count,
         }
     }
 }
@@ -149,6 +175,23 @@ impl GetValueTypeIdentifierRef for VertexVector {
     }
 }
 
+/// Counts how often the vertex vector was taken mutably, other than to write a single vertex element.
+/// A vertex value index is up to date while the count equals the count at which the index was built.
+pub(crate) trait CountVertexVectorOverwrites {
+    fn overwrite_count(&self) -> u64;
+    fn count_overwrite(&mut self);
+}
+
+impl CountVertexVectorOverwrites for VertexVector {
+    fn overwrite_count(&self) -> u64 {
+        self.overwrite_count
+    }
+
+    fn count_overwrite(&mut self) {
+        self.overwrite_count = self.overwrite_count.wrapping_add(1);
+    }
+}
+
 // TODO: this approach should work once Type Alias Impl Trait (TAIT) is stable
 // https://github.com/rust-lang/rust/issues/63063
 // fn apply_to_adjacency_matrices_of_all_value_types<T: ValueType, F: Fn(&SparseVector<T>) -> Result<(), GraphComputingError>>(&self, f: F) -> Result<(), GraphComputingError> {
@@ -1,6 +1,8 @@
+pub mod algorithms;
 pub mod error;
 pub mod graph;
 pub mod operators;
+pub mod query;
 
 pub use graphblas_sparse_linear_algebra;
 
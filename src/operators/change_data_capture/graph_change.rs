@@ -0,0 +1,143 @@
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex, VertexIndex,
+    VertexTypeIndex,
+};
+
+/// A committed change to the public state of a graph.
+///
+/// Changes refer to the affected elements by index, the changed values can be read from the graph.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GraphChange {
+    VertexTypeAdded {
+        vertex_type: VertexTypeIndex,
+    },
+    VertexTypeDropped {
+        vertex_type: VertexTypeIndex,
+    },
+    VertexAdded {
+        vertex: VertexIndex,
+    },
+    VertexDropped {
+        vertex: VertexIndex,
+    },
+    VertexValueSet {
+        vertex_type: VertexTypeIndex,
+        vertex: VertexIndex,
+    },
+    VertexValueDeleted {
+        vertex_type: VertexTypeIndex,
+        vertex: VertexIndex,
+    },
+    /// Any number of values of the vertex type may have changed.
+    VertexVectorOverwritten {
+        vertex_type: VertexTypeIndex,
+    },
+    EdgeTypeAdded {
+        edge_type: EdgeTypeIndex,
+    },
+    EdgeTypeDropped {
+        edge_type: EdgeTypeIndex,
+    },
+    EdgeWeightSet {
+        edge_type: EdgeTypeIndex,
+        tail: VertexIndex,
+        head: VertexIndex,
+    },
+    EdgeDeleted {
+        edge_type: EdgeTypeIndex,
+        tail: VertexIndex,
+        head: VertexIndex,
+    },
+    /// Any number of edges of the edge type may have changed.
+    AdjacencyMatrixOverwritten {
+        edge_type: EdgeTypeIndex,
+    },
+}
+
+impl GraphChange {
+    pub(crate) fn vertex_type_dropped(vertex_type: &impl GetVertexTypeIndex) -> Self {
+        Self::VertexTypeDropped {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type.index(),
+                vertex_type.generation(),
+            ),
+        }
+    }
+
+    pub(crate) fn vertex_dropped(vertex: &impl GetVertexIndexIndex) -> Self {
+        Self::VertexDropped {
+            vertex: VertexIndex::with_generation(vertex.index(), vertex.generation()),
+        }
+    }
+
+    pub(crate) fn vertex_value_set(
+        vertex_type: &impl GetVertexTypeIndex,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> Self {
+        Self::VertexValueSet {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type.index(),
+                vertex_type.generation(),
+            ),
+            vertex: VertexIndex::with_generation(vertex.index(), vertex.generation()),
+        }
+    }
+
+    pub(crate) fn vertex_value_deleted(
+        vertex_type: &impl GetVertexTypeIndex,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> Self {
+        Self::VertexValueDeleted {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type.index(),
+                vertex_type.generation(),
+            ),
+            vertex: VertexIndex::with_generation(vertex.index(), vertex.generation()),
+        }
+    }
+
+    pub(crate) fn vertex_vector_overwritten(vertex_type: &impl GetVertexTypeIndex) -> Self {
+        Self::VertexVectorOverwritten {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type.index(),
+                vertex_type.generation(),
+            ),
+        }
+    }
+
+    pub(crate) fn edge_type_dropped(edge_type: &impl GetEdgeTypeIndex) -> Self {
+        Self::EdgeTypeDropped {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+        }
+    }
+
+    pub(crate) fn edge_weight_set(
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Self {
+        Self::EdgeWeightSet {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            tail: VertexIndex::with_generation(tail.index(), tail.generation()),
+            head: VertexIndex::with_generation(head.index(), head.generation()),
+        }
+    }
+
+    pub(crate) fn edge_deleted(
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Self {
+        Self::EdgeDeleted {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            tail: VertexIndex::with_generation(tail.index(), tail.generation()),
+            head: VertexIndex::with_generation(head.index(), head.generation()),
+        }
+    }
+
+    pub(crate) fn adjacency_matrix_overwritten(edge_type: &impl GetEdgeTypeIndex) -> Self {
+        Self::AdjacencyMatrixOverwritten {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::mem;
+
+use super::GraphChange;
+
+/// Records the changes of a store transaction, partitioned by savepoint like its state restorers.
+#[derive(Debug, Default)]
+pub(crate) struct GraphChangeRecorder {
+    changes: Vec<GraphChange>,
+    changes_before_savepoints: Vec<Vec<GraphChange>>,
+}
+
+impl GraphChangeRecorder {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn record(&mut self, change: GraphChange) {
+        self.changes.push(change);
+    }
+
+    pub(crate) fn begin_savepoint(&mut self) {
+        let changes_before_savepoint = mem::take(&mut self.changes);
+        self.changes_before_savepoints
+            .push(changes_before_savepoint);
+    }
+
+    /// Discards the changes after the savepoint.
+    pub(crate) fn rollback_to_savepoint(&mut self, number_of_savepoints_to_retain: usize) {
+        self.changes_before_savepoints
+            .truncate(number_of_savepoints_to_retain);
+        self.changes.clear();
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.changes.clear();
+        self.changes_before_savepoints.clear();
+    }
+
+    /// Returns all recorded changes in order of occurrence, and resets the recorder.
+    pub(crate) fn take_changes(&mut self) -> Vec<GraphChange> {
+        let mut changes: Vec<GraphChange> = mem::take(&mut self.changes_before_savepoints)
+            .into_iter()
+            .flatten()
+            .collect();
+        changes.append(&mut self.changes);
+        changes
+    }
+}
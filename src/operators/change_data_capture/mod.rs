@@ -0,0 +1,7 @@
+mod graph_change;
+mod graph_change_recorder;
+mod subscribe;
+
+pub use graph_change::*;
+pub(crate) use graph_change_recorder::*;
+pub use subscribe::*;
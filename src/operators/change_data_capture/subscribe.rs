@@ -0,0 +1,218 @@
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::Graph;
+
+use super::GraphChange;
+
+pub trait SubscribeToGraphChanges {
+    /// Receives the changes of each transaction on the graph after it commits.
+    /// The vertex changes of a transaction are sent before its edge changes.
+    /// Operations that do not run in a transaction are sent when they complete,
+    /// except in a write transaction of a TransactionalGraph, whose changes are sent when it commits.
+    fn subscribe_to_changes(&self) -> Result<Receiver<GraphChange>, GraphComputingError>;
+}
+
+impl SubscribeToGraphChanges for Graph {
+    fn subscribe_to_changes(&self) -> Result<Receiver<GraphChange>, GraphComputingError> {
+        self.change_subscribers.subscribe()
+    }
+}
+
+impl Graph {
+    pub(crate) fn publish_change(&self, change: GraphChange) -> Result<(), GraphComputingError> {
+        self.change_subscribers.publish(vec![change])
+    }
+
+    pub(crate) fn publish_changes(
+        &self,
+        changes: Vec<GraphChange>,
+    ) -> Result<(), GraphComputingError> {
+        self.change_subscribers.publish(changes)
+    }
+
+    /// For changes that add indices an indexer would not assign next, e.g. by renumbering or restoring vertices.
+    pub(crate) fn publish_changes_that_reassign_indices(
+        &self,
+        changes: Vec<GraphChange>,
+    ) -> Result<(), GraphComputingError> {
+        self.change_subscribers
+            .publish_changes_that_reassign_indices(changes)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct GraphChangeSubscribers {
+    senders: Arc<Mutex<Vec<Sender<GraphChange>>>>,
+    // Some while the changes are held back until the copy of the graph commits
+    deferred_changes: Mutex<Option<DeferredChanges>>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DeferredChanges {
+    pub(crate) changes: Vec<GraphChange>,
+    /// Replaying the changes does not assign the same indices again
+    pub(crate) reassign_indices: bool,
+}
+
+// A cloned graph is a new graph, its changes are not published to the subscribers of the original.
+impl Clone for GraphChangeSubscribers {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl GraphChangeSubscribers {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// For a copy of the graph that replaces the original when it commits.
+    /// The copy publishes to the same subscribers, but holds back its changes until take_deferred_changes.
+    pub(crate) fn deferring_copy(&self) -> Self {
+        Self {
+            senders: self.senders.clone(),
+            deferred_changes: Mutex::new(Some(DeferredChanges::default())),
+        }
+    }
+
+    /// Returns the changes deferred so far, without publishing them.
+    pub(crate) fn deferred_changes(&self) -> Result<DeferredChanges, GraphComputingError> {
+        Ok(self.deferred_changes.lock()?.clone().unwrap_or_default())
+    }
+
+    /// Stops deferring, and returns the changes deferred so far.
+    pub(crate) fn take_deferred_changes(&self) -> Result<Vec<GraphChange>, GraphComputingError> {
+        Ok(self
+            .deferred_changes
+            .lock()?
+            .take()
+            .map(|deferred_changes| deferred_changes.changes)
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn subscribe(&self) -> Result<Receiver<GraphChange>, GraphComputingError> {
+        let (sender, receiver) = channel();
+        self.senders.lock()?.push(sender);
+        Ok(receiver)
+    }
+
+    /// Sends the changes to all subscribers, and unsubscribes those who dropped their receiver.
+    pub(crate) fn publish(&self, changes: Vec<GraphChange>) -> Result<(), GraphComputingError> {
+        self.publish_deferring(changes, |_| {})
+    }
+
+    /// For changes that add indices an indexer would not assign next, e.g. by renumbering or restoring vertices.
+    pub(crate) fn publish_changes_that_reassign_indices(
+        &self,
+        changes: Vec<GraphChange>,
+    ) -> Result<(), GraphComputingError> {
+        self.publish_deferring(changes, |deferred_changes| {
+            deferred_changes.reassign_indices = true
+        })
+    }
+
+    // Lets the caller mark the deferred changes while deferring
+    fn publish_deferring(
+        &self,
+        changes: Vec<GraphChange>,
+        mark_deferred_changes: impl FnOnce(&mut DeferredChanges),
+    ) -> Result<(), GraphComputingError> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(deferred_changes) = self.deferred_changes.lock()?.as_mut() {
+            deferred_changes.changes.extend(changes);
+            mark_deferred_changes(deferred_changes);
+            return Ok(());
+        }
+
+        let mut senders = self.senders.lock()?;
+        senders.retain(|sender| {
+            changes
+                .iter()
+                .all(|change| sender.send(change.to_owned()).is_ok())
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+    use crate::operators::operators::delete::DropVertexIndex;
+    use crate::operators::operators::new::{NewVertex, NewVertexType};
+    use crate::operators::operators::set::SetVertexValue;
+    use crate::operators::transaction::{UseSavepoints, UseTransaction};
+
+    #[test]
+    fn only_committed_changes_are_published() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        let changes = graph.subscribe_to_changes().unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            let vertex_type = NewVertexType::<u8>::apply(&mut transaction).unwrap();
+            transaction.new_vertex(&vertex_type, 1u8).unwrap();
+            transaction.revert().unwrap();
+        }
+        assert!(changes.try_recv().is_err());
+
+        let vertex_type;
+        let vertex;
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            vertex_type = NewVertexType::<u8>::apply(&mut transaction).unwrap();
+            vertex = transaction.new_vertex(&vertex_type, 1u8).unwrap();
+
+            let savepoint = transaction.savepoint().unwrap();
+            transaction
+                .set_vertex_value(&vertex_type, &vertex, 2u8)
+                .unwrap();
+            transaction.rollback_to(&savepoint).unwrap();
+
+            transaction.commit().unwrap();
+        }
+
+        assert_eq!(
+            changes.try_iter().collect::<Vec<GraphChange>>(),
+            vec![
+                GraphChange::VertexTypeAdded { vertex_type },
+                GraphChange::VertexAdded { vertex },
+                GraphChange::VertexValueSet {
+                    vertex_type,
+                    vertex
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn operations_without_transaction_are_published() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        let changes = graph.subscribe_to_changes().unwrap();
+
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex(&vertex_type, 1u8).unwrap();
+        graph
+            .drop_vertex_index_and_connected_edges(&vertex)
+            .unwrap();
+
+        assert_eq!(
+            changes.try_iter().collect::<Vec<GraphChange>>(),
+            vec![
+                GraphChange::VertexTypeAdded { vertex_type },
+                GraphChange::VertexAdded { vertex },
+                GraphChange::VertexValueSet {
+                    vertex_type,
+                    vertex
+                },
+                GraphChange::VertexDropped { vertex },
+            ]
+        );
+    }
+}
@@ -0,0 +1,450 @@
+use std::fmt;
+
+use graphblas_sparse_linear_algebra::graphblas_bindings::{
+    GrB_BinaryOp, GrB_Semiring, GrB_UnaryOp,
+};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    AccumulatorBinaryOperator, BinaryOperator,
+};
+use graphblas_sparse_linear_algebra::operators::semiring::Semiring;
+use graphblas_sparse_linear_algebra::operators::unary_operator::UnaryOperator;
+
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetVertexTypeIndex, VertexTypeIndex,
+};
+use crate::graph::value_type::ValueType;
+
+/// A handle to a vector in an Expression. Handles are only valid for the expression that returned them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VectorNode {
+    index: usize,
+}
+
+impl VectorNode {
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// A handle to an adjacency matrix in an Expression. Handles are only valid for the expression that returned them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MatrixNode {
+    index: usize,
+}
+
+impl MatrixNode {
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Records operations over vertex types and edge types without evaluating them.
+/// Nothing is computed until the expression is materialized, and then only what the requested outputs depend on.
+/// All operations evaluate in the domain T; masks select the vertices, or edges, for which the mask has a true value.
+/// The expression borrows its operators, which therefore outlive it.
+#[derive(Clone, Debug)]
+pub struct Expression<'a, T: ValueType> {
+    nodes: Vec<ExpressionNode<'a, T>>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum ExpressionNode<'a, T: ValueType> {
+    VertexType(VertexTypeIndex),
+    UnaryOperator {
+        operator: RecordedUnaryOperator<'a, T>,
+        argument: usize,
+        mask: Option<usize>,
+    },
+    ElementWiseAddition {
+        left_argument: usize,
+        operator: RecordedBinaryOperator<'a, T>,
+        right_argument: usize,
+        mask: Option<usize>,
+    },
+    ElementWiseMultiplication {
+        left_argument: usize,
+        operator: RecordedBinaryOperator<'a, T>,
+        right_argument: usize,
+        mask: Option<usize>,
+    },
+    VertexVectorAdjacencyMatrixMultiplication {
+        vertex_vector: usize,
+        operator: RecordedSemiring<'a, T>,
+        edge_type: EdgeTypeIndex,
+        mask: Option<usize>,
+    },
+    AdjacencyMatrixVertexVectorMultiplication {
+        edge_type: EdgeTypeIndex,
+        operator: RecordedSemiring<'a, T>,
+        vertex_vector: usize,
+        mask: Option<usize>,
+    },
+    EdgeType(EdgeTypeIndex),
+    AdjacencyMatrixUnaryOperator {
+        operator: RecordedUnaryOperator<'a, T>,
+        argument: usize,
+        mask: Option<usize>,
+    },
+    ElementWiseAdjacencyMatrixAddition {
+        left_argument: usize,
+        operator: RecordedBinaryOperator<'a, T>,
+        right_argument: usize,
+        mask: Option<usize>,
+    },
+    ElementWiseAdjacencyMatrixMultiplication {
+        left_argument: usize,
+        operator: RecordedBinaryOperator<'a, T>,
+        right_argument: usize,
+        mask: Option<usize>,
+    },
+    AdjacencyMatrixMultiplication {
+        left_argument: usize,
+        operator: RecordedSemiring<'a, T>,
+        right_argument: usize,
+        mask: Option<usize>,
+    },
+}
+
+impl<'a, T: ValueType> ExpressionNode<'a, T> {
+    /// The nodes that this node reads, including its mask.
+    pub(crate) fn operands(&self) -> Vec<usize> {
+        let (arguments, mask) = match self {
+            ExpressionNode::VertexType(_) | ExpressionNode::EdgeType(_) => (vec![], None),
+            ExpressionNode::UnaryOperator { argument, mask, .. }
+            | ExpressionNode::AdjacencyMatrixUnaryOperator { argument, mask, .. } => {
+                (vec![*argument], *mask)
+            }
+            ExpressionNode::ElementWiseAddition {
+                left_argument,
+                right_argument,
+                mask,
+                ..
+            }
+            | ExpressionNode::ElementWiseMultiplication {
+                left_argument,
+                right_argument,
+                mask,
+                ..
+            }
+            | ExpressionNode::ElementWiseAdjacencyMatrixAddition {
+                left_argument,
+                right_argument,
+                mask,
+                ..
+            }
+            | ExpressionNode::ElementWiseAdjacencyMatrixMultiplication {
+                left_argument,
+                right_argument,
+                mask,
+                ..
+            }
+            | ExpressionNode::AdjacencyMatrixMultiplication {
+                left_argument,
+                right_argument,
+                mask,
+                ..
+            } => (vec![*left_argument, *right_argument], *mask),
+            ExpressionNode::VertexVectorAdjacencyMatrixMultiplication {
+                vertex_vector,
+                mask,
+                ..
+            }
+            | ExpressionNode::AdjacencyMatrixVertexVectorMultiplication {
+                vertex_vector,
+                mask,
+                ..
+            } => (vec![*vertex_vector], *mask),
+        };
+        arguments.into_iter().chain(mask).collect()
+    }
+
+    pub(crate) fn is_multiplication(&self) -> bool {
+        matches!(
+            self,
+            ExpressionNode::VertexVectorAdjacencyMatrixMultiplication { .. }
+                | ExpressionNode::AdjacencyMatrixVertexVectorMultiplication { .. }
+                | ExpressionNode::AdjacencyMatrixMultiplication { .. }
+        )
+    }
+
+    /// Whether the node is an adjacency matrix, rather than a vertex vector.
+    pub(crate) fn is_matrix(&self) -> bool {
+        matches!(
+            self,
+            ExpressionNode::EdgeType(_)
+                | ExpressionNode::AdjacencyMatrixUnaryOperator { .. }
+                | ExpressionNode::ElementWiseAdjacencyMatrixAddition { .. }
+                | ExpressionNode::ElementWiseAdjacencyMatrixMultiplication { .. }
+                | ExpressionNode::AdjacencyMatrixMultiplication { .. }
+        )
+    }
+}
+
+impl<'a, T: ValueType> Expression<'a, T> {
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    /// Reads the vertex vector of the vertex type when the expression is materialized.
+    pub fn vertex_type(&mut self, vertex_type: &impl GetVertexTypeIndex) -> VectorNode {
+        self.push(ExpressionNode::VertexType(
+            VertexTypeIndex::with_generation(vertex_type.index(), vertex_type.generation()),
+        ))
+    }
+
+    pub fn apply_unary_operator(
+        &mut self,
+        operator: &'a impl UnaryOperator<T>,
+        argument: VectorNode,
+        mask: Option<VectorNode>,
+    ) -> VectorNode {
+        self.push(ExpressionNode::UnaryOperator {
+            operator: RecordedUnaryOperator::new(operator),
+            argument: argument.index,
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    pub fn element_wise_addition(
+        &mut self,
+        left_argument: VectorNode,
+        operator: &'a impl BinaryOperator<T>,
+        right_argument: VectorNode,
+        mask: Option<VectorNode>,
+    ) -> VectorNode {
+        self.push(ExpressionNode::ElementWiseAddition {
+            left_argument: left_argument.index,
+            operator: RecordedBinaryOperator::new(operator),
+            right_argument: right_argument.index,
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    pub fn element_wise_multiplication(
+        &mut self,
+        left_argument: VectorNode,
+        operator: &'a impl BinaryOperator<T>,
+        right_argument: VectorNode,
+        mask: Option<VectorNode>,
+    ) -> VectorNode {
+        self.push(ExpressionNode::ElementWiseMultiplication {
+            left_argument: left_argument.index,
+            operator: RecordedBinaryOperator::new(operator),
+            right_argument: right_argument.index,
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    /// vertex_vector * adjacency matrix, i.e. follows outgoing edges.
+    pub fn vertex_vector_adjacency_matrix_multiplication(
+        &mut self,
+        vertex_vector: VectorNode,
+        operator: &'a impl Semiring<T>,
+        edge_type: &impl GetEdgeTypeIndex,
+        mask: Option<VectorNode>,
+    ) -> VectorNode {
+        self.push(ExpressionNode::VertexVectorAdjacencyMatrixMultiplication {
+            vertex_vector: vertex_vector.index,
+            operator: RecordedSemiring::new(operator),
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    /// adjacency matrix * vertex_vector, i.e. follows incoming edges.
+    pub fn adjacency_matrix_vertex_vector_multiplication(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        operator: &'a impl Semiring<T>,
+        vertex_vector: VectorNode,
+        mask: Option<VectorNode>,
+    ) -> VectorNode {
+        self.push(ExpressionNode::AdjacencyMatrixVertexVectorMultiplication {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            operator: RecordedSemiring::new(operator),
+            vertex_vector: vertex_vector.index,
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    /// Reads the adjacency matrix of the edge type when the expression is materialized.
+    pub fn edge_type(&mut self, edge_type: &impl GetEdgeTypeIndex) -> MatrixNode {
+        self.push_matrix(ExpressionNode::EdgeType(EdgeTypeIndex::with_generation(
+            edge_type.index(),
+            edge_type.generation(),
+        )))
+    }
+
+    pub fn apply_unary_operator_to_adjacency_matrix(
+        &mut self,
+        operator: &'a impl UnaryOperator<T>,
+        argument: MatrixNode,
+        mask: Option<MatrixNode>,
+    ) -> MatrixNode {
+        self.push_matrix(ExpressionNode::AdjacencyMatrixUnaryOperator {
+            operator: RecordedUnaryOperator::new(operator),
+            argument: argument.index,
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    pub fn element_wise_adjacency_matrix_addition(
+        &mut self,
+        left_argument: MatrixNode,
+        operator: &'a impl BinaryOperator<T>,
+        right_argument: MatrixNode,
+        mask: Option<MatrixNode>,
+    ) -> MatrixNode {
+        self.push_matrix(ExpressionNode::ElementWiseAdjacencyMatrixAddition {
+            left_argument: left_argument.index,
+            operator: RecordedBinaryOperator::new(operator),
+            right_argument: right_argument.index,
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    pub fn element_wise_adjacency_matrix_multiplication(
+        &mut self,
+        left_argument: MatrixNode,
+        operator: &'a impl BinaryOperator<T>,
+        right_argument: MatrixNode,
+        mask: Option<MatrixNode>,
+    ) -> MatrixNode {
+        self.push_matrix(ExpressionNode::ElementWiseAdjacencyMatrixMultiplication {
+            left_argument: left_argument.index,
+            operator: RecordedBinaryOperator::new(operator),
+            right_argument: right_argument.index,
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    /// left_argument * right_argument, i.e. follows an edge of the left argument and then an edge of the right argument.
+    pub fn adjacency_matrix_multiplication(
+        &mut self,
+        left_argument: MatrixNode,
+        operator: &'a impl Semiring<T>,
+        right_argument: MatrixNode,
+        mask: Option<MatrixNode>,
+    ) -> MatrixNode {
+        self.push_matrix(ExpressionNode::AdjacencyMatrixMultiplication {
+            left_argument: left_argument.index,
+            operator: RecordedSemiring::new(operator),
+            right_argument: right_argument.index,
+            mask: mask.map(|mask| mask.index),
+        })
+    }
+
+    pub fn number_of_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(crate) fn nodes_ref(&self) -> &[ExpressionNode<'a, T>] {
+        &self.nodes
+    }
+
+    fn push(&mut self, node: ExpressionNode<'a, T>) -> VectorNode {
+        self.nodes.push(node);
+        VectorNode {
+            index: self.nodes.len() - 1,
+        }
+    }
+
+    fn push_matrix(&mut self, node: ExpressionNode<'a, T>) -> MatrixNode {
+        self.nodes.push(node);
+        MatrixNode {
+            index: self.nodes.len() - 1,
+        }
+    }
+}
+
+impl<'a, T: ValueType> Default for Expression<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The recorded operators borrow the operator, so that an expression can hold operators of different types.
+
+#[derive(Clone)]
+pub(crate) struct RecordedUnaryOperator<'a, T: ValueType> {
+    operator: &'a dyn UnaryOperator<T>,
+}
+
+impl<'a, T: ValueType> RecordedUnaryOperator<'a, T> {
+    fn new(operator: &'a impl UnaryOperator<T>) -> Self {
+        Self { operator }
+    }
+}
+
+impl<'a, T: ValueType> UnaryOperator<T> for RecordedUnaryOperator<'a, T> {
+    fn graphblas_type(&self) -> GrB_UnaryOp {
+        self.operator.graphblas_type()
+    }
+}
+
+impl<'a, T: ValueType> fmt::Debug for RecordedUnaryOperator<'a, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("RecordedUnaryOperator")
+            .field(&self.graphblas_type())
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct RecordedBinaryOperator<'a, T: ValueType> {
+    operator: &'a dyn BinaryOperator<T>,
+}
+
+impl<'a, T: ValueType> RecordedBinaryOperator<'a, T> {
+    fn new(operator: &'a impl BinaryOperator<T>) -> Self {
+        Self { operator }
+    }
+}
+
+impl<'a, T: ValueType> AccumulatorBinaryOperator<T> for RecordedBinaryOperator<'a, T> {
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        self.operator.accumulator_graphblas_type()
+    }
+}
+
+impl<'a, T: ValueType> BinaryOperator<T> for RecordedBinaryOperator<'a, T> {
+    fn graphblas_type(&self) -> GrB_BinaryOp {
+        self.operator.graphblas_type()
+    }
+}
+
+impl<'a, T: ValueType> fmt::Debug for RecordedBinaryOperator<'a, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("RecordedBinaryOperator")
+            .field(&self.graphblas_type())
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct RecordedSemiring<'a, T: ValueType> {
+    operator: &'a dyn Semiring<T>,
+}
+
+impl<'a, T: ValueType> RecordedSemiring<'a, T> {
+    fn new(operator: &'a impl Semiring<T>) -> Self {
+        Self { operator }
+    }
+}
+
+impl<'a, T: ValueType> Semiring<T> for RecordedSemiring<'a, T> {
+    fn graphblas_type(&self) -> GrB_Semiring {
+        self.operator.graphblas_type()
+    }
+}
+
+impl<'a, T: ValueType> fmt::Debug for RecordedSemiring<'a, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("RecordedSemiring")
+            .field(&self.graphblas_type())
+            .finish()
+    }
+}
@@ -0,0 +1,974 @@
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorLength;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::{
+    GetGraphblasSparseVector, SparseVector,
+};
+use graphblas_sparse_linear_algebra::context::{Context as GraphBLASContext, GetContext};
+use graphblas_sparse_linear_algebra::graphblas_bindings::{GrB_Matrix, GrB_Vector};
+use graphblas_sparse_linear_algebra::operators::apply::ApplyUnaryOperator;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    AccumulatorBinaryOperator, Assignment,
+};
+use graphblas_sparse_linear_algebra::operators::element_wise_addition::{
+    ApplyElementWiseMatrixAdditionBinaryOperator, ApplyElementWiseVectorAdditionBinaryOperator,
+};
+use graphblas_sparse_linear_algebra::operators::element_wise_multiplication::{
+    ApplyElementWiseMatrixMultiplicationBinaryOperator,
+    ApplyElementWiseVectorMultiplicationBinaryOperator,
+};
+use graphblas_sparse_linear_algebra::operators::mask::{MatrixMask, VectorMask};
+use graphblas_sparse_linear_algebra::operators::multiplication::{
+    MultiplyMatrices, MultiplyMatrixByVector, MultiplyVectorByMatrix,
+};
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixArgument, OptionsForOperatorWithMatrixArguments,
+    OptionsForOperatorWithMatrixAsFirstArgument, OptionsForOperatorWithMatrixAsSecondArgument,
+};
+use graphblas_sparse_linear_algebra::operators::unary_operator::{Identity, UnaryOperator};
+
+use crate::algorithms::results::{write_to_adjacency_matrix, write_to_vertex_vector};
+use crate::error::{GraphComputingError, LogicError, LogicErrorType};
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{
+    GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection,
+};
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{EdgeTypeIndex, VertexTypeIndex};
+use crate::graph::value_type::ValueType;
+use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::graph::vertex_store::GetVertexElementIndexer;
+use crate::operators::change_data_capture::GraphChange;
+
+use super::{Expression, ExpressionNode, MatrixNode, VectorNode};
+
+pub trait MaterializeExpression<T: ValueType> {
+    /// Evaluates only the nodes that the outputs depend on, and replaces the vertex vector of each output vertex type by its node.
+    /// Vertex types are read in place, and intermediate results are released after their last use.
+    fn materialize(
+        &mut self,
+        expression: &Expression<'_, T>,
+        outputs: &[(VectorNode, VertexTypeIndex)],
+    ) -> Result<(), GraphComputingError>;
+
+    /// Like materialize, and also replaces the adjacency matrix of each output edge type by its node.
+    /// The vertex type and edge type outputs share the evaluation of the nodes they depend on.
+    fn materialize_into_vertex_and_edge_types(
+        &mut self,
+        expression: &Expression<'_, T>,
+        vertex_type_outputs: &[(VectorNode, VertexTypeIndex)],
+        edge_type_outputs: &[(MatrixNode, EdgeTypeIndex)],
+    ) -> Result<(), GraphComputingError>;
+}
+
+impl<T> MaterializeExpression<T> for Graph
+where
+    T: ValueType,
+    Identity<T>: UnaryOperator<T>,
+{
+    fn materialize(
+        &mut self,
+        expression: &Expression<'_, T>,
+        outputs: &[(VectorNode, VertexTypeIndex)],
+    ) -> Result<(), GraphComputingError> {
+        self.materialize_into_vertex_and_edge_types(expression, outputs, &[])
+    }
+
+    fn materialize_into_vertex_and_edge_types(
+        &mut self,
+        expression: &Expression<'_, T>,
+        vertex_type_outputs: &[(VectorNode, VertexTypeIndex)],
+        edge_type_outputs: &[(MatrixNode, EdgeTypeIndex)],
+    ) -> Result<(), GraphComputingError> {
+        try_expression_validity(
+            &self.public_vertex_store,
+            &self.public_edge_store,
+            expression,
+            vertex_type_outputs,
+            edge_type_outputs,
+        )?;
+
+        let plan = EvaluationPlan::new(expression, vertex_type_outputs, edge_type_outputs);
+        let (vector_results, matrix_results) = materialize_unchecked(
+            &self.public_vertex_store,
+            &self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            expression,
+            &plan,
+            vertex_type_outputs,
+            edge_type_outputs,
+        )?;
+
+        for (result, (_, vertex_type)) in vector_results.iter().zip(vertex_type_outputs) {
+            write_to_vertex_vector(
+                &mut self.public_vertex_store,
+                &self.graphblas_operator_applier_collection,
+                result,
+                vertex_type,
+            )?;
+        }
+        for (result, (_, edge_type)) in matrix_results.iter().zip(edge_type_outputs) {
+            write_to_adjacency_matrix(
+                &mut self.public_edge_store,
+                &self.graphblas_operator_applier_collection,
+                result,
+                edge_type,
+            )?;
+        }
+        self.publish_changes(
+            vertex_type_outputs
+                .iter()
+                .map(|(_, vertex_type)| GraphChange::vertex_vector_overwritten(vertex_type))
+                .chain(
+                    edge_type_outputs
+                        .iter()
+                        .map(|(_, edge_type)| GraphChange::adjacency_matrix_overwritten(edge_type)),
+                )
+                .collect(),
+        )
+    }
+}
+
+fn try_expression_validity<T: ValueType>(
+    vertex_store: &impl CheckVertexTypeIndex,
+    edge_store: &impl EdgeTypeIndexing,
+    expression: &Expression<'_, T>,
+    vertex_type_outputs: &[(VectorNode, VertexTypeIndex)],
+    edge_type_outputs: &[(MatrixNode, EdgeTypeIndex)],
+) -> Result<(), GraphComputingError> {
+    let nodes = expression.nodes_ref();
+    for (node, vertex_type) in vertex_type_outputs {
+        if node.index() >= nodes.len() || nodes[node.index()].is_matrix() {
+            return Err(invalid_node(node.index()));
+        }
+        vertex_store.try_vertex_type_index_validity(vertex_type)?;
+    }
+    for (node, edge_type) in edge_type_outputs {
+        if node.index() >= nodes.len() || !nodes[node.index()].is_matrix() {
+            return Err(invalid_node(node.index()));
+        }
+        edge_store.try_edge_type_index_validity(edge_type)?;
+    }
+
+    for (index, node) in nodes.iter().enumerate() {
+        // A node can only read nodes recorded before it, of its own kind, unless it was given a node of another expression.
+        if node
+            .operands()
+            .iter()
+            .any(|operand| *operand >= index || nodes[*operand].is_matrix() != node.is_matrix())
+        {
+            return Err(invalid_node(index));
+        }
+        match node {
+            ExpressionNode::VertexType(vertex_type) => {
+                vertex_store.try_vertex_type_index_validity(vertex_type)?
+            }
+            ExpressionNode::EdgeType(edge_type)
+            | ExpressionNode::VertexVectorAdjacencyMatrixMultiplication { edge_type, .. }
+            | ExpressionNode::AdjacencyMatrixVertexVectorMultiplication { edge_type, .. } => {
+                edge_store.try_edge_type_index_validity(edge_type)?
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn invalid_node(index: usize) -> GraphComputingError {
+    LogicError::new(
+        LogicErrorType::InvalidIndex,
+        format!(
+            "Expression node {} refers to a node that is not part of the expression",
+            index
+        ),
+        None,
+    )
+    .into()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EvaluationStep {
+    /// No output depends on the node.
+    Skip,
+    Evaluate,
+    /// The multiplication is only read by an unmasked element-wise addition of its own kind, as its right argument.
+    /// The addition then multiplies into a copy of its left argument, with its operator as accumulator.
+    FuseIntoConsumer,
+}
+
+pub(crate) struct EvaluationPlan {
+    steps: Vec<EvaluationStep>,
+    /// The number of reads of each node, by other nodes and by the outputs.
+    uses: Vec<usize>,
+}
+
+impl EvaluationPlan {
+    pub(crate) fn new<T: ValueType>(
+        expression: &Expression<'_, T>,
+        vertex_type_outputs: &[(VectorNode, VertexTypeIndex)],
+        edge_type_outputs: &[(MatrixNode, EdgeTypeIndex)],
+    ) -> Self {
+        let nodes = expression.nodes_ref();
+        let mut steps = vec![EvaluationStep::Skip; nodes.len()];
+        let mut uses = vec![0; nodes.len()];
+
+        let output_nodes = vertex_type_outputs
+            .iter()
+            .map(|(node, _)| node.index())
+            .chain(edge_type_outputs.iter().map(|(node, _)| node.index()));
+        for node in output_nodes {
+            steps[node] = EvaluationStep::Evaluate;
+            uses[node] += 1;
+        }
+        for index in (0..nodes.len()).rev() {
+            if steps[index] == EvaluationStep::Evaluate {
+                for operand in nodes[index].operands() {
+                    steps[operand] = EvaluationStep::Evaluate;
+                    uses[operand] += 1;
+                }
+            }
+        }
+
+        for (index, node) in nodes.iter().enumerate() {
+            if let ExpressionNode::ElementWiseAddition {
+                right_argument,
+                mask: None,
+                ..
+            }
+            | ExpressionNode::ElementWiseAdjacencyMatrixAddition {
+                right_argument,
+                mask: None,
+                ..
+            } = node
+            {
+                if steps[index] == EvaluationStep::Evaluate
+                    && uses[*right_argument] == 1
+                    && nodes[*right_argument].is_multiplication()
+                {
+                    steps[*right_argument] = EvaluationStep::FuseIntoConsumer;
+                }
+            }
+        }
+
+        Self { steps, uses }
+    }
+
+    pub(crate) fn step(&self, index: usize) -> EvaluationStep {
+        self.steps[index]
+    }
+}
+
+/// Returns the value of each vertex type output node, and of each edge type output node.
+pub(crate) fn materialize_unchecked<T>(
+    vertex_store: &(impl GetVertexVector + GetVertexElementIndexer),
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    expression: &Expression<'_, T>,
+    plan: &EvaluationPlan,
+    vertex_type_outputs: &[(VectorNode, VertexTypeIndex)],
+    edge_type_outputs: &[(MatrixNode, EdgeTypeIndex)],
+) -> Result<(Vec<SparseVector<T>>, Vec<SparseMatrix<T>>), GraphComputingError>
+where
+    T: ValueType,
+    Identity<T>: UnaryOperator<T>,
+{
+    let valid_vertices = vertex_store
+        .element_indexer_ref()
+        .mask_with_valid_indices_ref();
+    let nodes = expression.nodes_ref();
+    let mut evaluation = Evaluation {
+        vertex_store,
+        edge_store,
+        graphblas_operator_applier_collection,
+        nodes,
+        context: valid_vertices.context(),
+        vertex_capacity: valid_vertices.length()?,
+        vector_values: nodes.iter().map(|_| None).collect(),
+        matrix_values: nodes.iter().map(|_| None).collect(),
+        uses: plan.uses.clone(),
+    };
+
+    for (index, node) in nodes.iter().enumerate() {
+        if plan.step(index) != EvaluationStep::Evaluate {
+            continue;
+        }
+        let reads = match node {
+            ExpressionNode::VertexType(_) | ExpressionNode::EdgeType(_) => continue,
+            ExpressionNode::ElementWiseAddition {
+                left_argument,
+                operator,
+                right_argument,
+                ..
+            } if plan.step(*right_argument) == EvaluationStep::FuseIntoConsumer => {
+                let mut product = evaluation.take_or_copy(*left_argument)?;
+                evaluation.evaluate_into(*right_argument, operator, &mut product)?;
+                evaluation.vector_values[index] = Some(product);
+                nodes[*right_argument].operands()
+            }
+            ExpressionNode::ElementWiseAdjacencyMatrixAddition {
+                left_argument,
+                operator,
+                right_argument,
+                ..
+            } if plan.step(*right_argument) == EvaluationStep::FuseIntoConsumer => {
+                let mut product = evaluation.take_or_copy_matrix(*left_argument)?;
+                evaluation.evaluate_matrix_into(*right_argument, operator, &mut product)?;
+                evaluation.matrix_values[index] = Some(product);
+                nodes[*right_argument].operands()
+            }
+            _ if node.is_matrix() => {
+                let mut product = evaluation.new_matrix()?;
+                evaluation.evaluate_matrix_into(index, &Assignment::new(), &mut product)?;
+                evaluation.matrix_values[index] = Some(product);
+                node.operands()
+            }
+            _ => {
+                let mut product = evaluation.new_vector()?;
+                evaluation.evaluate_into(index, &Assignment::new(), &mut product)?;
+                evaluation.vector_values[index] = Some(product);
+                node.operands()
+            }
+        };
+        for operand in reads {
+            evaluation.release(operand);
+        }
+    }
+
+    let vector_results = vertex_type_outputs
+        .iter()
+        .map(|(node, _)| evaluation.take_or_copy(node.index()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let matrix_results = edge_type_outputs
+        .iter()
+        .map(|(node, _)| evaluation.take_or_copy_matrix(node.index()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((vector_results, matrix_results))
+}
+
+struct Evaluation<'a, T: ValueType, V, E> {
+    vertex_store: &'a V,
+    edge_store: &'a E,
+    graphblas_operator_applier_collection: &'a GraphblasOperatorApplierCollection,
+    nodes: &'a [ExpressionNode<'a, T>],
+    context: Arc<GraphBLASContext>,
+    vertex_capacity: usize,
+    vector_values: Vec<Option<SparseVector<T>>>,
+    matrix_values: Vec<Option<SparseMatrix<T>>>,
+    uses: Vec<usize>,
+}
+
+impl<'a, T, V, E> Evaluation<'a, T, V, E>
+where
+    T: ValueType,
+    Identity<T>: UnaryOperator<T>,
+    V: GetVertexVector,
+    E: GetAdjacencyMatrix,
+{
+    fn new_vector(&self) -> Result<SparseVector<T>, GraphComputingError> {
+        Ok(SparseVector::<T>::new(
+            self.context.clone(),
+            self.vertex_capacity,
+        )?)
+    }
+
+    fn new_matrix(&self) -> Result<SparseMatrix<T>, GraphComputingError> {
+        let vertex_capacity = *self.edge_store.adjacency_matrix_size_ref();
+        Ok(SparseMatrix::<T>::new(
+            self.context.clone(),
+            Size::new(vertex_capacity, vertex_capacity),
+        )?)
+    }
+
+    fn argument(&self, index: usize) -> Result<VectorArgument<'_>, GraphComputingError> {
+        match &self.nodes[index] {
+            ExpressionNode::VertexType(vertex_type) => Ok(VectorArgument::new(
+                self.vertex_store.vertex_vector_ref_unchecked(vertex_type),
+            )),
+            _ => match &self.vector_values[index] {
+                Some(value) => Ok(VectorArgument::new(value)),
+                None => Err(released_node(index)),
+            },
+        }
+    }
+
+    fn matrix_argument(&self, index: usize) -> Result<MatrixArgument<'_>, GraphComputingError> {
+        match &self.nodes[index] {
+            ExpressionNode::EdgeType(edge_type) => Ok(MatrixArgument::new(
+                self.edge_store.adjacency_matrix_ref_unchecked(edge_type),
+            )),
+            _ => match &self.matrix_values[index] {
+                Some(value) => Ok(MatrixArgument::new(value)),
+                None => Err(released_node(index)),
+            },
+        }
+    }
+
+    fn mask(&self, mask: &Option<usize>) -> Result<VectorArgument<'_>, GraphComputingError> {
+        match mask {
+            Some(index) => self.argument(*index),
+            None => Ok(VectorArgument::entire_vector(self.context.clone())),
+        }
+    }
+
+    fn matrix_mask(&self, mask: &Option<usize>) -> Result<MatrixArgument<'_>, GraphComputingError> {
+        match mask {
+            Some(index) => self.matrix_argument(*index),
+            None => Ok(MatrixArgument::entire_matrix(self.context.clone())),
+        }
+    }
+
+    fn evaluate_into(
+        &self,
+        index: usize,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut SparseVector<T>,
+    ) -> Result<(), GraphComputingError> {
+        let appliers = self.graphblas_operator_applier_collection;
+        match &self.nodes[index] {
+            ExpressionNode::VertexType(_) => self.copy_into(index, accumulator, product)?,
+            ExpressionNode::UnaryOperator {
+                operator,
+                argument,
+                mask,
+            } => appliers.unary_operator_applier().apply_to_vector(
+                operator,
+                &self.argument(*argument)?,
+                accumulator,
+                product,
+                &self.mask(mask)?,
+                &OperatorOptions::new_default(),
+            )?,
+            ExpressionNode::ElementWiseAddition {
+                left_argument,
+                operator,
+                right_argument,
+                mask,
+            } => appliers
+                .element_wise_vector_addition_binary_operator()
+                .apply(
+                    &self.argument(*left_argument)?,
+                    operator,
+                    &self.argument(*right_argument)?,
+                    accumulator,
+                    product,
+                    &self.mask(mask)?,
+                    &OperatorOptions::new_default(),
+                )?,
+            ExpressionNode::ElementWiseMultiplication {
+                left_argument,
+                operator,
+                right_argument,
+                mask,
+            } => appliers
+                .element_wise_vector_multiplication_binary_operator()
+                .apply(
+                    &self.argument(*left_argument)?,
+                    operator,
+                    &self.argument(*right_argument)?,
+                    accumulator,
+                    product,
+                    &self.mask(mask)?,
+                    &OperatorOptions::new_default(),
+                )?,
+            ExpressionNode::VertexVectorAdjacencyMatrixMultiplication {
+                vertex_vector,
+                operator,
+                edge_type,
+                mask,
+            } => appliers.vector_matrix_multiplication_operator().apply(
+                &self.argument(*vertex_vector)?,
+                operator,
+                self.edge_store.adjacency_matrix_ref_unchecked(edge_type),
+                accumulator,
+                product,
+                &self.mask(mask)?,
+                &OptionsForOperatorWithMatrixAsSecondArgument::new_default(),
+            )?,
+            ExpressionNode::AdjacencyMatrixVertexVectorMultiplication {
+                edge_type,
+                operator,
+                vertex_vector,
+                mask,
+            } => appliers.matrix_vector_multiplication_operator().apply(
+                self.edge_store.adjacency_matrix_ref_unchecked(edge_type),
+                operator,
+                &self.argument(*vertex_vector)?,
+                accumulator,
+                product,
+                &self.mask(mask)?,
+                &OptionsForOperatorWithMatrixAsFirstArgument::new_default(),
+            )?,
+            _ => return Err(unexpected_node_kind(index)),
+        }
+        Ok(())
+    }
+
+    fn evaluate_matrix_into(
+        &self,
+        index: usize,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut SparseMatrix<T>,
+    ) -> Result<(), GraphComputingError> {
+        let appliers = self.graphblas_operator_applier_collection;
+        match &self.nodes[index] {
+            ExpressionNode::EdgeType(_) => self.copy_matrix_into(index, accumulator, product)?,
+            ExpressionNode::AdjacencyMatrixUnaryOperator {
+                operator,
+                argument,
+                mask,
+            } => appliers.unary_operator_applier().apply_to_matrix(
+                operator,
+                &self.matrix_argument(*argument)?,
+                accumulator,
+                product,
+                &self.matrix_mask(mask)?,
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )?,
+            ExpressionNode::ElementWiseAdjacencyMatrixAddition {
+                left_argument,
+                operator,
+                right_argument,
+                mask,
+            } => appliers
+                .element_wise_matrix_addition_binary_operator()
+                .apply(
+                    &self.matrix_argument(*left_argument)?,
+                    operator,
+                    &self.matrix_argument(*right_argument)?,
+                    accumulator,
+                    product,
+                    &self.matrix_mask(mask)?,
+                    &OptionsForOperatorWithMatrixArguments::new_default(),
+                )?,
+            ExpressionNode::ElementWiseAdjacencyMatrixMultiplication {
+                left_argument,
+                operator,
+                right_argument,
+                mask,
+            } => appliers
+                .element_wise_matrix_multiplication_binary_operator()
+                .apply(
+                    &self.matrix_argument(*left_argument)?,
+                    operator,
+                    &self.matrix_argument(*right_argument)?,
+                    accumulator,
+                    product,
+                    &self.matrix_mask(mask)?,
+                    &OptionsForOperatorWithMatrixArguments::new_default(),
+                )?,
+            ExpressionNode::AdjacencyMatrixMultiplication {
+                left_argument,
+                operator,
+                right_argument,
+                mask,
+            } => appliers.matrix_multiplication_operator().apply(
+                &self.matrix_argument(*left_argument)?,
+                operator,
+                &self.matrix_argument(*right_argument)?,
+                accumulator,
+                product,
+                &self.matrix_mask(mask)?,
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )?,
+            _ => return Err(unexpected_node_kind(index)),
+        }
+        Ok(())
+    }
+
+    /// Reads the node into a vector of its own, moving the value out at the last read.
+    fn take_or_copy(&mut self, index: usize) -> Result<SparseVector<T>, GraphComputingError> {
+        self.uses[index] -= 1;
+        if self.uses[index] == 0 {
+            if let Some(value) = self.vector_values[index].take() {
+                return Ok(value);
+            }
+        }
+        let mut copy = self.new_vector()?;
+        self.copy_into(index, &Assignment::new(), &mut copy)?;
+        Ok(copy)
+    }
+
+    fn take_or_copy_matrix(
+        &mut self,
+        index: usize,
+    ) -> Result<SparseMatrix<T>, GraphComputingError> {
+        self.uses[index] -= 1;
+        if self.uses[index] == 0 {
+            if let Some(value) = self.matrix_values[index].take() {
+                return Ok(value);
+            }
+        }
+        let mut copy = self.new_matrix()?;
+        self.copy_matrix_into(index, &Assignment::new(), &mut copy)?;
+        Ok(copy)
+    }
+
+    fn copy_into(
+        &self,
+        index: usize,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut SparseVector<T>,
+    ) -> Result<(), GraphComputingError> {
+        Ok(self
+            .graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_vector(
+                &Identity::<T>::new(),
+                &self.argument(index)?,
+                accumulator,
+                product,
+                &self.mask(&None)?,
+                &OperatorOptions::new_default(),
+            )?)
+    }
+
+    fn copy_matrix_into(
+        &self,
+        index: usize,
+        accumulator: &impl AccumulatorBinaryOperator<T>,
+        product: &mut SparseMatrix<T>,
+    ) -> Result<(), GraphComputingError> {
+        Ok(self
+            .graphblas_operator_applier_collection
+            .unary_operator_applier()
+            .apply_to_matrix(
+                &Identity::<T>::new(),
+                &self.matrix_argument(index)?,
+                accumulator,
+                product,
+                &self.matrix_mask(&None)?,
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )?)
+    }
+
+    fn release(&mut self, index: usize) {
+        self.uses[index] -= 1;
+        if self.uses[index] == 0 {
+            self.vector_values[index] = None;
+            self.matrix_values[index] = None;
+        }
+    }
+}
+
+fn released_node(index: usize) -> GraphComputingError {
+    LogicError::new(
+        LogicErrorType::Other,
+        format!("Expression node {} was released before it was read", index),
+        None,
+    )
+    .into()
+}
+
+fn unexpected_node_kind(index: usize) -> GraphComputingError {
+    LogicError::new(
+        LogicErrorType::Other,
+        format!(
+            "Expression node {} is evaluated as the wrong kind of node, vertex vector or adjacency matrix",
+            index
+        ),
+        None,
+    )
+    .into()
+}
+
+/// Refers to a vertex vector or an intermediate result without copying it.
+struct VectorArgument<'a> {
+    graphblas_vector: GrB_Vector,
+    context: Arc<GraphBLASContext>,
+    vector: PhantomData<&'a ()>,
+}
+
+impl<'a> VectorArgument<'a> {
+    fn new(vector: &'a impl GetGraphblasSparseVector) -> Self {
+        Self {
+            graphblas_vector: unsafe { vector.graphblas_vector() },
+            context: vector.context(),
+            vector: PhantomData,
+        }
+    }
+
+    /// Only valid as mask, like SelectEntireVector.
+    fn entire_vector(context: Arc<GraphBLASContext>) -> Self {
+        Self {
+            graphblas_vector: ptr::null_mut(),
+            context,
+            vector: PhantomData,
+        }
+    }
+}
+
+impl<'a> GetContext for VectorArgument<'a> {
+    fn context(&self) -> Arc<GraphBLASContext> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<GraphBLASContext> {
+        &self.context
+    }
+}
+
+impl<'a> GetGraphblasSparseVector for VectorArgument<'a> {
+    unsafe fn graphblas_vector(&self) -> GrB_Vector {
+        self.graphblas_vector
+    }
+
+    unsafe fn graphblas_vector_ref(&self) -> &GrB_Vector {
+        &self.graphblas_vector
+    }
+
+    unsafe fn graphblas_vector_mut_ref(&mut self) -> &mut GrB_Vector {
+        &mut self.graphblas_vector
+    }
+}
+
+impl<'a> VectorMask for VectorArgument<'a> {
+    unsafe fn graphblas_vector(&self) -> GrB_Vector {
+        self.graphblas_vector
+    }
+}
+
+/// Refers to an adjacency matrix or an intermediate result without copying it.
+struct MatrixArgument<'a> {
+    graphblas_matrix: GrB_Matrix,
+    context: Arc<GraphBLASContext>,
+    matrix: PhantomData<&'a ()>,
+}
+
+impl<'a> MatrixArgument<'a> {
+    fn new(matrix: &'a impl GetGraphblasSparseMatrix) -> Self {
+        Self {
+            graphblas_matrix: unsafe { matrix.graphblas_matrix() },
+            context: matrix.context(),
+            matrix: PhantomData,
+        }
+    }
+
+    /// Only valid as mask, like SelectEntireMatrix.
+    fn entire_matrix(context: Arc<GraphBLASContext>) -> Self {
+        Self {
+            graphblas_matrix: ptr::null_mut(),
+            context,
+            matrix: PhantomData,
+        }
+    }
+}
+
+impl<'a> GetContext for MatrixArgument<'a> {
+    fn context(&self) -> Arc<GraphBLASContext> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<GraphBLASContext> {
+        &self.context
+    }
+}
+
+impl<'a> GetGraphblasSparseMatrix for MatrixArgument<'a> {
+    unsafe fn graphblas_matrix(&self) -> GrB_Matrix {
+        self.graphblas_matrix
+    }
+
+    unsafe fn graphblas_matrix_ref(&self) -> &GrB_Matrix {
+        &self.graphblas_matrix
+    }
+
+    unsafe fn graphblas_matrix_mut_ref(&mut self) -> &mut GrB_Matrix {
+        &mut self.graphblas_matrix
+    }
+}
+
+impl<'a> MatrixMask for MatrixArgument<'a> {
+    unsafe fn graphblas_matrix(&self) -> GrB_Matrix {
+        self.graphblas_matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphblas_sparse_linear_algebra::operators::binary_operator::Plus;
+    use graphblas_sparse_linear_algebra::operators::semiring::PlusTimes;
+    use graphblas_sparse_linear_algebra::operators::unary_operator::AdditiveInverse;
+
+    use crate::graph::indexing::{EdgeTypeIndex, VertexIndex};
+    use crate::operators::operators::new::{
+        NewEdge, NewEdgeType, NewVertex, NewVertexIndex, NewVertexType,
+    };
+    use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+    use crate::operators::operators::set::SetVertexValue;
+
+    // a = [1, 2, _], b = [_, 10, 20], and edges 0 -> 1 with weight 3 and 1 -> 2 with weight 4
+    fn graph_with_two_vertex_types(
+        graph: &mut Graph,
+    ) -> (
+        VertexTypeIndex,
+        VertexTypeIndex,
+        EdgeTypeIndex,
+        Vec<VertexIndex>,
+    ) {
+        let a = NewVertexType::<f64>::apply(graph).unwrap();
+        let b = NewVertexType::<f64>::apply(graph).unwrap();
+        let edge_type = NewEdgeType::<f64>::apply(graph).unwrap();
+
+        let vertices = vec![
+            graph.new_vertex(&a, 1.0).unwrap(),
+            graph.new_vertex(&a, 2.0).unwrap(),
+            graph.new_vertex_index().unwrap(),
+        ];
+        graph.set_vertex_value(&b, &vertices[1], 10.0).unwrap();
+        graph.set_vertex_value(&b, &vertices[2], 20.0).unwrap();
+        graph
+            .new_edge(&edge_type, &vertices[0], &vertices[1], 3.0)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertices[1], &vertices[2], 4.0)
+            .unwrap();
+        (a, b, edge_type, vertices)
+    }
+
+    #[test]
+    fn materialize_negated_sum_of_vertex_type_and_product() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let (a, b, edge_type, vertices) = graph_with_two_vertex_types(&mut graph);
+        let sum_type = NewVertexType::<f64>::apply(&mut graph).unwrap();
+        let negated_type = NewVertexType::<i32>::apply(&mut graph).unwrap();
+
+        let plus_times = PlusTimes::<f64>::new();
+        let plus = Plus::<f64>::new();
+        let additive_inverse = AdditiveInverse::<f64>::new();
+        let mut expression = Expression::<f64>::new();
+        let a_node = expression.vertex_type(&a);
+        let b_node = expression.vertex_type(&b);
+        let product = expression.vertex_vector_adjacency_matrix_multiplication(
+            a_node,
+            &plus_times,
+            &edge_type,
+            None,
+        );
+        let sum = expression.element_wise_addition(b_node, &plus, product, None);
+        let negated = expression.apply_unary_operator(&additive_inverse, sum, Some(b_node));
+
+        graph
+            .materialize(&expression, &[(negated, negated_type), (sum, sum_type)])
+            .unwrap();
+
+        for (vertex, expected) in vertices.iter().zip([None, Some(13.0), Some(28.0)]) {
+            assert_eq!(
+                GetVertexValue::<f64>::vertex_value(&graph, &sum_type, vertex).unwrap(),
+                expected
+            );
+            assert_eq!(
+                GetVertexValue::<i32>::vertex_value(&graph, &negated_type, vertex).unwrap(),
+                expected.map(|value| -value as i32)
+            );
+        }
+    }
+
+    #[test]
+    fn plan_skips_unused_nodes_and_fuses_multiplication_into_addition() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let (a, b, edge_type, _) = graph_with_two_vertex_types(&mut graph);
+        let output = NewVertexType::<f64>::apply(&mut graph).unwrap();
+
+        let plus_times = PlusTimes::<f64>::new();
+        let plus = Plus::<f64>::new();
+        let additive_inverse = AdditiveInverse::<f64>::new();
+        let mut expression = Expression::<f64>::new();
+        let a_node = expression.vertex_type(&a);
+        let b_node = expression.vertex_type(&b);
+        let unused = expression.apply_unary_operator(&additive_inverse, a_node, None);
+        let product = expression.adjacency_matrix_vertex_vector_multiplication(
+            &edge_type,
+            &plus_times,
+            b_node,
+            Some(a_node),
+        );
+        let sum = expression.element_wise_addition(a_node, &plus, product, None);
+
+        let plan = EvaluationPlan::new(&expression, &[(sum, output)], &[]);
+        assert_eq!(plan.step(unused.index()), EvaluationStep::Skip);
+        assert_eq!(plan.step(product.index()), EvaluationStep::FuseIntoConsumer);
+        assert_eq!(plan.step(sum.index()), EvaluationStep::Evaluate);
+
+        // a + (A * b masked by a) = [1 + 3 * 10, 2 + 4 * 20, _]
+        graph.materialize(&expression, &[(sum, output)]).unwrap();
+        assert_eq!(
+            GetVertexValue::<f64>::vertex_value(&graph, &output, &VertexIndex::new(1)).unwrap(),
+            Some(82.0)
+        );
+
+        let plan = EvaluationPlan::new(&expression, &[(sum, output), (product, output)], &[]);
+        assert_eq!(plan.step(product.index()), EvaluationStep::Evaluate);
+    }
+
+    #[test]
+    fn materialize_adjacency_matrices_with_fused_multiplication() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let (_, _, edge_type, vertices) = graph_with_two_vertex_types(&mut graph);
+        let sum_type = NewEdgeType::<f64>::apply(&mut graph).unwrap();
+        let negated_type = NewEdgeType::<f64>::apply(&mut graph).unwrap();
+
+        let plus_times = PlusTimes::<f64>::new();
+        let plus = Plus::<f64>::new();
+        let additive_inverse = AdditiveInverse::<f64>::new();
+        let mut expression = Expression::<f64>::new();
+        let edges = expression.edge_type(&edge_type);
+        let two_hop_edges =
+            expression.adjacency_matrix_multiplication(edges, &plus_times, edges, None);
+        let sum =
+            expression.element_wise_adjacency_matrix_addition(edges, &plus, two_hop_edges, None);
+        let negated = expression.apply_unary_operator_to_adjacency_matrix(
+            &additive_inverse,
+            sum,
+            Some(edges),
+        );
+
+        let edge_type_outputs = [(sum, sum_type), (negated, negated_type)];
+        let plan = EvaluationPlan::new(&expression, &[], &edge_type_outputs);
+        assert_eq!(
+            plan.step(two_hop_edges.index()),
+            EvaluationStep::FuseIntoConsumer
+        );
+        assert_eq!(plan.step(sum.index()), EvaluationStep::Evaluate);
+
+        graph
+            .materialize_into_vertex_and_edge_types(&expression, &[], &edge_type_outputs)
+            .unwrap();
+
+        // A + A * A has the edges 0 -> 1 and 1 -> 2, and 0 -> 2 with weight 3 * 4
+        for (tail, head, expected_sum, expected_negated) in [
+            (0, 1, Some(3.0), Some(-3.0)),
+            (1, 2, Some(4.0), Some(-4.0)),
+            (0, 2, Some(12.0), None),
+            (1, 0, None, None),
+        ] {
+            assert_eq!(
+                GetEdgeWeight::<f64>::edge_weight(
+                    &graph,
+                    &sum_type,
+                    &vertices[tail],
+                    &vertices[head]
+                )
+                .unwrap(),
+                expected_sum
+            );
+            assert_eq!(
+                GetEdgeWeight::<f64>::edge_weight(
+                    &graph,
+                    &negated_type,
+                    &vertices[tail],
+                    &vertices[head]
+                )
+                .unwrap(),
+                expected_negated
+            );
+        }
+    }
+}
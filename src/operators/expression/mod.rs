@@ -0,0 +1,5 @@
+mod expression;
+mod materialize;
+
+pub use expression::*;
+pub use materialize::*;
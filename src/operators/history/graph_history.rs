@@ -0,0 +1,239 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Debug};
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::GetWeightedAdjacencyMatrix;
+use crate::graph::edge_store::operations::in_memory_transaction::EdgeStoreStateRestorer;
+use crate::graph::edge_store::{EdgeStore, GetAdjacencyMatrices, GetEdgeTypeIndicer};
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::Indexer;
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::VertexStoreStateRestorer;
+use crate::graph::vertex_store::{
+    GetVertexElementIndexer, GetVertexTypeIndexer, GetVertexVectors, VertexStore,
+};
+use crate::operators::transaction::RestoreState;
+
+use super::{
+    approximate_size_in_bytes_of_all, sparse_matrix_size_in_bytes, sparse_vector_size_in_bytes,
+    GetApproximateSizeInBytes,
+};
+
+/// Restores the public stores of a graph to their state before a step.
+pub(crate) enum HistoryStep {
+    /// The inverse of a committed transaction, restored latest first.
+    StateRestorers {
+        vertex_store_state_restorers: Vec<VertexStoreStateRestorer>,
+        edge_store_state_restorers: Vec<EdgeStoreStateRestorer>,
+    },
+    /// The stores share their vertex vectors and adjacency matrices until either changes them.
+    Snapshot {
+        vertex_store: VertexStore,
+        edge_store: EdgeStore,
+    },
+}
+
+impl HistoryStep {
+    pub(crate) fn snapshot(vertex_store: &VertexStore, edge_store: &EdgeStore) -> Self {
+        Self::Snapshot {
+            vertex_store: vertex_store.clone(),
+            edge_store: edge_store.clone(),
+        }
+    }
+
+    pub(crate) fn restore(
+        self,
+        vertex_store_to_restore: &mut VertexStore,
+        edge_store_to_restore: &mut EdgeStore,
+    ) -> Result<(), GraphComputingError> {
+        match self {
+            Self::StateRestorers {
+                vertex_store_state_restorers,
+                edge_store_state_restorers,
+            } => {
+                for state_restorer in vertex_store_state_restorers.into_iter().rev() {
+                    state_restorer.restore(vertex_store_to_restore)?;
+                }
+                for state_restorer in edge_store_state_restorers.into_iter().rev() {
+                    state_restorer.restore(edge_store_to_restore)?;
+                }
+            }
+            Self::Snapshot {
+                vertex_store,
+                edge_store,
+            } => {
+                *vertex_store_to_restore = vertex_store;
+                *edge_store_to_restore = edge_store;
+            }
+        }
+        Ok(())
+    }
+}
+
+// A snapshot is counted in full, also while it shares vertex vectors and adjacency matrices with the graph.
+impl GetApproximateSizeInBytes for HistoryStep {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        match self {
+            Self::StateRestorers {
+                vertex_store_state_restorers,
+                edge_store_state_restorers,
+            } => Ok(
+                approximate_size_in_bytes_of_all(vertex_store_state_restorers)?
+                    + approximate_size_in_bytes_of_all(edge_store_state_restorers)?,
+            ),
+            Self::Snapshot {
+                vertex_store,
+                edge_store,
+            } => {
+                let mut size_in_bytes =
+                    indexer_size_in_bytes(vertex_store.vertex_type_indexer_ref())?
+                        + indexer_size_in_bytes(vertex_store.element_indexer_ref())?
+                        + indexer_size_in_bytes(edge_store.edge_type_indexer_ref())?;
+                for vertex_vector in vertex_store.vertex_vector_for_all_vertex_types_ref() {
+                    size_in_bytes += sparse_vector_size_in_bytes(&**vertex_vector)?;
+                }
+                for adjacency_matrix in edge_store.adjacency_matrices_ref() {
+                    size_in_bytes += sparse_matrix_size_in_bytes(
+                        adjacency_matrix.weighted_adjacency_matrix_ref(),
+                    )?;
+                }
+                Ok(size_in_bytes)
+            }
+        }
+    }
+}
+
+fn indexer_size_in_bytes(indexer: &Indexer) -> Result<usize, GraphComputingError> {
+    sparse_vector_size_in_bytes(indexer.mask_with_valid_indices_ref())
+}
+
+/// The steps to undo and redo on a graph. Records nothing while the maximum number of steps is zero.
+///
+/// The oldest steps to undo are forgotten first when the history exceeds its maximum number of steps or size,
+/// followed by the steps to redo that are furthest from the current state.
+#[derive(Default)]
+pub(crate) struct GraphHistory {
+    maximum_number_of_steps: usize,
+    maximum_size_in_bytes: Option<usize>,
+    size_in_bytes: usize,
+    steps_to_undo: VecDeque<(HistoryStep, usize)>,
+    steps_to_redo: VecDeque<(HistoryStep, usize)>,
+}
+
+// A cloned graph is a new graph, it does not record history until enabled.
+impl Clone for GraphHistory {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl Debug for GraphHistory {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("GraphHistory")
+            .field("maximum_number_of_steps", &self.maximum_number_of_steps)
+            .field("maximum_size_in_bytes", &self.maximum_size_in_bytes)
+            .field("size_in_bytes", &self.size_in_bytes)
+            .field("number_of_steps_to_undo", &self.steps_to_undo.len())
+            .field("number_of_steps_to_redo", &self.steps_to_redo.len())
+            .finish()
+    }
+}
+
+impl GraphHistory {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn is_recording(&self) -> bool {
+        self.maximum_number_of_steps > 0
+    }
+
+    pub(crate) fn set_maximum_number_of_steps(&mut self, maximum_number_of_steps: usize) {
+        self.maximum_number_of_steps = maximum_number_of_steps;
+        self.forget_oldest_steps();
+    }
+
+    pub(crate) fn set_maximum_size_in_bytes(&mut self, maximum_size_in_bytes: Option<usize>) {
+        self.maximum_size_in_bytes = maximum_size_in_bytes;
+        self.forget_oldest_steps();
+    }
+
+    pub(crate) fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    pub(crate) fn number_of_steps_to_undo(&self) -> usize {
+        self.steps_to_undo.len()
+    }
+
+    pub(crate) fn number_of_steps_to_redo(&self) -> usize {
+        self.steps_to_redo.len()
+    }
+
+    /// A new step invalidates the steps to redo.
+    pub(crate) fn record(&mut self, step: HistoryStep) -> Result<(), GraphComputingError> {
+        if self.is_recording() {
+            for (_, size_in_bytes) in self.steps_to_redo.drain(..) {
+                self.size_in_bytes -= size_in_bytes;
+            }
+            self.push_step_to_undo(step)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn pop_step_to_undo(&mut self) -> Option<HistoryStep> {
+        let (step, size_in_bytes) = self.steps_to_undo.pop_back()?;
+        self.size_in_bytes -= size_in_bytes;
+        Some(step)
+    }
+
+    pub(crate) fn push_step_to_undo(
+        &mut self,
+        step: HistoryStep,
+    ) -> Result<(), GraphComputingError> {
+        let size_in_bytes = step.approximate_size_in_bytes()?;
+        self.size_in_bytes += size_in_bytes;
+        self.steps_to_undo.push_back((step, size_in_bytes));
+        self.forget_oldest_steps();
+        Ok(())
+    }
+
+    pub(crate) fn pop_step_to_redo(&mut self) -> Option<HistoryStep> {
+        let (step, size_in_bytes) = self.steps_to_redo.pop_back()?;
+        self.size_in_bytes -= size_in_bytes;
+        Some(step)
+    }
+
+    pub(crate) fn push_step_to_redo(
+        &mut self,
+        step: HistoryStep,
+    ) -> Result<(), GraphComputingError> {
+        let size_in_bytes = step.approximate_size_in_bytes()?;
+        self.size_in_bytes += size_in_bytes;
+        self.steps_to_redo.push_back((step, size_in_bytes));
+        self.forget_oldest_steps();
+        Ok(())
+    }
+
+    fn forget_oldest_steps(&mut self) {
+        while self.steps_to_undo.len() > self.maximum_number_of_steps || self.is_too_large() {
+            match self.steps_to_undo.pop_front() {
+                Some((_, size_in_bytes)) => self.size_in_bytes -= size_in_bytes,
+                None => break,
+            }
+        }
+        while self.steps_to_redo.len() > self.maximum_number_of_steps || self.is_too_large() {
+            match self.steps_to_redo.pop_front() {
+                Some((_, size_in_bytes)) => self.size_in_bytes -= size_in_bytes,
+                None => break,
+            }
+        }
+    }
+
+    fn is_too_large(&self) -> bool {
+        match self.maximum_size_in_bytes {
+            Some(maximum_size_in_bytes) => self.size_in_bytes > maximum_size_in_bytes,
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::mem::MaybeUninit;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::GetGraphblasSparseMatrix;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::GetGraphblasSparseVector;
+use graphblas_sparse_linear_algebra::context::{CallGraphBlasContext, GetContext};
+use graphblas_sparse_linear_algebra::graphblas_bindings::{
+    GxB_Matrix_memoryUsage, GxB_Vector_memoryUsage,
+};
+
+use crate::error::GraphComputingError;
+
+/// The approximate number of bytes that a recorded history step keeps in memory.
+pub(crate) trait GetApproximateSizeInBytes {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError>;
+}
+
+pub(crate) fn sparse_vector_size_in_bytes(
+    vector: &impl GetGraphblasSparseVector,
+) -> Result<usize, GraphComputingError> {
+    let mut size_in_bytes = MaybeUninit::uninit();
+    vector.context_ref().call(
+        || unsafe { GxB_Vector_memoryUsage(size_in_bytes.as_mut_ptr(), vector.graphblas_vector()) },
+        unsafe { vector.graphblas_vector_ref() },
+    )?;
+    Ok(unsafe { size_in_bytes.assume_init() })
+}
+
+pub(crate) fn sparse_matrix_size_in_bytes(
+    matrix: &impl GetGraphblasSparseMatrix,
+) -> Result<usize, GraphComputingError> {
+    let mut size_in_bytes = MaybeUninit::uninit();
+    matrix.context_ref().call(
+        || unsafe { GxB_Matrix_memoryUsage(size_in_bytes.as_mut_ptr(), matrix.graphblas_matrix()) },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(unsafe { size_in_bytes.assume_init() })
+}
+
+pub(crate) fn approximate_size_in_bytes_of_all<'a, S>(
+    sized: impl IntoIterator<Item = &'a S>,
+) -> Result<usize, GraphComputingError>
+where
+    S: GetApproximateSizeInBytes + 'a,
+{
+    sized.into_iter().try_fold(0, |size_in_bytes, sized| {
+        Ok(size_in_bytes + sized.approximate_size_in_bytes()?)
+    })
+}
@@ -0,0 +1,7 @@
+mod graph_history;
+mod history_size;
+mod undo_redo;
+
+pub(crate) use graph_history::*;
+pub(crate) use history_size::*;
+pub use undo_redo::*;
@@ -0,0 +1,373 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::{EdgeStore, GetAdjacencyMatrices, GetEdgeTypeIndicer};
+use crate::graph::graph::Graph;
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{
+    EdgeTypeIndex, Generation, GetIndexGenerations, Index, Indexer, VertexIndex, VertexTypeIndex,
+};
+use crate::graph::vertex_store::{
+    GetVertexElementIndexer, GetVertexTypeIndexer, GetVertexVectors, VertexStore,
+};
+use crate::operators::change_data_capture::GraphChange;
+use crate::operators::vertex_value_index::rebuild_vertex_value_indexes;
+
+use super::HistoryStep;
+
+/// Undoes and redoes the transactions committed to a graph with `InMemoryGraphTransaction`.
+/// Changes made without a transaction are not recorded, and should not be mixed with undo and redo.
+/// Vertex and edge properties are not recorded. Undo and redo remove the properties of elements that no longer exist.
+/// Undo and redo publish the restored vertex types and edge types as overwritten.
+pub trait UndoRedo {
+    /// Keeps up to the given number of committed transactions to undo, forgetting the oldest first.
+    /// Zero stops recording and forgets all steps.
+    fn set_maximum_number_of_history_steps(&mut self, maximum_number_of_steps: usize);
+
+    /// Forgets the oldest steps while the history takes more than the given number of bytes. None removes the bound.
+    /// A step that shares vertex vectors or adjacency matrices with the graph is counted in full.
+    fn set_maximum_history_size_in_bytes(&mut self, maximum_size_in_bytes: Option<usize>);
+
+    /// The approximate number of bytes taken by the steps to undo and redo.
+    fn history_size_in_bytes(&self) -> usize;
+
+    fn number_of_steps_to_undo(&self) -> usize;
+    fn number_of_steps_to_redo(&self) -> usize;
+
+    /// Reverts the last committed transaction that was not undone yet.
+    /// Returns false if there is no transaction to undo.
+    fn undo(&mut self) -> Result<bool, GraphComputingError>;
+
+    /// Reapplies the last undone transaction. A newly committed transaction forgets the transactions to redo.
+    /// Returns false if there is no transaction to redo.
+    fn redo(&mut self) -> Result<bool, GraphComputingError>;
+}
+
+impl UndoRedo for Graph {
+    fn set_maximum_number_of_history_steps(&mut self, maximum_number_of_steps: usize) {
+        self.history
+            .set_maximum_number_of_steps(maximum_number_of_steps)
+    }
+
+    fn set_maximum_history_size_in_bytes(&mut self, maximum_size_in_bytes: Option<usize>) {
+        self.history
+            .set_maximum_size_in_bytes(maximum_size_in_bytes)
+    }
+
+    fn history_size_in_bytes(&self) -> usize {
+        self.history.size_in_bytes()
+    }
+
+    fn number_of_steps_to_undo(&self) -> usize {
+        self.history.number_of_steps_to_undo()
+    }
+
+    fn number_of_steps_to_redo(&self) -> usize {
+        self.history.number_of_steps_to_redo()
+    }
+
+    fn undo(&mut self) -> Result<bool, GraphComputingError> {
+        let step_to_undo = match self.history.pop_step_to_undo() {
+            Some(step) => step,
+            None => return Ok(false),
+        };
+
+        let step_to_redo = restore_history_step(self, step_to_undo)?;
+        self.history.push_step_to_redo(step_to_redo)?;
+        Ok(true)
+    }
+
+    fn redo(&mut self) -> Result<bool, GraphComputingError> {
+        let step_to_redo = match self.history.pop_step_to_redo() {
+            Some(step) => step,
+            None => return Ok(false),
+        };
+
+        let step_to_undo = restore_history_step(self, step_to_redo)?;
+        self.history.push_step_to_undo(step_to_undo)?;
+        Ok(true)
+    }
+}
+
+// Returns a snapshot of the state before the restore, which reverts the restore.
+fn restore_history_step(
+    graph: &mut Graph,
+    step: HistoryStep,
+) -> Result<HistoryStep, GraphComputingError> {
+    let vertex_store = graph.public_vertex_store.clone();
+    let edge_store = graph.public_edge_store.clone();
+
+    step.restore(&mut graph.public_vertex_store, &mut graph.public_edge_store)?;
+    rebuild_vertex_value_indexes(&mut graph.vertex_value_indexes, &graph.public_vertex_store)?;
+    graph
+        .property_store
+        .retain_existing_elements(&graph.public_vertex_store, &graph.public_edge_store)?;
+
+    let changes = changes_of_restore(
+        &vertex_store,
+        &edge_store,
+        &graph.public_vertex_store,
+        &graph.public_edge_store,
+    )?;
+    graph.publish_changes_that_reassign_indices(changes)?;
+
+    Ok(HistoryStep::Snapshot {
+        vertex_store,
+        edge_store,
+    })
+}
+
+// A restore copies the vertex vectors and adjacency matrices it changes,
+// such that the collections still shared with the previous state are unchanged.
+fn changes_of_restore(
+    previous_vertex_store: &VertexStore,
+    previous_edge_store: &EdgeStore,
+    vertex_store: &VertexStore,
+    edge_store: &EdgeStore,
+) -> Result<Vec<GraphChange>, GraphComputingError> {
+    let mut changes = Vec::new();
+
+    for (index, generation) in indices_only_in(
+        previous_vertex_store.element_indexer_ref(),
+        vertex_store.element_indexer_ref(),
+    )? {
+        changes.push(GraphChange::VertexDropped {
+            vertex: VertexIndex::with_generation(index, Some(generation)),
+        });
+    }
+    for (index, generation) in indices_only_in(
+        previous_vertex_store.vertex_type_indexer_ref(),
+        vertex_store.vertex_type_indexer_ref(),
+    )? {
+        changes.push(GraphChange::VertexTypeDropped {
+            vertex_type: VertexTypeIndex::with_generation(index, Some(generation)),
+        });
+    }
+    for (index, generation) in indices_only_in(
+        previous_edge_store.edge_type_indexer_ref(),
+        edge_store.edge_type_indexer_ref(),
+    )? {
+        changes.push(GraphChange::EdgeTypeDropped {
+            edge_type: EdgeTypeIndex::with_generation(index, Some(generation)),
+        });
+    }
+
+    for (index, generation) in indices_only_in(
+        vertex_store.vertex_type_indexer_ref(),
+        previous_vertex_store.vertex_type_indexer_ref(),
+    )? {
+        changes.push(GraphChange::VertexTypeAdded {
+            vertex_type: VertexTypeIndex::with_generation(index, Some(generation)),
+        });
+    }
+    for (index, generation) in indices_only_in(
+        edge_store.edge_type_indexer_ref(),
+        previous_edge_store.edge_type_indexer_ref(),
+    )? {
+        changes.push(GraphChange::EdgeTypeAdded {
+            edge_type: EdgeTypeIndex::with_generation(index, Some(generation)),
+        });
+    }
+    for (index, generation) in indices_only_in(
+        vertex_store.element_indexer_ref(),
+        previous_vertex_store.element_indexer_ref(),
+    )? {
+        changes.push(GraphChange::VertexAdded {
+            vertex: VertexIndex::with_generation(index, Some(generation)),
+        });
+    }
+
+    let vertex_type_indexer = vertex_store.vertex_type_indexer_ref();
+    let vertex_vectors = vertex_store.vertex_vector_for_all_vertex_types_ref();
+    let previous_vertex_vectors = previous_vertex_store.vertex_vector_for_all_vertex_types_ref();
+    for index in vertex_type_indexer.valid_indices()? {
+        let is_unchanged =
+            previous_vertex_vectors
+                .get(index)
+                .is_some_and(|previous_vertex_vector| {
+                    Arc::ptr_eq(previous_vertex_vector, &vertex_vectors[index])
+                });
+        if !is_unchanged {
+            changes.push(GraphChange::VertexVectorOverwritten {
+                vertex_type: VertexTypeIndex::with_generation(
+                    index,
+                    Some(vertex_type_indexer.generation(index)),
+                ),
+            });
+        }
+    }
+    let edge_type_indexer = edge_store.edge_type_indexer_ref();
+    let adjacency_matrices = edge_store.adjacency_matrices_ref();
+    let previous_adjacency_matrices = previous_edge_store.adjacency_matrices_ref();
+    for index in edge_type_indexer.valid_indices()? {
+        let is_unchanged =
+            previous_adjacency_matrices
+                .get(index)
+                .is_some_and(|previous_adjacency_matrix| {
+                    Arc::ptr_eq(previous_adjacency_matrix, &adjacency_matrices[index])
+                });
+        if !is_unchanged {
+            changes.push(GraphChange::AdjacencyMatrixOverwritten {
+                edge_type: EdgeTypeIndex::with_generation(
+                    index,
+                    Some(edge_type_indexer.generation(index)),
+                ),
+            });
+        }
+    }
+    Ok(changes)
+}
+
+// The valid indices of the indexer that are not valid in the other indexer, or of another generation.
+fn indices_only_in(
+    indexer: &Indexer,
+    other_indexer: &Indexer,
+) -> Result<Vec<(Index, Generation)>, GraphComputingError> {
+    let other_valid_indices: HashSet<Index> = other_indexer.valid_indices()?.into_iter().collect();
+    Ok(indexer
+        .valid_indices()?
+        .into_iter()
+        .filter(|index| {
+            !other_valid_indices.contains(index)
+                || other_indexer.generation(*index) != indexer.generation(*index)
+        })
+        .map(|index| (index, indexer.generation(index)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::change_data_capture::SubscribeToGraphChanges;
+    use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+    use crate::operators::operators::new::{NewVertex, NewVertexType};
+    use crate::operators::operators::read::GetVertexValue;
+    use crate::operators::operators::update::UpdateVertexValue;
+    use crate::operators::transaction::UseTransaction;
+
+    #[test]
+    fn undo_and_redo_committed_transactions() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        graph.set_maximum_number_of_history_steps(10);
+
+        let vertex_type;
+        let vertex;
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            vertex_type = NewVertexType::<u8>::apply(&mut transaction).unwrap();
+            vertex = transaction.new_vertex(&vertex_type, 1u8).unwrap();
+            transaction.commit().unwrap();
+        }
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .update_vertex_value(&vertex_type, &vertex, 2u8)
+                .unwrap();
+            transaction.commit().unwrap();
+        }
+
+        let vertex_value = |graph: &Graph| {
+            GetVertexValue::<u8>::vertex_value(graph, &vertex_type, &vertex).unwrap()
+        };
+        assert_eq!(vertex_value(&graph), Some(2));
+
+        assert!(graph.undo().unwrap());
+        assert_eq!(vertex_value(&graph), Some(1));
+        assert!(graph.undo().unwrap());
+        assert!(GetVertexValue::<u8>::vertex_value(&graph, &vertex_type, &vertex).is_err());
+        assert!(!graph.undo().unwrap());
+
+        assert!(graph.redo().unwrap());
+        assert_eq!(vertex_value(&graph), Some(1));
+        assert!(graph.redo().unwrap());
+        assert_eq!(vertex_value(&graph), Some(2));
+        assert!(!graph.redo().unwrap());
+
+        assert!(graph.undo().unwrap());
+        assert_eq!(vertex_value(&graph), Some(1));
+    }
+
+    #[test]
+    fn history_forgets_oldest_steps() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        graph.set_maximum_number_of_history_steps(1);
+
+        for _ in 0..3 {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            NewVertexType::<u8>::apply(&mut transaction).unwrap();
+            transaction.commit().unwrap();
+        }
+
+        assert_eq!(graph.number_of_steps_to_undo(), 1);
+        assert!(graph.undo().unwrap());
+        assert!(!graph.undo().unwrap());
+        assert_eq!(graph.number_of_steps_to_redo(), 1);
+    }
+
+    #[test]
+    fn history_forgets_oldest_steps_beyond_maximum_size() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        graph.set_maximum_number_of_history_steps(10);
+
+        for value in 0u8..3 {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            let vertex_type = NewVertexType::<u8>::apply(&mut transaction).unwrap();
+            transaction.new_vertex(&vertex_type, value).unwrap();
+            transaction.commit().unwrap();
+        }
+        assert_eq!(graph.number_of_steps_to_undo(), 3);
+        let size_of_three_steps = graph.history_size_in_bytes();
+        assert!(size_of_three_steps > 0);
+
+        graph.set_maximum_history_size_in_bytes(Some(size_of_three_steps - 1));
+        assert!(graph.number_of_steps_to_undo() < 3);
+        assert!(graph.history_size_in_bytes() < size_of_three_steps);
+
+        graph.set_maximum_history_size_in_bytes(Some(0));
+        assert_eq!(graph.number_of_steps_to_undo(), 0);
+        assert_eq!(graph.history_size_in_bytes(), 0);
+        assert!(!graph.undo().unwrap());
+    }
+
+    #[test]
+    fn undo_and_redo_publish_restored_types() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        graph.set_maximum_number_of_history_steps(10);
+
+        let vertex_type;
+        let vertex;
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            vertex_type = NewVertexType::<u8>::apply(&mut transaction).unwrap();
+            vertex = transaction.new_vertex(&vertex_type, 1u8).unwrap();
+            transaction.commit().unwrap();
+        }
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .update_vertex_value(&vertex_type, &vertex, 2u8)
+                .unwrap();
+            transaction.commit().unwrap();
+        }
+        let changes = graph.subscribe_to_changes().unwrap();
+
+        assert!(graph.undo().unwrap());
+        assert_eq!(
+            changes.try_iter().collect::<Vec<GraphChange>>(),
+            vec![GraphChange::VertexVectorOverwritten { vertex_type }]
+        );
+
+        assert!(graph.undo().unwrap());
+        let changes_of_undo = changes.try_iter().collect::<Vec<GraphChange>>();
+        assert!(changes_of_undo.contains(&GraphChange::VertexDropped { vertex }));
+        assert!(changes_of_undo.contains(&GraphChange::VertexTypeDropped { vertex_type }));
+
+        assert!(graph.redo().unwrap());
+        let changes_of_redo = changes.try_iter().collect::<Vec<GraphChange>>();
+        assert!(changes_of_redo.contains(&GraphChange::VertexTypeAdded { vertex_type }));
+        assert!(changes_of_redo.contains(&GraphChange::VertexAdded { vertex }));
+        assert!(changes_of_redo.contains(&GraphChange::VertexVectorOverwritten { vertex_type }));
+    }
+}
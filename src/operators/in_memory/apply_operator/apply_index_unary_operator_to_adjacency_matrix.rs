@@ -19,6 +19,7 @@ use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArgument;
 use crate::error::GraphComputingError;
 use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
 use crate::graph::{graph::Graph, value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::apply_operator::ApplyIndexUnaryOperatorToAdjacencyMatrix;
 use crate::operators::operators::apply_operator::ApplyIndexUnaryOperatorToAdjacencyMatrixUnchecked;
 
@@ -47,7 +48,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -76,7 +78,8 @@ where
             mask,
             options,
             &mut self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
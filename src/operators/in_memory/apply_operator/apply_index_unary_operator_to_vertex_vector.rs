@@ -13,6 +13,7 @@ use crate::graph::indexing::{GetVertexTypeIndex, VertexTypeIndex};
 use crate::graph::value_type::ValueType;
 use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
 use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::apply_operator::ApplyIndexUnaryOperatorToVertexVector;
 use crate::operators::operators::apply_operator::ApplyIndexUnaryOperatorToVertexVectorUnchecked;
 
@@ -40,7 +41,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -69,7 +71,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -17,6 +17,7 @@ use crate::graph::edge_store::{
 use crate::graph::graph::GetGraphblasOperatorAppliers;
 use crate::graph::indexing::{EdgeTypeIndex, GetEdgeTypeIndex};
 use crate::graph::{graph::Graph, value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::apply_operator::ApplyScalarBinaryOperatorToAdjacencyMatrix;
 use crate::operators::operators::apply_operator::ApplyScalarBinaryOperatorToAdjacencyMatrixUnchecked;
 use crate::operators::options::{
@@ -56,7 +57,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 
     fn with_adjacency_matrix_as_right_argument(
@@ -79,7 +81,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -110,7 +113,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 
     fn with_adjacency_matrix_as_right_argument_and_by_unchecked_index(
@@ -135,7 +139,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -9,6 +9,7 @@ use crate::graph::graph::Graph;
 use crate::graph::indexing::{GetVertexTypeIndex, VertexTypeIndex};
 use crate::graph::value_type::ValueType;
 use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::apply_operator::ApplyScalarBinaryOperatorToVertexVector;
 use crate::operators::operators::apply_operator::ApplyScalarBinaryOperatorToVertexVectorUnchecked;
 use crate::{
@@ -44,7 +45,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 
     fn with_vertex_vector_as_right_argument(
@@ -67,7 +69,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -98,7 +101,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 
     fn with_vertex_vector_as_right_argument_and_by_unchecked_index(
@@ -123,7 +127,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
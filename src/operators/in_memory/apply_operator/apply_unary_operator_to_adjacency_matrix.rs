@@ -19,6 +19,7 @@ use crate::operators::operators::apply_operator::ApplyUnaryOperatorToAdjacencyMa
 use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArgument;
 use crate::graph::{graph::Graph, value_type::ValueType};
 use crate::error::GraphComputingError;
+use crate::operators::change_data_capture::GraphChange;
 
 impl<EvaluationDomain: ValueType> ApplyUnaryOperatorToAdjacencyMatrix<EvaluationDomain> for Graph {
     fn apply(
@@ -39,7 +40,8 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperatorToAdjacencyMatrix<Evaluation
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -64,7 +66,8 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperatorToAdjacencyMatrixUnchecked<E
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -9,6 +9,7 @@ use crate::graph::graph::GraphblasOperatorApplierCollection;
 use crate::graph::indexing::{GetVertexTypeIndex, VertexTypeIndex};
 use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
 use crate::graph::{graph::Graph, value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::apply_operator::ApplyUnaryOperatorToVertexVector;
 use crate::operators::operators::apply_operator::ApplyUnaryOperatorToVertexVectorUnchecked;
 
@@ -31,7 +32,8 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperatorToVertexVector<EvaluationDom
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -56,7 +58,8 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperatorToVertexVectorUnchecked<Eval
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
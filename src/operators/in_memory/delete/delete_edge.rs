@@ -5,6 +5,7 @@ use crate::graph::edge_store::operations::operations::edge_element::DeleteEdge a
 use crate::graph::graph::Graph;
 use crate::graph::indexing::GetEdgeTypeIndex;
 use crate::graph::indexing::GetVertexIndexIndex;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::delete::DeleteEdge;
 
 impl DeleteEdge for Graph {
@@ -14,8 +15,14 @@ impl DeleteEdge for Graph {
         tail: &impl GetVertexIndexIndex,
         head: &impl GetVertexIndexIndex,
     ) -> Result<(), GraphComputingError> {
-        self.public_edge_store
-            .delete_edge_weight(&self.public_vertex_store, edge_type, tail, head)
+        self.public_edge_store.delete_edge_weight(
+            &self.public_vertex_store,
+            edge_type,
+            tail,
+            head,
+        )?;
+        self.property_store.remove_edge(edge_type, tail, head);
+        self.publish_change(GraphChange::edge_deleted(edge_type, tail, head))
     }
 
     fn delete_edge_for_coordinate(
@@ -5,9 +5,8 @@ use crate::graph::edge_store::adjacency_matrix_with_cached_attributes::{
 };
 use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
 use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix_cached_attributes::GetAdjacencyMatrixCachedAttributes;
-use crate::graph::edge_store::operations::operations::edge_type::map::MapMutableAdjacencyMatrices;
 use crate::graph::graph::{GetEdgeStore, GetVertexStore};
-use crate::graph::indexing::{GetVertexIndexIndex, GetVertexTypeIndex};
+use crate::graph::indexing::{EdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex};
 
 use crate::graph::graph::Graph;
 use crate::graph::vertex_store::operations::vertex_element::DeleteVertexValue as DeleteVertexValueFromVertexStore;
@@ -16,8 +15,10 @@ use crate::graph::vertex_store::operations::vertex_element::{
 };
 use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
 use crate::graph::weighted_adjacency_matrix::operations::DeleteVertexConnections;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::delete::DeleteVertexValue;
 use crate::operators::operators::delete::DropVertexIndex;
+use crate::operators::vertex_value_index::update_indexed_vertex_value;
 
 impl DropVertexIndex for Graph {
     fn drop_vertex_index_and_connected_edges(
@@ -29,16 +30,27 @@ impl DropVertexIndex for Graph {
             .is_valid_vertex_index(vertex_index)?
         {
             true => {
-                self.edge_store_mut_ref().map_mut_all_adjacency_matrices(
-                    |adjacency_matrix: &mut WeightedAdjacencyMatrixWithCachedAttributes| {
-                        adjacency_matrix
-                            .weighted_adjacency_matrix_mut_ref()
-                            .delete_vertex_connections_unchecked(vertex_index)
-                    },
-                )?;
+                let mut changes = Vec::new();
+                self.edge_store_mut_ref()
+                    .indexed_map_mut_all_adjacency_matrices(
+                        |edge_type_index: &EdgeTypeIndex,
+                         adjacency_matrix: &mut WeightedAdjacencyMatrixWithCachedAttributes| {
+                            adjacency_matrix
+                                .weighted_adjacency_matrix_mut_ref()
+                                .delete_vertex_connections_unchecked(vertex_index)?;
+                            changes.push(GraphChange::adjacency_matrix_overwritten(
+                                edge_type_index,
+                            ));
+                            Ok(())
+                        },
+                    )?;
 
                 self.vertex_store_mut_ref()
-                    .delete_vertex_for_all_valid_vertex_types_and_value_types(vertex_index)
+                    .delete_vertex_for_all_valid_vertex_types_and_value_types(vertex_index)?;
+                self.vertex_value_indexes.remove_vertex(vertex_index);
+                self.property_store.remove_vertex(vertex_index);
+                changes.push(GraphChange::vertex_dropped(vertex_index));
+                self.publish_changes(changes)
             }
             false => Ok(()),
         }
@@ -52,7 +64,19 @@ impl DeleteVertexValue for Graph {
         vertex_index: &impl GetVertexIndexIndex,
     ) -> Result<(), GraphComputingError> {
         self.vertex_store_mut_ref()
-            .delete_vertex_element(vertex_type_index, vertex_index)
+            .delete_vertex_element(vertex_type_index, vertex_index)?;
+        update_indexed_vertex_value(
+            &mut self.vertex_value_indexes,
+            &self.public_vertex_store,
+            vertex_type_index,
+            vertex_index,
+        )?;
+        self.property_store
+            .remove_vertex_element(vertex_type_index, vertex_index);
+        self.publish_change(GraphChange::vertex_value_deleted(
+            vertex_type_index,
+            vertex_index,
+        ))
     }
 }
 
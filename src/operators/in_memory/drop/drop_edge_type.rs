@@ -3,6 +3,7 @@ use crate::error::GraphComputingError;
 use crate::graph::edge_store::operations::operations::edge_type::delete_edge_type::DropEdgeType as DropEdgeTypeFromEdgeStore;
 use crate::graph::graph::{GetEdgeStore, Graph};
 use crate::graph::indexing::GetEdgeTypeIndex;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::drop::DropEdgeType;
 
 impl DropEdgeType for Graph {
@@ -10,7 +11,9 @@ impl DropEdgeType for Graph {
         &mut self,
         edge_type_index: &impl GetEdgeTypeIndex,
     ) -> Result<(), GraphComputingError> {
-        self.edge_store_mut_ref().drop_edge_type(edge_type_index)
+        self.edge_store_mut_ref().drop_edge_type(edge_type_index)?;
+        self.property_store.remove_edge_type(edge_type_index);
+        self.publish_change(GraphChange::edge_type_dropped(edge_type_index))
     }
 }
 
@@ -3,6 +3,7 @@ use crate::error::GraphComputingError;
 use crate::graph::graph::{GetVertexStore, Graph};
 use crate::graph::indexing::GetVertexTypeIndex;
 use crate::graph::vertex_store::operations::vertex_type::DeleteVertexType;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::drop::DropVertexType;
 
 impl DropVertexType for Graph {
@@ -11,7 +12,11 @@ impl DropVertexType for Graph {
         vertex_type_index: &impl GetVertexTypeIndex,
     ) -> Result<(), GraphComputingError> {
         self.vertex_store_mut_ref()
-            .delete_vertex_type(vertex_type_index)
+            .delete_vertex_type(vertex_type_index)?;
+        self.vertex_value_indexes
+            .replace_index(vertex_type_index, None);
+        self.property_store.remove_vertex_type(vertex_type_index);
+        self.publish_change(GraphChange::vertex_type_dropped(vertex_type_index))
     }
 }
 
@@ -8,6 +8,7 @@ use crate::graph::graph::GetGraphblasOperatorAppliers;
 use crate::graph::graph::{Graph, GraphblasOperatorApplierCollection};
 use crate::graph::indexing::{GetVertexTypeIndex, VertexTypeIndex};
 use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::element_wise_addition::{
     BinaryOperatorElementWiseVertexVectorAddition,
     BinaryOperatorElementWiseVertexVectorAdditionUnchecked,
@@ -38,7 +39,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -67,7 +69,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
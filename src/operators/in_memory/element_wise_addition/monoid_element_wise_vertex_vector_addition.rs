@@ -7,6 +7,7 @@ use crate::graph::graph::GetGraphblasOperatorAppliers;
 use crate::graph::graph::{Graph, GraphblasOperatorApplierCollection};
 use crate::graph::indexing::{GetVertexTypeIndex, VertexTypeIndex};
 use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::element_wise_addition::{
     MonoidElementWiseVertexVectorAddition, MonoidElementWiseVertexVectorAdditionUnchecked,
 };
@@ -36,7 +37,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -64,7 +66,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -20,6 +20,7 @@ use crate::operators::operators::element_wise_addition::SemiringElementWiseAdjac
 use crate::operators::operators::indexing::CheckIndex;
 use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArguments;
 use crate::{error::GraphComputingError, graph::value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<EvaluationDomain: ValueType> SemiringElementWiseAdjacencyMatrixAddition<EvaluationDomain>
     for Graph
@@ -44,7 +45,8 @@ impl<EvaluationDomain: ValueType> SemiringElementWiseAdjacencyMatrixAddition<Eva
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -71,7 +73,8 @@ impl<EvaluationDomain: ValueType>
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -9,6 +9,7 @@ use crate::graph::graph::GetGraphblasOperatorAppliers;
 use crate::graph::graph::{Graph, GraphblasOperatorApplierCollection};
 use crate::graph::indexing::{GetVertexTypeIndex, VertexTypeIndex};
 use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::element_wise_multiplication::{
     BinaryOperatorElementWiseVertexVectorMultiplication,
     BinaryOperatorElementWiseVertexVectorMultiplicationUnchecked,
@@ -40,7 +41,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -59,7 +61,9 @@ where
         mask: Option<&VertexTypeIndex>,
         options: &OperatorOptions,
     ) -> Result<(), GraphComputingError> {
-        apply_binary_operator_element_wise_vertex_vector_multiplication_unchecked::<EvaluationDomain>(
+        apply_binary_operator_element_wise_vertex_vector_multiplication_unchecked::<
+            EvaluationDomain,
+        >(
             &mut self.public_vertex_store,
             left_argument,
             operator,
@@ -69,7 +73,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -17,6 +17,7 @@ use crate::operators::operators::element_wise_multiplication::MonoidElementWiseA
 use crate::operators::operators::element_wise_multiplication::MonoidElementWiseAdjacencyMatrixMultiplicationUnchecked;
 use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArguments;
 use crate::{error::GraphComputingError, graph::value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<EvaluationDomain: ValueType> MonoidElementWiseAdjacencyMatrixMultiplication<EvaluationDomain>
     for Graph
@@ -41,7 +42,8 @@ impl<EvaluationDomain: ValueType> MonoidElementWiseAdjacencyMatrixMultiplication
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -68,7 +70,8 @@ impl<EvaluationDomain: ValueType>
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -18,6 +18,7 @@ use crate::operators::operators::element_wise_multiplication::SemiringElementWis
 use crate::operators::operators::element_wise_multiplication::SemiringElementWiseAdjacencyMatrixMultiplicationUnchecked;
 use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArguments;
 use crate::{error::GraphComputingError, graph::value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<EvaluationDomain: ValueType> SemiringElementWiseAdjacencyMatrixMultiplication<EvaluationDomain>
     for Graph
@@ -42,7 +43,8 @@ impl<EvaluationDomain: ValueType> SemiringElementWiseAdjacencyMatrixMultiplicati
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -69,7 +71,8 @@ impl<EvaluationDomain: ValueType>
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
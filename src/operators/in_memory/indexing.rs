@@ -114,4 +114,39 @@ impl CheckIndex for Graph {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    use crate::error::{GraphComputingErrorType, LogicErrorType};
+    use crate::graph::indexing::{GetIndex, VertexIndex};
+    use crate::operators::operators::delete::DropVertexIndex;
+    use crate::operators::operators::new::{NewVertex, NewVertexType};
+
+    #[test]
+    fn reject_stale_vertex_index() {
+        let mut graph = Graph::with_initial_capacity(1, 5, 5).unwrap();
+
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex(&vertex_type, 1u8).unwrap();
+        graph.new_vertex(&vertex_type, 2u8).unwrap();
+
+        graph
+            .drop_vertex_index_and_connected_edges(&vertex)
+            .unwrap();
+        let reusing_vertex = graph.new_vertex(&vertex_type, 3u8).unwrap();
+        assert_eq!(reusing_vertex.index(), vertex.index());
+
+        assert!(!graph.is_valid_vertex_index(&vertex).unwrap());
+        assert!(graph.is_valid_vertex_index(&reusing_vertex).unwrap());
+        assert!(graph
+            .is_valid_vertex_index(&VertexIndex::new(vertex.index()))
+            .unwrap());
+        assert_eq!(
+            graph
+                .try_vertex_index_validity(&vertex)
+                .unwrap_err()
+                .error_type(),
+            GraphComputingErrorType::LogicErrorType(LogicErrorType::StaleIndex)
+        );
+    }
+}
@@ -6,8 +6,10 @@ pub mod element_wise_multiplication;
 pub mod indexing;
 pub mod multiplication;
 pub mod new;
+pub mod property;
 pub mod read;
 pub mod select;
 pub mod set;
 pub mod transpose;
 pub mod update;
+pub mod value_index;
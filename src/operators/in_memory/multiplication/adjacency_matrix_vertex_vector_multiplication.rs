@@ -20,6 +20,7 @@ use crate::operators::operators::multiplication::AdjacencyMatrixVertexVectorMult
 use crate::operators::operators::multiplication::AdjacencyMatrixVertexVectorMultiplicationUnchecked;
 use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixAsLeftArgument;
 use crate::{error::GraphComputingError, graph::value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<EvaluationDomain: ValueType> AdjacencyMatrixVertexVectorMultiplication<EvaluationDomain>
     for Graph
@@ -46,7 +47,8 @@ impl<EvaluationDomain: ValueType> AdjacencyMatrixVertexVectorMultiplication<Eval
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
@@ -75,7 +77,8 @@ impl<EvaluationDomain: ValueType>
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(product))
     }
 }
 
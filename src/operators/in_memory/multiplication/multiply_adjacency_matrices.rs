@@ -18,6 +18,7 @@ use crate::operators::operators::multiplication::AdjacencyMatrixMultiplication;
 use crate::operators::operators::multiplication::AdjacencyMatrixMultiplicationUnchecked;
 use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArguments;
 use crate::{error::GraphComputingError, graph::value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<EvaluationDomain: ValueType> AdjacencyMatrixMultiplication<EvaluationDomain> for Graph {
     fn apply(
@@ -40,7 +41,8 @@ impl<EvaluationDomain: ValueType> AdjacencyMatrixMultiplication<EvaluationDomain
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -67,7 +69,8 @@ impl<EvaluationDomain: ValueType> AdjacencyMatrixMultiplicationUnchecked<Evaluat
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(product))
     }
 }
 
@@ -0,0 +1,451 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::str::FromStr;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::FromMatrixElementList;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    MatrixElementList, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    AccumulatorBinaryOperator, BinaryOperator, First, Second,
+};
+use graphblas_sparse_linear_algebra::operators::element_wise_addition::{
+    ApplyElementWiseMatrixAdditionBinaryOperator, ElementWiseMatrixAdditionBinaryOperator,
+};
+use graphblas_sparse_linear_algebra::operators::mask::SelectEntireMatrix;
+use graphblas_sparse_linear_algebra::operators::options::OptionsForOperatorWithMatrixArguments;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{invalid_file_format_error, GetGraphblasContext, Graph};
+use crate::graph::indexing::{ElementIndex, GetEdgeTypeIndex, GetIndex, VertexIndex};
+use crate::graph::value_type::ValueType;
+use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::operators::change_data_capture::GraphChange;
+use crate::operators::operators::delete::DropVertexIndex;
+use crate::operators::operators::new::{LoadEdgeList, NewVertexIndex};
+
+impl<T> LoadEdgeList<T> for Graph
+where
+    T: ValueType,
+    SparseMatrix<T>: FromMatrixElementList<T>,
+    First<T>: BinaryOperator<T>,
+    Second<T>: BinaryOperator<T> + AccumulatorBinaryOperator<T>,
+{
+    fn load_edge_list(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        edges: impl IntoIterator<Item = (VertexIndex, VertexIndex, T)>,
+    ) -> Result<(), GraphComputingError> {
+        load_edge_list(
+            &self.public_vertex_store,
+            &mut self.public_edge_store,
+            edge_type,
+            edges,
+        )?;
+        self.publish_change(GraphChange::adjacency_matrix_overwritten(edge_type))
+    }
+
+    fn load_edge_list_with_external_ids<Id: Hash + Eq>(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        edges: impl IntoIterator<Item = (Id, Id, T)>,
+    ) -> Result<HashMap<Id, VertexIndex>, GraphComputingError> {
+        self.public_edge_store
+            .try_edge_type_index_validity(edge_type)?;
+        load_edge_list_with_external_ids(self, edge_type, edges)
+    }
+
+    fn load_edge_list_from_file(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+        delimiter: char,
+    ) -> Result<(), GraphComputingError>
+    where
+        T: FromStr,
+    {
+        let edges = read_edge_list_with_vertex_indices(path, delimiter)?;
+        LoadEdgeList::<T>::load_edge_list(self, edge_type, edges)
+    }
+
+    fn load_edge_list_from_file_with_external_ids(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+        delimiter: char,
+    ) -> Result<HashMap<String, VertexIndex>, GraphComputingError>
+    where
+        T: FromStr,
+    {
+        let edges = read_edge_list::<T>(path, delimiter)?;
+        LoadEdgeList::<T>::load_edge_list_with_external_ids(self, edge_type, edges)
+    }
+}
+
+pub(crate) fn load_edge_list<T>(
+    vertex_store: &impl CheckVertexIndex,
+    edge_store: &mut impl GetAdjacencyMatrix,
+    edge_type: &impl GetEdgeTypeIndex,
+    edges: impl IntoIterator<Item = (VertexIndex, VertexIndex, T)>,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType,
+    SparseMatrix<T>: FromMatrixElementList<T>,
+    First<T>: BinaryOperator<T>,
+    Second<T>: BinaryOperator<T> + AccumulatorBinaryOperator<T>,
+{
+    let edges = edges.into_iter();
+    let mut tails = Vec::with_capacity(edges.size_hint().0);
+    let mut heads = Vec::with_capacity(edges.size_hint().0);
+    let mut weights = Vec::with_capacity(edges.size_hint().0);
+    for (tail, head, weight) in edges {
+        vertex_store.try_vertex_index_validity(&tail)?;
+        vertex_store.try_vertex_index_validity(&head)?;
+        tails.push(tail.index());
+        heads.push(head.index());
+        weights.push(weight);
+    }
+
+    load_edge_list_unchecked(edge_store, edge_type, tails, heads, weights)
+}
+
+pub(crate) fn load_edge_list_unchecked<T>(
+    edge_store: &mut impl GetAdjacencyMatrix,
+    edge_type: &impl GetEdgeTypeIndex,
+    tails: Vec<ElementIndex>,
+    heads: Vec<ElementIndex>,
+    weights: Vec<T>,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType,
+    SparseMatrix<T>: FromMatrixElementList<T>,
+    First<T>: BinaryOperator<T>,
+    Second<T>: BinaryOperator<T> + AccumulatorBinaryOperator<T>,
+{
+    let adjacency_matrix_size = *edge_store.adjacency_matrix_size_ref();
+    let adjacency_matrix = edge_store.adjacency_matrix_mut_ref(edge_type)?;
+    let context = adjacency_matrix.graphblas_context();
+
+    let edges = SparseMatrix::<T>::from_element_list(
+        context.clone(),
+        Size::new(adjacency_matrix_size, adjacency_matrix_size),
+        MatrixElementList::from_vectors(tails, heads, weights)?,
+        &Second::<T>::new(),
+    )?;
+
+    // Accumulating with Second replaces existing weights, and keeps all other edges
+    ElementWiseMatrixAdditionBinaryOperator::new().apply(
+        &edges,
+        &First::<T>::new(),
+        &edges,
+        &Second::<T>::new(),
+        adjacency_matrix,
+        &SelectEntireMatrix::new(context),
+        &OptionsForOperatorWithMatrixArguments::new_default(),
+    )?;
+    Ok(())
+}
+
+/// Drops the vertex indices allocated for the external ids if loading fails.
+pub(crate) fn load_edge_list_with_external_ids<G, Id, T>(
+    graph: &mut G,
+    edge_type: &impl GetEdgeTypeIndex,
+    edges: impl IntoIterator<Item = (Id, Id, T)>,
+) -> Result<HashMap<Id, VertexIndex>, GraphComputingError>
+where
+    G: LoadEdgeList<T> + NewVertexIndex + DropVertexIndex,
+    Id: Hash + Eq,
+    T: ValueType,
+{
+    let mut vertex_indices = HashMap::new();
+    match assign_vertex_indices_to_external_ids(graph, &mut vertex_indices, edges)
+        .and_then(|edges| LoadEdgeList::<T>::load_edge_list(graph, edge_type, edges))
+    {
+        Ok(()) => Ok(vertex_indices),
+        Err(error) => {
+            for vertex_index in vertex_indices.values() {
+                graph.drop_vertex_index_and_connected_edges(vertex_index)?;
+            }
+            Err(error)
+        }
+    }
+}
+
+fn assign_vertex_indices_to_external_ids<Id: Hash + Eq, T>(
+    graph: &mut impl NewVertexIndex,
+    vertex_indices: &mut HashMap<Id, VertexIndex>,
+    edges: impl IntoIterator<Item = (Id, Id, T)>,
+) -> Result<Vec<(VertexIndex, VertexIndex, T)>, GraphComputingError> {
+    let mut edges_with_vertex_indices = Vec::new();
+    for (tail, head, weight) in edges {
+        let tail = vertex_index_for_external_id(graph, vertex_indices, tail)?;
+        let head = vertex_index_for_external_id(graph, vertex_indices, head)?;
+        edges_with_vertex_indices.push((tail, head, weight));
+    }
+    Ok(edges_with_vertex_indices)
+}
+
+fn vertex_index_for_external_id<Id: Hash + Eq>(
+    graph: &mut impl NewVertexIndex,
+    vertex_indices: &mut HashMap<Id, VertexIndex>,
+    external_id: Id,
+) -> Result<VertexIndex, GraphComputingError> {
+    match vertex_indices.entry(external_id) {
+        Entry::Occupied(entry) => Ok(*entry.get()),
+        Entry::Vacant(entry) => Ok(*entry.insert(graph.new_vertex_index()?)),
+    }
+}
+
+pub(crate) fn read_edge_list<T: FromStr>(
+    path: impl AsRef<Path>,
+    delimiter: char,
+) -> Result<Vec<(String, String, T)>, GraphComputingError> {
+    let mut edges = Vec::new();
+    for (line_index, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('%') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(delimiter).map(str::trim).collect();
+        let edge = match fields.as_slice() {
+            [tail, head, weight] => weight
+                .parse::<T>()
+                .ok()
+                .map(|weight| (tail.to_string(), head.to_string(), weight)),
+            _ => None,
+        };
+        match edge {
+            Some(edge) => edges.push(edge),
+            None => {
+                return Err(invalid_file_format_error(format!(
+                    "Invalid edge on line {}: {}",
+                    line_index + 1,
+                    line
+                )))
+            }
+        }
+    }
+    Ok(edges)
+}
+
+pub(crate) fn read_edge_list_with_vertex_indices<T: FromStr>(
+    path: impl AsRef<Path>,
+    delimiter: char,
+) -> Result<Vec<(VertexIndex, VertexIndex, T)>, GraphComputingError> {
+    read_edge_list::<T>(path, delimiter)?
+        .into_iter()
+        .map(|(tail, head, weight)| {
+            Ok((
+                parse_vertex_index(&tail)?,
+                parse_vertex_index(&head)?,
+                weight,
+            ))
+        })
+        .collect()
+}
+
+fn parse_vertex_index(vertex_index: &str) -> Result<VertexIndex, GraphComputingError> {
+    match vertex_index.parse::<ElementIndex>() {
+        Ok(vertex_index) => Ok(VertexIndex::new(vertex_index)),
+        Err(_) => Err(invalid_file_format_error(format!(
+            "Invalid vertex index: {}",
+            vertex_index
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::{LogicError, LogicErrorType};
+    use crate::graph::indexing::GetVertexIndexIndex;
+    use crate::operators::operators::indexing::CheckIndex;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType};
+    use crate::operators::operators::read::GetEdgeWeight;
+
+    // Allocates vertex indices in the graph, but fails to load any edge list
+    struct GraphFailingToLoadEdges(Graph);
+
+    fn failed_to_load_edges() -> GraphComputingError {
+        LogicError::new(
+            LogicErrorType::Other,
+            String::from("Failed to load edges"),
+            None,
+        )
+        .into()
+    }
+
+    impl NewVertexIndex for GraphFailingToLoadEdges {
+        fn new_vertex_index(&mut self) -> Result<VertexIndex, GraphComputingError> {
+            self.0.new_vertex_index()
+        }
+    }
+
+    impl DropVertexIndex for GraphFailingToLoadEdges {
+        fn drop_vertex_index_and_connected_edges(
+            &mut self,
+            vertex_index: &(impl GetVertexIndexIndex + Sync),
+        ) -> Result<(), GraphComputingError> {
+            self.0.drop_vertex_index_and_connected_edges(vertex_index)
+        }
+    }
+
+    impl LoadEdgeList<u8> for GraphFailingToLoadEdges {
+        fn load_edge_list(
+            &mut self,
+            _edge_type: &impl GetEdgeTypeIndex,
+            _edges: impl IntoIterator<Item = (VertexIndex, VertexIndex, u8)>,
+        ) -> Result<(), GraphComputingError> {
+            Err(failed_to_load_edges())
+        }
+
+        fn load_edge_list_with_external_ids<Id: Hash + Eq>(
+            &mut self,
+            edge_type: &impl GetEdgeTypeIndex,
+            edges: impl IntoIterator<Item = (Id, Id, u8)>,
+        ) -> Result<HashMap<Id, VertexIndex>, GraphComputingError> {
+            load_edge_list_with_external_ids(self, edge_type, edges)
+        }
+
+        fn load_edge_list_from_file(
+            &mut self,
+            _edge_type: &impl GetEdgeTypeIndex,
+            _path: impl AsRef<Path>,
+            _delimiter: char,
+        ) -> Result<(), GraphComputingError> {
+            Err(failed_to_load_edges())
+        }
+
+        fn load_edge_list_from_file_with_external_ids(
+            &mut self,
+            _edge_type: &impl GetEdgeTypeIndex,
+            _path: impl AsRef<Path>,
+            _delimiter: char,
+        ) -> Result<HashMap<String, VertexIndex>, GraphComputingError> {
+            Err(failed_to_load_edges())
+        }
+    }
+
+    #[test]
+    fn load_edge_list_into_existing_edges() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<u16>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex_index().unwrap();
+        let vertex_2 = graph.new_vertex_index().unwrap();
+        let vertex_3 = graph.new_vertex_index().unwrap();
+
+        graph
+            .new_edge(&edge_type, &vertex_1, &vertex_2, 1u16)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_2, &vertex_3, 2u16)
+            .unwrap();
+
+        graph
+            .load_edge_list(
+                &edge_type,
+                vec![
+                    (vertex_1, vertex_2, 10u16),
+                    (vertex_3, vertex_1, 3u16),
+                    (vertex_3, vertex_1, 4u16),
+                ],
+            )
+            .unwrap();
+
+        let edge_weight = |tail: &VertexIndex, head: &VertexIndex| {
+            GetEdgeWeight::<u16>::edge_weight(&graph, &edge_type, tail, head).unwrap()
+        };
+        assert_eq!(edge_weight(&vertex_1, &vertex_2), Some(10));
+        assert_eq!(edge_weight(&vertex_2, &vertex_3), Some(2));
+        assert_eq!(edge_weight(&vertex_3, &vertex_1), Some(4));
+    }
+
+    #[test]
+    fn load_edge_list_with_invalid_vertex_index() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let edge_type = NewEdgeType::<u16>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex_index().unwrap();
+
+        assert!(graph
+            .load_edge_list(&edge_type, vec![(vertex_1, VertexIndex::new(3), 1u16)])
+            .is_err());
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(&graph, &edge_type, &vertex_1, &vertex_1).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn drop_vertex_indices_of_failed_load() {
+        let mut graph = Graph::with_initial_capacity(1, 5, 1).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let mut graph = GraphFailingToLoadEdges(graph);
+
+        assert!(graph
+            .load_edge_list_with_external_ids(&edge_type, vec![("a", "b", 1u8), ("b", "c", 2u8)])
+            .is_err());
+        for vertex_index in 0..3 {
+            assert!(!graph
+                .0
+                .is_valid_vertex_index(&VertexIndex::new(vertex_index))
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn load_edge_list_from_file_with_external_ids() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        let edge_type = NewEdgeType::<f32>::apply(&mut graph).unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_edge_list_{}.tsv",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            "# tail\thead\tweight\n\
+             alice\tbob\t1.5\n\
+             bob\tcarol\t2.5\n\
+             \n\
+             carol\talice\t3.5\n",
+        )
+        .unwrap();
+        let vertex_indices = graph
+            .load_edge_list_from_file_with_external_ids(&edge_type, &path, '\t')
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(vertex_indices.len(), 3);
+        assert_eq!(
+            GetEdgeWeight::<f32>::edge_weight(
+                &graph,
+                &edge_type,
+                &vertex_indices["bob"],
+                &vertex_indices["carol"]
+            )
+            .unwrap(),
+            Some(2.5)
+        );
+        assert_eq!(
+            GetEdgeWeight::<f32>::edge_weight(
+                &graph,
+                &edge_type,
+                &vertex_indices["carol"],
+                &vertex_indices["alice"]
+            )
+            .unwrap(),
+            Some(3.5)
+        );
+    }
+}
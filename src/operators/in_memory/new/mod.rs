@@ -1,9 +1,11 @@
+mod load_edge_list;
 mod new_edge;
 mod new_edge_type;
 mod new_vertex;
 mod new_vertex_index;
 mod new_vertex_type;
 
+pub use load_edge_list::*;
 pub use new_edge::*;
 pub use new_edge_type::*;
 pub use new_vertex::*;
@@ -6,6 +6,7 @@ use crate::graph::edge_store::operations::operations::edge_element::NewEdge as N
 use crate::graph::graph::Graph;
 use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex};
 use crate::graph::value_type::ValueType;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::new::NewEdge;
 
 impl<T> NewEdge<T> for Graph
@@ -32,8 +33,14 @@ where
         head: &impl GetVertexIndexIndex,
         weight: T,
     ) -> Result<(), GraphComputingError> {
-        self.public_edge_store
-            .new_edge(&self.public_vertex_store, edge_type, tail, head, weight)
+        self.public_edge_store.new_edge(
+            &self.public_vertex_store,
+            edge_type,
+            tail,
+            head,
+            weight,
+        )?;
+        self.publish_change(GraphChange::edge_weight_set(edge_type, tail, head))
     }
 }
 
@@ -4,11 +4,14 @@ use crate::graph::edge_store::operations::operations::edge_type::add_edge_type::
 use crate::graph::graph::{GetEdgeStore, Graph};
 use crate::graph::indexing::EdgeTypeIndex;
 use crate::graph::value_type::{GetValueTypeIdentifier, ValueType};
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::new::NewEdgeType;
 
 impl<T: ValueType + GetValueTypeIdentifier> NewEdgeType<T> for Graph {
     fn apply(&mut self) -> Result<EdgeTypeIndex, GraphComputingError> {
-        AddEdgeTypeToEdgeStore::<T>::apply(self.edge_store_mut_ref())
+        let edge_type = AddEdgeTypeToEdgeStore::<T>::apply(self.edge_store_mut_ref())?;
+        self.publish_change(GraphChange::EdgeTypeAdded { edge_type })?;
+        Ok(edge_type)
     }
 }
 
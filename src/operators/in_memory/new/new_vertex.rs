@@ -10,7 +10,9 @@ use crate::graph::indexing::{GetAssignedIndexData, GetVertexTypeIndex, VertexInd
 use crate::graph::value_type::ValueType;
 use crate::graph::vertex_store::operations::in_memory_transaction::transaction::GetSparseVectorStateRevertersByVertexTypeMap;
 use crate::graph::vertex_store::operations::vertex_element::AddVertex as AddVertexToVertexVector;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::new::NewVertex;
+use crate::operators::vertex_value_index::update_indexed_vertex_value;
 
 impl<T> NewVertex<T> for Graph
 where
@@ -21,12 +23,25 @@ where
         vertex_type: &impl GetVertexTypeIndex,
         value: T,
     ) -> Result<VertexIndex, GraphComputingError> {
-        new_vertex(
+        let vertex_index = new_vertex(
             &mut self.public_vertex_store,
             &mut self.public_edge_store,
             vertex_type,
             value,
-        )
+        )?;
+        update_indexed_vertex_value(
+            &mut self.vertex_value_indexes,
+            &self.public_vertex_store,
+            vertex_type,
+            &vertex_index,
+        )?;
+        self.publish_changes(vec![
+            GraphChange::VertexAdded {
+                vertex: vertex_index,
+            },
+            GraphChange::vertex_value_set(vertex_type, &vertex_index),
+        ])?;
+        Ok(vertex_index)
     }
 }
 
@@ -51,7 +66,7 @@ where
         None => {}
     }
 
-    Ok(VertexIndex::new(assigned_vertex_index.index()))
+    Ok(VertexIndex::from(assigned_vertex_index))
 }
 
 #[cfg(test)]
@@ -3,11 +3,14 @@ use crate::graph::edge_store::operations::operations::edge_type::resize_adjacenc
 use crate::graph::graph::Graph;
 use crate::graph::indexing::{GetAssignedIndexData, VertexIndex};
 use crate::graph::vertex_store::operations::vertex_element::CreateVertexIndex as CreateVertexIndexInVertexStore;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::new::NewVertexIndex;
 
 impl NewVertexIndex for Graph {
     fn new_vertex_index(&mut self) -> Result<VertexIndex, GraphComputingError> {
-        new_vertex_index(&mut self.public_vertex_store, &mut self.public_edge_store)
+        let vertex = new_vertex_index(&mut self.public_vertex_store, &mut self.public_edge_store)?;
+        self.publish_change(GraphChange::VertexAdded { vertex })?;
+        Ok(vertex)
     }
 }
 
@@ -22,7 +25,7 @@ pub(crate) fn new_vertex_index(
         None => {}
     }
 
-    Ok(VertexIndex::new(assigned_vertex_index.index()))
+    Ok(VertexIndex::from(assigned_vertex_index))
 }
 
 #[cfg(test)]
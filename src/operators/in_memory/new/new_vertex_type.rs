@@ -3,10 +3,13 @@ use crate::graph::graph::{GetVertexStore, Graph};
 use crate::graph::indexing::VertexTypeIndex;
 use crate::graph::value_type::{GetValueTypeIdentifier, ValueType};
 use crate::graph::vertex_store::operations::vertex_type::AddVertexType as AddVertexTypeToVertexStore;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::new::NewVertexType;
 
 impl<T: ValueType + GetValueTypeIdentifier> NewVertexType<T> for Graph {
     fn apply(&mut self) -> Result<VertexTypeIndex, GraphComputingError> {
-        AddVertexTypeToVertexStore::<T>::apply(self.vertex_store_mut_ref())
+        let vertex_type = AddVertexTypeToVertexStore::<T>::apply(self.vertex_store_mut_ref())?;
+        self.publish_change(GraphChange::VertexTypeAdded { vertex_type })?;
+        Ok(vertex_type)
     }
 }
@@ -0,0 +1,149 @@
+use crate::error::GraphComputingError;
+use crate::graph::edge::GetDirectedEdgeCoordinateIndex;
+use crate::graph::edge_store::operations::operations::edge_element::Indexing;
+use crate::graph::graph::Graph;
+use crate::operators::operators::property::EdgeProperties;
+use crate::operators::properties::PropertyValue;
+
+impl EdgeProperties for Graph {
+    fn set_edge_property(
+        &mut self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+        value: impl Into<PropertyValue>,
+    ) -> Result<(), GraphComputingError> {
+        self.public_edge_store.try_is_valid_edge(
+            &self.public_vertex_store,
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+        )?;
+        self.public_edge_store.try_is_edge(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+        )?;
+        self.property_store.set_edge_property(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+            property_name,
+            Some(value.into()),
+        );
+        Ok(())
+    }
+
+    fn edge_property(
+        &self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+    ) -> Result<Option<&PropertyValue>, GraphComputingError> {
+        self.public_edge_store.try_is_valid_edge(
+            &self.public_vertex_store,
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+        )?;
+        Ok(self.property_store.edge_property_ref(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+            property_name,
+        ))
+    }
+
+    fn delete_edge_property(
+        &mut self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+    ) -> Result<Option<PropertyValue>, GraphComputingError> {
+        self.public_edge_store.try_is_valid_edge(
+            &self.public_vertex_store,
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+        )?;
+        Ok(self
+            .property_store
+            .set_edge_property(
+                edge.edge_type_ref(),
+                edge.tail_ref(),
+                edge.head_ref(),
+                property_name,
+                None,
+            )
+            .into_previous_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::edge::DirectedEdgeCoordinate;
+    use crate::operators::operators::delete::{DeleteEdge, DropVertexIndex};
+    use crate::operators::operators::drop::DropEdgeType;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+
+    #[test]
+    fn edge_properties_are_removed_with_their_edge() {
+        let mut graph = Graph::with_initial_capacity(1, 5, 2).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex(&vertex_type, 1u8).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type, 2u8).unwrap();
+        let vertex_3 = graph.new_vertex(&vertex_type, 3u8).unwrap();
+        let edge_type_1 = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let edge_type_2 = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+
+        let edge_1_2 = DirectedEdgeCoordinate::new(edge_type_1, vertex_1, vertex_2);
+        let edge_2_3 = DirectedEdgeCoordinate::new(edge_type_1, vertex_2, vertex_3);
+        let edge_3_1 = DirectedEdgeCoordinate::new(edge_type_1, vertex_3, vertex_1);
+        let edge_1_3 = DirectedEdgeCoordinate::new(edge_type_2, vertex_1, vertex_3);
+
+        assert!(graph
+            .set_edge_property(&edge_1_2, "label", "missing")
+            .is_err());
+
+        for edge in [&edge_1_2, &edge_2_3, &edge_3_1, &edge_1_3] {
+            graph
+                .new_edge(edge.edge_type_ref(), edge.tail_ref(), edge.head_ref(), 1u8)
+                .unwrap();
+            graph.set_edge_property(edge, "label", "edge").unwrap();
+        }
+
+        graph
+            .delete_edge(&edge_type_1, &vertex_1, &vertex_2)
+            .unwrap();
+        assert_eq!(graph.edge_property(&edge_1_2, "label").unwrap(), None);
+        assert_eq!(
+            graph.edge_property(&edge_2_3, "label").unwrap(),
+            Some(&PropertyValue::String(String::from("edge")))
+        );
+
+        graph
+            .drop_vertex_index_and_connected_edges(&vertex_2)
+            .unwrap();
+        assert!(graph.edge_property(&edge_3_1, "label").unwrap().is_some());
+        let vertex_4 = graph.new_vertex(&vertex_type, 4u8).unwrap();
+        let edge_4_3 = DirectedEdgeCoordinate::new(edge_type_1, vertex_4, vertex_3);
+        graph
+            .new_edge(&edge_type_1, &vertex_4, &vertex_3, 1u8)
+            .unwrap();
+        assert_eq!(graph.edge_property(&edge_4_3, "label").unwrap(), None);
+
+        graph.drop_edge_type(&edge_type_2).unwrap();
+        let edge_type_3 = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        graph
+            .new_edge(&edge_type_3, &vertex_1, &vertex_3, 1u8)
+            .unwrap();
+        assert_eq!(
+            graph
+                .edge_property(
+                    &DirectedEdgeCoordinate::new(edge_type_3, vertex_1, vertex_3),
+                    "label"
+                )
+                .unwrap(),
+            None
+        );
+    }
+}
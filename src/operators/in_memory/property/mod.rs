@@ -0,0 +1,5 @@
+mod edge_properties;
+mod vertex_properties;
+
+pub use edge_properties::*;
+pub use vertex_properties::*;
@@ -0,0 +1,153 @@
+use crate::error::GraphComputingError;
+use crate::graph::graph::Graph;
+use crate::graph::indexing::{GetVertexIndexIndex, GetVertexTypeIndex};
+use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::operators::operators::property::VertexProperties;
+use crate::operators::properties::PropertyValue;
+
+impl VertexProperties for Graph {
+    fn set_vertex_property(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+        value: impl Into<PropertyValue>,
+    ) -> Result<(), GraphComputingError> {
+        self.public_vertex_store
+            .try_vertex_index_validity(vertex_index)?;
+        self.public_vertex_store
+            .try_is_valid_vertex_element(vertex_type_index, vertex_index)?;
+        self.property_store.set_vertex_property(
+            vertex_type_index,
+            vertex_index,
+            property_name,
+            Some(value.into()),
+        );
+        Ok(())
+    }
+
+    fn vertex_property(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+    ) -> Result<Option<&PropertyValue>, GraphComputingError> {
+        self.public_vertex_store
+            .try_vertex_type_index_validity(vertex_type_index)?;
+        self.public_vertex_store
+            .try_vertex_index_validity(vertex_index)?;
+        Ok(self
+            .property_store
+            .vertex_property_ref(vertex_type_index, vertex_index, property_name))
+    }
+
+    fn delete_vertex_property(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+    ) -> Result<Option<PropertyValue>, GraphComputingError> {
+        self.public_vertex_store
+            .try_vertex_type_index_validity(vertex_type_index)?;
+        self.public_vertex_store
+            .try_vertex_index_validity(vertex_index)?;
+        Ok(self
+            .property_store
+            .set_vertex_property(vertex_type_index, vertex_index, property_name, None)
+            .into_previous_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::operators::delete::{DeleteVertexValue, DropVertexIndex};
+    use crate::operators::operators::drop::DropVertexType;
+    use crate::operators::operators::new::{NewVertex, NewVertexType};
+    use crate::operators::operators::set::SetVertexValue;
+
+    #[test]
+    fn vertex_properties_are_removed_with_their_vertex_element() {
+        let mut graph = Graph::with_initial_capacity(2, 5, 1).unwrap();
+        let vertex_type_1 = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex_type_2 = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex(&vertex_type_1, 1u8).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type_1, 2u8).unwrap();
+        graph
+            .set_vertex_value(&vertex_type_2, &vertex_1, 3u8)
+            .unwrap();
+
+        assert!(graph
+            .set_vertex_property(&vertex_type_2, &vertex_2, "name", "missing")
+            .is_err());
+
+        graph
+            .set_vertex_property(&vertex_type_1, &vertex_1, "name", "first")
+            .unwrap();
+        graph
+            .set_vertex_property(
+                &vertex_type_2,
+                &vertex_1,
+                "tags",
+                PropertyValue::List(vec!["a".into(), "b".into()]),
+            )
+            .unwrap();
+        graph
+            .set_vertex_property(&vertex_type_1, &vertex_2, "blob", vec![0u8, 1, 2])
+            .unwrap();
+        assert_eq!(
+            graph
+                .vertex_property(&vertex_type_1, &vertex_1, "name")
+                .unwrap(),
+            Some(&PropertyValue::String(String::from("first")))
+        );
+
+        graph
+            .delete_vertex_value(&vertex_type_1, &vertex_1)
+            .unwrap();
+        assert_eq!(
+            graph
+                .vertex_property(&vertex_type_1, &vertex_1, "name")
+                .unwrap(),
+            None
+        );
+        assert!(graph
+            .vertex_property(&vertex_type_2, &vertex_1, "tags")
+            .unwrap()
+            .is_some());
+
+        graph.drop_vertex_type(&vertex_type_2).unwrap();
+        let vertex_type_3 = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        graph
+            .set_vertex_value(&vertex_type_3, &vertex_1, 3u8)
+            .unwrap();
+        assert_eq!(
+            graph
+                .vertex_property(&vertex_type_3, &vertex_1, "tags")
+                .unwrap(),
+            None
+        );
+
+        assert_eq!(
+            graph
+                .delete_vertex_property(&vertex_type_1, &vertex_2, "blob")
+                .unwrap(),
+            Some(PropertyValue::Bytes(vec![0, 1, 2]))
+        );
+        graph
+            .set_vertex_property(&vertex_type_1, &vertex_2, "blob", vec![3u8])
+            .unwrap();
+        graph
+            .drop_vertex_index_and_connected_edges(&vertex_2)
+            .unwrap();
+        let vertex_3 = graph.new_vertex(&vertex_type_1, 4u8).unwrap();
+        assert_eq!(
+            graph
+                .vertex_property(&vertex_type_1, &vertex_3, "blob")
+                .unwrap(),
+            None
+        );
+    }
+}
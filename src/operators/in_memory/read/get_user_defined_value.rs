@@ -0,0 +1,295 @@
+use crate::error::{GraphComputingError, LogicError, LogicErrorType};
+
+use crate::graph::edge_store::operations::operations::edge_element::Indexing;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::graph::Graph;
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex};
+use crate::graph::value_type::{
+    user_defined_matrix_element_value, user_defined_vector_element_value, UserDefinedValueType,
+};
+use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::operators::operators::read::{GetUserDefinedEdgeWeight, GetUserDefinedVertexValue};
+
+impl<T: UserDefinedValueType> GetUserDefinedVertexValue<T> for Graph {
+    fn user_defined_vertex_value(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Result<Option<T>, GraphComputingError> {
+        let vertex_vector = self
+            .public_vertex_store
+            .vertex_vector_ref(vertex_type_index)?;
+        self.public_vertex_store
+            .try_vertex_index_validity(vertex_index)?;
+        Ok(user_defined_vector_element_value(
+            vertex_vector,
+            vertex_index.index(),
+        )?)
+    }
+
+    fn try_user_defined_vertex_value(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Result<T, GraphComputingError> {
+        match self.user_defined_vertex_value(vertex_type_index, vertex_index)? {
+            Some(value) => Ok(value),
+            None => Err(LogicError::new(
+                LogicErrorType::VertexMustExist,
+                format!(
+                    "No vertex value exists at vertex index: {:?}, for vertex type index: {:?}",
+                    vertex_index.index(),
+                    vertex_type_index.index()
+                ),
+                None,
+            )
+            .into()),
+        }
+    }
+}
+
+impl<T: UserDefinedValueType> GetUserDefinedEdgeWeight<T> for Graph {
+    fn user_defined_edge_weight(
+        &self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Result<Option<T>, GraphComputingError> {
+        self.public_edge_store.try_is_valid_edge(
+            &self.public_vertex_store,
+            edge_type,
+            tail,
+            head,
+        )?;
+        Ok(user_defined_matrix_element_value(
+            self.public_edge_store
+                .adjacency_matrix_ref_unchecked(edge_type),
+            tail.index(),
+            head.index(),
+        )?)
+    }
+
+    fn try_user_defined_edge_weight(
+        &self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Result<T, GraphComputingError> {
+        match self.user_defined_edge_weight(edge_type, tail, head)? {
+            Some(weight) => Ok(weight),
+            None => Err(LogicError::new(
+                LogicErrorType::EdgeMustExist,
+                format!(
+                    "No edge exists from tail: {:?}, to head: {:?}, for edge type index: {:?}",
+                    tail.index(),
+                    head.index(),
+                    edge_type.index()
+                ),
+                None,
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+    use graphblas_sparse_linear_algebra::operators::options::OperatorOptions;
+
+    use crate::graph::value_type::{UserDefinedBinaryOperator, UserDefinedIndexUnaryOperator};
+    use crate::implement_user_defined_value_type;
+    use crate::operators::operators::element_wise_addition::BinaryOperatorElementWiseVertexVectorAddition;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+    use crate::operators::operators::read::GetVertexValue;
+    use crate::operators::operators::select::SelectFromVertexVector;
+    use crate::operators::operators::set::SetVertexValue;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Measurement {
+        timestamp: u64,
+        score: f32,
+        flags: u8,
+    }
+    implement_user_defined_value_type!(unsafe Measurement);
+
+    unsafe extern "C" fn add_scores(
+        z: *mut std::ffi::c_void,
+        x: *const std::ffi::c_void,
+        y: *const std::ffi::c_void,
+    ) {
+        let x = &*(x as *const Measurement);
+        let y = &*(y as *const Measurement);
+        *(z as *mut Measurement) = Measurement {
+            timestamp: x.timestamp.max(y.timestamp),
+            score: x.score + y.score,
+            flags: x.flags | y.flags,
+        };
+    }
+
+    unsafe extern "C" fn has_flags(
+        z: *mut std::ffi::c_void,
+        x: *const std::ffi::c_void,
+        _row_index: u64,
+        _column_index: u64,
+        y: *const std::ffi::c_void,
+    ) {
+        let x = &*(x as *const Measurement);
+        let y = &*(y as *const Measurement);
+        *(z as *mut bool) = x.flags & y.flags == y.flags;
+    }
+
+    #[test]
+    fn set_get_select_and_add_user_defined_values() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let measurements = NewVertexType::<Measurement>::apply(&mut graph).unwrap();
+        let selected = NewVertexType::<Measurement>::apply(&mut graph).unwrap();
+        let first = graph
+            .new_vertex(
+                &measurements,
+                Measurement {
+                    timestamp: 1,
+                    score: 0.5,
+                    flags: 0b01,
+                },
+            )
+            .unwrap();
+        let second = graph
+            .new_vertex(
+                &measurements,
+                Measurement {
+                    timestamp: 2,
+                    score: 1.5,
+                    flags: 0b11,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            GetUserDefinedVertexValue::<Measurement>::user_defined_vertex_value(
+                &graph,
+                &measurements,
+                &first
+            )
+            .unwrap(),
+            Some(Measurement {
+                timestamp: 1,
+                score: 0.5,
+                flags: 0b01,
+            })
+        );
+        assert!(
+            GetVertexValue::<u8>::vertex_value_or_default(&graph, &measurements, &first).is_err()
+        );
+
+        let has_flags = UserDefinedIndexUnaryOperator::<Measurement>::new(Some(has_flags)).unwrap();
+        SelectFromVertexVector::<Measurement>::by_index(
+            &mut graph,
+            &has_flags,
+            Measurement {
+                flags: 0b10,
+                ..Default::default()
+            },
+            &measurements,
+            &Assignment::new(),
+            &selected,
+            None,
+            &OperatorOptions::new_default(),
+        )
+        .unwrap();
+        assert_eq!(
+            GetUserDefinedVertexValue::<Measurement>::user_defined_vertex_value(
+                &graph, &selected, &first
+            )
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            GetUserDefinedVertexValue::<Measurement>::try_user_defined_vertex_value(
+                &graph, &selected, &second
+            )
+            .unwrap()
+            .timestamp,
+            2
+        );
+
+        let add_scores = UserDefinedBinaryOperator::<Measurement>::new(Some(add_scores)).unwrap();
+        BinaryOperatorElementWiseVertexVectorAddition::<Measurement>::apply(
+            &mut graph,
+            &measurements,
+            &add_scores,
+            &selected,
+            &add_scores,
+            &selected,
+            None,
+            &OperatorOptions::new_default(),
+        )
+        .unwrap();
+        let sum = GetUserDefinedVertexValue::<Measurement>::try_user_defined_vertex_value(
+            &graph, &selected, &second,
+        )
+        .unwrap();
+        assert_eq!(sum.score, 4.5);
+        assert_eq!(sum.flags, 0b11);
+
+        graph
+            .set_vertex_value(
+                &selected,
+                &first,
+                Measurement {
+                    timestamp: 3,
+                    score: 2.0,
+                    flags: 0,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            GetUserDefinedVertexValue::<Measurement>::try_user_defined_vertex_value(
+                &graph, &selected, &first
+            )
+            .unwrap()
+            .timestamp,
+            3
+        );
+
+        let connections = NewEdgeType::<Measurement>::apply(&mut graph).unwrap();
+        graph
+            .new_edge(
+                &connections,
+                &first,
+                &second,
+                Measurement {
+                    timestamp: 4,
+                    score: 1.0,
+                    flags: 0b100,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            GetUserDefinedEdgeWeight::<Measurement>::try_user_defined_edge_weight(
+                &graph,
+                &connections,
+                &first,
+                &second
+            )
+            .unwrap()
+            .flags,
+            0b100
+        );
+        assert_eq!(
+            GetUserDefinedEdgeWeight::<Measurement>::user_defined_edge_weight(
+                &graph,
+                &connections,
+                &second,
+                &first
+            )
+            .unwrap(),
+            None
+        );
+    }
+}
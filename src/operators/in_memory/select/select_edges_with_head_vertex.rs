@@ -18,6 +18,7 @@ use graphblas_sparse_linear_algebra::operators::extract::ExtractMatrixColumn;
 use crate::graph::graph::GetGraphblasOperatorAppliers;
 use crate::graph::graph::{Graph, GraphblasOperatorApplierCollection};
 use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::operators::change_data_capture::GraphChange;
 use crate::{error::GraphComputingError, graph::value_type::ValueType};
 
 impl<EvaluationDomain> SelectEdgesWithHeadVertex<EvaluationDomain> for Graph
@@ -43,7 +44,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(extract_to))
     }
 }
 
@@ -70,7 +72,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(extract_to))
     }
 }
 
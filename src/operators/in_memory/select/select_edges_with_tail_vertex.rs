@@ -26,6 +26,7 @@ use crate::operators::operators::select::{
 };
 use crate::operators::options::OptionsForOperatorWithAdjacencyMatrixArgument;
 use crate::{error::GraphComputingError, graph::value_type::ValueType};
+use crate::operators::change_data_capture::GraphChange;
 
 impl<EvaluationDomain> SelectEdgesWithTailVertex<EvaluationDomain> for Graph
 where
@@ -51,7 +52,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(extract_to))
     }
 }
 
@@ -80,7 +82,8 @@ where
             mask,
             options,
             &self.graphblas_operator_applier_collection,
-        )
+        )?;
+        self.publish_change(GraphChange::vertex_vector_overwritten(extract_to))
     }
 }
 
@@ -5,6 +5,7 @@ use crate::graph::edge::{GetDirectedEdgeCoordinateIndex, GetEdgeWeight};
 use crate::graph::edge_store::operations::operations::edge_element::SetEdge;
 use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex};
 use crate::graph::value_type::ValueType;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::set::SetEdgeWeight;
 use crate::{error::GraphComputingError, graph::graph::Graph};
 
@@ -28,8 +29,14 @@ impl<T: ValueType + SetSparseMatrixElementTyped<T> + Copy> SetEdgeWeight<T> for
         head: &impl GetVertexIndexIndex,
         weight: T,
     ) -> Result<(), GraphComputingError> {
-        self.public_edge_store
-            .set_edge(&self.public_vertex_store, edge_type, tail, head, weight)
+        self.public_edge_store.set_edge(
+            &self.public_vertex_store,
+            edge_type,
+            tail,
+            head,
+            weight,
+        )?;
+        self.publish_change(GraphChange::edge_weight_set(edge_type, tail, head))
     }
 }
 
@@ -6,7 +6,9 @@ use crate::graph::graph::Graph;
 use crate::graph::indexing::{GetVertexIndexIndex, GetVertexTypeIndex};
 use crate::graph::value_type::ValueType;
 use crate::graph::vertex_store::operations::vertex_element::SetVertex;
+use crate::operators::change_data_capture::GraphChange;
 use crate::operators::operators::set::SetVertexValue;
+use crate::operators::vertex_value_index::update_indexed_vertex_value;
 
 impl<T> SetVertexValue<T> for Graph
 where
@@ -19,7 +21,17 @@ where
         value: T,
     ) -> Result<(), GraphComputingError> {
         self.vertex_store_mut_ref()
-            .set_vertex(vertex_type_index, vertex_index, value)
+            .set_vertex(vertex_type_index, vertex_index, value)?;
+        update_indexed_vertex_value(
+            &mut self.vertex_value_indexes,
+            &self.public_vertex_store,
+            vertex_type_index,
+            vertex_index,
+        )?;
+        self.publish_change(GraphChange::vertex_value_set(
+            vertex_type_index,
+            vertex_index,
+        ))
     }
 }
 
@@ -0,0 +1,153 @@
+use crate::error::GraphComputingError;
+use crate::graph::graph::Graph;
+use crate::graph::indexing::GetVertexTypeIndex;
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::operators::operators::value_index::VertexValueIndexing;
+use crate::operators::vertex_value_index::build_vertex_value_index;
+
+impl VertexValueIndexing for Graph {
+    fn create_vertex_value_index(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        let index = build_vertex_value_index(&self.public_vertex_store, vertex_type_index)?;
+        self.vertex_value_indexes
+            .replace_index(vertex_type_index, Some(index));
+        Ok(())
+    }
+
+    fn drop_vertex_value_index(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.public_vertex_store
+            .try_vertex_type_index_validity(vertex_type_index)?;
+        self.vertex_value_indexes
+            .replace_index(vertex_type_index, None);
+        Ok(())
+    }
+
+    fn has_vertex_value_index(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<bool, GraphComputingError> {
+        self.public_vertex_store
+            .try_vertex_type_index_validity(vertex_type_index)?;
+        Ok(self.vertex_value_indexes.is_indexed(vertex_type_index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorElementList;
+    use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+    use graphblas_sparse_linear_algebra::operators::options::OperatorOptions;
+    use graphblas_sparse_linear_algebra::operators::unary_operator::Identity;
+
+    use crate::error::{GraphComputingErrorType, LogicErrorType};
+    use crate::graph::indexing::{GetIndex, VertexIndex};
+    use crate::operators::operators::apply_operator::ApplyUnaryOperatorToVertexVector;
+    use crate::operators::operators::delete::{DeleteVertexValue, DropVertexIndex};
+    use crate::operators::operators::drop::DropVertexType;
+    use crate::operators::operators::new::{NewVertex, NewVertexIndex, NewVertexType};
+    use crate::operators::operators::set::SetVertexValue;
+    use crate::operators::operators::update::UpdateVertexValue;
+    use crate::operators::operators::value_index::LookupVertexValue;
+
+    #[test]
+    fn vertex_value_index_follows_vertex_values() {
+        let mut graph = Graph::with_initial_capacity(1, 10, 1).unwrap();
+        let vertex_type = NewVertexType::<i32>::apply(&mut graph).unwrap();
+        let vertices: Vec<VertexIndex> = [5, -2, 5, 9]
+            .into_iter()
+            .map(|value| graph.new_vertex(&vertex_type, value).unwrap())
+            .collect();
+
+        graph.create_vertex_value_index(&vertex_type).unwrap();
+        assert!(graph.has_vertex_value_index(&vertex_type).unwrap());
+
+        graph
+            .update_vertex_value(&vertex_type, &vertices[3], 1)
+            .unwrap();
+        graph
+            .delete_vertex_value(&vertex_type, &vertices[0])
+            .unwrap();
+        let vertex = graph.new_vertex_index().unwrap();
+        // Set with a different value type is indexed as the stored value
+        graph
+            .set_vertex_value(&vertex_type, &vertex, 5.7f64)
+            .unwrap();
+
+        assert_eq!(
+            graph.vertex_indices_with_value(&vertex_type, 5i32).unwrap(),
+            vec![vertices[2], vertex]
+        );
+        assert_eq!(
+            graph
+                .vertex_indices_with_value_in_range(&vertex_type, ..=1i32)
+                .unwrap(),
+            vec![vertices[1], vertices[3]]
+        );
+        assert!(graph
+            .vertex_indices_with_value_in_range(&vertex_type, 0u8..)
+            .is_err());
+
+        let mask = graph
+            .vertex_mask_with_value_in_range(&vertex_type, 1i32..6)
+            .unwrap()
+            .element_list()
+            .unwrap();
+        assert_eq!(
+            mask.indices_ref(),
+            &vec![vertices[2].index(), vertices[3].index(), vertex.index()]
+        );
+
+        graph
+            .drop_vertex_index_and_connected_edges(&vertices[2])
+            .unwrap();
+        assert_eq!(
+            graph.vertex_indices_with_value(&vertex_type, 5i32).unwrap(),
+            vec![vertex]
+        );
+
+        graph.drop_vertex_type(&vertex_type).unwrap();
+        let vertex_type = NewVertexType::<i32>::apply(&mut graph).unwrap();
+        assert!(!graph.has_vertex_value_index(&vertex_type).unwrap());
+    }
+
+    #[test]
+    fn overwriting_the_vertex_vector_outdates_the_vertex_value_index() {
+        let mut graph = Graph::with_initial_capacity(1, 10, 1).unwrap();
+        let vertex_type = NewVertexType::<i32>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex(&vertex_type, 5).unwrap();
+        graph.create_vertex_value_index(&vertex_type).unwrap();
+
+        ApplyUnaryOperatorToVertexVector::<i32>::apply(
+            &mut graph,
+            &Identity::<i32>::new(),
+            &vertex_type,
+            &Assignment::new(),
+            &vertex_type,
+            None,
+            &OperatorOptions::new_default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            graph
+                .vertex_indices_with_value(&vertex_type, 5i32)
+                .unwrap_err()
+                .error_type(),
+            GraphComputingErrorType::LogicErrorType(LogicErrorType::VertexValueIndexMustBeUpToDate)
+        );
+
+        graph.create_vertex_value_index(&vertex_type).unwrap();
+        graph.set_vertex_value(&vertex_type, &vertex, 7i32).unwrap();
+        assert_eq!(
+            graph.vertex_indices_with_value(&vertex_type, 7i32).unwrap(),
+            vec![vertex]
+        );
+    }
+}
@@ -19,7 +19,10 @@ impl<'g> DeleteEdge for InMemoryGraphTransaction<'g> {
             edge_type,
             tail,
             head,
-        )
+        )?;
+        let mut changes = self.property_store.remove_edge(edge_type, tail, head);
+        self.property_changes.append(&mut changes);
+        Ok(())
     }
 
     fn delete_edge_for_coordinate(
@@ -33,7 +33,12 @@ impl<'g> DropVertexIndex for InMemoryGraphTransaction<'g> {
                 )?;
 
                 self.vertex_store_transaction
-                    .delete_vertex_for_all_valid_vertex_types_and_value_types(vertex_index)
+                    .delete_vertex_for_all_valid_vertex_types_and_value_types(vertex_index)?;
+                let mut changes = self.vertex_value_indexes.remove_vertex(vertex_index);
+                self.vertex_value_index_changes.append(&mut changes);
+                let mut changes = self.property_store.remove_vertex(vertex_index);
+                self.property_changes.append(&mut changes);
+                Ok(())
             }
             false => Ok(()),
         }
@@ -47,7 +52,13 @@ impl<'g> DeleteVertexValue for InMemoryGraphTransaction<'g> {
         vertex_index: &impl GetVertexIndexIndex,
     ) -> Result<(), GraphComputingError> {
         self.vertex_store_transaction
-            .delete_vertex_element(vertex_type_index, vertex_index)
+            .delete_vertex_element(vertex_type_index, vertex_index)?;
+        self.update_indexed_vertex_value(vertex_type_index, vertex_index)?;
+        let mut changes = self
+            .property_store
+            .remove_vertex_element(vertex_type_index, vertex_index);
+        self.property_changes.append(&mut changes);
+        Ok(())
     }
 }
 
@@ -10,7 +10,11 @@ impl<'g> DropEdgeType for InMemoryGraphTransaction<'g> {
         &mut self,
         edge_type_index: &impl GetEdgeTypeIndex,
     ) -> Result<(), GraphComputingError> {
-        self.edge_store_transaction.drop_edge_type(edge_type_index)
+        self.edge_store_transaction
+            .drop_edge_type(edge_type_index)?;
+        let mut changes = self.property_store.remove_edge_type(edge_type_index);
+        self.property_changes.append(&mut changes);
+        Ok(())
     }
 }
 
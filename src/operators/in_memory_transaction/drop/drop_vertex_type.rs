@@ -11,7 +11,14 @@ impl<'g> DropVertexType for InMemoryGraphTransaction<'g> {
         vertex_type_index: &impl GetVertexTypeIndex,
     ) -> Result<(), GraphComputingError> {
         self.vertex_store_transaction
-            .delete_vertex_type(vertex_type_index)
+            .delete_vertex_type(vertex_type_index)?;
+        let change = self
+            .vertex_value_indexes
+            .replace_index(vertex_type_index, None);
+        self.vertex_value_index_changes.push(change);
+        let mut changes = self.property_store.remove_vertex_type(vertex_type_index);
+        self.property_changes.append(&mut changes);
+        Ok(())
     }
 }
 
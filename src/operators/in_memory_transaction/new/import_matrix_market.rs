@@ -0,0 +1,232 @@
+use std::cmp::max;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::mem;
+use std::path::Path;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::FromMatrixElementList;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, MatrixElementList, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::FromVectorElementList;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::{
+    GetGraphblasSparseVector, SparseVector, VectorElementList,
+};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{BinaryOperator, First};
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::resize_adjacency_matrices::ResizeAdjacencyMatrices;
+use crate::graph::graph::{
+    invalid_file_format_error, read_matrix_market_elements, read_matrix_market_header,
+    GetGraphblasContext, ImportMatrixMarket, MatrixMarketHeader, MatrixMarketValue,
+};
+use crate::graph::indexing::operations::SetIndexCapacity;
+use crate::graph::indexing::{EdgeTypeIndex, ElementCount, GetIndexCapacity, VertexTypeIndex};
+use crate::graph::value_type::{call_generic_function_for_value_type_identifier, ValueType};
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::GetVertexStore;
+use crate::graph::vertex_store::operations::vertex_element::ClaimVertexIndices;
+use crate::graph::vertex_store::operations::vertex_type::{GetVertexVector, ResizeVertexVectors};
+use crate::graph::vertex_store::GetVertexElementIndexer;
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::new::{NewEdgeType, NewVertexType};
+
+impl<'g> ImportMatrixMarket for InMemoryGraphTransaction<'g> {
+    fn import_adjacency_matrix_from_matrix_market(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<EdgeTypeIndex, GraphComputingError> {
+        let mut lines = BufReader::new(File::open(path)?).lines();
+        let header = read_matrix_market_header(&mut lines)?;
+        call_generic_function_for_value_type_identifier!(
+            header.value_type_ref(),
+            import_adjacency_matrix,
+            (self, &mut lines, &header)
+        )
+    }
+
+    fn import_vertex_vector_from_matrix_market(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<VertexTypeIndex, GraphComputingError> {
+        let mut lines = BufReader::new(File::open(path)?).lines();
+        let header = read_matrix_market_header(&mut lines)?;
+        call_generic_function_for_value_type_identifier!(
+            header.value_type_ref(),
+            import_vertex_vector,
+            (self, &mut lines, &header)
+        )
+    }
+}
+
+fn import_adjacency_matrix<'g, T>(
+    transaction: &mut InMemoryGraphTransaction<'g>,
+    lines: &mut impl Iterator<Item = std::io::Result<String>>,
+    header: &MatrixMarketHeader,
+) -> Result<EdgeTypeIndex, GraphComputingError>
+where
+    T: ValueType + MatrixMarketValue,
+    InMemoryGraphTransaction<'g>: NewEdgeType<T>,
+    SparseMatrix<T>: FromMatrixElementList<T>,
+    First<T>: BinaryOperator<T>,
+{
+    let elements = read_matrix_market_elements::<T>(lines, header)?;
+
+    claim_vertex_indices(transaction, max(header.row_height(), header.column_width()))?;
+    let edge_type = NewEdgeType::<T>::apply(transaction)?;
+
+    let vertex_capacity = *transaction
+        .edge_store_transaction
+        .adjacency_matrix_size_ref();
+    let mut sparse_matrix = SparseMatrix::<T>::from_element_list(
+        transaction.graphblas_context(),
+        Size::new(vertex_capacity, vertex_capacity),
+        MatrixElementList::from_vectors(
+            elements.row_indices,
+            elements.column_indices,
+            elements.values,
+        )?,
+        &First::<T>::new(),
+    )?;
+
+    // Records the overwritten adjacency matrix in the transaction
+    unsafe {
+        mem::swap(
+            transaction
+                .edge_store_transaction
+                .adjacency_matrix_mut_ref(&edge_type)?
+                .graphblas_matrix_mut_ref(),
+            sparse_matrix.graphblas_matrix_mut_ref(),
+        )
+    };
+    Ok(edge_type)
+}
+
+fn import_vertex_vector<'g, T>(
+    transaction: &mut InMemoryGraphTransaction<'g>,
+    lines: &mut impl Iterator<Item = std::io::Result<String>>,
+    header: &MatrixMarketHeader,
+) -> Result<VertexTypeIndex, GraphComputingError>
+where
+    T: ValueType + MatrixMarketValue,
+    InMemoryGraphTransaction<'g>: NewVertexType<T>,
+    SparseVector<T>: FromVectorElementList<T>,
+    First<T>: BinaryOperator<T>,
+{
+    let elements = read_matrix_market_elements::<T>(lines, header)?;
+    let (length, indices) = if header.column_width() == 1 {
+        (header.row_height(), elements.row_indices)
+    } else if header.row_height() == 1 {
+        (header.column_width(), elements.column_indices)
+    } else {
+        return Err(invalid_file_format_error(format!(
+            "A vertex vector must have a single row or column, found a {} by {} matrix",
+            header.row_height(),
+            header.column_width()
+        )));
+    };
+
+    claim_vertex_indices(transaction, length)?;
+    let vertex_type = NewVertexType::<T>::apply(transaction)?;
+
+    let vertex_capacity = transaction
+        .vertex_store_transaction
+        .vertex_store_ref()
+        .element_indexer_ref()
+        .capacity()?;
+    let mut sparse_vector = SparseVector::<T>::from_element_list(
+        transaction.graphblas_context(),
+        vertex_capacity,
+        VectorElementList::from_vectors(indices, elements.values)?,
+        &First::<T>::new(),
+    )?;
+
+    // Records the overwritten vertex vector in the transaction
+    unsafe {
+        mem::swap(
+            transaction
+                .vertex_store_transaction
+                .vertex_vector_mut_ref(&vertex_type)?
+                .graphblas_vector_mut_ref(),
+            sparse_vector.graphblas_vector_mut_ref(),
+        )
+    };
+    Ok(vertex_type)
+}
+
+// The indices in a Matrix Market file are taken as vertex indices.
+fn claim_vertex_indices(
+    transaction: &mut InMemoryGraphTransaction,
+    number_of_vertices: ElementCount,
+) -> Result<(), GraphComputingError> {
+    let vertex_capacity = transaction
+        .vertex_store_transaction
+        .vertex_store_ref()
+        .element_indexer_ref()
+        .capacity()?;
+    if vertex_capacity < number_of_vertices {
+        transaction
+            .vertex_store_transaction
+            .resize_vertex_vectors(number_of_vertices)?;
+        // The mask length of the indexer is restored by the transaction
+        transaction
+            .vertex_store_transaction
+            .vertex_store_mut_ref()
+            .element_indexer_mut_ref()
+            .set_index_capacity(number_of_vertices)?;
+        transaction
+            .edge_store_transaction
+            .resize_adjacency_matrices(number_of_vertices)?;
+    }
+
+    transaction
+        .vertex_store_transaction
+        .claim_vertex_indices_below(number_of_vertices)?;
+    transaction.changes_reassign_indices = true;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::graph::{ExportMatrixMarket, Graph};
+    use crate::graph::indexing::{GetIndex, VertexIndex};
+    use crate::operators::operators::indexing::CheckIndex;
+    use crate::operators::operators::new::NewVertexIndex;
+    use crate::operators::transaction::UseTransaction;
+
+    #[test]
+    fn revert_imported_vertex_vector() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u16>::apply(&mut graph).unwrap();
+        for _ in 0..4 {
+            graph.new_vertex_index().unwrap();
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_revert_imported_vertex_vector_{}.mtx",
+            std::process::id()
+        ));
+        graph
+            .export_vertex_vector_to_matrix_market(&vertex_type, &path)
+            .unwrap();
+
+        let mut imported_graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        let vertex = imported_graph.new_vertex_index().unwrap();
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut imported_graph).unwrap();
+            transaction
+                .import_vertex_vector_from_matrix_market(&path)
+                .unwrap();
+            transaction.revert().unwrap();
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(imported_graph.is_valid_vertex_index(&vertex).unwrap());
+        assert!(!imported_graph
+            .is_valid_vertex_index(&VertexIndex::new(1))
+            .unwrap());
+        assert_eq!(imported_graph.new_vertex_index().unwrap().index(), 1);
+    }
+}
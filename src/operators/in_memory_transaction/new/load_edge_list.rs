@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::Path;
+use std::str::FromStr;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::FromMatrixElementList;
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::SparseMatrix;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    AccumulatorBinaryOperator, BinaryOperator, First, Second,
+};
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::indexing::{GetEdgeTypeIndex, VertexIndex};
+use crate::graph::value_type::ValueType;
+use crate::operators::in_memory::new::{
+    load_edge_list, load_edge_list_with_external_ids, read_edge_list,
+    read_edge_list_with_vertex_indices,
+};
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::new::LoadEdgeList;
+
+impl<'g, T> LoadEdgeList<T> for InMemoryGraphTransaction<'g>
+where
+    T: ValueType,
+    SparseMatrix<T>: FromMatrixElementList<T>,
+    First<T>: BinaryOperator<T>,
+    Second<T>: BinaryOperator<T> + AccumulatorBinaryOperator<T>,
+{
+    fn load_edge_list(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        edges: impl IntoIterator<Item = (VertexIndex, VertexIndex, T)>,
+    ) -> Result<(), GraphComputingError> {
+        load_edge_list(
+            &self.vertex_store_transaction,
+            &mut self.edge_store_transaction,
+            edge_type,
+            edges,
+        )
+    }
+
+    fn load_edge_list_with_external_ids<Id: Hash + Eq>(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        edges: impl IntoIterator<Item = (Id, Id, T)>,
+    ) -> Result<HashMap<Id, VertexIndex>, GraphComputingError> {
+        self.edge_store_transaction
+            .try_edge_type_index_validity(edge_type)?;
+        load_edge_list_with_external_ids(self, edge_type, edges)
+    }
+
+    fn load_edge_list_from_file(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+        delimiter: char,
+    ) -> Result<(), GraphComputingError>
+    where
+        T: FromStr,
+    {
+        let edges = read_edge_list_with_vertex_indices(path, delimiter)?;
+        LoadEdgeList::<T>::load_edge_list(self, edge_type, edges)
+    }
+
+    fn load_edge_list_from_file_with_external_ids(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+        delimiter: char,
+    ) -> Result<HashMap<String, VertexIndex>, GraphComputingError>
+    where
+        T: FromStr,
+    {
+        let edges = read_edge_list::<T>(path, delimiter)?;
+        LoadEdgeList::<T>::load_edge_list_with_external_ids(self, edge_type, edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::graph::Graph;
+    use crate::operators::operators::indexing::CheckIndex;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertexIndex};
+    use crate::operators::operators::read::GetEdgeWeight;
+    use crate::operators::transaction::UseTransaction;
+
+    #[test]
+    fn revert_loaded_edge_list() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex_index().unwrap();
+        let vertex_2 = graph.new_vertex_index().unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_1, &vertex_2, 1u8)
+            .unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .load_edge_list_with_external_ids(
+                    &edge_type,
+                    vec![(0, 1, 2u8), (1, 2, 3u8), (2, 0, 4u8)],
+                )
+                .unwrap();
+            transaction
+                .load_edge_list(&edge_type, vec![(vertex_1, vertex_2, 5u8)])
+                .unwrap();
+            transaction.revert().unwrap();
+        }
+
+        assert_eq!(
+            GetEdgeWeight::<u8>::edge_weight(&graph, &edge_type, &vertex_1, &vertex_2).unwrap(),
+            Some(1)
+        );
+        assert!(!graph.is_valid_vertex_index(&VertexIndex::new(2)).unwrap());
+    }
+}
@@ -25,12 +25,14 @@ where
         vertex_type: &impl GetVertexTypeIndex,
         value: T,
     ) -> Result<VertexIndex, GraphComputingError> {
-        new_vertex(
+        let vertex_index = new_vertex(
             &mut self.vertex_store_transaction,
             &mut self.edge_store_transaction,
             vertex_type,
             value,
-        )
+        )?;
+        self.update_indexed_vertex_value(vertex_type, &vertex_index)?;
+        Ok(vertex_index)
     }
 }
 
@@ -0,0 +1,168 @@
+use crate::error::GraphComputingError;
+use crate::graph::edge::GetDirectedEdgeCoordinateIndex;
+use crate::graph::edge_store::operations::operations::edge_element::Indexing;
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::property::EdgeProperties;
+use crate::operators::properties::PropertyValue;
+
+impl<'g> EdgeProperties for InMemoryGraphTransaction<'g> {
+    fn set_edge_property(
+        &mut self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+        value: impl Into<PropertyValue>,
+    ) -> Result<(), GraphComputingError> {
+        self.edge_store_transaction.try_is_valid_edge(
+            &self.vertex_store_transaction,
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+        )?;
+        self.edge_store_transaction.try_is_edge(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+        )?;
+        let change = self.property_store.set_edge_property(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+            property_name,
+            Some(value.into()),
+        );
+        self.property_changes.push(change);
+        Ok(())
+    }
+
+    fn edge_property(
+        &self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+    ) -> Result<Option<&PropertyValue>, GraphComputingError> {
+        self.edge_store_transaction.try_is_valid_edge(
+            &self.vertex_store_transaction,
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+        )?;
+        Ok(self.property_store.edge_property_ref(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+            property_name,
+        ))
+    }
+
+    fn delete_edge_property(
+        &mut self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+    ) -> Result<Option<PropertyValue>, GraphComputingError> {
+        self.edge_store_transaction.try_is_valid_edge(
+            &self.vertex_store_transaction,
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+        )?;
+        let change = self.property_store.set_edge_property(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+            property_name,
+            None,
+        );
+        let deleted_value = change.previous_value_ref().cloned();
+        self.property_changes.push(change);
+        Ok(deleted_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::edge::DirectedEdgeCoordinate;
+    use crate::graph::graph::Graph;
+    use crate::operators::operators::delete::{DeleteEdge, DeleteVertexValue, DropVertexIndex};
+    use crate::operators::operators::drop::DropEdgeType;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+    use crate::operators::operators::property::VertexProperties;
+    use crate::operators::transaction::{UseSavepoints, UseTransaction};
+
+    #[test]
+    fn properties_are_consistent_after_revert() {
+        let mut graph = Graph::with_initial_capacity(1, 5, 1).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex(&vertex_type, 1u8).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type, 2u8).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_1, &vertex_2, 1u8)
+            .unwrap();
+        let edge = DirectedEdgeCoordinate::new(edge_type, vertex_1, vertex_2);
+        graph
+            .set_vertex_property(&vertex_type, &vertex_1, "name", "first")
+            .unwrap();
+        graph
+            .set_edge_property(&edge, "payload", vec![1u8, 2])
+            .unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .set_vertex_property(&vertex_type, &vertex_2, "name", "second")
+                .unwrap();
+            let savepoint = transaction.savepoint().unwrap();
+            transaction.delete_edge_for_coordinate(&edge).unwrap();
+            transaction
+                .delete_vertex_value(&vertex_type, &vertex_1)
+                .unwrap();
+            assert_eq!(transaction.edge_property(&edge, "payload").unwrap(), None);
+            assert_eq!(
+                transaction
+                    .vertex_property(&vertex_type, &vertex_1, "name")
+                    .unwrap(),
+                None
+            );
+
+            transaction.rollback_to(&savepoint).unwrap();
+            assert_eq!(
+                transaction.edge_property(&edge, "payload").unwrap(),
+                Some(&PropertyValue::Bytes(vec![1, 2]))
+            );
+
+            transaction
+                .drop_vertex_index_and_connected_edges(&vertex_2)
+                .unwrap();
+            transaction.drop_edge_type(&edge_type).unwrap();
+            transaction.revert().unwrap();
+        }
+
+        assert_eq!(
+            graph
+                .vertex_property(&vertex_type, &vertex_1, "name")
+                .unwrap(),
+            Some(&PropertyValue::String(String::from("first")))
+        );
+        assert_eq!(
+            graph
+                .vertex_property(&vertex_type, &vertex_2, "name")
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            graph.edge_property(&edge, "payload").unwrap(),
+            Some(&PropertyValue::Bytes(vec![1, 2]))
+        );
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            assert_eq!(
+                transaction.delete_edge_property(&edge, "payload").unwrap(),
+                Some(PropertyValue::Bytes(vec![1, 2]))
+            );
+            transaction.commit().unwrap();
+        }
+        assert_eq!(graph.edge_property(&edge, "payload").unwrap(), None);
+    }
+}
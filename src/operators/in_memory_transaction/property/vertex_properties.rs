@@ -0,0 +1,69 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::{GetVertexIndexIndex, GetVertexTypeIndex};
+use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::property::VertexProperties;
+use crate::operators::properties::PropertyValue;
+
+impl<'g> VertexProperties for InMemoryGraphTransaction<'g> {
+    fn set_vertex_property(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+        value: impl Into<PropertyValue>,
+    ) -> Result<(), GraphComputingError> {
+        self.vertex_store_transaction
+            .try_vertex_index_validity(vertex_index)?;
+        self.vertex_store_transaction
+            .try_is_valid_vertex_element(vertex_type_index, vertex_index)?;
+        let change = self.property_store.set_vertex_property(
+            vertex_type_index,
+            vertex_index,
+            property_name,
+            Some(value.into()),
+        );
+        self.property_changes.push(change);
+        Ok(())
+    }
+
+    fn vertex_property(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+    ) -> Result<Option<&PropertyValue>, GraphComputingError> {
+        self.vertex_store_transaction
+            .try_vertex_type_index_validity(vertex_type_index)?;
+        self.vertex_store_transaction
+            .try_vertex_index_validity(vertex_index)?;
+        Ok(self
+            .property_store
+            .vertex_property_ref(vertex_type_index, vertex_index, property_name))
+    }
+
+    fn delete_vertex_property(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+    ) -> Result<Option<PropertyValue>, GraphComputingError> {
+        self.vertex_store_transaction
+            .try_vertex_type_index_validity(vertex_type_index)?;
+        self.vertex_store_transaction
+            .try_vertex_index_validity(vertex_index)?;
+        let change = self.property_store.set_vertex_property(
+            vertex_type_index,
+            vertex_index,
+            property_name,
+            None,
+        );
+        let deleted_value = change.previous_value_ref().cloned();
+        self.property_changes.push(change);
+        Ok(deleted_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,7 @@
+mod scratch_space;
+mod scratch_type;
+mod scratch_value;
+
+pub use scratch_space::*;
+pub use scratch_type::*;
+pub use scratch_value::*;
@@ -0,0 +1,144 @@
+use crate::error::{GraphComputingError, LogicError, LogicErrorType};
+use crate::graph::edge_store::operations::in_memory_transaction::GetEdgeStore;
+use crate::graph::edge_store::operations::operations::edge_type::delete_edge_type::DropEdgeType;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::resize_adjacency_matrices::ResizeAdjacencyMatrices;
+use crate::graph::edge_store::GetEdgeTypeIndicer;
+use crate::graph::indexing::operations::{GetValidIndices, SetIndexCapacity};
+use crate::graph::indexing::{
+    EdgeTypeIndex, ElementIndexMap, GetIndex, GetIndexCapacity, GetVertexIndexIndex, Index,
+    ScratchEdgeTypeIndex, ScratchGeneration, ScratchVertexTypeIndex, VertexTypeIndex,
+};
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::GetVertexStore;
+use crate::graph::vertex_store::operations::vertex_type::{
+    delete_vertex_type_unchecked, ResizeVertexVectors,
+};
+use crate::graph::vertex_store::{GetVertexElementIndexer, GetVertexTypeIndexer};
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+
+impl<'g> InMemoryGraphTransaction<'g> {
+    /// Sizes the private stores to the vertex capacity of the public stores.
+    /// Operations that change the vertex capacity do not resize the scratch types,
+    /// so writing to a scratch type synchronizes first.
+    pub(crate) fn synchronize_scratch_capacity(&mut self) -> Result<(), GraphComputingError> {
+        let vertex_capacity = self
+            .vertex_store_transaction
+            .vertex_store_ref()
+            .element_indexer_ref()
+            .capacity()?;
+        let scratch_vertex_capacity = self
+            .private_vertex_store_transaction
+            .vertex_store_ref()
+            .element_indexer_ref()
+            .capacity()?;
+        if scratch_vertex_capacity != vertex_capacity {
+            self.private_vertex_store_transaction
+                .resize_vertex_vectors(vertex_capacity)?;
+            // The mask length of the indexer is restored by the transaction
+            self.private_vertex_store_transaction
+                .vertex_store_mut_ref()
+                .element_indexer_mut_ref()
+                .set_index_capacity(vertex_capacity)?;
+        }
+
+        let adjacency_matrix_size = *self.edge_store_transaction.adjacency_matrix_size_ref();
+        if *self
+            .private_edge_store_transaction
+            .adjacency_matrix_size_ref()
+            != adjacency_matrix_size
+        {
+            self.private_edge_store_transaction
+                .resize_adjacency_matrices(adjacency_matrix_size)?;
+        }
+        Ok(())
+    }
+
+    /// Vertex indices beyond the capacity of the private stores have no scratch values yet.
+    pub(in crate::operators::in_memory_transaction) fn is_within_scratch_vertex_capacity(
+        &self,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Result<bool, GraphComputingError> {
+        Ok(vertex_index.index()
+            < self
+                .private_vertex_store_transaction
+                .vertex_store_ref()
+                .element_indexer_ref()
+                .capacity()?)
+    }
+
+    pub(in crate::operators::in_memory_transaction) fn is_within_scratch_adjacency_matrix_size(
+        &self,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> bool {
+        let adjacency_matrix_size = *self
+            .private_edge_store_transaction
+            .adjacency_matrix_size_ref();
+        tail.index() < adjacency_matrix_size && head.index() < adjacency_matrix_size
+    }
+
+    /// Frees all scratch types without recording the change in the transaction.
+    pub(in crate::operators::in_memory_transaction) fn drop_all_scratch_types(
+        &mut self,
+    ) -> Result<(), GraphComputingError> {
+        let vertex_store = self.private_vertex_store_transaction.vertex_store_mut_ref();
+        for index in vertex_store.vertex_type_indexer_ref().valid_indices()? {
+            delete_vertex_type_unchecked(vertex_store, &VertexTypeIndex::new(index))?;
+        }
+
+        let edge_store = self.private_edge_store_transaction.edge_store_mut_ref();
+        for index in edge_store.edge_type_indexer_ref().valid_indices()? {
+            edge_store.drop_edge_type_unchecked(&EdgeTypeIndex::new(index))?;
+        }
+        Ok(())
+    }
+
+    /// The index in the private vertex store, unless the scratch vertex type has been dropped.
+    pub(in crate::operators::in_memory_transaction) fn try_private_vertex_type_index(
+        &self,
+        scratch_vertex_type: &ScratchVertexTypeIndex,
+    ) -> Result<VertexTypeIndex, GraphComputingError> {
+        try_scratch_generation(
+            &self.scratch_vertex_type_generations,
+            scratch_vertex_type.index(),
+            scratch_vertex_type.scratch_generation(),
+            "vertex",
+        )?;
+        Ok(scratch_vertex_type.private_vertex_type_index())
+    }
+
+    /// The index in the private edge store, unless the scratch edge type has been dropped.
+    pub(in crate::operators::in_memory_transaction) fn try_private_edge_type_index(
+        &self,
+        scratch_edge_type: &ScratchEdgeTypeIndex,
+    ) -> Result<EdgeTypeIndex, GraphComputingError> {
+        try_scratch_generation(
+            &self.scratch_edge_type_generations,
+            scratch_edge_type.index(),
+            scratch_edge_type.scratch_generation(),
+            "edge",
+        )?;
+        Ok(scratch_edge_type.private_edge_type_index())
+    }
+}
+
+fn try_scratch_generation(
+    scratch_generations: &ElementIndexMap<ScratchGeneration>,
+    index: Index,
+    scratch_generation: ScratchGeneration,
+    description: &str,
+) -> Result<(), GraphComputingError> {
+    if scratch_generations.get(&index) == Some(&scratch_generation) {
+        Ok(())
+    } else {
+        Err(LogicError::new(
+            LogicErrorType::StaleIndex,
+            format!(
+                "Scratch {} type [{}] has been dropped, or belongs to another transaction.",
+                description, index
+            ),
+            None,
+        )
+        .into())
+    }
+}
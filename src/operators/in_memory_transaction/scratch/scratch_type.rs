@@ -0,0 +1,72 @@
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::add_edge_type::AddEdgeType as AddEdgeTypeToEdgeStore;
+use crate::graph::edge_store::operations::operations::edge_type::delete_edge_type::DropEdgeType as DropEdgeTypeFromEdgeStore;
+use crate::graph::indexing::{GetIndex, ScratchEdgeTypeIndex, ScratchVertexTypeIndex};
+use crate::graph::value_type::{GetValueTypeIdentifier, ValueType};
+use crate::graph::vertex_store::operations::vertex_type::{
+    AddVertexType as AddVertexTypeToVertexStore, DeleteVertexType,
+};
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::scratch::{
+    DropScratchEdgeType, DropScratchVertexType, NewScratchEdgeType, NewScratchVertexType,
+};
+
+impl<'g, T: ValueType + GetValueTypeIdentifier> NewScratchVertexType<T>
+    for InMemoryGraphTransaction<'g>
+{
+    fn new_scratch_vertex_type(&mut self) -> Result<ScratchVertexTypeIndex, GraphComputingError> {
+        self.synchronize_scratch_capacity()?;
+        let vertex_type_index =
+            AddVertexTypeToVertexStore::<T>::apply(&mut self.private_vertex_store_transaction)?;
+        let scratch_vertex_type = ScratchVertexTypeIndex::new(vertex_type_index.index());
+        self.scratch_vertex_type_generations.insert(
+            scratch_vertex_type.index(),
+            scratch_vertex_type.scratch_generation(),
+        );
+        Ok(scratch_vertex_type)
+    }
+}
+
+impl<'g, T: ValueType + GetValueTypeIdentifier> NewScratchEdgeType<T>
+    for InMemoryGraphTransaction<'g>
+{
+    fn new_scratch_edge_type(&mut self) -> Result<ScratchEdgeTypeIndex, GraphComputingError> {
+        self.synchronize_scratch_capacity()?;
+        let edge_type_index =
+            AddEdgeTypeToEdgeStore::<T>::apply(&mut self.private_edge_store_transaction)?;
+        let scratch_edge_type = ScratchEdgeTypeIndex::new(edge_type_index.index());
+        self.scratch_edge_type_generations.insert(
+            scratch_edge_type.index(),
+            scratch_edge_type.scratch_generation(),
+        );
+        Ok(scratch_edge_type)
+    }
+}
+
+impl<'g> DropScratchVertexType for InMemoryGraphTransaction<'g> {
+    fn drop_scratch_vertex_type(
+        &mut self,
+        scratch_vertex_type: &ScratchVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        let vertex_type_index = self.try_private_vertex_type_index(scratch_vertex_type)?;
+        self.private_vertex_store_transaction
+            .delete_vertex_type(&vertex_type_index)?;
+        self.scratch_vertex_type_generations
+            .remove(&scratch_vertex_type.index());
+        Ok(())
+    }
+}
+
+impl<'g> DropScratchEdgeType for InMemoryGraphTransaction<'g> {
+    fn drop_scratch_edge_type(
+        &mut self,
+        scratch_edge_type: &ScratchEdgeTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        let edge_type_index = self.try_private_edge_type_index(scratch_edge_type)?;
+        self.private_edge_store_transaction
+            .drop_edge_type(&edge_type_index)?;
+        self.scratch_edge_type_generations
+            .remove(&scratch_edge_type.index());
+        Ok(())
+    }
+}
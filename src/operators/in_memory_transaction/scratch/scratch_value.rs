@@ -0,0 +1,361 @@
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::{
+    GetSparseMatrixElementValueTyped, SetSparseMatrixElementTyped,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::{
+    GetSparseVectorElementValueTyped, SetSparseVectorElementTyped,
+};
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::in_memory_transaction::adjacency_matrices_state_restorer::adjacency_matrices_state_restorer::GetAdjacencyMatrixStateRevertersByEdgeTypeMap;
+use crate::graph::edge_store::operations::operations::edge_element::{
+    GetEdgeWeight as GetEdgeWeightFromEdgeStore, SetEdge,
+};
+use crate::graph::edge_store::weighted_adjacency_matrix::IntoSparseMatrixForValueType;
+use crate::graph::indexing::{GetVertexIndexIndex, ScratchEdgeTypeIndex, ScratchVertexTypeIndex};
+use crate::graph::value_type::{IntoValueType, ValueType};
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::GetSparseVectorStateRevertersByVertexTypeMap;
+use crate::graph::vertex_store::operations::vertex_element::{
+    CheckVertexIndex, GetVertexValue as GetVertexValueFromVertexStore, SetVertex,
+};
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::graph::vertex_store::ToSparseVectorForValueType;
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::scratch::{
+    GetScratchEdgeWeight, GetScratchVertexValue, SetScratchEdgeWeight, SetScratchVertexValue,
+};
+
+// Vertex indices are assigned by the public vertex store. The private stores only hold values.
+
+impl<'g, T> GetScratchVertexValue<T> for InMemoryGraphTransaction<'g>
+where
+    T: ValueType + ToSparseVectorForValueType<T> + GetSparseVectorElementValueTyped<T> + Default,
+    bool: IntoValueType<T>,
+    i8: IntoValueType<T>,
+    i16: IntoValueType<T>,
+    i32: IntoValueType<T>,
+    i64: IntoValueType<T>,
+    u8: IntoValueType<T>,
+    u16: IntoValueType<T>,
+    u32: IntoValueType<T>,
+    u64: IntoValueType<T>,
+    f32: IntoValueType<T>,
+    f64: IntoValueType<T>,
+    isize: IntoValueType<T>,
+    usize: IntoValueType<T>,
+{
+    fn scratch_vertex_value(
+        &self,
+        scratch_vertex_type: &ScratchVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Result<Option<T>, GraphComputingError> {
+        let vertex_type_index = self.try_private_vertex_type_index(scratch_vertex_type)?;
+        self.private_vertex_store_transaction
+            .try_vertex_type_index_validity(&vertex_type_index)?;
+        self.vertex_store_transaction
+            .try_vertex_index_validity(vertex_index)?;
+        if !self.is_within_scratch_vertex_capacity(vertex_index)? {
+            return Ok(None);
+        }
+        self.private_vertex_store_transaction
+            .vertex_value_unchecked(&vertex_type_index, vertex_index)
+    }
+}
+
+impl<'g, T> SetScratchVertexValue<T> for InMemoryGraphTransaction<'g>
+where
+    T: ValueType
+        + SetSparseVectorElementTyped<T>
+        + Default
+        + GetSparseVectorElementValueTyped<T>
+        + GetSparseVectorStateRevertersByVertexTypeMap<T>,
+{
+    fn set_scratch_vertex_value(
+        &mut self,
+        scratch_vertex_type: &ScratchVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        value: T,
+    ) -> Result<(), GraphComputingError> {
+        let vertex_type_index = self.try_private_vertex_type_index(scratch_vertex_type)?;
+        self.private_vertex_store_transaction
+            .try_vertex_type_index_validity(&vertex_type_index)?;
+        self.vertex_store_transaction
+            .try_vertex_index_validity(vertex_index)?;
+        self.synchronize_scratch_capacity()?;
+        self.private_vertex_store_transaction.set_vertex_unchecked(
+            &vertex_type_index,
+            vertex_index,
+            value,
+        )
+    }
+}
+
+impl<'g, T> GetScratchEdgeWeight<T> for InMemoryGraphTransaction<'g>
+where
+    T: ValueType + IntoSparseMatrixForValueType<T> + GetSparseMatrixElementValueTyped<T> + Default,
+    bool: IntoValueType<T>,
+    i8: IntoValueType<T>,
+    i16: IntoValueType<T>,
+    i32: IntoValueType<T>,
+    i64: IntoValueType<T>,
+    u8: IntoValueType<T>,
+    u16: IntoValueType<T>,
+    u32: IntoValueType<T>,
+    u64: IntoValueType<T>,
+    f32: IntoValueType<T>,
+    f64: IntoValueType<T>,
+    isize: IntoValueType<T>,
+    usize: IntoValueType<T>,
+{
+    fn scratch_edge_weight(
+        &self,
+        scratch_edge_type: &ScratchEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Result<Option<T>, GraphComputingError> {
+        let edge_type_index = self.try_private_edge_type_index(scratch_edge_type)?;
+        if !self.is_within_scratch_adjacency_matrix_size(tail, head) {
+            // Validates the vertices, without reading beyond the scratch adjacency matrix
+            self.vertex_store_transaction
+                .try_vertex_index_validity(tail)?;
+            self.vertex_store_transaction
+                .try_vertex_index_validity(head)?;
+            return Ok(None);
+        }
+        self.private_edge_store_transaction.edge_weight(
+            &self.vertex_store_transaction,
+            &edge_type_index,
+            tail,
+            head,
+        )
+    }
+}
+
+impl<'g, T> SetScratchEdgeWeight<T> for InMemoryGraphTransaction<'g>
+where
+    T: ValueType
+        + SetSparseMatrixElementTyped<T>
+        + Copy
+        + Default
+        + GetSparseMatrixElementValueTyped<T>
+        + GetAdjacencyMatrixStateRevertersByEdgeTypeMap<T>,
+{
+    fn set_scratch_edge_weight(
+        &mut self,
+        scratch_edge_type: &ScratchEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+        weight: T,
+    ) -> Result<(), GraphComputingError> {
+        let edge_type_index = self.try_private_edge_type_index(scratch_edge_type)?;
+        self.synchronize_scratch_capacity()?;
+        self.private_edge_store_transaction.set_edge(
+            &self.vertex_store_transaction,
+            &edge_type_index,
+            tail,
+            head,
+            weight,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::edge_store::GetEdgeTypeIndicer;
+    use crate::graph::graph::Graph;
+    use crate::graph::indexing::operations::GetValidIndices;
+    use crate::graph::indexing::GetIndex;
+    use crate::graph::vertex_store::GetVertexTypeIndexer;
+    use crate::operators::operators::new::NewVertexIndex;
+    use crate::operators::operators::scratch::{NewScratchEdgeType, NewScratchVertexType};
+    use crate::operators::transaction::{UseSavepoints, UseTransaction};
+
+    fn number_of_scratch_types(graph: &Graph) -> (usize, usize) {
+        (
+            graph
+                .private_vertex_store
+                .vertex_type_indexer_ref()
+                .valid_indices()
+                .unwrap()
+                .len(),
+            graph
+                .private_edge_store
+                .edge_type_indexer_ref()
+                .valid_indices()
+                .unwrap()
+                .len(),
+        )
+    }
+
+    #[test]
+    fn scratch_types_are_dropped_on_commit() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        let mut vertices = Vec::new();
+        for _ in 0..50 {
+            vertices.push(graph.new_vertex_index().unwrap());
+        }
+        let tail = vertices[3];
+        let head = vertices[49];
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            let scratch_vertex_type =
+                NewScratchVertexType::<u8>::new_scratch_vertex_type(&mut transaction).unwrap();
+            let scratch_edge_type =
+                NewScratchEdgeType::<u8>::new_scratch_edge_type(&mut transaction).unwrap();
+
+            transaction
+                .set_scratch_vertex_value(&scratch_vertex_type, &head, 1u8)
+                .unwrap();
+            transaction
+                .set_scratch_edge_weight(&scratch_edge_type, &tail, &head, 2u8)
+                .unwrap();
+
+            let savepoint = transaction.savepoint().unwrap();
+            transaction
+                .set_scratch_vertex_value(&scratch_vertex_type, &head, 3u8)
+                .unwrap();
+            transaction
+                .set_scratch_edge_weight(&scratch_edge_type, &head, &tail, 4u8)
+                .unwrap();
+            transaction.rollback_to(&savepoint).unwrap();
+
+            assert_eq!(
+                GetScratchVertexValue::<u8>::scratch_vertex_value(
+                    &transaction,
+                    &scratch_vertex_type,
+                    &head
+                )
+                .unwrap(),
+                Some(1)
+            );
+            assert_eq!(
+                GetScratchEdgeWeight::<u8>::scratch_edge_weight(
+                    &transaction,
+                    &scratch_edge_type,
+                    &tail,
+                    &head
+                )
+                .unwrap(),
+                Some(2)
+            );
+            assert_eq!(
+                GetScratchEdgeWeight::<u8>::scratch_edge_weight(
+                    &transaction,
+                    &scratch_edge_type,
+                    &head,
+                    &tail
+                )
+                .unwrap(),
+                None
+            );
+
+            transaction.commit().unwrap();
+        }
+
+        assert_eq!(number_of_scratch_types(&graph), (0, 0));
+    }
+
+    #[test]
+    fn reject_scratch_type_of_other_transaction() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        let vertex = graph.new_vertex_index().unwrap();
+
+        let dropped_scratch_vertex_type = {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            let scratch_vertex_type =
+                NewScratchVertexType::<u8>::new_scratch_vertex_type(&mut transaction).unwrap();
+            transaction.commit().unwrap();
+            scratch_vertex_type
+        };
+
+        let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+        let scratch_vertex_type =
+            NewScratchVertexType::<u8>::new_scratch_vertex_type(&mut transaction).unwrap();
+        assert_eq!(
+            scratch_vertex_type.index(),
+            dropped_scratch_vertex_type.index()
+        );
+
+        assert!(transaction
+            .set_scratch_vertex_value(&dropped_scratch_vertex_type, &vertex, 1u8)
+            .is_err());
+        transaction
+            .set_scratch_vertex_value(&scratch_vertex_type, &vertex, 1u8)
+            .unwrap();
+    }
+
+    #[test]
+    fn scratch_types_are_dropped_on_revert() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            let scratch_vertex_type =
+                NewScratchVertexType::<u8>::new_scratch_vertex_type(&mut transaction).unwrap();
+            NewScratchEdgeType::<u8>::new_scratch_edge_type(&mut transaction).unwrap();
+
+            // Setting a scratch value grows the scratch types to the new vertices
+            let mut vertex = transaction.new_vertex_index().unwrap();
+            for _ in 0..50 {
+                vertex = transaction.new_vertex_index().unwrap();
+            }
+            transaction
+                .set_scratch_vertex_value(&scratch_vertex_type, &vertex, 1u8)
+                .unwrap();
+        }
+
+        assert_eq!(number_of_scratch_types(&graph), (0, 0));
+    }
+
+    #[test]
+    fn access_scratch_values_of_vertices_beyond_scratch_capacity() {
+        let mut graph = Graph::with_initial_capacity(1, 1, 1).unwrap();
+        let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+        let scratch_vertex_type =
+            NewScratchVertexType::<u8>::new_scratch_vertex_type(&mut transaction).unwrap();
+        let scratch_edge_type =
+            NewScratchEdgeType::<u8>::new_scratch_edge_type(&mut transaction).unwrap();
+
+        let tail = transaction.new_vertex_index().unwrap();
+        let mut head = tail;
+        for _ in 0..50 {
+            head = transaction.new_vertex_index().unwrap();
+        }
+
+        assert_eq!(
+            GetScratchVertexValue::<u8>::scratch_vertex_value(
+                &transaction,
+                &scratch_vertex_type,
+                &head
+            )
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            GetScratchEdgeWeight::<u8>::scratch_edge_weight(
+                &transaction,
+                &scratch_edge_type,
+                &tail,
+                &head
+            )
+            .unwrap(),
+            None
+        );
+
+        transaction
+            .set_scratch_edge_weight(&scratch_edge_type, &tail, &head, 2u8)
+            .unwrap();
+        assert_eq!(
+            GetScratchEdgeWeight::<u8>::scratch_edge_weight(
+                &transaction,
+                &scratch_edge_type,
+                &tail,
+                &head
+            )
+            .unwrap(),
+            Some(2)
+        );
+    }
+}
@@ -24,7 +24,8 @@ where
         value: T,
     ) -> Result<(), GraphComputingError> {
         self.vertex_store_transaction
-            .set_vertex(vertex_type_index, vertex_index, value)
+            .set_vertex(vertex_type_index, vertex_index, value)?;
+        self.update_indexed_vertex_value(vertex_type_index, vertex_index)
     }
 }
 
@@ -1,10 +1,12 @@
+use std::mem;
+
 use crate::{
     error::GraphComputingError,
     graph::{
         indexing::{ElementCount, Queue, VecDequeQueue},
         value_type::ValueType,
     },
-    operators::transaction::RestoreState,
+    operators::{history::GetApproximateSizeInBytes, transaction::RestoreState},
 };
 
 #[derive(Debug, Clone)]
@@ -73,6 +75,12 @@ impl<T: ValueType> QueueStateReverter<T> {
     }
 }
 
+impl<T: ValueType> GetApproximateSizeInBytes for QueueStateReverter<T> {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        Ok(self.front_to_restore.length() * mem::size_of::<T>())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
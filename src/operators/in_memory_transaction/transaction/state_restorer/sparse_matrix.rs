@@ -10,6 +10,7 @@ use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
 
 use crate::error::GraphComputingError;
 use crate::graph::value_type::ValueType;
+use crate::operators::history::{sparse_matrix_size_in_bytes, GetApproximateSizeInBytes};
 use crate::operators::transaction::RestoreState;
 
 #[derive(Debug)]
@@ -182,6 +183,18 @@ impl<T: ValueType + SetSparseMatrixElementTyped<T>> RestoreState<SparseMatrix<T>
     }
 }
 
+impl<T: ValueType> GetApproximateSizeInBytes for SparseMatrixStateReverter<T> {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        let mut size_in_bytes = mem::size_of_val(self.state_to_restore.as_slice());
+        for state_to_restore in self.state_to_restore.iter() {
+            if let SparseMatrixStateToRestore::SparseMatrix(sparse_matrix) = state_to_restore {
+                size_in_bytes += sparse_matrix_size_in_bytes(sparse_matrix)?;
+            }
+        }
+        Ok(size_in_bytes)
+    }
+}
+
 pub(crate) fn restore_sparse_matrix_state<T: ValueType + SetSparseMatrixElementTyped<T>>(
     state_reverter: SparseMatrixStateReverter<T>,
     instance_to_restore: &mut impl GetGraphblasSparseMatrix,
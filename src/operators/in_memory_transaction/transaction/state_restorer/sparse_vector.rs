@@ -11,6 +11,7 @@ use graphblas_sparse_linear_algebra::collections::sparse_vector::{
 use crate::error::GraphComputingError;
 use crate::graph::indexing::{ElementCount, ElementIndex};
 use crate::graph::value_type::ValueType;
+use crate::operators::history::{sparse_vector_size_in_bytes, GetApproximateSizeInBytes};
 use crate::operators::transaction::RestoreState;
 
 #[derive(Debug, Clone)]
@@ -228,6 +229,18 @@ impl<T: ValueType> SparseVectorStateReverter<T> {
     // }
 }
 
+impl<T: ValueType> GetApproximateSizeInBytes for SparseVectorStateReverter<T> {
+    fn approximate_size_in_bytes(&self) -> Result<usize, GraphComputingError> {
+        let mut size_in_bytes = mem::size_of_val(self.state_to_restore.as_slice());
+        for state_to_restore in self.state_to_restore.iter() {
+            if let SparseVectorStateToRestore::SparseVector(sparse_vector) = state_to_restore {
+                size_in_bytes += sparse_vector_size_in_bytes(sparse_vector)?;
+            }
+        }
+        Ok(size_in_bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::{
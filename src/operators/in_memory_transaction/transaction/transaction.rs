@@ -2,14 +2,25 @@ use std::sync::Arc;
 
 use graphblas_sparse_linear_algebra::context::Context as GraphblasContext;
 
-use crate::error::GraphComputingError;
+use crate::error::{GraphComputingError, LogicError, LogicErrorType};
 use crate::graph::edge_store::operations::in_memory_transaction::InMemoryEdgeStoreTransaction;
 use crate::graph::graph::{
     GetGraphblasContext, GetGraphblasOperatorApplierCollection, Graph,
     GraphblasOperatorApplierCollection,
 };
-use crate::graph::vertex_store::operations::in_memory_transaction::transaction::InMemoryVertexStoreTransaction;
-use crate::operators::transaction::UseTransaction;
+use crate::graph::indexing::{
+    ElementIndexMap, GetVertexIndexIndex, GetVertexTypeIndex, ScratchGeneration,
+};
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::{
+    GetVertexStore, InMemoryVertexStoreTransaction,
+};
+use crate::operators::change_data_capture::GraphChangeSubscribers;
+use crate::operators::history::{GraphHistory, HistoryStep};
+use crate::operators::properties::{PropertyChange, PropertyStore};
+use crate::operators::transaction::{Savepoint, UseSavepoints, UseTransaction};
+use crate::operators::vertex_value_index::{
+    update_indexed_vertex_value, VertexValueIndexChange, VertexValueIndexes,
+};
 
 // pub struct Graph {
 //     pub(crate) graphblas_context: Arc<GraphblasContext>,
@@ -31,18 +42,127 @@ pub struct InMemoryGraphTransaction<'g> {
         InMemoryVertexStoreTransaction<'g>,
     pub(in crate::operators::in_memory_transaction) edge_store_transaction:
         InMemoryEdgeStoreTransaction<'g>,
+    // The private stores hold the scratch types of the transaction
+    pub(in crate::operators::in_memory_transaction) private_vertex_store_transaction:
+        InMemoryVertexStoreTransaction<'g>,
+    pub(in crate::operators::in_memory_transaction) private_edge_store_transaction:
+        InMemoryEdgeStoreTransaction<'g>,
+    savepoints: Vec<Savepoint>,
+    next_savepoint_id: usize,
+    change_subscribers: &'g GraphChangeSubscribers,
+    history: &'g mut GraphHistory,
+    pub(in crate::operators::in_memory_transaction) vertex_value_indexes:
+        &'g mut VertexValueIndexes,
+    // Reverted latest first
+    pub(in crate::operators::in_memory_transaction) vertex_value_index_changes:
+        Vec<VertexValueIndexChange>,
+    pub(in crate::operators::in_memory_transaction) property_store: &'g mut PropertyStore,
+    // Reverted latest first
+    pub(in crate::operators::in_memory_transaction) property_changes: Vec<PropertyChange>,
+    // The generation of each scratch type, to reject handles of dropped scratch types
+    pub(in crate::operators::in_memory_transaction) scratch_vertex_type_generations:
+        ElementIndexMap<ScratchGeneration>,
+    pub(in crate::operators::in_memory_transaction) scratch_edge_type_generations:
+        ElementIndexMap<ScratchGeneration>,
+    // Set by operations that add indices an indexer would not assign next
+    pub(in crate::operators::in_memory_transaction) changes_reassign_indices: bool,
 }
 
 impl<'g> UseTransaction for InMemoryGraphTransaction<'g> {
     fn revert(&mut self) -> Result<(), GraphComputingError> {
+        self.savepoints.clear();
         self.vertex_store_transaction.revert()?;
         self.edge_store_transaction.revert()?;
+        self.private_vertex_store_transaction.revert()?;
+        self.private_edge_store_transaction.revert()?;
+        self.revert_vertex_value_index_changes(0);
+        self.revert_property_changes(0);
+        self.scratch_vertex_type_generations.clear();
+        self.scratch_edge_type_generations.clear();
+        self.changes_reassign_indices = false;
         Ok(())
     }
 
     fn commit(&mut self) -> Result<(), GraphComputingError> {
+        self.savepoints.clear();
+        self.drop_all_scratch_types()?;
+        self.scratch_vertex_type_generations.clear();
+        self.scratch_edge_type_generations.clear();
+
+        // Changes to scratch types are private to the transaction
+        let mut changes = self.vertex_store_transaction.take_changes();
+        changes.append(&mut self.edge_store_transaction.take_changes());
+
+        let step_to_undo = if self.history.is_recording() && !changes.is_empty() {
+            Some(HistoryStep::StateRestorers {
+                vertex_store_state_restorers: self
+                    .vertex_store_transaction
+                    .take_state_restorers()?,
+                edge_store_state_restorers: self.edge_store_transaction.take_state_restorers()?,
+            })
+        } else {
+            None
+        };
+
         self.vertex_store_transaction.commit()?;
         self.edge_store_transaction.commit()?;
+        self.private_vertex_store_transaction.commit()?;
+        self.private_edge_store_transaction.commit()?;
+        self.vertex_value_index_changes.clear();
+        self.property_changes.clear();
+
+        if let Some(step_to_undo) = step_to_undo {
+            self.history.record(step_to_undo)?;
+        }
+        if self.changes_reassign_indices {
+            self.changes_reassign_indices = false;
+            self.change_subscribers
+                .publish_changes_that_reassign_indices(changes)
+        } else {
+            self.change_subscribers.publish(changes)
+        }
+    }
+}
+
+impl<'g> UseSavepoints for InMemoryGraphTransaction<'g> {
+    fn savepoint(&mut self) -> Result<Savepoint, GraphComputingError> {
+        let number_of_state_restorers = self.vertex_store_transaction.begin_savepoint()?;
+        self.edge_store_transaction.begin_savepoint()?;
+        self.private_vertex_store_transaction.begin_savepoint()?;
+        self.private_edge_store_transaction.begin_savepoint()?;
+
+        let savepoint = Savepoint::new(
+            self.next_savepoint_id,
+            number_of_state_restorers,
+            self.vertex_value_index_changes.len(),
+            self.property_changes.len(),
+        );
+        self.next_savepoint_id += 1;
+        self.savepoints.push(savepoint.clone());
+        Ok(savepoint)
+    }
+
+    fn rollback_to(&mut self, savepoint: &Savepoint) -> Result<(), GraphComputingError> {
+        let position = self.savepoint_position(savepoint)?;
+        self.savepoints.truncate(position + 1);
+
+        self.vertex_store_transaction
+            .rollback_to_savepoint(savepoint.number_of_state_restorers())?;
+        self.edge_store_transaction
+            .rollback_to_savepoint(savepoint.number_of_state_restorers())?;
+        self.private_vertex_store_transaction
+            .rollback_to_savepoint(savepoint.number_of_state_restorers())?;
+        self.private_edge_store_transaction
+            .rollback_to_savepoint(savepoint.number_of_state_restorers())?;
+        self.revert_vertex_value_index_changes(savepoint.number_of_vertex_value_index_changes());
+        self.revert_property_changes(savepoint.number_of_property_changes());
+        Ok(())
+    }
+
+    // The recorded changes stay partitioned until the transaction commits or reverts.
+    fn release(&mut self, savepoint: Savepoint) -> Result<(), GraphComputingError> {
+        let position = self.savepoint_position(&savepoint)?;
+        self.savepoints.truncate(position);
         Ok(())
     }
 }
@@ -51,11 +171,19 @@ impl<'t> InMemoryGraphTransaction<'t> {
     pub fn new(graph: &'t mut Graph) -> Result<Self, GraphComputingError> {
         let graphblas_context = graph.graphblas_context();
         let graphblas_operator_applier_collection = &graph.graphblas_operator_applier_collection;
+        let change_subscribers = &graph.change_subscribers;
+        let history = &mut graph.history;
+        let vertex_value_indexes = &mut graph.vertex_value_indexes;
+        let property_store = &mut graph.property_store;
 
         let vertex_store_transaction =
             InMemoryVertexStoreTransaction::new(&mut graph.public_vertex_store)?;
         let edge_store_transaction =
             InMemoryEdgeStoreTransaction::new(&mut graph.public_edge_store)?;
+        let private_vertex_store_transaction =
+            InMemoryVertexStoreTransaction::new(&mut graph.private_vertex_store)?;
+        let private_edge_store_transaction =
+            InMemoryEdgeStoreTransaction::new(&mut graph.private_edge_store)?;
 
         Ok(Self {
             graphblas_context,
@@ -63,8 +191,72 @@ impl<'t> InMemoryGraphTransaction<'t> {
             // graph,
             vertex_store_transaction,
             edge_store_transaction,
+            private_vertex_store_transaction,
+            private_edge_store_transaction,
+            savepoints: Vec::new(),
+            next_savepoint_id: 0,
+            change_subscribers,
+            history,
+            vertex_value_indexes,
+            vertex_value_index_changes: Vec::new(),
+            property_store,
+            property_changes: Vec::new(),
+            scratch_vertex_type_generations: ElementIndexMap::default(),
+            scratch_edge_type_generations: ElementIndexMap::default(),
+            changes_reassign_indices: false,
         })
     }
+
+    pub(in crate::operators::in_memory_transaction) fn update_indexed_vertex_value(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Result<(), GraphComputingError> {
+        if let Some(change) = update_indexed_vertex_value(
+            self.vertex_value_indexes,
+            self.vertex_store_transaction.vertex_store_ref(),
+            vertex_type_index,
+            vertex_index,
+        )? {
+            self.vertex_value_index_changes.push(change);
+        }
+        Ok(())
+    }
+
+    fn revert_vertex_value_index_changes(&mut self, number_of_changes_to_retain: usize) {
+        let changes_to_revert = self
+            .vertex_value_index_changes
+            .split_off(number_of_changes_to_retain);
+        for change in changes_to_revert.into_iter().rev() {
+            self.vertex_value_indexes.revert(change);
+        }
+    }
+
+    fn revert_property_changes(&mut self, number_of_changes_to_retain: usize) {
+        let changes_to_revert = self.property_changes.split_off(number_of_changes_to_retain);
+        for change in changes_to_revert.into_iter().rev() {
+            self.property_store.revert(change);
+        }
+    }
+
+    fn savepoint_position(&self, savepoint: &Savepoint) -> Result<usize, GraphComputingError> {
+        match self
+            .savepoints
+            .iter()
+            .position(|valid_savepoint| valid_savepoint.id() == savepoint.id())
+        {
+            Some(position) => Ok(position),
+            None => Err(LogicError::new(
+                LogicErrorType::Other,
+                format!(
+                    "Savepoint {} is not valid in this transaction",
+                    savepoint.id()
+                ),
+                None,
+            )
+            .into()),
+        }
+    }
 }
 
 // impl<'t> GetGraph for InMemoryGraphTransaction<'t> {
@@ -233,3 +425,208 @@ impl<'g> GetGraphblasOperatorApplierCollection for InMemoryGraphTransaction<'g>
 //         }
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::{EdgeTypeIndex, GetIndex, VertexIndex, VertexTypeIndex};
+    use crate::implement_user_defined_value_type;
+    use crate::operators::operators::delete::{DeleteEdge, DropVertexIndex};
+    use crate::operators::operators::new::{
+        NewEdge, NewEdgeType, NewVertex, NewVertexIndex, NewVertexType,
+    };
+    use crate::operators::operators::read::{
+        GetEdgeWeight, GetUserDefinedEdgeWeight, GetUserDefinedVertexValue, GetVertexValue,
+    };
+    use crate::operators::operators::set::SetVertexValue;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Measurement {
+        timestamp: u64,
+        score: f32,
+    }
+    implement_user_defined_value_type!(unsafe Measurement);
+
+    fn vertex_value(
+        transaction: &InMemoryGraphTransaction,
+        vertex_type: &VertexTypeIndex,
+        vertex: &VertexIndex,
+    ) -> Option<u8> {
+        GetVertexValue::<u8>::vertex_value(transaction, vertex_type, vertex).unwrap()
+    }
+
+    fn edge_weight(
+        transaction: &InMemoryGraphTransaction,
+        edge_type: &EdgeTypeIndex,
+        tail: &VertexIndex,
+        head: &VertexIndex,
+    ) -> Option<u8> {
+        GetEdgeWeight::<u8>::edge_weight(transaction, edge_type, tail, head).unwrap()
+    }
+
+    #[test]
+    fn rollback_to_savepoint() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex_index().unwrap();
+        let vertex_2 = graph.new_vertex_index().unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .set_vertex_value(&vertex_type, &vertex_1, 1)
+                .unwrap();
+
+            let savepoint = transaction.savepoint().unwrap();
+            transaction
+                .set_vertex_value(&vertex_type, &vertex_1, 2)
+                .unwrap();
+            transaction
+                .set_vertex_value(&vertex_type, &vertex_2, 3)
+                .unwrap();
+            transaction
+                .new_edge(&edge_type, &vertex_1, &vertex_2, 4)
+                .unwrap();
+
+            let later_savepoint = transaction.savepoint().unwrap();
+            transaction
+                .set_vertex_value(&vertex_type, &vertex_2, 5)
+                .unwrap();
+
+            transaction.rollback_to(&savepoint).unwrap();
+            assert_eq!(vertex_value(&transaction, &vertex_type, &vertex_1), Some(1));
+            assert_eq!(vertex_value(&transaction, &vertex_type, &vertex_2), None);
+            assert_eq!(
+                edge_weight(&transaction, &edge_type, &vertex_1, &vertex_2),
+                None
+            );
+            assert!(transaction.rollback_to(&later_savepoint).is_err());
+
+            transaction
+                .set_vertex_value(&vertex_type, &vertex_2, 6)
+                .unwrap();
+            transaction.rollback_to(&savepoint).unwrap();
+            assert_eq!(vertex_value(&transaction, &vertex_type, &vertex_2), None);
+
+            transaction.commit().unwrap();
+        }
+
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&graph, &vertex_type, &vertex_1).unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&graph, &vertex_type, &vertex_2).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn released_savepoint_is_reverted_with_transaction() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex_index().unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            let savepoint = transaction.savepoint().unwrap();
+            transaction
+                .set_vertex_value(&vertex_type, &vertex, 1)
+                .unwrap();
+
+            transaction.release(savepoint.clone()).unwrap();
+            assert!(transaction.rollback_to(&savepoint).is_err());
+            assert_eq!(vertex_value(&transaction, &vertex_type, &vertex), Some(1));
+        }
+
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&graph, &vertex_type, &vertex).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn revert_reused_vertex_index() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex(&vertex_type, 1u8).unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .drop_vertex_index_and_connected_edges(&vertex)
+                .unwrap();
+            let reused_vertex = transaction.new_vertex(&vertex_type, 2u8).unwrap();
+            assert_eq!(reused_vertex.index(), vertex.index());
+            assert!(
+                GetVertexValue::<u8>::vertex_value(&transaction, &vertex_type, &vertex).is_err()
+            );
+        }
+
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(&graph, &vertex_type, &vertex).unwrap(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn revert_user_defined_values() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<Measurement>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<Measurement>::apply(&mut graph).unwrap();
+
+        let measurement = Measurement {
+            timestamp: 1,
+            score: 0.5,
+        };
+        let weight = Measurement {
+            timestamp: 2,
+            score: 1.5,
+        };
+        let vertex_1 = graph.new_vertex(&vertex_type, measurement).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type, measurement).unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_1, &vertex_2, weight)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_2, &vertex_1, weight)
+            .unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .delete_edge(&edge_type, &vertex_2, &vertex_1)
+                .unwrap();
+            transaction
+                .drop_vertex_index_and_connected_edges(&vertex_1)
+                .unwrap();
+        }
+
+        assert_eq!(
+            GetUserDefinedVertexValue::<Measurement>::user_defined_vertex_value(
+                &graph,
+                &vertex_type,
+                &vertex_1
+            )
+            .unwrap(),
+            Some(measurement)
+        );
+        assert_eq!(
+            GetUserDefinedEdgeWeight::<Measurement>::user_defined_edge_weight(
+                &graph, &edge_type, &vertex_1, &vertex_2
+            )
+            .unwrap(),
+            Some(weight)
+        );
+        assert_eq!(
+            GetUserDefinedEdgeWeight::<Measurement>::user_defined_edge_weight(
+                &graph, &edge_type, &vertex_2, &vertex_1
+            )
+            .unwrap(),
+            Some(weight)
+        );
+    }
+}
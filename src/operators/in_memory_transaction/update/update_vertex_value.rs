@@ -20,7 +20,8 @@ where
         value: T,
     ) -> Result<(), GraphComputingError> {
         self.vertex_store_transaction
-            .update_vertex(vertex_type_index, vertex_index, value)
+            .update_vertex(vertex_type_index, vertex_index, value)?;
+        self.update_indexed_vertex_value(vertex_type_index, vertex_index)
     }
 }
 
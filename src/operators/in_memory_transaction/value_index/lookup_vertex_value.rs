@@ -0,0 +1,67 @@
+use std::ops::RangeBounds;
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+
+use crate::error::GraphComputingError;
+use crate::graph::indexing::{GetVertexTypeIndex, VertexIndex};
+use crate::graph::value_type::{GetValueTypeIdentifier, ValueType};
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::GetVertexStore;
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::value_index::LookupVertexValue;
+use crate::operators::vertex_value_index::{
+    indexed_vertex_indices_in_range, vertex_indices_with_generation, vertex_mask, IntoValueIndexKey,
+};
+
+impl<'g, T> LookupVertexValue<T> for InMemoryGraphTransaction<'g>
+where
+    T: ValueType + GetValueTypeIdentifier + IntoValueIndexKey + Copy,
+{
+    fn vertex_indices_with_value(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        value: T,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError> {
+        self.vertex_indices_with_value_in_range(vertex_type_index, value..=value)
+    }
+
+    fn vertex_indices_with_value_in_range(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        range: impl RangeBounds<T>,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError> {
+        let vertex_store = self.vertex_store_transaction.vertex_store_ref();
+        let indices = indexed_vertex_indices_in_range(
+            &*self.vertex_value_indexes,
+            vertex_store,
+            vertex_type_index,
+            range,
+        )?;
+        Ok(vertex_indices_with_generation(vertex_store, indices))
+    }
+
+    fn vertex_mask_with_value(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        value: T,
+    ) -> Result<SparseVector<bool>, GraphComputingError> {
+        self.vertex_mask_with_value_in_range(vertex_type_index, value..=value)
+    }
+
+    fn vertex_mask_with_value_in_range(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        range: impl RangeBounds<T>,
+    ) -> Result<SparseVector<bool>, GraphComputingError> {
+        let vertex_store = self.vertex_store_transaction.vertex_store_ref();
+        let indices = indexed_vertex_indices_in_range(
+            &*self.vertex_value_indexes,
+            vertex_store,
+            vertex_type_index,
+            range,
+        )?;
+        vertex_mask(vertex_store, vertex_type_index, indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {}
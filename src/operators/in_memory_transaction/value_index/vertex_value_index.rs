@@ -0,0 +1,119 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::GetVertexTypeIndex;
+use crate::graph::vertex_store::operations::in_memory_transaction::transaction::GetVertexStore;
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::value_index::VertexValueIndexing;
+use crate::operators::vertex_value_index::build_vertex_value_index;
+
+impl<'g> VertexValueIndexing for InMemoryGraphTransaction<'g> {
+    fn create_vertex_value_index(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        let index = build_vertex_value_index(
+            self.vertex_store_transaction.vertex_store_ref(),
+            vertex_type_index,
+        )?;
+        let change = self
+            .vertex_value_indexes
+            .replace_index(vertex_type_index, Some(index));
+        self.vertex_value_index_changes.push(change);
+        Ok(())
+    }
+
+    fn drop_vertex_value_index(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.vertex_store_transaction
+            .try_vertex_type_index_validity(vertex_type_index)?;
+        let change = self
+            .vertex_value_indexes
+            .replace_index(vertex_type_index, None);
+        self.vertex_value_index_changes.push(change);
+        Ok(())
+    }
+
+    fn has_vertex_value_index(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<bool, GraphComputingError> {
+        self.vertex_store_transaction
+            .try_vertex_type_index_validity(vertex_type_index)?;
+        Ok(self.vertex_value_indexes.is_indexed(vertex_type_index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::graph::Graph;
+    use crate::operators::operators::delete::DeleteVertexValue;
+    use crate::operators::operators::drop::DropVertexType;
+    use crate::operators::operators::new::{NewVertex, NewVertexType};
+    use crate::operators::operators::update::UpdateVertexValue;
+    use crate::operators::operators::value_index::LookupVertexValue;
+    use crate::operators::transaction::{UseSavepoints, UseTransaction};
+
+    #[test]
+    fn vertex_value_index_is_consistent_after_revert() {
+        let mut graph = Graph::with_initial_capacity(1, 10, 1).unwrap();
+        let vertex_type = NewVertexType::<u16>::apply(&mut graph).unwrap();
+        let vertex_1 = graph.new_vertex(&vertex_type, 1u16).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type, 2u16).unwrap();
+        graph.create_vertex_value_index(&vertex_type).unwrap();
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .update_vertex_value(&vertex_type, &vertex_1, 2u16)
+                .unwrap();
+            let savepoint = transaction.savepoint().unwrap();
+            transaction
+                .delete_vertex_value(&vertex_type, &vertex_2)
+                .unwrap();
+            let vertex_3 = transaction.new_vertex(&vertex_type, 2u16).unwrap();
+            assert_eq!(
+                transaction
+                    .vertex_indices_with_value(&vertex_type, 2u16)
+                    .unwrap(),
+                vec![vertex_1, vertex_3]
+            );
+
+            transaction.rollback_to(&savepoint).unwrap();
+            assert_eq!(
+                transaction
+                    .vertex_indices_with_value(&vertex_type, 2u16)
+                    .unwrap(),
+                vec![vertex_1, vertex_2]
+            );
+
+            transaction.drop_vertex_type(&vertex_type).unwrap();
+            transaction.revert().unwrap();
+        }
+
+        assert!(graph.has_vertex_value_index(&vertex_type).unwrap());
+        assert_eq!(
+            graph
+                .vertex_indices_with_value_in_range(&vertex_type, ..)
+                .unwrap(),
+            vec![vertex_1, vertex_2]
+        );
+
+        {
+            let mut transaction = InMemoryGraphTransaction::new(&mut graph).unwrap();
+            transaction
+                .update_vertex_value(&vertex_type, &vertex_1, 3u16)
+                .unwrap();
+            transaction.commit().unwrap();
+        }
+        assert_eq!(
+            graph
+                .vertex_indices_with_value_in_range(&vertex_type, 2u16..)
+                .unwrap(),
+            vec![vertex_2, vertex_1]
+        );
+    }
+}
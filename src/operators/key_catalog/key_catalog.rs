@@ -0,0 +1,357 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::mem;
+
+use crate::error::{GraphComputingError, LogicError, LogicErrorType};
+use crate::graph::graph::VertexIndexRemapping;
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex, Index, VertexIndex,
+    VertexTypeIndex,
+};
+
+/// Maps user keys to vertex indices, vertex type indices and edge type indices.
+/// Each key and each index has at most one mapping.
+#[derive(Clone, Debug)]
+pub struct KeyCatalog<K> {
+    vertices: IndexKeyMap<K>,
+    vertex_types: IndexKeyMap<K>,
+    edge_types: IndexKeyMap<K>,
+}
+
+#[derive(Clone, Debug)]
+struct IndexKeyMap<K> {
+    indices: HashMap<K, Index>,
+    keys: HashMap<Index, K>,
+}
+
+impl<K: Hash + Eq + Clone + Debug> KeyCatalog<K> {
+    pub fn new() -> Self {
+        Self {
+            vertices: IndexKeyMap::new(),
+            vertex_types: IndexKeyMap::new(),
+            edge_types: IndexKeyMap::new(),
+        }
+    }
+
+    pub fn vertex_index<Q>(&self, key: &Q) -> Option<VertexIndex>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        self.vertices.index(key).map(VertexIndex::new)
+    }
+
+    pub fn try_vertex_index<Q>(&self, key: &Q) -> Result<VertexIndex, GraphComputingError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        self.vertices.try_index(key, "vertex").map(VertexIndex::new)
+    }
+
+    pub fn vertex_key(&self, vertex: &impl GetVertexIndexIndex) -> Option<&K> {
+        self.vertices.key(vertex.index())
+    }
+
+    pub fn vertex_type_index<Q>(&self, key: &Q) -> Option<VertexTypeIndex>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        self.vertex_types.index(key).map(VertexTypeIndex::new)
+    }
+
+    pub fn try_vertex_type_index<Q>(&self, key: &Q) -> Result<VertexTypeIndex, GraphComputingError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        self.vertex_types
+            .try_index(key, "vertex type")
+            .map(VertexTypeIndex::new)
+    }
+
+    pub fn vertex_type_key(&self, vertex_type: &impl GetVertexTypeIndex) -> Option<&K> {
+        self.vertex_types.key(vertex_type.index())
+    }
+
+    pub fn edge_type_index<Q>(&self, key: &Q) -> Option<EdgeTypeIndex>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        self.edge_types.index(key).map(EdgeTypeIndex::new)
+    }
+
+    pub fn try_edge_type_index<Q>(&self, key: &Q) -> Result<EdgeTypeIndex, GraphComputingError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        self.edge_types
+            .try_index(key, "edge type")
+            .map(EdgeTypeIndex::new)
+    }
+
+    pub fn edge_type_key(&self, edge_type: &impl GetEdgeTypeIndex) -> Option<&K> {
+        self.edge_types.key(edge_type.index())
+    }
+
+    pub(crate) fn try_vertex_key_availability(
+        &self,
+        key: &K,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.vertices
+            .try_availability(key, vertex.index(), "vertex")
+    }
+
+    pub(crate) fn try_vertex_type_key_availability(
+        &self,
+        key: &K,
+        vertex_type: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.vertex_types
+            .try_availability(key, vertex_type.index(), "vertex type")
+    }
+
+    pub(crate) fn try_edge_type_key_availability(
+        &self,
+        key: &K,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.edge_types
+            .try_availability(key, edge_type.index(), "edge type")
+    }
+
+    pub(crate) fn try_new_key_availability(
+        &self,
+        key: &K,
+        kind: KeyedIndexKind,
+    ) -> Result<(), GraphComputingError> {
+        let (index_key_map, description) = match kind {
+            KeyedIndexKind::Vertex => (&self.vertices, "vertex"),
+            KeyedIndexKind::VertexType => (&self.vertex_types, "vertex type"),
+            KeyedIndexKind::EdgeType => (&self.edge_types, "edge type"),
+        };
+        index_key_map.try_key_availability(key, description)
+    }
+
+    /// The caller must check availability first.
+    pub(crate) fn insert_vertex_key_unchecked(
+        &mut self,
+        key: K,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> KeyCatalogChange<K> {
+        self.insert_unchecked(KeyedIndexKind::Vertex, key, vertex.index())
+    }
+
+    pub(crate) fn insert_vertex_type_key_unchecked(
+        &mut self,
+        key: K,
+        vertex_type: &impl GetVertexTypeIndex,
+    ) -> KeyCatalogChange<K> {
+        self.insert_unchecked(KeyedIndexKind::VertexType, key, vertex_type.index())
+    }
+
+    pub(crate) fn insert_edge_type_key_unchecked(
+        &mut self,
+        key: K,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> KeyCatalogChange<K> {
+        self.insert_unchecked(KeyedIndexKind::EdgeType, key, edge_type.index())
+    }
+
+    /// None if the vertex has no key.
+    pub(crate) fn remove_vertex_key(
+        &mut self,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> Option<KeyCatalogChange<K>> {
+        self.remove(KeyedIndexKind::Vertex, vertex.index())
+    }
+
+    pub(crate) fn remove_vertex_type_key(
+        &mut self,
+        vertex_type: &impl GetVertexTypeIndex,
+    ) -> Option<KeyCatalogChange<K>> {
+        self.remove(KeyedIndexKind::VertexType, vertex_type.index())
+    }
+
+    pub(crate) fn remove_edge_type_key(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Option<KeyCatalogChange<K>> {
+        self.remove(KeyedIndexKind::EdgeType, edge_type.index())
+    }
+
+    /// Moves the vertex keys to the vertex indices after a compaction.
+    /// Keys of vertex indices that were not valid at the compaction are removed.
+    pub(crate) fn apply_vertex_index_remapping(
+        &mut self,
+        vertex_index_remapping: &VertexIndexRemapping,
+    ) {
+        self.vertices.remap(|index| {
+            vertex_index_remapping
+                .new_vertex_index(&VertexIndex::new(index))
+                .map(|vertex| vertex.index())
+        });
+    }
+
+    pub(crate) fn revert(&mut self, change: KeyCatalogChange<K>) {
+        match change {
+            KeyCatalogChange::Inserted { kind, index } => {
+                self.index_key_map_mut(kind).remove(index);
+            }
+            KeyCatalogChange::Removed { kind, key, index } => {
+                self.index_key_map_mut(kind).insert(key, index);
+            }
+        }
+    }
+
+    fn insert_unchecked(
+        &mut self,
+        kind: KeyedIndexKind,
+        key: K,
+        index: Index,
+    ) -> KeyCatalogChange<K> {
+        self.index_key_map_mut(kind).insert(key, index);
+        KeyCatalogChange::Inserted { kind, index }
+    }
+
+    fn remove(&mut self, kind: KeyedIndexKind, index: Index) -> Option<KeyCatalogChange<K>> {
+        let key = self.index_key_map_mut(kind).remove(index)?;
+        Some(KeyCatalogChange::Removed { kind, key, index })
+    }
+
+    fn index_key_map_mut(&mut self, kind: KeyedIndexKind) -> &mut IndexKeyMap<K> {
+        match kind {
+            KeyedIndexKind::Vertex => &mut self.vertices,
+            KeyedIndexKind::VertexType => &mut self.vertex_types,
+            KeyedIndexKind::EdgeType => &mut self.edge_types,
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone + Debug> Default for KeyCatalog<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum KeyedIndexKind {
+    Vertex,
+    VertexType,
+    EdgeType,
+}
+
+/// Reverts a change to the key catalog in a transaction.
+#[derive(Debug)]
+pub(crate) enum KeyCatalogChange<K> {
+    Inserted {
+        kind: KeyedIndexKind,
+        index: Index,
+    },
+    Removed {
+        kind: KeyedIndexKind,
+        key: K,
+        index: Index,
+    },
+}
+
+impl<K: Hash + Eq + Clone + Debug> IndexKeyMap<K> {
+    fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            keys: HashMap::new(),
+        }
+    }
+
+    fn index<Q>(&self, key: &Q) -> Option<Index>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.get(key).copied()
+    }
+
+    fn try_index<Q>(&self, key: &Q, description: &str) -> Result<Index, GraphComputingError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        match self.index(key) {
+            Some(index) => Ok(index),
+            None => Err(LogicError::new(
+                LogicErrorType::InvalidKey,
+                format!("No {} exists for key {:?}", description, key),
+                None,
+            )
+            .into()),
+        }
+    }
+
+    fn key(&self, index: Index) -> Option<&K> {
+        self.keys.get(&index)
+    }
+
+    fn try_key_availability(&self, key: &K, description: &str) -> Result<(), GraphComputingError> {
+        match self.indices.get(key) {
+            Some(index) => Err(LogicError::new(
+                LogicErrorType::KeyAlreadyExists,
+                format!(
+                    "Key {:?} already refers to {} index {}",
+                    key, description, index
+                ),
+                None,
+            )
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    fn try_availability(
+        &self,
+        key: &K,
+        index: Index,
+        description: &str,
+    ) -> Result<(), GraphComputingError> {
+        self.try_key_availability(key, description)?;
+        match self.keys.get(&index) {
+            Some(existing_key) => Err(LogicError::new(
+                LogicErrorType::KeyAlreadyExists,
+                format!(
+                    "The {} with index {} already has key {:?}",
+                    description, index, existing_key
+                ),
+                None,
+            )
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    fn insert(&mut self, key: K, index: Index) {
+        if let Some(previous_key) = self.keys.insert(index, key.clone()) {
+            self.indices.remove(&previous_key);
+        }
+        self.indices.insert(key, index);
+    }
+
+    fn remove(&mut self, index: Index) -> Option<K> {
+        let key = self.keys.remove(&index)?;
+        self.indices.remove(&key);
+        Some(key)
+    }
+
+    fn remap(&mut self, new_index: impl Fn(Index) -> Option<Index>) {
+        self.indices.clear();
+        for (index, key) in mem::take(&mut self.keys) {
+            if let Some(new_index) = new_index(index) {
+                self.insert(key, new_index);
+            }
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::Graph;
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex, VertexIndex,
+    VertexTypeIndex,
+};
+use crate::graph::value_type::ValueType;
+use crate::operators::operators::indexing::CheckIndex;
+use crate::operators::operators::new::{NewEdgeType, NewVertex, NewVertexIndex, NewVertexType};
+
+use super::{KeyCatalog, KeyCatalogChange, KeyedGraphTransaction, KeyedIndexKind};
+
+/// Wraps a graph, such as a Graph or DurableGraph, with an opt-in catalog of user keys.
+/// Dropping a vertex index, vertex type or edge type through the KeyedGraph also removes its key.
+/// Compacting through the KeyedGraph moves the vertex keys to the new vertex indices.
+#[derive(Clone, Debug)]
+pub struct KeyedGraph<G, K = String> {
+    graph: G,
+    key_catalog: KeyCatalog<K>,
+}
+
+impl<G, K: Hash + Eq + Clone + Debug> KeyedGraph<G, K> {
+    pub fn new(graph: G) -> Self {
+        Self {
+            graph,
+            key_catalog: KeyCatalog::new(),
+        }
+    }
+
+    pub fn graph_ref(&self) -> &G {
+        &self.graph
+    }
+
+    /// Indices dropped through the graph itself are not removed from the key catalog.
+    pub fn graph_mut_ref(&mut self) -> &mut G {
+        &mut self.graph
+    }
+
+    pub fn key_catalog_ref(&self) -> &KeyCatalog<K> {
+        &self.key_catalog
+    }
+
+    pub fn into_parts(self) -> (G, KeyCatalog<K>) {
+        (self.graph, self.key_catalog)
+    }
+
+    pub(crate) fn key_catalog_mut_ref(&mut self) -> &mut KeyCatalog<K> {
+        &mut self.key_catalog
+    }
+
+    pub fn new_vertex_index_with_key(&mut self, key: K) -> Result<VertexIndex, GraphComputingError>
+    where
+        G: NewVertexIndex,
+    {
+        new_vertex_index_with_key(&mut self.graph, &mut self.key_catalog, key)
+            .map(|(vertex, _)| vertex)
+    }
+
+    pub fn new_vertex_with_key<T: ValueType>(
+        &mut self,
+        key: K,
+        vertex_type: &impl GetVertexTypeIndex,
+        value: T,
+    ) -> Result<VertexIndex, GraphComputingError>
+    where
+        G: NewVertex<T>,
+    {
+        new_vertex_with_key(
+            &mut self.graph,
+            &mut self.key_catalog,
+            key,
+            vertex_type,
+            value,
+        )
+        .map(|(vertex, _)| vertex)
+    }
+
+    pub fn new_vertex_type_with_key<T: ValueType>(
+        &mut self,
+        key: K,
+    ) -> Result<VertexTypeIndex, GraphComputingError>
+    where
+        G: NewVertexType<T>,
+    {
+        new_vertex_type_with_key::<G, K, T>(&mut self.graph, &mut self.key_catalog, key)
+            .map(|(vertex_type, _)| vertex_type)
+    }
+
+    pub fn new_edge_type_with_key<T: ValueType>(
+        &mut self,
+        key: K,
+    ) -> Result<EdgeTypeIndex, GraphComputingError>
+    where
+        G: NewEdgeType<T>,
+    {
+        new_edge_type_with_key::<G, K, T>(&mut self.graph, &mut self.key_catalog, key)
+            .map(|(edge_type, _)| edge_type)
+    }
+
+    /// Assigns a key to an existing vertex index without a key.
+    pub fn set_vertex_key(
+        &mut self,
+        key: K,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> Result<(), GraphComputingError>
+    where
+        G: CheckIndex,
+    {
+        set_vertex_key(&self.graph, &mut self.key_catalog, key, vertex)?;
+        Ok(())
+    }
+
+    pub fn set_vertex_type_key(
+        &mut self,
+        key: K,
+        vertex_type: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>
+    where
+        G: CheckIndex,
+    {
+        set_vertex_type_key(&self.graph, &mut self.key_catalog, key, vertex_type)?;
+        Ok(())
+    }
+
+    pub fn set_edge_type_key(
+        &mut self,
+        key: K,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Result<(), GraphComputingError>
+    where
+        G: CheckIndex,
+    {
+        set_edge_type_key(&self.graph, &mut self.key_catalog, key, edge_type)?;
+        Ok(())
+    }
+}
+
+impl<K: Hash + Eq + Clone + Debug> KeyedGraph<Graph, K> {
+    /// Starts a transaction whose revert also reverts the changes to the key catalog.
+    pub fn transaction(&mut self) -> Result<KeyedGraphTransaction<'_, K>, GraphComputingError> {
+        KeyedGraphTransaction::new(&mut self.graph, &mut self.key_catalog)
+    }
+}
+
+// The functions below return the change to the key catalog, so that a transaction can revert it.
+
+pub(super) fn new_vertex_index_with_key<G: NewVertexIndex, K: Hash + Eq + Clone + Debug>(
+    graph: &mut G,
+    key_catalog: &mut KeyCatalog<K>,
+    key: K,
+) -> Result<(VertexIndex, KeyCatalogChange<K>), GraphComputingError> {
+    key_catalog.try_new_key_availability(&key, KeyedIndexKind::Vertex)?;
+    let vertex = graph.new_vertex_index()?;
+    let change = key_catalog.insert_vertex_key_unchecked(key, &vertex);
+    Ok((vertex, change))
+}
+
+pub(super) fn new_vertex_with_key<G, K, T>(
+    graph: &mut G,
+    key_catalog: &mut KeyCatalog<K>,
+    key: K,
+    vertex_type: &impl GetVertexTypeIndex,
+    value: T,
+) -> Result<(VertexIndex, KeyCatalogChange<K>), GraphComputingError>
+where
+    G: NewVertex<T>,
+    K: Hash + Eq + Clone + Debug,
+    T: ValueType,
+{
+    key_catalog.try_new_key_availability(&key, KeyedIndexKind::Vertex)?;
+    let vertex = graph.new_vertex(vertex_type, value)?;
+    let change = key_catalog.insert_vertex_key_unchecked(key, &vertex);
+    Ok((vertex, change))
+}
+
+pub(super) fn new_vertex_type_with_key<G, K, T>(
+    graph: &mut G,
+    key_catalog: &mut KeyCatalog<K>,
+    key: K,
+) -> Result<(VertexTypeIndex, KeyCatalogChange<K>), GraphComputingError>
+where
+    G: NewVertexType<T>,
+    K: Hash + Eq + Clone + Debug,
+    T: ValueType,
+{
+    key_catalog.try_new_key_availability(&key, KeyedIndexKind::VertexType)?;
+    let vertex_type = NewVertexType::<T>::apply(graph)?;
+    let change = key_catalog.insert_vertex_type_key_unchecked(key, &vertex_type);
+    Ok((vertex_type, change))
+}
+
+pub(super) fn new_edge_type_with_key<G, K, T>(
+    graph: &mut G,
+    key_catalog: &mut KeyCatalog<K>,
+    key: K,
+) -> Result<(EdgeTypeIndex, KeyCatalogChange<K>), GraphComputingError>
+where
+    G: NewEdgeType<T>,
+    K: Hash + Eq + Clone + Debug,
+    T: ValueType,
+{
+    key_catalog.try_new_key_availability(&key, KeyedIndexKind::EdgeType)?;
+    let edge_type = NewEdgeType::<T>::apply(graph)?;
+    let change = key_catalog.insert_edge_type_key_unchecked(key, &edge_type);
+    Ok((edge_type, change))
+}
+
+pub(super) fn set_vertex_key<G: CheckIndex, K: Hash + Eq + Clone + Debug>(
+    graph: &G,
+    key_catalog: &mut KeyCatalog<K>,
+    key: K,
+    vertex: &impl GetVertexIndexIndex,
+) -> Result<KeyCatalogChange<K>, GraphComputingError> {
+    graph.try_vertex_index_validity(vertex)?;
+    key_catalog.try_vertex_key_availability(&key, vertex)?;
+    Ok(key_catalog.insert_vertex_key_unchecked(key, vertex))
+}
+
+pub(super) fn set_vertex_type_key<G: CheckIndex, K: Hash + Eq + Clone + Debug>(
+    graph: &G,
+    key_catalog: &mut KeyCatalog<K>,
+    key: K,
+    vertex_type: &impl GetVertexTypeIndex,
+) -> Result<KeyCatalogChange<K>, GraphComputingError> {
+    graph.try_vertex_type_index_validity(vertex_type)?;
+    key_catalog.try_vertex_type_key_availability(&key, vertex_type)?;
+    Ok(key_catalog.insert_vertex_type_key_unchecked(key, vertex_type))
+}
+
+pub(super) fn set_edge_type_key<G: CheckIndex, K: Hash + Eq + Clone + Debug>(
+    graph: &G,
+    key_catalog: &mut KeyCatalog<K>,
+    key: K,
+    edge_type: &impl GetEdgeTypeIndex,
+) -> Result<KeyCatalogChange<K>, GraphComputingError> {
+    graph.try_edge_type_index_validity(edge_type)?;
+    key_catalog.try_edge_type_key_availability(&key, edge_type)?;
+    Ok(key_catalog.insert_edge_type_key_unchecked(key, edge_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::graph::Compact;
+    use crate::operators::operators::delete::DropVertexIndex;
+    use crate::operators::operators::drop::{DropEdgeType, DropVertexType};
+    use crate::operators::operators::new::NewEdge;
+    use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+
+    #[test]
+    fn look_up_keys() {
+        let mut graph = KeyedGraph::new(Graph::with_initial_capacity(5, 5, 5).unwrap());
+
+        let number = graph
+            .new_vertex_type_with_key::<u8>(String::from("number"))
+            .unwrap();
+        let smaller_than = graph
+            .new_edge_type_with_key::<bool>(String::from("smaller_than"))
+            .unwrap();
+        let one = graph
+            .new_vertex_with_key(String::from("1"), &number, 1u8)
+            .unwrap();
+        let two = graph
+            .new_vertex_with_key(String::from("2"), &number, 2u8)
+            .unwrap();
+        graph.new_edge(&smaller_than, &one, &two, true).unwrap();
+
+        let key_catalog = graph.key_catalog_ref();
+
+        assert_eq!(key_catalog.try_vertex_index("1").unwrap(), one);
+        assert_eq!(
+            key_catalog.try_edge_type_index("smaller_than").unwrap(),
+            smaller_than
+        );
+        assert_eq!(key_catalog.vertex_key(&two).unwrap(), "2");
+        assert_eq!(
+            GetEdgeWeight::<bool>::edge_weight(
+                graph.graph_ref(),
+                &key_catalog.try_edge_type_index("smaller_than").unwrap(),
+                &key_catalog.try_vertex_index("1").unwrap(),
+                &key_catalog.try_vertex_index("2").unwrap()
+            )
+            .unwrap(),
+            Some(true)
+        );
+        assert!(key_catalog.vertex_index("3").is_none());
+    }
+
+    #[test]
+    fn reject_duplicate_key() {
+        let mut graph = KeyedGraph::new(Graph::with_initial_capacity(5, 5, 5).unwrap());
+
+        let vertex_type = graph
+            .new_vertex_type_with_key::<u8>(String::from("number"))
+            .unwrap();
+        graph
+            .new_vertex_with_key(String::from("one"), &vertex_type, 1u8)
+            .unwrap();
+
+        assert!(graph
+            .new_vertex_with_key(String::from("one"), &vertex_type, 2u8)
+            .is_err());
+        assert!(graph
+            .new_vertex_type_with_key::<u16>(String::from("number"))
+            .is_err());
+    }
+
+    #[test]
+    fn remove_keys_of_dropped_indices() {
+        let mut graph = KeyedGraph::new(Graph::with_initial_capacity(5, 5, 5).unwrap());
+
+        let vertex_type = graph
+            .new_vertex_type_with_key::<u8>(String::from("number"))
+            .unwrap();
+        let edge_type = graph
+            .new_edge_type_with_key::<u8>(String::from("successor"))
+            .unwrap();
+        let one = graph
+            .new_vertex_with_key(String::from("one"), &vertex_type, 1u8)
+            .unwrap();
+        let two = graph
+            .new_vertex_with_key(String::from("two"), &vertex_type, 2u8)
+            .unwrap();
+        graph.new_edge(&edge_type, &one, &two, 1u8).unwrap();
+
+        graph.drop_vertex_index_and_connected_edges(&one).unwrap();
+        graph.drop_edge_type(&edge_type).unwrap();
+        graph.drop_vertex_type(&vertex_type).unwrap();
+
+        assert!(graph.key_catalog_ref().vertex_index("one").is_none());
+        assert!(graph.key_catalog_ref().vertex_key(&one).is_none());
+        assert!(graph
+            .key_catalog_ref()
+            .edge_type_index("successor")
+            .is_none());
+        assert!(graph
+            .key_catalog_ref()
+            .vertex_type_index("number")
+            .is_none());
+        assert_eq!(graph.key_catalog_ref().vertex_index("two"), Some(two));
+
+        // The freed index may be reused for a new key
+        let three = graph
+            .new_vertex_index_with_key(String::from("three"))
+            .unwrap();
+        assert_eq!(graph.key_catalog_ref().vertex_key(&three).unwrap(), "three");
+    }
+
+    #[test]
+    fn compact_moves_vertex_keys() {
+        let mut graph = KeyedGraph::new(Graph::with_initial_capacity(5, 5, 5).unwrap());
+
+        let vertex_type = graph
+            .new_vertex_type_with_key::<u8>(String::from("number"))
+            .unwrap();
+        let zero = graph
+            .new_vertex_with_key(String::from("zero"), &vertex_type, 0u8)
+            .unwrap();
+        let one = graph
+            .new_vertex_with_key(String::from("one"), &vertex_type, 1u8)
+            .unwrap();
+        let two = graph
+            .new_vertex_with_key(String::from("two"), &vertex_type, 2u8)
+            .unwrap();
+        graph.drop_vertex_index_and_connected_edges(&zero).unwrap();
+
+        let remapping = graph.compact().unwrap();
+
+        let key_catalog = graph.key_catalog_ref();
+        for (key, vertex, value) in [("one", one, 1u8), ("two", two, 2u8)] {
+            let new_vertex = key_catalog.try_vertex_index(key).unwrap();
+            assert_eq!(
+                new_vertex.index(),
+                remapping.new_vertex_index(&vertex).unwrap().index()
+            );
+            assert_eq!(key_catalog.vertex_key(&new_vertex).unwrap(), key);
+            assert_eq!(
+                GetVertexValue::<u8>::vertex_value(graph.graph_ref(), &vertex_type, &new_vertex)
+                    .unwrap(),
+                Some(value)
+            );
+        }
+        assert_eq!(key_catalog.try_vertex_index("one").unwrap().index(), 0);
+        assert!(key_catalog.vertex_index("zero").is_none());
+    }
+}
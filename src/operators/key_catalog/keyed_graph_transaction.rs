@@ -0,0 +1,309 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::Graph;
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex, VertexIndex,
+    VertexTypeIndex,
+};
+use crate::graph::value_type::ValueType;
+use crate::operators::in_memory_transaction::transaction::InMemoryGraphTransaction;
+use crate::operators::operators::delete::DropVertexIndex;
+use crate::operators::operators::drop::{DropEdgeType, DropVertexType};
+use crate::operators::operators::new::{NewEdgeType, NewVertex, NewVertexType};
+use crate::operators::transaction::{Savepoint, UseSavepoints, UseTransaction};
+
+use super::keyed_graph::{
+    new_edge_type_with_key, new_vertex_index_with_key, new_vertex_type_with_key,
+    new_vertex_with_key, set_edge_type_key, set_vertex_key, set_vertex_type_key,
+};
+use super::{KeyCatalog, KeyCatalogChange};
+
+/// A transaction on a KeyedGraph. Reverting the transaction, or rolling back to a savepoint,
+/// also reverts the changes to the key catalog.
+pub struct KeyedGraphTransaction<'g, K: Hash + Eq + Clone + Debug> {
+    transaction: InMemoryGraphTransaction<'g>,
+    key_catalog: &'g mut KeyCatalog<K>,
+    // Reverted latest first
+    key_catalog_changes: Vec<KeyCatalogChange<K>>,
+    // Savepoint id and the number of key catalog changes at the savepoint
+    savepoints: Vec<(usize, usize)>,
+}
+
+impl<'g, K: Hash + Eq + Clone + Debug> KeyedGraphTransaction<'g, K> {
+    pub(super) fn new(
+        graph: &'g mut Graph,
+        key_catalog: &'g mut KeyCatalog<K>,
+    ) -> Result<Self, GraphComputingError> {
+        Ok(Self {
+            transaction: InMemoryGraphTransaction::new(graph)?,
+            key_catalog,
+            key_catalog_changes: Vec::new(),
+            savepoints: Vec::new(),
+        })
+    }
+
+    pub fn transaction_ref(&self) -> &InMemoryGraphTransaction<'g> {
+        &self.transaction
+    }
+
+    /// Indices dropped through the transaction itself are not removed from the key catalog.
+    pub fn transaction_mut_ref(&mut self) -> &mut InMemoryGraphTransaction<'g> {
+        &mut self.transaction
+    }
+
+    pub fn key_catalog_ref(&self) -> &KeyCatalog<K> {
+        self.key_catalog
+    }
+
+    pub fn new_vertex_index_with_key(
+        &mut self,
+        key: K,
+    ) -> Result<VertexIndex, GraphComputingError> {
+        let (vertex, change) =
+            new_vertex_index_with_key(&mut self.transaction, self.key_catalog, key)?;
+        self.key_catalog_changes.push(change);
+        Ok(vertex)
+    }
+
+    pub fn new_vertex_with_key<T: ValueType>(
+        &mut self,
+        key: K,
+        vertex_type: &impl GetVertexTypeIndex,
+        value: T,
+    ) -> Result<VertexIndex, GraphComputingError>
+    where
+        InMemoryGraphTransaction<'g>: NewVertex<T>,
+    {
+        let (vertex, change) = new_vertex_with_key(
+            &mut self.transaction,
+            self.key_catalog,
+            key,
+            vertex_type,
+            value,
+        )?;
+        self.key_catalog_changes.push(change);
+        Ok(vertex)
+    }
+
+    pub fn new_vertex_type_with_key<T: ValueType>(
+        &mut self,
+        key: K,
+    ) -> Result<VertexTypeIndex, GraphComputingError>
+    where
+        InMemoryGraphTransaction<'g>: NewVertexType<T>,
+    {
+        let (vertex_type, change) =
+            new_vertex_type_with_key::<_, K, T>(&mut self.transaction, self.key_catalog, key)?;
+        self.key_catalog_changes.push(change);
+        Ok(vertex_type)
+    }
+
+    pub fn new_edge_type_with_key<T: ValueType>(
+        &mut self,
+        key: K,
+    ) -> Result<EdgeTypeIndex, GraphComputingError>
+    where
+        InMemoryGraphTransaction<'g>: NewEdgeType<T>,
+    {
+        let (edge_type, change) =
+            new_edge_type_with_key::<_, K, T>(&mut self.transaction, self.key_catalog, key)?;
+        self.key_catalog_changes.push(change);
+        Ok(edge_type)
+    }
+
+    /// Assigns a key to an existing vertex index without a key.
+    pub fn set_vertex_key(
+        &mut self,
+        key: K,
+        vertex: &impl GetVertexIndexIndex,
+    ) -> Result<(), GraphComputingError> {
+        let change = set_vertex_key(&self.transaction, self.key_catalog, key, vertex)?;
+        self.key_catalog_changes.push(change);
+        Ok(())
+    }
+
+    pub fn set_vertex_type_key(
+        &mut self,
+        key: K,
+        vertex_type: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        let change = set_vertex_type_key(&self.transaction, self.key_catalog, key, vertex_type)?;
+        self.key_catalog_changes.push(change);
+        Ok(())
+    }
+
+    pub fn set_edge_type_key(
+        &mut self,
+        key: K,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        let change = set_edge_type_key(&self.transaction, self.key_catalog, key, edge_type)?;
+        self.key_catalog_changes.push(change);
+        Ok(())
+    }
+
+    fn revert_key_catalog_changes(&mut self, number_of_changes_to_retain: usize) {
+        let changes_to_revert = self
+            .key_catalog_changes
+            .split_off(number_of_changes_to_retain);
+        for change in changes_to_revert.into_iter().rev() {
+            self.key_catalog.revert(change);
+        }
+    }
+
+    fn savepoint_position(&self, savepoint: &Savepoint) -> Option<usize> {
+        self.savepoints
+            .iter()
+            .position(|(id, _)| *id == savepoint.id())
+    }
+}
+
+impl<'g, K: Hash + Eq + Clone + Debug> DropVertexIndex for KeyedGraphTransaction<'g, K> {
+    fn drop_vertex_index_and_connected_edges(
+        &mut self,
+        vertex_index: &(impl GetVertexIndexIndex + Sync),
+    ) -> Result<(), GraphComputingError> {
+        self.transaction
+            .drop_vertex_index_and_connected_edges(vertex_index)?;
+        if let Some(change) = self.key_catalog.remove_vertex_key(vertex_index) {
+            self.key_catalog_changes.push(change);
+        }
+        Ok(())
+    }
+}
+
+impl<'g, K: Hash + Eq + Clone + Debug> DropVertexType for KeyedGraphTransaction<'g, K> {
+    fn drop_vertex_type(
+        &mut self,
+        vertex_type: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.transaction.drop_vertex_type(vertex_type)?;
+        if let Some(change) = self.key_catalog.remove_vertex_type_key(vertex_type) {
+            self.key_catalog_changes.push(change);
+        }
+        Ok(())
+    }
+}
+
+impl<'g, K: Hash + Eq + Clone + Debug> DropEdgeType for KeyedGraphTransaction<'g, K> {
+    fn drop_edge_type(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.transaction.drop_edge_type(edge_type)?;
+        if let Some(change) = self.key_catalog.remove_edge_type_key(edge_type) {
+            self.key_catalog_changes.push(change);
+        }
+        Ok(())
+    }
+}
+
+impl<'g, K: Hash + Eq + Clone + Debug> UseTransaction for KeyedGraphTransaction<'g, K> {
+    fn revert(&mut self) -> Result<(), GraphComputingError> {
+        self.savepoints.clear();
+        self.transaction.revert()?;
+        self.revert_key_catalog_changes(0);
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), GraphComputingError> {
+        self.savepoints.clear();
+        self.transaction.commit()?;
+        self.key_catalog_changes.clear();
+        Ok(())
+    }
+}
+
+impl<'g, K: Hash + Eq + Clone + Debug> UseSavepoints for KeyedGraphTransaction<'g, K> {
+    fn savepoint(&mut self) -> Result<Savepoint, GraphComputingError> {
+        let savepoint = self.transaction.savepoint()?;
+        self.savepoints
+            .push((savepoint.id(), self.key_catalog_changes.len()));
+        Ok(savepoint)
+    }
+
+    fn rollback_to(&mut self, savepoint: &Savepoint) -> Result<(), GraphComputingError> {
+        self.transaction.rollback_to(savepoint)?;
+        if let Some(position) = self.savepoint_position(savepoint) {
+            let (_, number_of_key_catalog_changes) = self.savepoints[position];
+            self.savepoints.truncate(position + 1);
+            self.revert_key_catalog_changes(number_of_key_catalog_changes);
+        }
+        Ok(())
+    }
+
+    fn release(&mut self, savepoint: Savepoint) -> Result<(), GraphComputingError> {
+        let position = self.savepoint_position(&savepoint);
+        self.transaction.release(savepoint)?;
+        if let Some(position) = position {
+            self.savepoints.truncate(position);
+        }
+        Ok(())
+    }
+}
+
+// The wrapped InMemoryGraphTransaction reverts the graph when it is dropped, after the key catalog
+impl<'g, K: Hash + Eq + Clone + Debug> Drop for KeyedGraphTransaction<'g, K> {
+    fn drop(&mut self) {
+        self.revert_key_catalog_changes(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::operators::key_catalog::KeyedGraph;
+    use crate::operators::operators::indexing::CheckIndex;
+
+    #[test]
+    fn revert_key_catalog_changes_with_transaction() {
+        let mut graph = KeyedGraph::new(Graph::with_initial_capacity(5, 5, 5).unwrap());
+        let vertex_type = graph
+            .new_vertex_type_with_key::<u8>(String::from("number"))
+            .unwrap();
+        let one = graph
+            .new_vertex_with_key(String::from("one"), &vertex_type, 1u8)
+            .unwrap();
+
+        {
+            let mut transaction = graph.transaction().unwrap();
+            transaction
+                .new_vertex_with_key(String::from("two"), &vertex_type, 2u8)
+                .unwrap();
+            transaction
+                .drop_vertex_index_and_connected_edges(&one)
+                .unwrap();
+
+            let savepoint = transaction.savepoint().unwrap();
+            transaction
+                .new_edge_type_with_key::<u8>(String::from("successor"))
+                .unwrap();
+            transaction.rollback_to(&savepoint).unwrap();
+            assert!(transaction
+                .key_catalog_ref()
+                .edge_type_index("successor")
+                .is_none());
+
+            assert!(transaction.key_catalog_ref().vertex_index("one").is_none());
+            assert!(transaction.key_catalog_ref().vertex_index("two").is_some());
+        }
+
+        assert_eq!(graph.key_catalog_ref().vertex_index("one"), Some(one));
+        assert!(graph.key_catalog_ref().vertex_index("two").is_none());
+        assert!(graph.graph_ref().is_valid_vertex_index(&one).unwrap());
+
+        {
+            let mut transaction = graph.transaction().unwrap();
+            let two = transaction
+                .new_vertex_with_key(String::from("two"), &vertex_type, 2u8)
+                .unwrap();
+            transaction.commit().unwrap();
+            drop(transaction);
+
+            assert_eq!(graph.key_catalog_ref().vertex_index("two"), Some(two));
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod key_catalog;
+mod keyed_graph;
+mod keyed_graph_transaction;
+mod operators;
+
+pub use key_catalog::*;
+pub use keyed_graph::*;
+pub use keyed_graph_transaction::*;
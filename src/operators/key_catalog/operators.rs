@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge::{GetDirectedEdgeCoordinateIndex, GetEdgeWeight};
+use crate::graph::graph::{Compact, VertexIndexRemapping};
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex, VertexIndex,
+    VertexTypeIndex,
+};
+use crate::graph::value_type::ValueType;
+use crate::operators::operators::delete::DropVertexIndex;
+use crate::operators::operators::drop::{DropEdgeType, DropVertexType};
+use crate::operators::operators::new::{
+    NewEdge, NewEdgeType, NewVertex, NewVertexIndex, NewVertexType,
+};
+
+use super::KeyedGraph;
+
+impl<G, K, T> NewVertexType<T> for KeyedGraph<G, K>
+where
+    G: NewVertexType<T>,
+    K: Hash + Eq + Clone + Debug,
+    T: ValueType,
+{
+    fn apply(&mut self) -> Result<VertexTypeIndex, GraphComputingError> {
+        NewVertexType::<T>::apply(self.graph_mut_ref())
+    }
+}
+
+impl<G, K, T> NewEdgeType<T> for KeyedGraph<G, K>
+where
+    G: NewEdgeType<T>,
+    K: Hash + Eq + Clone + Debug,
+    T: ValueType,
+{
+    fn apply(&mut self) -> Result<EdgeTypeIndex, GraphComputingError> {
+        NewEdgeType::<T>::apply(self.graph_mut_ref())
+    }
+}
+
+impl<G, K> NewVertexIndex for KeyedGraph<G, K>
+where
+    G: NewVertexIndex,
+    K: Hash + Eq + Clone + Debug,
+{
+    fn new_vertex_index(&mut self) -> Result<VertexIndex, GraphComputingError> {
+        self.graph_mut_ref().new_vertex_index()
+    }
+}
+
+impl<G, K, T> NewVertex<T> for KeyedGraph<G, K>
+where
+    G: NewVertex<T>,
+    K: Hash + Eq + Clone + Debug,
+    T: ValueType,
+{
+    fn new_vertex(
+        &mut self,
+        vertex_type: &impl GetVertexTypeIndex,
+        value: T,
+    ) -> Result<VertexIndex, GraphComputingError> {
+        self.graph_mut_ref().new_vertex(vertex_type, value)
+    }
+}
+
+impl<G, K, T> NewEdge<T> for KeyedGraph<G, K>
+where
+    G: NewEdge<T>,
+    K: Hash + Eq + Clone + Debug,
+    T: ValueType,
+{
+    fn new_edge_from_edge(
+        &mut self,
+        edge: impl GetDirectedEdgeCoordinateIndex + GetEdgeWeight<T>,
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref().new_edge_from_edge(edge)
+    }
+
+    fn new_edge(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+        weight: T,
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref().new_edge(edge_type, tail, head, weight)
+    }
+}
+
+impl<G, K> DropVertexIndex for KeyedGraph<G, K>
+where
+    G: DropVertexIndex,
+    K: Hash + Eq + Clone + Debug,
+{
+    fn drop_vertex_index_and_connected_edges(
+        &mut self,
+        vertex_index: &(impl GetVertexIndexIndex + Sync),
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref()
+            .drop_vertex_index_and_connected_edges(vertex_index)?;
+        self.key_catalog_mut_ref().remove_vertex_key(vertex_index);
+        Ok(())
+    }
+}
+
+impl<G, K> DropVertexType for KeyedGraph<G, K>
+where
+    G: DropVertexType,
+    K: Hash + Eq + Clone + Debug,
+{
+    fn drop_vertex_type(
+        &mut self,
+        vertex_type: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref().drop_vertex_type(vertex_type)?;
+        self.key_catalog_mut_ref()
+            .remove_vertex_type_key(vertex_type);
+        Ok(())
+    }
+}
+
+impl<G, K> DropEdgeType for KeyedGraph<G, K>
+where
+    G: DropEdgeType,
+    K: Hash + Eq + Clone + Debug,
+{
+    fn drop_edge_type(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref().drop_edge_type(edge_type)?;
+        self.key_catalog_mut_ref().remove_edge_type_key(edge_type);
+        Ok(())
+    }
+}
+
+impl<G, K> Compact for KeyedGraph<G, K>
+where
+    G: Compact,
+    K: Hash + Eq + Clone + Debug,
+{
+    fn compact(&mut self) -> Result<VertexIndexRemapping, GraphComputingError> {
+        let vertex_index_remapping = self.graph_mut_ref().compact()?;
+        self.key_catalog_mut_ref()
+            .apply_vertex_index_remapping(&vertex_index_remapping);
+        Ok(vertex_index_remapping)
+    }
+}
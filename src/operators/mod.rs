@@ -1,5 +1,12 @@
+pub mod change_data_capture;
+pub mod expression;
+pub mod history;
 pub mod in_memory;
 pub mod in_memory_transaction;
+pub mod key_catalog;
 pub mod operators;
 pub mod options;
+pub mod properties;
 pub mod transaction;
+pub(crate) mod vertex_value_index;
+pub mod write_ahead_log;
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::error::GraphComputingError;
+use crate::graph::indexing::{GetEdgeTypeIndex, VertexIndex};
+use crate::graph::value_type::ValueType;
+
+/// Adds many edges of a single edge type at once, by building the edges in a single GraphBLAS call.
+/// Existing edges are overwritten. For repeated coordinates, the last weight is stored.
+pub trait LoadEdgeList<T: ValueType> {
+    fn load_edge_list(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        edges: impl IntoIterator<Item = (VertexIndex, VertexIndex, T)>,
+    ) -> Result<(), GraphComputingError>;
+
+    /// Allocates a new vertex index for each distinct external id, and returns the map from external id to vertex index.
+    /// If loading fails, the allocated vertex indices are dropped again.
+    fn load_edge_list_with_external_ids<Id: Hash + Eq>(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        edges: impl IntoIterator<Item = (Id, Id, T)>,
+    ) -> Result<HashMap<Id, VertexIndex>, GraphComputingError>;
+
+    /// Reads rows of tail, head and weight, separated by the delimiter.
+    /// Empty lines, and lines starting with '#' or '%', are skipped.
+    fn load_edge_list_from_file(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+        delimiter: char,
+    ) -> Result<(), GraphComputingError>
+    where
+        T: FromStr;
+
+    fn load_edge_list_from_file_with_external_ids(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+        delimiter: char,
+    ) -> Result<HashMap<String, VertexIndex>, GraphComputingError>
+    where
+        T: FromStr;
+}
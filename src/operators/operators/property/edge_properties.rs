@@ -0,0 +1,31 @@
+use crate::error::GraphComputingError;
+use crate::graph::edge::GetDirectedEdgeCoordinateIndex;
+use crate::operators::properties::PropertyValue;
+
+/// Named properties of edges, stored next to the adjacency matrices.
+/// A property is removed with its edge by DeleteEdge, DropVertexIndex and DropEdgeType.
+pub trait EdgeProperties {
+    /// The edge must exist. Replaces an existing property with the same name.
+    fn set_edge_property(
+        &mut self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+        value: impl Into<PropertyValue>,
+    ) -> Result<(), GraphComputingError>;
+
+    fn edge_property(
+        &self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+    ) -> Result<Option<&PropertyValue>, GraphComputingError>;
+
+    /// Returns the deleted property, if any.
+    fn delete_edge_property(
+        &mut self,
+        edge: &impl GetDirectedEdgeCoordinateIndex,
+        property_name: &str,
+    ) -> Result<Option<PropertyValue>, GraphComputingError>;
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,35 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::{GetVertexIndexIndex, GetVertexTypeIndex};
+use crate::operators::properties::PropertyValue;
+
+/// Named properties of vertex elements, stored next to the vertex vectors.
+/// A property belongs to the value of a vertex in a vertex type,
+/// and is removed with it by DeleteVertexValue, DropVertexIndex and DropVertexType.
+pub trait VertexProperties {
+    /// The vertex must have a value in the vertex type. Replaces an existing property with the same name.
+    fn set_vertex_property(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+        value: impl Into<PropertyValue>,
+    ) -> Result<(), GraphComputingError>;
+
+    fn vertex_property(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+    ) -> Result<Option<&PropertyValue>, GraphComputingError>;
+
+    /// Returns the deleted property, if any.
+    fn delete_vertex_property(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+    ) -> Result<Option<PropertyValue>, GraphComputingError>;
+}
+
+#[cfg(test)]
+mod tests {}
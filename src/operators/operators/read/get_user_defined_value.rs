@@ -0,0 +1,41 @@
+use crate::error::GraphComputingError;
+
+use crate::graph::indexing::{GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex};
+use crate::graph::value_type::UserDefinedValueType;
+
+/// Reads values of a user-defined value type.
+/// Unlike GetVertexValue, values are not cast, so T must be the value type of the vertex type.
+pub trait GetUserDefinedVertexValue<T: UserDefinedValueType> {
+    fn user_defined_vertex_value(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Result<Option<T>, GraphComputingError>;
+
+    fn try_user_defined_vertex_value(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Result<T, GraphComputingError>;
+}
+
+/// Reads weights of a user-defined value type.
+/// Unlike GetEdgeWeight, weights are not cast, so T must be the value type of the edge type.
+pub trait GetUserDefinedEdgeWeight<T: UserDefinedValueType> {
+    fn user_defined_edge_weight(
+        &self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Result<Option<T>, GraphComputingError>;
+
+    fn try_user_defined_edge_weight(
+        &self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Result<T, GraphComputingError>;
+}
+
+#[cfg(test)]
+mod tests {}
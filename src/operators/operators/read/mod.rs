@@ -1,9 +1,11 @@
 mod get_adjacency_matrix;
 mod get_edge_weight;
+mod get_user_defined_value;
 mod get_vertex_value;
 mod get_vertex_vector;
 
 pub use get_adjacency_matrix::*;
 pub use get_edge_weight::*;
+pub use get_user_defined_value::*;
 pub use get_vertex_value::*;
 pub use get_vertex_vector::*;
@@ -0,0 +1,5 @@
+mod scratch_type;
+mod scratch_value;
+
+pub use scratch_type::*;
+pub use scratch_value::*;
@@ -0,0 +1,27 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::{ScratchEdgeTypeIndex, ScratchVertexTypeIndex};
+use crate::graph::value_type::ValueType;
+
+/// Scratch types hold intermediate results. They are dropped when the transaction commits or reverts.
+pub trait NewScratchVertexType<T: ValueType> {
+    fn new_scratch_vertex_type(&mut self) -> Result<ScratchVertexTypeIndex, GraphComputingError>;
+}
+
+/// Scratch types hold intermediate results. They are dropped when the transaction commits or reverts.
+pub trait NewScratchEdgeType<T: ValueType> {
+    fn new_scratch_edge_type(&mut self) -> Result<ScratchEdgeTypeIndex, GraphComputingError>;
+}
+
+pub trait DropScratchVertexType {
+    fn drop_scratch_vertex_type(
+        &mut self,
+        scratch_vertex_type: &ScratchVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+}
+
+pub trait DropScratchEdgeType {
+    fn drop_scratch_edge_type(
+        &mut self,
+        scratch_edge_type: &ScratchEdgeTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+}
@@ -0,0 +1,39 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::{GetVertexIndexIndex, ScratchEdgeTypeIndex, ScratchVertexTypeIndex};
+use crate::graph::value_type::ValueType;
+
+pub trait GetScratchVertexValue<T: ValueType> {
+    fn scratch_vertex_value(
+        &self,
+        scratch_vertex_type: &ScratchVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Result<Option<T>, GraphComputingError>;
+}
+
+pub trait SetScratchVertexValue<T: ValueType> {
+    fn set_scratch_vertex_value(
+        &mut self,
+        scratch_vertex_type: &ScratchVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        value: T,
+    ) -> Result<(), GraphComputingError>;
+}
+
+pub trait GetScratchEdgeWeight<T: ValueType> {
+    fn scratch_edge_weight(
+        &self,
+        scratch_edge_type: &ScratchEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Result<Option<T>, GraphComputingError>;
+}
+
+pub trait SetScratchEdgeWeight<T: ValueType> {
+    fn set_scratch_edge_weight(
+        &mut self,
+        scratch_edge_type: &ScratchEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+        weight: T,
+    ) -> Result<(), GraphComputingError>;
+}
@@ -0,0 +1,41 @@
+use std::ops::RangeBounds;
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+
+use crate::error::GraphComputingError;
+use crate::graph::indexing::{GetVertexTypeIndex, VertexIndex};
+use crate::graph::value_type::ValueType;
+
+/// Looks up vertices by value in the vertex value index of a vertex type.
+/// The value type must be the value type of the vertex type.
+pub trait LookupVertexValue<T: ValueType> {
+    /// Ordered by value, then by vertex index.
+    fn vertex_indices_with_value(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        value: T,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError>;
+
+    /// Ordered by value, then by vertex index.
+    fn vertex_indices_with_value_in_range(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        range: impl RangeBounds<T>,
+    ) -> Result<Vec<VertexIndex>, GraphComputingError>;
+
+    /// The mask is true for the matching vertices, and has the length of the vertex vectors.
+    fn vertex_mask_with_value(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        value: T,
+    ) -> Result<SparseVector<bool>, GraphComputingError>;
+
+    fn vertex_mask_with_value_in_range(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        range: impl RangeBounds<T>,
+    ) -> Result<SparseVector<bool>, GraphComputingError>;
+}
+
+#[cfg(test)]
+mod tests {}
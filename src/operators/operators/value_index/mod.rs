@@ -0,0 +1,5 @@
+mod lookup_vertex_value;
+mod vertex_value_index;
+
+pub use lookup_vertex_value::*;
+pub use vertex_value_index::*;
@@ -0,0 +1,27 @@
+use crate::error::GraphComputingError;
+use crate::graph::indexing::GetVertexTypeIndex;
+
+/// Optional sorted index of the values of a vertex type, for equality and range lookups without scanning the vertex vector.
+/// NewVertex, SetVertexValue, UpdateVertexValue, DeleteVertexValue, DropVertexIndex and DropVertexType keep the index up to date.
+/// Other operators that write to the vertex vector, such as apply and element-wise operators, make the index outdated,
+/// also if their transaction is rolled back. Lookups in an outdated index return an error until the index is created again.
+pub trait VertexValueIndexing {
+    /// Builds the index from the current values of the vertex type, replacing an existing index.
+    fn create_vertex_value_index(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+
+    fn drop_vertex_value_index(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError>;
+
+    fn has_vertex_value_index(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<bool, GraphComputingError>;
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,5 @@
+mod property_store;
+mod property_value;
+
+pub(crate) use property_store::*;
+pub use property_value::*;
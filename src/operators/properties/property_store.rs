@@ -0,0 +1,591 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::mem;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_element::Indexing;
+use crate::graph::edge_store::EdgeStore;
+use crate::graph::indexing::{
+    EdgeTypeIndex, ElementIndexMap, GetEdgeTypeIndex, GetVertexIndexIndex, GetVertexTypeIndex,
+    Index, VertexIndex, VertexTypeIndex,
+};
+use crate::graph::vertex_store::operations::vertex_element::CheckVertexIndex;
+use crate::graph::vertex_store::operations::vertex_type::CheckVertexTypeIndex;
+use crate::graph::vertex_store::VertexStore;
+
+use super::PropertyValue;
+
+// Vertex type index and vertex index
+type VertexPropertyKey = (Index, Index);
+// Edge type index, tail and head
+type EdgePropertyKey = (Index, Index, Index);
+
+/// The vertex and edge properties of a graph, by property name.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PropertyStore {
+    vertex_properties: Properties<VertexPropertyKey>,
+    edge_properties: Properties<EdgePropertyKey>,
+}
+
+trait PropertyKey: Copy + Eq + Hash {
+    fn type_index(&self) -> Index;
+    fn vertex_indices(&self) -> Vec<Index>;
+}
+
+impl PropertyKey for VertexPropertyKey {
+    fn type_index(&self) -> Index {
+        self.0
+    }
+
+    fn vertex_indices(&self) -> Vec<Index> {
+        vec![self.1]
+    }
+}
+
+impl PropertyKey for EdgePropertyKey {
+    fn type_index(&self) -> Index {
+        self.0
+    }
+
+    fn vertex_indices(&self) -> Vec<Index> {
+        vec![self.1, self.2]
+    }
+}
+
+/// The values of each property, with the keys that hold properties by type and by vertex,
+/// such that removing a type or vertex does not scan the keys of all properties.
+#[derive(Clone, Debug)]
+struct Properties<K: PropertyKey> {
+    values: HashMap<String, HashMap<K, PropertyValue>>,
+    number_of_properties: HashMap<K, usize>,
+    keys_by_type: ElementIndexMap<HashSet<K>>,
+    keys_by_vertex: ElementIndexMap<HashSet<K>>,
+}
+
+/// Reverts a change to the property store in a transaction.
+#[derive(Debug)]
+pub(crate) enum PropertyChange {
+    Vertex {
+        property_name: String,
+        key: VertexPropertyKey,
+        previous_value: Option<PropertyValue>,
+    },
+    Edge {
+        property_name: String,
+        key: EdgePropertyKey,
+        previous_value: Option<PropertyValue>,
+    },
+}
+
+impl PropertyChange {
+    pub(crate) fn previous_value_ref(&self) -> Option<&PropertyValue> {
+        match self {
+            PropertyChange::Vertex { previous_value, .. }
+            | PropertyChange::Edge { previous_value, .. } => previous_value.as_ref(),
+        }
+    }
+
+    pub(crate) fn into_previous_value(self) -> Option<PropertyValue> {
+        match self {
+            PropertyChange::Vertex { previous_value, .. }
+            | PropertyChange::Edge { previous_value, .. } => previous_value,
+        }
+    }
+}
+
+impl PropertyStore {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.vertex_properties.is_empty() && self.edge_properties.is_empty()
+    }
+
+    pub(crate) fn vertex_property_ref(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+    ) -> Option<&PropertyValue> {
+        self.vertex_properties.value_ref(
+            property_name,
+            &(vertex_type_index.index(), vertex_index.index()),
+        )
+    }
+
+    /// None removes the property.
+    pub(crate) fn set_vertex_property(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        property_name: &str,
+        value: Option<PropertyValue>,
+    ) -> PropertyChange {
+        let key = (vertex_type_index.index(), vertex_index.index());
+        let previous_value = self
+            .vertex_properties
+            .replace_value(property_name, key, value);
+        PropertyChange::Vertex {
+            property_name: property_name.to_owned(),
+            key,
+            previous_value,
+        }
+    }
+
+    pub(crate) fn edge_property_ref(
+        &self,
+        edge_type_index: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+        property_name: &str,
+    ) -> Option<&PropertyValue> {
+        self.edge_properties.value_ref(
+            property_name,
+            &(edge_type_index.index(), tail.index(), head.index()),
+        )
+    }
+
+    /// None removes the property.
+    pub(crate) fn set_edge_property(
+        &mut self,
+        edge_type_index: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+        property_name: &str,
+        value: Option<PropertyValue>,
+    ) -> PropertyChange {
+        let key = (edge_type_index.index(), tail.index(), head.index());
+        let previous_value = self
+            .edge_properties
+            .replace_value(property_name, key, value);
+        PropertyChange::Edge {
+            property_name: property_name.to_owned(),
+            key,
+            previous_value,
+        }
+    }
+
+    pub(crate) fn remove_vertex_element(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Vec<PropertyChange> {
+        let key = (vertex_type_index.index(), vertex_index.index());
+        vertex_property_changes(self.vertex_properties.remove_key(&key))
+    }
+
+    pub(crate) fn remove_vertex_type(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Vec<PropertyChange> {
+        vertex_property_changes(
+            self.vertex_properties
+                .remove_keys_of_type(vertex_type_index.index()),
+        )
+    }
+
+    /// Removes the properties of the vertex for all vertex types, and of its connected edges.
+    pub(crate) fn remove_vertex(
+        &mut self,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Vec<PropertyChange> {
+        let mut changes = vertex_property_changes(
+            self.vertex_properties
+                .remove_keys_of_vertex(vertex_index.index()),
+        );
+        changes.append(&mut edge_property_changes(
+            self.edge_properties
+                .remove_keys_of_vertex(vertex_index.index()),
+        ));
+        changes
+    }
+
+    pub(crate) fn remove_edge(
+        &mut self,
+        edge_type_index: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Vec<PropertyChange> {
+        let key = (edge_type_index.index(), tail.index(), head.index());
+        edge_property_changes(self.edge_properties.remove_key(&key))
+    }
+
+    pub(crate) fn remove_edge_type(
+        &mut self,
+        edge_type_index: &impl GetEdgeTypeIndex,
+    ) -> Vec<PropertyChange> {
+        edge_property_changes(
+            self.edge_properties
+                .remove_keys_of_type(edge_type_index.index()),
+        )
+    }
+
+    pub(crate) fn revert(&mut self, change: PropertyChange) {
+        match change {
+            PropertyChange::Vertex {
+                property_name,
+                key,
+                previous_value,
+            } => {
+                self.vertex_properties
+                    .replace_value(&property_name, key, previous_value);
+            }
+            PropertyChange::Edge {
+                property_name,
+                key,
+                previous_value,
+            } => {
+                self.edge_properties
+                    .replace_value(&property_name, key, previous_value);
+            }
+        }
+    }
+
+    /// Moves the properties to the new vertex indices. Properties of vertex indices without a new index are dropped.
+    pub(crate) fn renumber_vertex_indices(&mut self, new_indices: &ElementIndexMap<Index>) {
+        self.vertex_properties
+            .map_keys(|(vertex_type, vertex)| Some((vertex_type, *new_indices.get(&vertex)?)));
+        self.edge_properties.map_keys(|(edge_type, tail, head)| {
+            Some((
+                edge_type,
+                *new_indices.get(&tail)?,
+                *new_indices.get(&head)?,
+            ))
+        });
+    }
+
+    /// Removes the properties of vertex elements and edges that no longer exist,
+    /// for example after the stores were restored from a history step.
+    pub(crate) fn retain_existing_elements(
+        &mut self,
+        vertex_store: &VertexStore,
+        edge_store: &EdgeStore,
+    ) -> Result<(), GraphComputingError> {
+        let mut missing_vertex_elements = Vec::new();
+        for key in self.vertex_properties.keys() {
+            if !is_existing_vertex_element(vertex_store, key)? {
+                missing_vertex_elements.push(*key);
+            }
+        }
+        for key in missing_vertex_elements {
+            self.vertex_properties.remove_key(&key);
+        }
+
+        let mut missing_edges = Vec::new();
+        for key in self.edge_properties.keys() {
+            if !is_existing_edge(vertex_store, edge_store, key)? {
+                missing_edges.push(*key);
+            }
+        }
+        for key in missing_edges {
+            self.edge_properties.remove_key(&key);
+        }
+        Ok(())
+    }
+}
+
+fn vertex_property_changes(
+    removed_properties: Vec<(String, VertexPropertyKey, PropertyValue)>,
+) -> Vec<PropertyChange> {
+    removed_properties
+        .into_iter()
+        .map(|(property_name, key, value)| PropertyChange::Vertex {
+            property_name,
+            key,
+            previous_value: Some(value),
+        })
+        .collect()
+}
+
+fn edge_property_changes(
+    removed_properties: Vec<(String, EdgePropertyKey, PropertyValue)>,
+) -> Vec<PropertyChange> {
+    removed_properties
+        .into_iter()
+        .map(|(property_name, key, value)| PropertyChange::Edge {
+            property_name,
+            key,
+            previous_value: Some(value),
+        })
+        .collect()
+}
+
+impl<K: PropertyKey> Default for Properties<K> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            number_of_properties: HashMap::new(),
+            keys_by_type: ElementIndexMap::default(),
+            keys_by_vertex: ElementIndexMap::default(),
+        }
+    }
+}
+
+impl<K: PropertyKey> Properties<K> {
+    fn is_empty(&self) -> bool {
+        self.number_of_properties.is_empty()
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &K> {
+        self.number_of_properties.keys()
+    }
+
+    fn value_ref(&self, property_name: &str, key: &K) -> Option<&PropertyValue> {
+        self.values.get(property_name)?.get(key)
+    }
+
+    /// Returns the value that was stored before. None removes the property.
+    fn replace_value(
+        &mut self,
+        property_name: &str,
+        key: K,
+        value: Option<PropertyValue>,
+    ) -> Option<PropertyValue> {
+        match value {
+            Some(value) => {
+                let previous_value = self
+                    .values
+                    .entry(property_name.to_owned())
+                    .or_default()
+                    .insert(key, value);
+                if previous_value.is_none() {
+                    self.register_property(key);
+                }
+                previous_value
+            }
+            None => {
+                let values = self.values.get_mut(property_name)?;
+                let previous_value = values.remove(&key);
+                if values.is_empty() {
+                    self.values.remove(property_name);
+                }
+                if previous_value.is_some() {
+                    self.unregister_property(key);
+                }
+                previous_value
+            }
+        }
+    }
+
+    /// Removes the key from each property, without scanning the keys of the properties.
+    fn remove_key(&mut self, key: &K) -> Vec<(String, K, PropertyValue)> {
+        let mut removed_properties = Vec::new();
+        if self.number_of_properties.remove(key).is_none() {
+            return removed_properties;
+        }
+        self.values.retain(|property_name, values| {
+            if let Some(value) = values.remove(key) {
+                removed_properties.push((property_name.clone(), *key, value));
+            }
+            !values.is_empty()
+        });
+        self.remove_from_secondary_keys(*key);
+        removed_properties
+    }
+
+    fn remove_keys_of_type(&mut self, type_index: Index) -> Vec<(String, K, PropertyValue)> {
+        let keys = secondary_keys(&self.keys_by_type, type_index);
+        self.remove_keys(keys)
+    }
+
+    fn remove_keys_of_vertex(&mut self, vertex_index: Index) -> Vec<(String, K, PropertyValue)> {
+        let keys = secondary_keys(&self.keys_by_vertex, vertex_index);
+        self.remove_keys(keys)
+    }
+
+    fn remove_keys(&mut self, keys: Vec<K>) -> Vec<(String, K, PropertyValue)> {
+        keys.iter().flat_map(|key| self.remove_key(key)).collect()
+    }
+
+    /// Keys mapped to None are removed.
+    fn map_keys(&mut self, new_key: impl Fn(K) -> Option<K>) {
+        let values = mem::take(&mut self.values);
+        *self = Self::default();
+        for (property_name, values) in values {
+            for (key, value) in values {
+                if let Some(key) = new_key(key) {
+                    self.replace_value(&property_name, key, Some(value));
+                }
+            }
+        }
+    }
+
+    fn register_property(&mut self, key: K) {
+        let number_of_properties = self.number_of_properties.entry(key).or_insert(0);
+        *number_of_properties += 1;
+        if *number_of_properties == 1 {
+            self.keys_by_type
+                .entry(key.type_index())
+                .or_default()
+                .insert(key);
+            for vertex_index in key.vertex_indices() {
+                self.keys_by_vertex
+                    .entry(vertex_index)
+                    .or_default()
+                    .insert(key);
+            }
+        }
+    }
+
+    fn unregister_property(&mut self, key: K) {
+        if let Some(number_of_properties) = self.number_of_properties.get_mut(&key) {
+            *number_of_properties -= 1;
+            if *number_of_properties == 0 {
+                self.number_of_properties.remove(&key);
+                self.remove_from_secondary_keys(key);
+            }
+        }
+    }
+
+    fn remove_from_secondary_keys(&mut self, key: K) {
+        remove_secondary_key(&mut self.keys_by_type, key.type_index(), &key);
+        for vertex_index in key.vertex_indices() {
+            remove_secondary_key(&mut self.keys_by_vertex, vertex_index, &key);
+        }
+    }
+}
+
+fn secondary_keys<K: PropertyKey>(
+    keys_by_index: &ElementIndexMap<HashSet<K>>,
+    index: Index,
+) -> Vec<K> {
+    match keys_by_index.get(&index) {
+        Some(keys) => keys.iter().copied().collect(),
+        None => Vec::new(),
+    }
+}
+
+fn remove_secondary_key<K: PropertyKey>(
+    keys_by_index: &mut ElementIndexMap<HashSet<K>>,
+    index: Index,
+    key: &K,
+) {
+    if let Some(keys) = keys_by_index.get_mut(&index) {
+        keys.remove(key);
+        if keys.is_empty() {
+            keys_by_index.remove(&index);
+        }
+    }
+}
+
+fn is_existing_vertex_element(
+    vertex_store: &VertexStore,
+    (vertex_type_index, vertex_index): &VertexPropertyKey,
+) -> Result<bool, GraphComputingError> {
+    let vertex_type_index = VertexTypeIndex::new(*vertex_type_index);
+    let vertex_index = VertexIndex::new(*vertex_index);
+    Ok(vertex_store.is_valid_vertex_type_index(&vertex_type_index)?
+        && vertex_store.is_valid_vertex_index(&vertex_index)?
+        && vertex_store.is_valid_vertex_element(&vertex_type_index, &vertex_index)?)
+}
+
+fn is_existing_edge(
+    vertex_store: &VertexStore,
+    edge_store: &EdgeStore,
+    (edge_type_index, tail, head): &EdgePropertyKey,
+) -> Result<bool, GraphComputingError> {
+    let edge_type_index = EdgeTypeIndex::new(*edge_type_index);
+    let tail = VertexIndex::new(*tail);
+    let head = VertexIndex::new(*head);
+    Ok(
+        edge_store.is_valid_edge(vertex_store, &edge_type_index, &tail, &head)?
+            && edge_store.is_edge(&edge_type_index, &tail, &head)?,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revert_removed_vertex_and_edge_properties() {
+        let mut properties = PropertyStore::new();
+        let vertex_type = VertexTypeIndex::new(0);
+        let edge_type = EdgeTypeIndex::new(1);
+        let vertex_1 = VertexIndex::new(2);
+        let vertex_2 = VertexIndex::new(3);
+
+        properties.set_vertex_property(&vertex_type, &vertex_1, "name", Some("first".into()));
+        properties.set_vertex_property(&vertex_type, &vertex_2, "name", Some("second".into()));
+        properties.set_edge_property(
+            &edge_type,
+            &vertex_2,
+            &vertex_1,
+            "payload",
+            Some(vec![1u8, 2].into()),
+        );
+
+        let changes = properties.remove_vertex(&vertex_1);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            properties.vertex_property_ref(&vertex_type, &vertex_1, "name"),
+            None
+        );
+        assert_eq!(
+            properties.edge_property_ref(&edge_type, &vertex_2, &vertex_1, "payload"),
+            None
+        );
+        assert_eq!(
+            properties.vertex_property_ref(&vertex_type, &vertex_2, "name"),
+            Some(&PropertyValue::String(String::from("second")))
+        );
+
+        for change in changes.into_iter().rev() {
+            properties.revert(change);
+        }
+        assert_eq!(
+            properties.vertex_property_ref(&vertex_type, &vertex_1, "name"),
+            Some(&PropertyValue::String(String::from("first")))
+        );
+        assert_eq!(
+            properties.edge_property_ref(&edge_type, &vertex_2, &vertex_1, "payload"),
+            Some(&PropertyValue::Bytes(vec![1, 2]))
+        );
+
+        let new_indices: ElementIndexMap<Index> = [(3, 0), (2, 1)].into_iter().collect();
+        properties.renumber_vertex_indices(&new_indices);
+        assert_eq!(
+            properties.edge_property_ref(
+                &edge_type,
+                &VertexIndex::new(0),
+                &VertexIndex::new(1),
+                "payload"
+            ),
+            Some(&PropertyValue::Bytes(vec![1, 2]))
+        );
+    }
+
+    #[test]
+    fn remove_properties_of_types() {
+        let mut properties = PropertyStore::new();
+        let vertex_type_1 = VertexTypeIndex::new(0);
+        let vertex_type_2 = VertexTypeIndex::new(1);
+        let edge_type = EdgeTypeIndex::new(0);
+        let vertex_1 = VertexIndex::new(0);
+        let vertex_2 = VertexIndex::new(1);
+
+        properties.set_vertex_property(&vertex_type_1, &vertex_1, "name", Some("first".into()));
+        properties.set_vertex_property(&vertex_type_1, &vertex_1, "label", Some("a".into()));
+        properties.set_vertex_property(&vertex_type_2, &vertex_2, "name", Some("second".into()));
+        properties.set_edge_property(
+            &edge_type,
+            &vertex_1,
+            &vertex_1,
+            "payload",
+            Some(vec![1u8].into()),
+        );
+
+        assert_eq!(properties.remove_vertex_type(&vertex_type_1).len(), 2);
+        assert_eq!(
+            properties.vertex_property_ref(&vertex_type_2, &vertex_2, "name"),
+            Some(&PropertyValue::String(String::from("second")))
+        );
+        assert!(properties.remove_vertex_type(&vertex_type_1).is_empty());
+
+        assert_eq!(properties.remove_edge_type(&edge_type).len(), 1);
+        assert!(properties.remove_vertex(&vertex_1).is_empty());
+        assert_eq!(properties.remove_vertex(&vertex_2).len(), 1);
+        assert!(properties.is_empty());
+    }
+}
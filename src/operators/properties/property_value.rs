@@ -0,0 +1,31 @@
+/// A vertex or edge property value that cannot be stored in a GraphBLAS container.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+    String(String),
+    Bytes(Vec<u8>),
+    List(Vec<PropertyValue>),
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        PropertyValue::String(value)
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        PropertyValue::String(value.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for PropertyValue {
+    fn from(value: Vec<u8>) -> Self {
+        PropertyValue::Bytes(value)
+    }
+}
+
+impl From<Vec<PropertyValue>> for PropertyValue {
+    fn from(value: Vec<PropertyValue>) -> Self {
+        PropertyValue::List(value)
+    }
+}
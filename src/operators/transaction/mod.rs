@@ -1,3 +1,5 @@
 mod transaction;
+mod transactional_graph;
 
 pub use transaction::*;
+pub use transactional_graph::*;
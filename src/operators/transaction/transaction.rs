@@ -6,6 +6,58 @@ pub trait UseTransaction: Drop {
     fn commit(&mut self) -> Result<(), GraphComputingError>;
 }
 
+/// Marks a state within a transaction, to which the transaction can roll back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Savepoint {
+    id: usize,
+    number_of_state_restorers: usize,
+    number_of_vertex_value_index_changes: usize,
+    number_of_property_changes: usize,
+}
+
+impl Savepoint {
+    pub(crate) fn new(
+        id: usize,
+        number_of_state_restorers: usize,
+        number_of_vertex_value_index_changes: usize,
+        number_of_property_changes: usize,
+    ) -> Self {
+        Self {
+            id,
+            number_of_state_restorers,
+            number_of_vertex_value_index_changes,
+            number_of_property_changes,
+        }
+    }
+
+    pub(crate) fn id(&self) -> usize {
+        self.id
+    }
+
+    pub(crate) fn number_of_state_restorers(&self) -> usize {
+        self.number_of_state_restorers
+    }
+
+    pub(crate) fn number_of_vertex_value_index_changes(&self) -> usize {
+        self.number_of_vertex_value_index_changes
+    }
+
+    pub(crate) fn number_of_property_changes(&self) -> usize {
+        self.number_of_property_changes
+    }
+}
+
+pub trait UseSavepoints: UseTransaction {
+    fn savepoint(&mut self) -> Result<Savepoint, GraphComputingError>;
+
+    /// Reverts the changes after the savepoint. The savepoint remains valid, later savepoints do not.
+    fn rollback_to(&mut self, savepoint: &Savepoint) -> Result<(), GraphComputingError>;
+
+    /// Keeps the changes after the savepoint as part of the enclosing savepoint or transaction.
+    /// The savepoint and later savepoints become invalid.
+    fn release(&mut self, savepoint: Savepoint) -> Result<(), GraphComputingError>;
+}
+
 // pub(crate) trait GetGraph {
 //     fn graph_ref(&self) -> &Graph;
 //     fn graph_mut_ref(&mut self) -> &mut Graph;
@@ -0,0 +1,615 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+use crate::error::{GraphComputingError, UserError, UserErrorType};
+use crate::graph::graph::Graph;
+use crate::operators::change_data_capture::{GraphChange, SubscribeToGraphChanges};
+use crate::operators::write_ahead_log::{
+    log_records_of_changes, write_next_checkpoint, DurableGraph, LogRecord, SyncPolicy,
+    WriteAheadLog,
+};
+
+use super::UseTransaction;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IsolationLevel {
+    /// Each read sees the last commit at the time of the read.
+    ReadCommitted,
+    /// All reads see the last commit at the start of the transaction.
+    Snapshot,
+}
+
+/// A graph that is shared by read transactions and one write transaction at a time.
+///
+/// A write transaction works on its own copy of the last commit, which is made on the first write.
+/// Readers therefore never see uncommitted changes, and a commit replaces the vertex store and the edge store at once.
+/// Write transactions are serializable, because each one starts from the commit of the previous one.
+///
+/// Subscribers receive the changes of a write transaction when it commits.
+///
+/// A durable TransactionalGraph appends the changes of each commit to its write-ahead log as a single record,
+/// before the commit becomes visible to readers. A commit is durable when commit returns;
+/// after a crash, recover replays the write-ahead log up to the last completed commit.
+/// Every 1000 commits, and for changes the write-ahead log cannot hold,
+/// e.g. compaction, undo and redo, or user-defined value types, the commit is saved as a checkpoint instead.
+/// Properties are not durable, a durable TransactionalGraph does not commit a graph with properties.
+#[derive(Debug)]
+pub struct TransactionalGraph {
+    committed: RwLock<Arc<Graph>>,
+    writer: Mutex<Option<Checkpoints>>,
+}
+
+const NUMBER_OF_COMMITS_PER_CHECKPOINT: usize = 1000;
+
+#[derive(Debug)]
+struct Checkpoints {
+    directory: PathBuf,
+    generation: u64,
+    write_ahead_log: WriteAheadLog,
+    number_of_commits_since_checkpoint: usize,
+    // A failed append may have left a partial record, after which no record could be recovered,
+    // and a failed checkpoint may have left a newer snapshot, which recover loads instead of the write-ahead log
+    requires_checkpoint: bool,
+}
+
+impl Checkpoints {
+    fn write_next_checkpoint(&mut self, graph: &Graph) -> Result<(), GraphComputingError> {
+        self.requires_checkpoint = true;
+        self.write_ahead_log =
+            write_next_checkpoint(&self.directory, self.generation, graph, SyncPolicy::Always)?;
+        self.generation += 1;
+        self.number_of_commits_since_checkpoint = 0;
+        self.requires_checkpoint = false;
+        Ok(())
+    }
+
+    fn log_commit(&mut self, graph: &Graph) -> Result<(), GraphComputingError> {
+        if !graph.property_store.is_empty() {
+            return Err(UserError::new(
+                UserErrorType::Other,
+                String::from(
+                    "Properties are not durable, a durable TransactionalGraph cannot commit a graph with properties",
+                ),
+                None,
+            )
+            .into());
+        }
+
+        let changes = graph.change_subscribers.deferred_changes()?;
+        if changes.changes.is_empty() {
+            return Ok(());
+        }
+        if self.requires_checkpoint
+            || self.number_of_commits_since_checkpoint + 1 >= NUMBER_OF_COMMITS_PER_CHECKPOINT
+        {
+            return self.write_next_checkpoint(graph);
+        }
+
+        match log_records_of_changes(graph, &changes)? {
+            Some(records) => {
+                if let Err(error) = self
+                    .write_ahead_log
+                    .append(&LogRecord::Transaction { records })
+                {
+                    self.requires_checkpoint = true;
+                    return Err(error);
+                }
+                self.number_of_commits_since_checkpoint += 1;
+                Ok(())
+            }
+            None => self.write_next_checkpoint(graph),
+        }
+    }
+}
+
+impl TransactionalGraph {
+    /// Commits are kept in memory only.
+    pub fn new(graph: Graph) -> Result<Self, GraphComputingError> {
+        graph.complete_pending_work()?;
+        Ok(Self {
+            committed: RwLock::new(Arc::new(graph)),
+            writer: Mutex::new(None),
+        })
+    }
+
+    /// Writes an initial checkpoint of the graph to a directory that does not hold a checkpoint yet.
+    pub fn create_durable(
+        directory: impl AsRef<Path>,
+        graph: Graph,
+    ) -> Result<Self, GraphComputingError> {
+        Self::from_durable_graph(DurableGraph::create(directory, graph, SyncPolicy::Always)?)
+    }
+
+    /// Loads the last checkpoint, also if it was written by a DurableGraph.
+    pub fn recover(directory: impl AsRef<Path>) -> Result<Self, GraphComputingError> {
+        Self::from_durable_graph(DurableGraph::recover(directory, SyncPolicy::Always)?)
+    }
+
+    fn from_durable_graph(durable_graph: DurableGraph) -> Result<Self, GraphComputingError> {
+        let (graph, write_ahead_log, directory, generation) = durable_graph.into_parts();
+        graph.complete_pending_work()?;
+        Ok(Self {
+            committed: RwLock::new(Arc::new(graph)),
+            writer: Mutex::new(Some(Checkpoints {
+                directory,
+                generation,
+                write_ahead_log,
+                number_of_commits_since_checkpoint: 0,
+                requires_checkpoint: false,
+            })),
+        })
+    }
+
+    pub fn read_transaction(
+        &self,
+        isolation_level: IsolationLevel,
+    ) -> Result<ReadTransaction<'_>, GraphComputingError> {
+        let snapshot = match isolation_level {
+            IsolationLevel::ReadCommitted => None,
+            IsolationLevel::Snapshot => Some(self.last_commit()?),
+        };
+        Ok(ReadTransaction {
+            transactional_graph: self,
+            snapshot,
+        })
+    }
+
+    /// Waits until no other write transaction is open.
+    pub fn write_transaction(&self) -> Result<WriteTransaction<'_>, GraphComputingError> {
+        let checkpoints = self.writer.lock()?;
+        Ok(WriteTransaction {
+            transactional_graph: self,
+            checkpoints,
+            graph: self.last_commit()?,
+        })
+    }
+
+    pub fn last_commit(&self) -> Result<Arc<Graph>, GraphComputingError> {
+        Ok(self.committed.read()?.clone())
+    }
+
+    /// Saves the last commit as a checkpoint, such that recover does not need to replay the commits before it.
+    /// Waits until no write transaction is open. Does nothing if the graph is not durable.
+    pub fn checkpoint(&self) -> Result<(), GraphComputingError> {
+        let mut writer = self.writer.lock()?;
+        if let Some(checkpoints) = writer.as_mut() {
+            checkpoints.write_next_checkpoint(&self.last_commit()?)?;
+        }
+        Ok(())
+    }
+}
+
+impl SubscribeToGraphChanges for TransactionalGraph {
+    fn subscribe_to_changes(&self) -> Result<Receiver<GraphChange>, GraphComputingError> {
+        self.last_commit()?.subscribe_to_changes()
+    }
+}
+
+pub struct ReadTransaction<'g> {
+    transactional_graph: &'g TransactionalGraph,
+    snapshot: Option<Arc<Graph>>,
+}
+
+impl<'g> ReadTransaction<'g> {
+    /// Returns the graph to read, according to the isolation level of the transaction.
+    pub fn graph(&self) -> Result<Arc<Graph>, GraphComputingError> {
+        match &self.snapshot {
+            Some(snapshot) => Ok(snapshot.clone()),
+            None => self.transactional_graph.last_commit(),
+        }
+    }
+}
+
+pub struct WriteTransaction<'g> {
+    transactional_graph: &'g TransactionalGraph,
+    checkpoints: MutexGuard<'g, Option<Checkpoints>>,
+    graph: Arc<Graph>,
+}
+
+impl<'g> WriteTransaction<'g> {
+    /// Reads include the uncommitted changes of the transaction.
+    pub fn graph_ref(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Copies the last commit on the first write after beginning, committing or reverting.
+    pub fn graph_mut_ref(&mut self) -> &mut Graph {
+        if Arc::get_mut(&mut self.graph).is_none() {
+            // The copy publishes to the subscribers of the last commit, once it commits
+            let change_subscribers = self.graph.change_subscribers.deferring_copy();
+            Arc::make_mut(&mut self.graph).change_subscribers = change_subscribers;
+        }
+        Arc::make_mut(&mut self.graph)
+    }
+}
+
+impl<'g> UseTransaction for WriteTransaction<'g> {
+    fn revert(&mut self) -> Result<(), GraphComputingError> {
+        self.graph = self.transactional_graph.last_commit()?;
+        Ok(())
+    }
+
+    /// Logs the changes first if the graph is durable. If that fails, nothing is committed.
+    fn commit(&mut self) -> Result<(), GraphComputingError> {
+        // Readers on other threads must not finish pending GraphBLAS work concurrently
+        self.graph.complete_pending_work()?;
+
+        if let Some(checkpoints) = self.checkpoints.as_mut() {
+            checkpoints.log_commit(&self.graph)?;
+        }
+
+        let changes = self.graph.change_subscribers.take_deferred_changes()?;
+        *self.transactional_graph.committed.write()? = self.graph.clone();
+        self.graph.change_subscribers.publish(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    use crate::graph::indexing::{EdgeTypeIndex, VertexIndex, VertexTypeIndex};
+    use crate::operators::operators::delete::{DeleteEdge, DeleteVertexValue, DropVertexIndex};
+    use crate::operators::operators::indexing::CheckIndex;
+    use crate::operators::operators::new::{
+        NewEdge, NewEdgeType, NewVertex, NewVertexIndex, NewVertexType,
+    };
+    use crate::operators::operators::property::VertexProperties;
+    use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+    use crate::operators::operators::set::SetVertexValue;
+
+    fn vertex_value(
+        graph: &Graph,
+        vertex_type: &VertexTypeIndex,
+        vertex: &VertexIndex,
+    ) -> Option<u8> {
+        GetVertexValue::<u8>::vertex_value(graph, vertex_type, vertex).unwrap()
+    }
+
+    fn edge_weight(
+        graph: &Graph,
+        edge_type: &EdgeTypeIndex,
+        tail: &VertexIndex,
+        head: &VertexIndex,
+    ) -> Option<u8> {
+        GetEdgeWeight::<u8>::edge_weight(graph, edge_type, tail, head).unwrap()
+    }
+
+    #[test]
+    fn readers_are_isolated_from_open_write_transaction() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let tail = graph.new_vertex(&vertex_type, 1).unwrap();
+        let head = graph.new_vertex_index().unwrap();
+        let graph = TransactionalGraph::new(graph).unwrap();
+
+        let snapshot_reader = graph.read_transaction(IsolationLevel::Snapshot).unwrap();
+        let read_committed_reader = graph
+            .read_transaction(IsolationLevel::ReadCommitted)
+            .unwrap();
+
+        let mut writer = graph.write_transaction().unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &head, 2)
+            .unwrap();
+        writer
+            .graph_mut_ref()
+            .new_edge(&edge_type, &tail, &head, 3)
+            .unwrap();
+
+        assert_eq!(
+            vertex_value(writer.graph_ref(), &vertex_type, &head),
+            Some(2)
+        );
+        let last_commit = read_committed_reader.graph().unwrap();
+        assert_eq!(vertex_value(&last_commit, &vertex_type, &head), None);
+        assert_eq!(edge_weight(&last_commit, &edge_type, &tail, &head), None);
+
+        writer.commit().unwrap();
+
+        let last_commit = read_committed_reader.graph().unwrap();
+        assert_eq!(vertex_value(&last_commit, &vertex_type, &head), Some(2));
+        assert_eq!(edge_weight(&last_commit, &edge_type, &tail, &head), Some(3));
+
+        let snapshot = snapshot_reader.graph().unwrap();
+        assert_eq!(vertex_value(&snapshot, &vertex_type, &tail), Some(1));
+        assert_eq!(vertex_value(&snapshot, &vertex_type, &head), None);
+        assert_eq!(edge_weight(&snapshot, &edge_type, &tail, &head), None);
+    }
+
+    #[test]
+    fn reverted_and_dropped_write_transactions_are_not_committed() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex_index().unwrap();
+        let graph = TransactionalGraph::new(graph).unwrap();
+
+        let mut writer = graph.write_transaction().unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &vertex, 1)
+            .unwrap();
+        writer.revert().unwrap();
+        assert_eq!(
+            vertex_value(writer.graph_ref(), &vertex_type, &vertex),
+            None
+        );
+
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &vertex, 2)
+            .unwrap();
+        drop(writer);
+
+        let reader = graph.read_transaction(IsolationLevel::Snapshot).unwrap();
+        assert_eq!(
+            vertex_value(&reader.graph().unwrap(), &vertex_type, &vertex),
+            None
+        );
+    }
+
+    #[test]
+    fn subscribers_receive_changes_of_committed_write_transactions() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex_index().unwrap();
+        let graph = TransactionalGraph::new(graph).unwrap();
+        let changes = graph.subscribe_to_changes().unwrap();
+
+        let mut writer = graph.write_transaction().unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &vertex, 1)
+            .unwrap();
+        writer.revert().unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &vertex, 2)
+            .unwrap();
+        assert!(changes.try_recv().is_err());
+
+        writer.commit().unwrap();
+        assert_eq!(
+            changes.try_iter().collect::<Vec<GraphChange>>(),
+            vec![GraphChange::VertexValueSet {
+                vertex_type,
+                vertex
+            }]
+        );
+
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &vertex, 3)
+            .unwrap();
+        drop(writer);
+        assert!(changes.try_recv().is_err());
+
+        let mut writer = graph.write_transaction().unwrap();
+        writer
+            .graph_mut_ref()
+            .delete_vertex_value(&vertex_type, &vertex)
+            .unwrap();
+        writer.commit().unwrap();
+        assert_eq!(
+            changes.try_iter().collect::<Vec<GraphChange>>(),
+            vec![GraphChange::VertexValueDeleted {
+                vertex_type,
+                vertex
+            }]
+        );
+    }
+
+    #[test]
+    fn recover_last_commit_of_durable_graph() {
+        let directory = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_transactional_graph_{}",
+            std::process::id()
+        ));
+
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let committed_vertex = graph.new_vertex_index().unwrap();
+        let uncommitted_vertex = graph.new_vertex_index().unwrap();
+        let graph = TransactionalGraph::create_durable(&directory, graph).unwrap();
+
+        let mut writer = graph.write_transaction().unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &committed_vertex, 1)
+            .unwrap();
+        writer.commit().unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &uncommitted_vertex, 2)
+            .unwrap();
+        drop(writer);
+        drop(graph);
+
+        let recovered_graph = TransactionalGraph::recover(&directory).unwrap();
+        let last_commit = recovered_graph.last_commit().unwrap();
+        assert_eq!(
+            vertex_value(&last_commit, &vertex_type, &committed_vertex),
+            Some(1)
+        );
+        assert_eq!(
+            vertex_value(&last_commit, &vertex_type, &uncommitted_vertex),
+            None
+        );
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn recover_logged_commits_of_durable_graph() {
+        let directory = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_transactional_graph_logged_commits_{}",
+            std::process::id()
+        ));
+
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let head = graph.new_vertex_index().unwrap();
+        let graph = TransactionalGraph::create_durable(&directory, graph).unwrap();
+        let checkpoint_files = checkpoint_files(&directory);
+
+        let mut writer = graph.write_transaction().unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(writer.graph_mut_ref()).unwrap();
+        let tail = writer.graph_mut_ref().new_vertex(&vertex_type, 1).unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &head, 2)
+            .unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_value(&vertex_type, &head, 3)
+            .unwrap();
+        writer
+            .graph_mut_ref()
+            .new_edge(&edge_type, &tail, &head, 4)
+            .unwrap();
+        writer.commit().unwrap();
+
+        let dropped_vertex = writer.graph_mut_ref().new_vertex(&vertex_type, 5).unwrap();
+        writer
+            .graph_mut_ref()
+            .new_edge(&edge_type, &head, &dropped_vertex, 6)
+            .unwrap();
+        writer
+            .graph_mut_ref()
+            .drop_vertex_index_and_connected_edges(&dropped_vertex)
+            .unwrap();
+        writer
+            .graph_mut_ref()
+            .delete_vertex_value(&vertex_type, &tail)
+            .unwrap();
+        writer.commit().unwrap();
+        drop(writer);
+
+        // The commits were logged, not saved as checkpoints
+        assert_eq!(checkpoint_files(&directory), checkpoint_files);
+        let last_commit = graph.last_commit().unwrap();
+        drop(graph);
+
+        let recovered_graph = TransactionalGraph::recover(&directory).unwrap();
+        let recovered_commit = recovered_graph.last_commit().unwrap();
+        assert_eq!(vertex_value(&recovered_commit, &vertex_type, &tail), None);
+        assert_eq!(
+            vertex_value(&recovered_commit, &vertex_type, &head),
+            Some(3)
+        );
+        assert_eq!(
+            edge_weight(&recovered_commit, &edge_type, &tail, &head),
+            Some(4)
+        );
+        assert!(!recovered_commit
+            .is_valid_vertex_index(&dropped_vertex)
+            .unwrap());
+
+        // Recovery assigns the same indices as the graph before the crash
+        let mut expected_graph = (*last_commit).clone();
+        let mut writer = recovered_graph.write_transaction().unwrap();
+        assert_eq!(
+            writer.graph_mut_ref().new_vertex_index().unwrap(),
+            expected_graph.new_vertex_index().unwrap()
+        );
+        drop(writer);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn durable_graph_with_properties_is_not_committed() {
+        let directory = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_transactional_graph_properties_{}",
+            std::process::id()
+        ));
+
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let vertex = graph.new_vertex(&vertex_type, 1).unwrap();
+        let graph = TransactionalGraph::create_durable(&directory, graph).unwrap();
+
+        let mut writer = graph.write_transaction().unwrap();
+        writer
+            .graph_mut_ref()
+            .set_vertex_property(&vertex_type, &vertex, "name", String::from("a"))
+            .unwrap();
+        assert!(writer.commit().is_err());
+        drop(writer);
+
+        let last_commit = graph.last_commit().unwrap();
+        assert_eq!(
+            last_commit
+                .vertex_property(&vertex_type, &vertex, "name")
+                .unwrap(),
+            None
+        );
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    fn checkpoint_files(directory: &Path) -> Vec<std::ffi::OsString> {
+        let mut file_names: Vec<_> = std::fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        file_names.sort();
+        file_names
+    }
+
+    #[test]
+    fn concurrent_readers_only_see_complete_commits() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let tail = graph.new_vertex(&vertex_type, 0).unwrap();
+        let head = graph.new_vertex(&vertex_type, 0).unwrap();
+        let graph = TransactionalGraph::new(graph).unwrap();
+        let number_of_commits = 50u8;
+
+        thread::scope(|scope| {
+            let readers: Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut last_seen_value = 0;
+                        while last_seen_value < number_of_commits {
+                            let reader = graph.read_transaction(IsolationLevel::Snapshot).unwrap();
+                            let snapshot = reader.graph().unwrap();
+                            let value = vertex_value(&snapshot, &vertex_type, &tail).unwrap();
+                            assert_eq!(vertex_value(&snapshot, &vertex_type, &head), Some(value));
+                            if value > 0 {
+                                assert_eq!(
+                                    edge_weight(&snapshot, &edge_type, &tail, &head),
+                                    Some(value)
+                                );
+                            }
+                            assert!(value >= last_seen_value);
+                            last_seen_value = value;
+                        }
+                    })
+                })
+                .collect();
+
+            for value in 1..=number_of_commits {
+                let mut writer = graph.write_transaction().unwrap();
+                let graph = writer.graph_mut_ref();
+                graph.set_vertex_value(&vertex_type, &tail, value).unwrap();
+                graph.set_vertex_value(&vertex_type, &head, value).unwrap();
+                if value > 1 {
+                    graph.delete_edge(&edge_type, &tail, &head).unwrap();
+                }
+                graph.new_edge(&edge_type, &tail, &head, value).unwrap();
+                writer.commit().unwrap();
+            }
+
+            for reader in readers {
+                reader.join().unwrap();
+            }
+        });
+    }
+}
@@ -0,0 +1,5 @@
+mod value_index_key;
+mod vertex_value_indexes;
+
+pub(crate) use value_index_key::*;
+pub(crate) use vertex_value_indexes::*;
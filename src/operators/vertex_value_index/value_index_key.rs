@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+
+/// Totally ordered representation of a vertex value.
+/// All keys of a vertex value index have the same variant, because a vertex type has one value type.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ValueIndexKey {
+    Integer(i128),
+    Float(f64),
+}
+
+impl PartialEq for ValueIndexKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ValueIndexKey {}
+
+impl PartialOrd for ValueIndexKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ValueIndexKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(value), Self::Integer(other_value)) => value.cmp(other_value),
+            (Self::Float(value), Self::Float(other_value)) => value.total_cmp(other_value),
+            (Self::Integer(_), Self::Float(_)) => Ordering::Less,
+            (Self::Float(_), Self::Integer(_)) => Ordering::Greater,
+        }
+    }
+}
+
+pub(crate) trait IntoValueIndexKey {
+    fn into_value_index_key(self) -> ValueIndexKey;
+}
+
+impl IntoValueIndexKey for bool {
+    fn into_value_index_key(self) -> ValueIndexKey {
+        ValueIndexKey::Integer(self as i128)
+    }
+}
+
+macro_rules! implement_into_integer_value_index_key {
+    ($value_type:ty) => {
+        impl IntoValueIndexKey for $value_type {
+            fn into_value_index_key(self) -> ValueIndexKey {
+                ValueIndexKey::Integer(self as i128)
+            }
+        }
+    };
+}
+implement_into_integer_value_index_key!(i8);
+implement_into_integer_value_index_key!(i16);
+implement_into_integer_value_index_key!(i32);
+implement_into_integer_value_index_key!(i64);
+implement_into_integer_value_index_key!(u8);
+implement_into_integer_value_index_key!(u16);
+implement_into_integer_value_index_key!(u32);
+implement_into_integer_value_index_key!(u64);
+implement_into_integer_value_index_key!(isize);
+implement_into_integer_value_index_key!(usize);
+
+macro_rules! implement_into_float_value_index_key {
+    ($value_type:ty) => {
+        impl IntoValueIndexKey for $value_type {
+            fn into_value_index_key(self) -> ValueIndexKey {
+                // Adding zero turns -0.0 into 0.0, such that both are the same key
+                ValueIndexKey::Float(self as f64 + 0.0)
+            }
+        }
+    };
+}
+implement_into_float_value_index_key!(f32);
+implement_into_float_value_index_key!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_keys_are_totally_ordered() {
+        assert_eq!(
+            (-0.0f64).into_value_index_key(),
+            0.0f32.into_value_index_key()
+        );
+        assert!(f64::NEG_INFINITY.into_value_index_key() < (-1.5f64).into_value_index_key());
+        assert!(1u64.into_value_index_key() < u64::MAX.into_value_index_key());
+        assert!((-1i8).into_value_index_key() < false.into_value_index_key());
+    }
+}
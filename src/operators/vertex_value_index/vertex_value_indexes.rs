@@ -0,0 +1,431 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::{Bound, RangeBounds};
+
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::{
+    FromVectorElementList, GetSparseVectorElementList,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::{
+    SparseVector, VectorElementList,
+};
+use graphblas_sparse_linear_algebra::operators::binary_operator::First;
+
+use crate::error::{GraphComputingError, LogicError, LogicErrorType};
+use crate::graph::graph::GetGraphblasContext;
+use crate::graph::indexing::operations::CheckIndex;
+use crate::graph::indexing::{
+    ElementIndexMap, GetIndexGenerations, GetVertexIndexIndex, GetVertexTypeIndex, Index,
+    VertexIndex, VertexTypeIndex,
+};
+use crate::graph::value_type::{
+    call_generic_function_for_value_type_identifier, GetValueTypeIdentifier,
+    GetValueTypeIdentifierRef, ValueType, ValueTypeIdentifier,
+};
+use crate::graph::vertex_store::operations::vertex_element::GetVertexValue;
+use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::graph::vertex_store::{
+    CountVertexVectorOverwrites, GetVectorLength, GetVertexElementIndexer, GetVertexTypeIndexer,
+    ToSparseVector, VertexStore, VertexVector,
+};
+
+use super::{IntoValueIndexKey, ValueIndexKey};
+
+/// Sorted index of the values of one vertex type.
+#[derive(Clone, Debug)]
+pub(crate) struct VertexValueIndex {
+    value_type: ValueTypeIdentifier,
+    // The overwrite count of the vertex vector that the index was built from
+    overwrite_count: u64,
+    vertex_indices_by_value: BTreeMap<ValueIndexKey, BTreeSet<Index>>,
+    values_by_vertex_index: ElementIndexMap<ValueIndexKey>,
+}
+
+impl VertexValueIndex {
+    fn new(value_type: ValueTypeIdentifier, overwrite_count: u64) -> Self {
+        Self {
+            value_type,
+            overwrite_count,
+            vertex_indices_by_value: BTreeMap::new(),
+            values_by_vertex_index: ElementIndexMap::default(),
+        }
+    }
+
+    /// Returns the value that was indexed before.
+    fn set_value(
+        &mut self,
+        vertex_index: Index,
+        value: Option<ValueIndexKey>,
+    ) -> Option<ValueIndexKey> {
+        let previous_value = match value {
+            Some(value) => self.values_by_vertex_index.insert(vertex_index, value),
+            None => self.values_by_vertex_index.remove(&vertex_index),
+        };
+        if let Some(previous_value) = previous_value {
+            if let Some(vertex_indices) = self.vertex_indices_by_value.get_mut(&previous_value) {
+                vertex_indices.remove(&vertex_index);
+                if vertex_indices.is_empty() {
+                    self.vertex_indices_by_value.remove(&previous_value);
+                }
+            }
+        }
+        if let Some(value) = value {
+            self.vertex_indices_by_value
+                .entry(value)
+                .or_default()
+                .insert(vertex_index);
+        }
+        previous_value
+    }
+
+    /// Vertex indices ordered by value, and by vertex index for equal values.
+    fn vertex_indices_in_range(
+        &self,
+        start: Bound<ValueIndexKey>,
+        end: Bound<ValueIndexKey>,
+    ) -> Vec<Index> {
+        // BTreeMap::range panics on ranges that are empty by their bounds
+        let is_empty_range = match (&start, &end) {
+            (
+                Bound::Included(start_value) | Bound::Excluded(start_value),
+                Bound::Included(end_value) | Bound::Excluded(end_value),
+            ) => {
+                start_value > end_value
+                    || (start_value == end_value
+                        && !(matches!(start, Bound::Included(_))
+                            && matches!(end, Bound::Included(_))))
+            }
+            _ => false,
+        };
+        if is_empty_range {
+            return Vec::new();
+        }
+
+        self.vertex_indices_by_value
+            .range((start, end))
+            .flat_map(|(_, vertex_indices)| vertex_indices.iter().copied())
+            .collect()
+    }
+}
+
+/// The vertex value indexes of a graph, by vertex type.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct VertexValueIndexes {
+    indexes: ElementIndexMap<VertexValueIndex>,
+}
+
+/// Reverts a change to the vertex value indexes in a transaction.
+#[derive(Debug)]
+pub(crate) enum VertexValueIndexChange {
+    Value {
+        vertex_type_index: Index,
+        vertex_index: Index,
+        previous_value: Option<ValueIndexKey>,
+    },
+    Index {
+        vertex_type_index: Index,
+        previous_index: Option<VertexValueIndex>,
+    },
+}
+
+impl VertexValueIndexes {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn is_indexed(&self, vertex_type_index: &impl GetVertexTypeIndex) -> bool {
+        self.indexes.contains_key(&vertex_type_index.index())
+    }
+
+    pub(crate) fn replace_index(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        index: Option<VertexValueIndex>,
+    ) -> VertexValueIndexChange {
+        let previous_index = match index {
+            Some(index) => self.indexes.insert(vertex_type_index.index(), index),
+            None => self.indexes.remove(&vertex_type_index.index()),
+        };
+        VertexValueIndexChange::Index {
+            vertex_type_index: vertex_type_index.index(),
+            previous_index,
+        }
+    }
+
+    /// None if the vertex type is not indexed.
+    pub(crate) fn set_value(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        value: Option<ValueIndexKey>,
+    ) -> Option<VertexValueIndexChange> {
+        let index = self.indexes.get_mut(&vertex_type_index.index())?;
+        let previous_value = index.set_value(vertex_index.index(), value);
+        Some(VertexValueIndexChange::Value {
+            vertex_type_index: vertex_type_index.index(),
+            vertex_index: vertex_index.index(),
+            previous_value,
+        })
+    }
+
+    pub(crate) fn remove_vertex(
+        &mut self,
+        vertex_index: &impl GetVertexIndexIndex,
+    ) -> Vec<VertexValueIndexChange> {
+        self.indexes
+            .iter_mut()
+            .filter_map(|(vertex_type_index, index)| {
+                index
+                    .set_value(vertex_index.index(), None)
+                    .map(|previous_value| VertexValueIndexChange::Value {
+                        vertex_type_index: *vertex_type_index,
+                        vertex_index: vertex_index.index(),
+                        previous_value: Some(previous_value),
+                    })
+            })
+            .collect()
+    }
+
+    pub(crate) fn revert(&mut self, change: VertexValueIndexChange) {
+        match change {
+            VertexValueIndexChange::Value {
+                vertex_type_index,
+                vertex_index,
+                previous_value,
+            } => {
+                if let Some(index) = self.indexes.get_mut(&vertex_type_index) {
+                    index.set_value(vertex_index, previous_value);
+                }
+            }
+            VertexValueIndexChange::Index {
+                vertex_type_index,
+                previous_index,
+            } => {
+                self.replace_index(&VertexTypeIndex::new(vertex_type_index), previous_index);
+            }
+        }
+    }
+
+    fn try_index_ref(
+        &self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+    ) -> Result<&VertexValueIndex, GraphComputingError> {
+        match self.indexes.get(&vertex_type_index.index()) {
+            Some(index) => Ok(index),
+            None => Err(LogicError::new(
+                LogicErrorType::VertexValueIndexMustExist,
+                format!(
+                    "Vertex type {} does not have a vertex value index",
+                    vertex_type_index.index()
+                ),
+                None,
+            )
+            .into()),
+        }
+    }
+}
+
+pub(crate) fn build_vertex_value_index(
+    vertex_store: &VertexStore,
+    vertex_type_index: &impl GetVertexTypeIndex,
+) -> Result<VertexValueIndex, GraphComputingError> {
+    vertex_store.try_vertex_type_index_validity(vertex_type_index)?;
+    let vertex_vector = vertex_store.vertex_vector_ref_unchecked(vertex_type_index);
+    let value_type = vertex_vector.value_type_identifier_ref().clone();
+    call_generic_function_for_value_type_identifier!(
+        value_type,
+        build_vertex_value_index_for_value_type,
+        (vertex_vector)
+    )
+}
+
+fn build_vertex_value_index_for_value_type<T>(
+    vertex_vector: &VertexVector,
+) -> Result<VertexValueIndex, GraphComputingError>
+where
+    T: ValueType + GetValueTypeIdentifier + IntoValueIndexKey + Copy,
+    VertexVector: ToSparseVector<T>,
+    SparseVector<T>: GetSparseVectorElementList<T>,
+{
+    let element_list = ToSparseVector::<T>::to_sparse_vector(vertex_vector)?.element_list()?;
+    let mut index =
+        VertexValueIndex::new(T::value_type_identifier(), vertex_vector.overwrite_count());
+    for (vertex_index, value) in element_list
+        .indices_ref()
+        .iter()
+        .zip(element_list.values_ref())
+    {
+        index.set_value(*vertex_index, Some(value.into_value_index_key()));
+    }
+    Ok(index)
+}
+
+/// Indexes the stored value of the vertex, if the vertex type is indexed.
+/// The stored value is indexed, because the vertex vector may have cast the value that was set.
+pub(crate) fn update_indexed_vertex_value(
+    value_indexes: &mut VertexValueIndexes,
+    vertex_store: &VertexStore,
+    vertex_type_index: &impl GetVertexTypeIndex,
+    vertex_index: &impl GetVertexIndexIndex,
+) -> Result<Option<VertexValueIndexChange>, GraphComputingError> {
+    if !value_indexes.is_indexed(vertex_type_index) {
+        return Ok(None);
+    }
+    let value_type = vertex_store
+        .vertex_vector_ref(vertex_type_index)?
+        .value_type_identifier_ref()
+        .clone();
+    let value = call_generic_function_for_value_type_identifier!(
+        value_type,
+        stored_value_index_key,
+        (vertex_store, vertex_type_index, vertex_index)
+    )?;
+    Ok(value_indexes.set_value(vertex_type_index, vertex_index, value))
+}
+
+fn stored_value_index_key<T>(
+    vertex_store: &VertexStore,
+    vertex_type_index: &impl GetVertexTypeIndex,
+    vertex_index: &impl GetVertexIndexIndex,
+) -> Result<Option<ValueIndexKey>, GraphComputingError>
+where
+    T: ValueType + IntoValueIndexKey,
+    VertexStore: GetVertexValue<T>,
+{
+    Ok(
+        GetVertexValue::<T>::vertex_value_unchecked(vertex_store, vertex_type_index, vertex_index)?
+            .map(IntoValueIndexKey::into_value_index_key),
+    )
+}
+
+/// Rebuilds all indexes from the vertex store, and drops the indexes of vertex types that are no longer valid.
+pub(crate) fn rebuild_vertex_value_indexes(
+    value_indexes: &mut VertexValueIndexes,
+    vertex_store: &VertexStore,
+) -> Result<(), GraphComputingError> {
+    let vertex_type_indices: Vec<Index> = value_indexes.indexes.keys().copied().collect();
+    for vertex_type_index in vertex_type_indices {
+        let vertex_type_index = VertexTypeIndex::new(vertex_type_index);
+        let index = if vertex_store
+            .vertex_type_indexer_ref()
+            .is_valid_index(vertex_type_index.index())?
+        {
+            Some(build_vertex_value_index(vertex_store, &vertex_type_index)?)
+        } else {
+            None
+        };
+        value_indexes.replace_index(&vertex_type_index, index);
+    }
+    Ok(())
+}
+
+pub(crate) fn indexed_vertex_indices_in_range<T>(
+    value_indexes: &VertexValueIndexes,
+    vertex_store: &VertexStore,
+    vertex_type_index: &impl GetVertexTypeIndex,
+    range: impl RangeBounds<T>,
+) -> Result<Vec<Index>, GraphComputingError>
+where
+    T: ValueType + GetValueTypeIdentifier + IntoValueIndexKey + Copy,
+{
+    vertex_store.try_vertex_type_index_validity(vertex_type_index)?;
+    let index = value_indexes.try_index_ref(vertex_type_index)?;
+    if vertex_store
+        .vertex_vector_ref_unchecked(vertex_type_index)
+        .overwrite_count()
+        != index.overwrite_count
+    {
+        return Err(LogicError::new(
+            LogicErrorType::VertexValueIndexMustBeUpToDate,
+            format!(
+                "The vertex vector of vertex type {} was overwritten after its vertex value index was created, create the index again",
+                vertex_type_index.index()
+            ),
+            None,
+        )
+        .into());
+    }
+    if T::value_type_identifier() != index.value_type {
+        return Err(LogicError::new(
+            LogicErrorType::UnsafeTypeConversion,
+            format!(
+                "Cannot look up a value of type {:?} in the vertex value index of type {:?}",
+                T::value_type_identifier(),
+                index.value_type
+            ),
+            None,
+        )
+        .into());
+    }
+
+    Ok(index.vertex_indices_in_range(
+        range
+            .start_bound()
+            .cloned()
+            .map(IntoValueIndexKey::into_value_index_key),
+        range
+            .end_bound()
+            .cloned()
+            .map(IntoValueIndexKey::into_value_index_key),
+    ))
+}
+
+pub(crate) fn vertex_indices_with_generation(
+    vertex_store: &VertexStore,
+    indices: Vec<Index>,
+) -> Vec<VertexIndex> {
+    let element_indexer = vertex_store.element_indexer_ref();
+    indices
+        .into_iter()
+        .map(|index| VertexIndex::with_generation(index, Some(element_indexer.generation(index))))
+        .collect()
+}
+
+/// Mask with the length of the vertex vectors.
+pub(crate) fn vertex_mask(
+    vertex_store: &VertexStore,
+    vertex_type_index: &impl GetVertexTypeIndex,
+    indices: Vec<Index>,
+) -> Result<SparseVector<bool>, GraphComputingError> {
+    let length = vertex_store
+        .vertex_vector_ref(vertex_type_index)?
+        .length()?;
+    let values = vec![true; indices.len()];
+    Ok(SparseVector::<bool>::from_element_list(
+        vertex_store.graphblas_context(),
+        length,
+        VectorElementList::from_vectors(indices, values)?,
+        &First::<bool>::new(),
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_lookup_in_vertex_value_index() {
+        let mut index = VertexValueIndex::new(ValueTypeIdentifier::Int32, 0);
+        for (vertex_index, value) in [(0, 5i32), (1, -2), (2, 5), (3, 9)] {
+            index.set_value(vertex_index, Some(value.into_value_index_key()));
+        }
+        index.set_value(3, Some(1i32.into_value_index_key()));
+        index.set_value(0, None);
+
+        let key = |value: i32| value.into_value_index_key();
+        assert_eq!(
+            index.vertex_indices_in_range(Bound::Unbounded, Bound::Unbounded),
+            vec![1, 3, 2]
+        );
+        assert_eq!(
+            index.vertex_indices_in_range(Bound::Included(key(1)), Bound::Included(key(5))),
+            vec![3, 2]
+        );
+        assert_eq!(
+            index.vertex_indices_in_range(Bound::Excluded(key(5)), Bound::Excluded(key(5))),
+            Vec::<Index>::new()
+        );
+        assert_eq!(
+            index.vertex_indices_in_range(Bound::Included(key(9)), Bound::Included(key(1))),
+            Vec::<Index>::new()
+        );
+    }
+}
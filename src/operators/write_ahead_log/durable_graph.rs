@@ -0,0 +1,477 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::error::{GraphComputingError, SystemError, SystemErrorType, UserError, UserErrorType};
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::graph::{
+    encode_adjacency_matrix, encode_vertex_vector, sync_directory, GetEdgeStore, GetVertexStore,
+    Graph, LoadSnapshot, SaveSnapshot,
+};
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetIndex, GetVertexTypeIndex, VertexTypeIndex,
+};
+use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+
+use super::{replay_log_record, LogRecord, SyncPolicy, WriteAheadLog};
+
+/// A graph that logs every public mutation to a write-ahead log.
+///
+/// A directory holds the last checkpoint, i.e. a snapshot of the graph,
+/// and the write-ahead log with all mutations since that checkpoint.
+/// A mutation is logged after it has been applied to the graph; a mutation that fails is not logged.
+/// If an applied mutation cannot be logged, the graph is ahead of its write-ahead log,
+/// and rejects all further mutations until a checkpoint succeeds.
+#[derive(Debug)]
+pub struct DurableGraph {
+    graph: Graph,
+    write_ahead_log: WriteAheadLog,
+    directory: PathBuf,
+    generation: u64,
+    sync_policy: SyncPolicy,
+    has_unlogged_mutation: bool,
+}
+
+impl DurableGraph {
+    /// Writes an initial checkpoint of the graph to a directory that does not hold a checkpoint yet.
+    pub fn create(
+        directory: impl AsRef<Path>,
+        graph: Graph,
+        sync_policy: SyncPolicy,
+    ) -> Result<Self, GraphComputingError> {
+        let directory = directory.as_ref().to_path_buf();
+        fs::create_dir_all(&directory)?;
+        if !checkpoint_generations(&directory)?.is_empty() {
+            return Err(UserError::new(
+                UserErrorType::Other,
+                format!("The directory already holds a checkpoint: {:?}", directory),
+                None,
+            )
+            .into());
+        }
+
+        let generation = 0;
+        graph.save_snapshot(snapshot_path(&directory, generation))?;
+        let write_ahead_log =
+            WriteAheadLog::create(write_ahead_log_path(&directory, generation), sync_policy)?;
+        sync_directory(&directory)?;
+
+        Ok(Self {
+            graph,
+            write_ahead_log,
+            directory,
+            generation,
+            sync_policy,
+            has_unlogged_mutation: false,
+        })
+    }
+
+    /// Loads the last checkpoint, and replays the write-ahead log on top of it.
+    pub fn recover(
+        directory: impl AsRef<Path>,
+        sync_policy: SyncPolicy,
+    ) -> Result<Self, GraphComputingError> {
+        let directory = directory.as_ref().to_path_buf();
+        let generations = checkpoint_generations(&directory)?;
+        let generation = match generations.iter().max() {
+            Some(generation) => *generation,
+            None => {
+                return Err(UserError::new(
+                    UserErrorType::Other,
+                    format!("The directory does not hold a checkpoint: {:?}", directory),
+                    None,
+                )
+                .into())
+            }
+        };
+
+        let mut graph = Graph::load_snapshot(snapshot_path(&directory, generation))?;
+
+        // A crash during a checkpoint may leave a snapshot without a write-ahead log
+        let log_path = write_ahead_log_path(&directory, generation);
+        let write_ahead_log = if log_path.exists() {
+            let (write_ahead_log, records) = WriteAheadLog::open(&log_path, sync_policy)?;
+            for record in records {
+                replay_log_record(&mut graph, record)?;
+            }
+            write_ahead_log
+        } else {
+            let write_ahead_log = WriteAheadLog::create(&log_path, sync_policy)?;
+            sync_directory(&directory)?;
+            write_ahead_log
+        };
+
+        for stale_generation in generations
+            .into_iter()
+            .filter(|stale_generation| *stale_generation < generation)
+        {
+            remove_checkpoint(&directory, stale_generation)?;
+        }
+
+        Ok(Self {
+            graph,
+            write_ahead_log,
+            directory,
+            generation,
+            sync_policy,
+            has_unlogged_mutation: false,
+        })
+    }
+
+    /// Saves a snapshot of the graph, and continues with an empty write-ahead log.
+    /// The snapshot includes any mutation that could not be logged, so the graph accepts mutations again.
+    pub fn checkpoint(&mut self) -> Result<(), GraphComputingError> {
+        self.write_ahead_log = write_next_checkpoint(
+            &self.directory,
+            self.generation,
+            &self.graph,
+            self.sync_policy,
+        )?;
+        self.generation += 1;
+        self.has_unlogged_mutation = false;
+        Ok(())
+    }
+
+    /// Whether an applied mutation could not be logged since the last checkpoint.
+    pub fn has_unlogged_mutation(&self) -> bool {
+        self.has_unlogged_mutation
+    }
+
+    /// Syncs all logged mutations to disk.
+    pub fn sync(&mut self) -> Result<(), GraphComputingError> {
+        self.write_ahead_log.sync()
+    }
+
+    pub fn graph_ref(&self) -> &Graph {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> Graph {
+        self.graph
+    }
+
+    /// Applies an operator with a vertex vector as product, and logs the resulting vertex vector.
+    /// The operator must not change anything else, because only the product is logged.
+    pub(crate) fn apply_to_vertex_vector<R>(
+        &mut self,
+        product: &impl GetVertexTypeIndex,
+        operator: impl FnOnce(&mut Graph) -> Result<R, GraphComputingError>,
+    ) -> Result<R, GraphComputingError> {
+        let result = operator(self.graph_mut_ref()?)?;
+
+        let mut vertex_vector = Vec::new();
+        let encoded = self
+            .graph
+            .vertex_store_ref()
+            .vertex_vector_ref(product)
+            .and_then(|product| encode_vertex_vector(&mut vertex_vector, product));
+        self.mark_unlogged_mutation_on_error(encoded)?;
+        self.log(LogRecord::VertexVectorOverwritten {
+            vertex_type: VertexTypeIndex::with_generation(product.index(), product.generation()),
+            vertex_vector,
+        })?;
+        Ok(result)
+    }
+
+    /// Applies an operator with an adjacency matrix as product, and logs the resulting adjacency matrix.
+    /// The operator must not change anything else, because only the product is logged.
+    pub(crate) fn apply_to_adjacency_matrix<R>(
+        &mut self,
+        product: &impl GetEdgeTypeIndex,
+        operator: impl FnOnce(&mut Graph) -> Result<R, GraphComputingError>,
+    ) -> Result<R, GraphComputingError> {
+        let result = operator(self.graph_mut_ref()?)?;
+
+        let mut adjacency_matrix = Vec::new();
+        let encoded = self
+            .graph
+            .edge_store_ref()
+            .adjacency_matrix_ref(product)
+            .and_then(|product| encode_adjacency_matrix(&mut adjacency_matrix, product));
+        self.mark_unlogged_mutation_on_error(encoded)?;
+        self.log(LogRecord::AdjacencyMatrixOverwritten {
+            edge_type: EdgeTypeIndex::with_generation(product.index(), product.generation()),
+            adjacency_matrix,
+        })?;
+        Ok(result)
+    }
+
+    /// Fails if an applied mutation could not be logged since the last checkpoint.
+    pub(crate) fn graph_mut_ref(&mut self) -> Result<&mut Graph, GraphComputingError> {
+        if self.has_unlogged_mutation {
+            return Err(SystemError::new(
+                SystemErrorType::Io,
+                format!(
+                    "A mutation could not be logged to the write-ahead log in {:?}, save a checkpoint first",
+                    self.directory
+                ),
+                None,
+            )
+            .into());
+        }
+        Ok(&mut self.graph)
+    }
+
+    /// Logs a mutation that has been applied to the graph.
+    pub(crate) fn log(&mut self, record: LogRecord) -> Result<(), GraphComputingError> {
+        let appended = self.write_ahead_log.append(&record);
+        self.mark_unlogged_mutation_on_error(appended)
+    }
+
+    fn mark_unlogged_mutation_on_error<R>(
+        &mut self,
+        result: Result<R, GraphComputingError>,
+    ) -> Result<R, GraphComputingError> {
+        if result.is_err() {
+            self.has_unlogged_mutation = true;
+        }
+        result
+    }
+
+    /// Returns the graph, its write-ahead log, its directory and the generation of its last checkpoint.
+    pub(crate) fn into_parts(self) -> (Graph, WriteAheadLog, PathBuf, u64) {
+        (
+            self.graph,
+            self.write_ahead_log,
+            self.directory,
+            self.generation,
+        )
+    }
+}
+
+/// Saves the graph as the checkpoint after the given generation, with an empty write-ahead log,
+/// and then removes the checkpoint of the given generation.
+/// A crash at any point leaves either checkpoint to recover from.
+/// The new checkpoint is durable once this returns, also if the old checkpoint could not be removed.
+pub(crate) fn write_next_checkpoint(
+    directory: &Path,
+    generation: u64,
+    graph: &Graph,
+    sync_policy: SyncPolicy,
+) -> Result<WriteAheadLog, GraphComputingError> {
+    let next_generation = generation + 1;
+
+    graph.save_snapshot(snapshot_path(directory, next_generation))?;
+    let write_ahead_log = WriteAheadLog::create(
+        write_ahead_log_path(directory, next_generation),
+        sync_policy,
+    )?;
+    sync_directory(directory)?;
+
+    // The new checkpoint is already durable, so failing here would report a commit as failed that recover loads.
+    // recover removes checkpoints older than the last one.
+    let _ = remove_checkpoint(directory, generation);
+    Ok(write_ahead_log)
+}
+
+fn snapshot_path(directory: &Path, generation: u64) -> PathBuf {
+    directory.join(format!("snapshot_{}.slag", generation))
+}
+
+fn write_ahead_log_path(directory: &Path, generation: u64) -> PathBuf {
+    directory.join(format!("write_ahead_log_{}.log", generation))
+}
+
+fn checkpoint_generations(directory: &Path) -> Result<Vec<u64>, GraphComputingError> {
+    let mut generations = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let file_name = entry?.file_name();
+        if let Some(generation) = file_name
+            .to_str()
+            .and_then(|file_name| file_name.strip_prefix("snapshot_"))
+            .and_then(|file_name| file_name.strip_suffix(".slag"))
+            .and_then(|generation| generation.parse::<u64>().ok())
+        {
+            generations.push(generation);
+        }
+    }
+    Ok(generations)
+}
+
+fn remove_checkpoint(directory: &Path, generation: u64) -> Result<(), GraphComputingError> {
+    for path in [
+        snapshot_path(directory, generation),
+        write_ahead_log_path(directory, generation),
+    ] {
+        match fs::remove_file(path) {
+            Err(error) if error.kind() != ErrorKind::NotFound => return Err(error.into()),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use graphblas_sparse_linear_algebra::operators::binary_operator::{Assignment, Plus};
+    use graphblas_sparse_linear_algebra::operators::semiring::PlusTimes;
+
+    use super::*;
+
+    use crate::operators::operators::delete::DropVertexIndex;
+    use crate::operators::operators::multiplication::AdjacencyMatrixMultiplication;
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+    use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+    use crate::operators::operators::set::SetVertexValue;
+    use crate::operators::operators::transpose::TransposeAdjacencyMatrix;
+    use crate::operators::options::{
+        OptionsForOperatorWithAdjacencyMatrixArgument,
+        OptionsForOperatorWithAdjacencyMatrixArguments,
+    };
+
+    #[test]
+    fn recover_from_checkpoint_and_write_ahead_log() {
+        let directory = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_durable_graph_{}",
+            std::process::id()
+        ));
+
+        let mut graph = DurableGraph::create(
+            &directory,
+            Graph::with_initial_capacity(5, 5, 5).unwrap(),
+            SyncPolicy::EveryNumberOfRecords(2),
+        )
+        .unwrap();
+
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+        let edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+        let product_edge_type = NewEdgeType::<u16>::apply(&mut graph).unwrap();
+        let transposed_edge_type = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+
+        let vertex_1 = graph.new_vertex(&vertex_type, 1u8).unwrap();
+        let vertex_2 = graph.new_vertex(&vertex_type, 2u8).unwrap();
+        let vertex_3 = graph.new_vertex(&vertex_type, 3u8).unwrap();
+
+        graph.checkpoint().unwrap();
+
+        graph
+            .new_edge(&edge_type, &vertex_1, &vertex_2, 2u8)
+            .unwrap();
+        graph
+            .new_edge(&edge_type, &vertex_2, &vertex_1, 3u8)
+            .unwrap();
+        graph
+            .set_vertex_value(&vertex_type, &vertex_1, 10u8)
+            .unwrap();
+        graph
+            .drop_vertex_index_and_connected_edges(&vertex_3)
+            .unwrap();
+
+        AdjacencyMatrixMultiplication::<u8>::apply(
+            &mut graph,
+            &edge_type,
+            &PlusTimes::<u8>::new(),
+            &edge_type,
+            &Plus::<u8>::new(),
+            &product_edge_type,
+            None,
+            &OptionsForOperatorWithAdjacencyMatrixArguments::new_default(),
+        )
+        .unwrap();
+
+        TransposeAdjacencyMatrix::<u8>::apply(
+            &mut graph,
+            &edge_type,
+            &Assignment::<u8>::new(),
+            &transposed_edge_type,
+            None,
+            &OptionsForOperatorWithAdjacencyMatrixArgument::new_default(),
+        )
+        .unwrap();
+
+        graph.sync().unwrap();
+        drop(graph);
+
+        let mut recovered_graph = DurableGraph::recover(&directory, SyncPolicy::Always).unwrap();
+
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(
+                recovered_graph.graph_ref(),
+                &vertex_type,
+                &vertex_1
+            )
+            .unwrap(),
+            Some(10)
+        );
+        assert_eq!(
+            GetEdgeWeight::<u8>::edge_weight(
+                recovered_graph.graph_ref(),
+                &edge_type,
+                &vertex_2,
+                &vertex_1
+            )
+            .unwrap(),
+            Some(3)
+        );
+        assert_eq!(
+            GetEdgeWeight::<u16>::edge_weight(
+                recovered_graph.graph_ref(),
+                &product_edge_type,
+                &vertex_1,
+                &vertex_1
+            )
+            .unwrap(),
+            Some(6)
+        );
+        assert_eq!(
+            GetEdgeWeight::<u8>::edge_weight(
+                recovered_graph.graph_ref(),
+                &transposed_edge_type,
+                &vertex_2,
+                &vertex_1
+            )
+            .unwrap(),
+            Some(2)
+        );
+        assert!(GetVertexValue::<u8>::vertex_value(
+            recovered_graph.graph_ref(),
+            &vertex_type,
+            &vertex_3
+        )
+        .is_err());
+
+        // The dropped vertex index is reused, as it would have been without recovery
+        assert_eq!(
+            recovered_graph.new_vertex(&vertex_type, 4u8).unwrap(),
+            vertex_3
+        );
+
+        drop(recovered_graph);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn reject_mutations_after_unlogged_mutation_until_checkpoint() {
+        let directory = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_durable_graph_unlogged_{}",
+            std::process::id()
+        ));
+
+        let mut graph = DurableGraph::create(
+            &directory,
+            Graph::with_initial_capacity(5, 5, 5).unwrap(),
+            SyncPolicy::Always,
+        )
+        .unwrap();
+        let vertex_type = NewVertexType::<u8>::apply(&mut graph).unwrap();
+
+        // As if appending the last mutation to the write-ahead log had failed
+        graph.has_unlogged_mutation = true;
+        assert!(graph.new_vertex(&vertex_type, 1u8).is_err());
+
+        graph.checkpoint().unwrap();
+        assert!(!graph.has_unlogged_mutation());
+        let vertex = graph.new_vertex(&vertex_type, 2u8).unwrap();
+        drop(graph);
+
+        let recovered_graph = DurableGraph::recover(&directory, SyncPolicy::Always).unwrap();
+        assert_eq!(
+            GetVertexValue::<u8>::vertex_value(recovered_graph.graph_ref(), &vertex_type, &vertex)
+                .unwrap(),
+            Some(2)
+        );
+
+        drop(recovered_graph);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
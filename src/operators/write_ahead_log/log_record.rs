@@ -0,0 +1,395 @@
+use std::io::{Read, Write};
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::{
+    decode_value_type_identifier, decode_values, encode_value_type_identifier, encode_values,
+    invalid_file_format_error, EncodeValue,
+};
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetIndex, VertexIndex, VertexTypeIndex,
+};
+use crate::graph::value_type::{GetValueTypeIdentifier, ValueTypeIdentifier};
+
+/// A value of any value type, together with its encoding.
+#[derive(Clone, Debug)]
+pub(crate) struct LoggedValue {
+    value_type: ValueTypeIdentifier,
+    encoded_value: Vec<u8>,
+}
+
+impl LoggedValue {
+    pub(crate) fn new<T>(value: &T) -> Result<Self, GraphComputingError>
+    where
+        T: EncodeValue + GetValueTypeIdentifier,
+    {
+        let mut encoded_value = Vec::new();
+        value.encode(&mut encoded_value)?;
+        Ok(Self {
+            value_type: T::value_type_identifier(),
+            encoded_value,
+        })
+    }
+
+    pub(crate) fn value_type_ref(&self) -> &ValueTypeIdentifier {
+        &self.value_type
+    }
+
+    pub(crate) fn value<T: EncodeValue>(&self) -> Result<T, GraphComputingError> {
+        T::decode(&mut self.encoded_value.as_slice())
+    }
+
+    fn encode(&self, writer: &mut impl Write) -> Result<(), GraphComputingError> {
+        encode_value_type_identifier(writer, &self.value_type)?;
+        encode_values(writer, self.encoded_value.as_slice())
+    }
+
+    fn decode(reader: &mut impl Read) -> Result<Self, GraphComputingError> {
+        Ok(Self {
+            value_type: decode_value_type_identifier(reader)?,
+            encoded_value: decode_values(reader)?,
+        })
+    }
+}
+
+/// A single public mutation of a graph.
+///
+/// Indices assigned by the graph are logged, such that replaying can verify that the same indices are assigned again.
+/// Operator applications are logged by the resulting state of their product.
+#[derive(Clone, Debug)]
+pub(crate) enum LogRecord {
+    NewVertexType {
+        value_type: ValueTypeIdentifier,
+        vertex_type: VertexTypeIndex,
+    },
+    NewEdgeType {
+        value_type: ValueTypeIdentifier,
+        edge_type: EdgeTypeIndex,
+    },
+    NewVertexIndex {
+        vertex: VertexIndex,
+    },
+    NewVertex {
+        vertex_type: VertexTypeIndex,
+        value: LoggedValue,
+        vertex: VertexIndex,
+    },
+    NewEdge {
+        edge_type: EdgeTypeIndex,
+        tail: VertexIndex,
+        head: VertexIndex,
+        weight: LoggedValue,
+    },
+    SetVertexValue {
+        vertex_type: VertexTypeIndex,
+        vertex: VertexIndex,
+        value: LoggedValue,
+    },
+    SetEdgeWeight {
+        edge_type: EdgeTypeIndex,
+        tail: VertexIndex,
+        head: VertexIndex,
+        weight: LoggedValue,
+    },
+    UpdateVertexValue {
+        vertex_type: VertexTypeIndex,
+        vertex: VertexIndex,
+        value: LoggedValue,
+    },
+    UpdateEdgeWeight {
+        edge_type: EdgeTypeIndex,
+        tail: VertexIndex,
+        head: VertexIndex,
+        weight: LoggedValue,
+    },
+    DeleteVertexValue {
+        vertex_type: VertexTypeIndex,
+        vertex: VertexIndex,
+    },
+    DeleteEdge {
+        edge_type: EdgeTypeIndex,
+        tail: VertexIndex,
+        head: VertexIndex,
+    },
+    DropVertexIndex {
+        vertex: VertexIndex,
+    },
+    DropVertexType {
+        vertex_type: VertexTypeIndex,
+    },
+    DropEdgeType {
+        edge_type: EdgeTypeIndex,
+    },
+    /// Holds the vertex vector, as encoded in a snapshot
+    VertexVectorOverwritten {
+        vertex_type: VertexTypeIndex,
+        vertex_vector: Vec<u8>,
+    },
+    /// Holds the adjacency matrix, as encoded in a snapshot
+    AdjacencyMatrixOverwritten {
+        edge_type: EdgeTypeIndex,
+        adjacency_matrix: Vec<u8>,
+    },
+    /// The changes of a committed write transaction, which are replayed completely or not at all
+    Transaction {
+        records: Vec<LogRecord>,
+    },
+}
+
+impl LogRecord {
+    pub(crate) fn encode(&self, writer: &mut impl Write) -> Result<(), GraphComputingError> {
+        match self {
+            LogRecord::NewVertexType {
+                value_type,
+                vertex_type,
+            } => {
+                0u8.encode(writer)?;
+                encode_value_type_identifier(writer, value_type)?;
+                vertex_type.index().encode(writer)
+            }
+            LogRecord::NewEdgeType {
+                value_type,
+                edge_type,
+            } => {
+                1u8.encode(writer)?;
+                encode_value_type_identifier(writer, value_type)?;
+                edge_type.index().encode(writer)
+            }
+            LogRecord::NewVertexIndex { vertex } => {
+                2u8.encode(writer)?;
+                vertex.index().encode(writer)
+            }
+            LogRecord::NewVertex {
+                vertex_type,
+                value,
+                vertex,
+            } => {
+                3u8.encode(writer)?;
+                vertex_type.index().encode(writer)?;
+                value.encode(writer)?;
+                vertex.index().encode(writer)
+            }
+            LogRecord::NewEdge {
+                edge_type,
+                tail,
+                head,
+                weight,
+            } => {
+                4u8.encode(writer)?;
+                encode_edge_coordinate(writer, edge_type, tail, head)?;
+                weight.encode(writer)
+            }
+            LogRecord::SetVertexValue {
+                vertex_type,
+                vertex,
+                value,
+            } => {
+                5u8.encode(writer)?;
+                vertex_type.index().encode(writer)?;
+                vertex.index().encode(writer)?;
+                value.encode(writer)
+            }
+            LogRecord::SetEdgeWeight {
+                edge_type,
+                tail,
+                head,
+                weight,
+            } => {
+                6u8.encode(writer)?;
+                encode_edge_coordinate(writer, edge_type, tail, head)?;
+                weight.encode(writer)
+            }
+            LogRecord::UpdateVertexValue {
+                vertex_type,
+                vertex,
+                value,
+            } => {
+                7u8.encode(writer)?;
+                vertex_type.index().encode(writer)?;
+                vertex.index().encode(writer)?;
+                value.encode(writer)
+            }
+            LogRecord::UpdateEdgeWeight {
+                edge_type,
+                tail,
+                head,
+                weight,
+            } => {
+                8u8.encode(writer)?;
+                encode_edge_coordinate(writer, edge_type, tail, head)?;
+                weight.encode(writer)
+            }
+            LogRecord::DeleteVertexValue {
+                vertex_type,
+                vertex,
+            } => {
+                9u8.encode(writer)?;
+                vertex_type.index().encode(writer)?;
+                vertex.index().encode(writer)
+            }
+            LogRecord::DeleteEdge {
+                edge_type,
+                tail,
+                head,
+            } => {
+                10u8.encode(writer)?;
+                encode_edge_coordinate(writer, edge_type, tail, head)
+            }
+            LogRecord::DropVertexIndex { vertex } => {
+                11u8.encode(writer)?;
+                vertex.index().encode(writer)
+            }
+            LogRecord::DropVertexType { vertex_type } => {
+                12u8.encode(writer)?;
+                vertex_type.index().encode(writer)
+            }
+            LogRecord::DropEdgeType { edge_type } => {
+                13u8.encode(writer)?;
+                edge_type.index().encode(writer)
+            }
+            LogRecord::VertexVectorOverwritten {
+                vertex_type,
+                vertex_vector,
+            } => {
+                14u8.encode(writer)?;
+                vertex_type.index().encode(writer)?;
+                encode_values(writer, vertex_vector.as_slice())
+            }
+            LogRecord::AdjacencyMatrixOverwritten {
+                edge_type,
+                adjacency_matrix,
+            } => {
+                15u8.encode(writer)?;
+                edge_type.index().encode(writer)?;
+                encode_values(writer, adjacency_matrix.as_slice())
+            }
+            LogRecord::Transaction { records } => {
+                16u8.encode(writer)?;
+                records.len().encode(writer)?;
+                for record in records {
+                    record.encode(writer)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn decode(reader: &mut impl Read) -> Result<Self, GraphComputingError> {
+        match u8::decode(reader)? {
+            0 => Ok(LogRecord::NewVertexType {
+                value_type: decode_value_type_identifier(reader)?,
+                vertex_type: VertexTypeIndex::new(usize::decode(reader)?),
+            }),
+            1 => Ok(LogRecord::NewEdgeType {
+                value_type: decode_value_type_identifier(reader)?,
+                edge_type: EdgeTypeIndex::new(usize::decode(reader)?),
+            }),
+            2 => Ok(LogRecord::NewVertexIndex {
+                vertex: VertexIndex::new(usize::decode(reader)?),
+            }),
+            3 => Ok(LogRecord::NewVertex {
+                vertex_type: VertexTypeIndex::new(usize::decode(reader)?),
+                value: LoggedValue::decode(reader)?,
+                vertex: VertexIndex::new(usize::decode(reader)?),
+            }),
+            4 => {
+                let (edge_type, tail, head) = decode_edge_coordinate(reader)?;
+                Ok(LogRecord::NewEdge {
+                    edge_type,
+                    tail,
+                    head,
+                    weight: LoggedValue::decode(reader)?,
+                })
+            }
+            5 => Ok(LogRecord::SetVertexValue {
+                vertex_type: VertexTypeIndex::new(usize::decode(reader)?),
+                vertex: VertexIndex::new(usize::decode(reader)?),
+                value: LoggedValue::decode(reader)?,
+            }),
+            6 => {
+                let (edge_type, tail, head) = decode_edge_coordinate(reader)?;
+                Ok(LogRecord::SetEdgeWeight {
+                    edge_type,
+                    tail,
+                    head,
+                    weight: LoggedValue::decode(reader)?,
+                })
+            }
+            7 => Ok(LogRecord::UpdateVertexValue {
+                vertex_type: VertexTypeIndex::new(usize::decode(reader)?),
+                vertex: VertexIndex::new(usize::decode(reader)?),
+                value: LoggedValue::decode(reader)?,
+            }),
+            8 => {
+                let (edge_type, tail, head) = decode_edge_coordinate(reader)?;
+                Ok(LogRecord::UpdateEdgeWeight {
+                    edge_type,
+                    tail,
+                    head,
+                    weight: LoggedValue::decode(reader)?,
+                })
+            }
+            9 => Ok(LogRecord::DeleteVertexValue {
+                vertex_type: VertexTypeIndex::new(usize::decode(reader)?),
+                vertex: VertexIndex::new(usize::decode(reader)?),
+            }),
+            10 => {
+                let (edge_type, tail, head) = decode_edge_coordinate(reader)?;
+                Ok(LogRecord::DeleteEdge {
+                    edge_type,
+                    tail,
+                    head,
+                })
+            }
+            11 => Ok(LogRecord::DropVertexIndex {
+                vertex: VertexIndex::new(usize::decode(reader)?),
+            }),
+            12 => Ok(LogRecord::DropVertexType {
+                vertex_type: VertexTypeIndex::new(usize::decode(reader)?),
+            }),
+            13 => Ok(LogRecord::DropEdgeType {
+                edge_type: EdgeTypeIndex::new(usize::decode(reader)?),
+            }),
+            14 => Ok(LogRecord::VertexVectorOverwritten {
+                vertex_type: VertexTypeIndex::new(usize::decode(reader)?),
+                vertex_vector: decode_values(reader)?,
+            }),
+            15 => Ok(LogRecord::AdjacencyMatrixOverwritten {
+                edge_type: EdgeTypeIndex::new(usize::decode(reader)?),
+                adjacency_matrix: decode_values(reader)?,
+            }),
+            16 => {
+                let number_of_records = usize::decode(reader)?;
+                let mut records = Vec::new();
+                for _ in 0..number_of_records {
+                    records.push(LogRecord::decode(reader)?);
+                }
+                Ok(LogRecord::Transaction { records })
+            }
+            tag => Err(invalid_file_format_error(format!(
+                "Unknown write-ahead log record: {}",
+                tag
+            ))),
+        }
+    }
+}
+
+fn encode_edge_coordinate(
+    writer: &mut impl Write,
+    edge_type: &EdgeTypeIndex,
+    tail: &VertexIndex,
+    head: &VertexIndex,
+) -> Result<(), GraphComputingError> {
+    edge_type.index().encode(writer)?;
+    tail.index().encode(writer)?;
+    head.index().encode(writer)
+}
+
+fn decode_edge_coordinate(
+    reader: &mut impl Read,
+) -> Result<(EdgeTypeIndex, VertexIndex, VertexIndex), GraphComputingError> {
+    Ok((
+        EdgeTypeIndex::new(usize::decode(reader)?),
+        VertexIndex::new(usize::decode(reader)?),
+        VertexIndex::new(usize::decode(reader)?),
+    ))
+}
@@ -0,0 +1,240 @@
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::graph::{
+    encode_adjacency_matrix, encode_vertex_vector, EncodeValue, GetEdgeStore, GetVertexStore, Graph,
+};
+use crate::graph::indexing::{EdgeTypeIndex, VertexIndex, VertexTypeIndex};
+use crate::graph::value_type::{
+    call_generic_function_for_value_type_identifier, GetValueTypeIdentifier,
+    GetValueTypeIdentifierRef, ValueType, ValueTypeIdentifier,
+};
+use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::operators::change_data_capture::{DeferredChanges, GraphChange};
+use crate::operators::operators::indexing::CheckIndex;
+use crate::operators::operators::read::{GetEdgeWeight, GetVertexValue};
+
+use super::{LogRecord, LoggedValue};
+
+/// Converts the changes of a write transaction into log records,
+/// which replay the transaction on the graph it started from.
+///
+/// Values are read from the graph after the transaction, so a value that changed several times is logged with its final value.
+/// Returns None if the changes cannot be replayed, i.e. if they reassign indices or hold a user-defined value type.
+pub(crate) fn log_records_of_changes(
+    graph: &Graph,
+    changes: &DeferredChanges,
+) -> Result<Option<Vec<LogRecord>>, GraphComputingError> {
+    if changes.reassign_indices {
+        return Ok(None);
+    }
+
+    let mut records = Vec::with_capacity(changes.changes.len());
+    // Logged by their final state, after all other records
+    let mut overwritten_vertex_types: Vec<VertexTypeIndex> = Vec::new();
+    let mut overwritten_edge_types: Vec<EdgeTypeIndex> = Vec::new();
+
+    for change in changes.changes.iter() {
+        match *change {
+            GraphChange::VertexTypeAdded { vertex_type } => {
+                // A vertex type that was dropped again has no vertex vector left to tell its value type,
+                // but no other record refers to it.
+                let value_type = if graph.is_valid_vertex_type_index(&vertex_type)? {
+                    graph
+                        .vertex_store_ref()
+                        .vertex_vector_ref(&vertex_type)?
+                        .value_type_identifier_ref()
+                        .to_owned()
+                } else {
+                    ValueTypeIdentifier::Bool
+                };
+                if is_user_defined(&value_type) {
+                    return Ok(None);
+                }
+                records.push(LogRecord::NewVertexType {
+                    value_type,
+                    vertex_type,
+                });
+            }
+            GraphChange::EdgeTypeAdded { edge_type } => {
+                let value_type = if graph.is_valid_edge_type_index(&edge_type)? {
+                    graph
+                        .edge_store_ref()
+                        .adjacency_matrix_ref(&edge_type)?
+                        .value_type_identifier_ref()
+                        .to_owned()
+                } else {
+                    ValueTypeIdentifier::Bool
+                };
+                if is_user_defined(&value_type) {
+                    return Ok(None);
+                }
+                records.push(LogRecord::NewEdgeType {
+                    value_type,
+                    edge_type,
+                });
+            }
+            GraphChange::VertexTypeDropped { vertex_type } => {
+                records.push(LogRecord::DropVertexType { vertex_type })
+            }
+            GraphChange::EdgeTypeDropped { edge_type } => {
+                records.push(LogRecord::DropEdgeType { edge_type })
+            }
+            GraphChange::VertexAdded { vertex } => {
+                records.push(LogRecord::NewVertexIndex { vertex })
+            }
+            GraphChange::VertexDropped { vertex } => {
+                records.push(LogRecord::DropVertexIndex { vertex })
+            }
+            GraphChange::VertexValueSet {
+                vertex_type,
+                vertex,
+            }
+            | GraphChange::VertexValueDeleted {
+                vertex_type,
+                vertex,
+            } => {
+                // Elements that did not survive the transaction are dropped by another record
+                if !graph.is_valid_vertex_type_index(&vertex_type)?
+                    || !graph.is_valid_vertex_index(&vertex)?
+                    || overwritten_vertex_types.contains(&vertex_type)
+                {
+                    continue;
+                }
+                let value_type = graph
+                    .vertex_store_ref()
+                    .vertex_vector_ref(&vertex_type)?
+                    .value_type_identifier_ref()
+                    .to_owned();
+                if is_user_defined(&value_type) {
+                    return Ok(None);
+                }
+                records.push(call_generic_function_for_value_type_identifier!(
+                    value_type,
+                    vertex_value_record,
+                    (graph, vertex_type, vertex)
+                )?);
+            }
+            GraphChange::EdgeWeightSet {
+                edge_type,
+                tail,
+                head,
+            }
+            | GraphChange::EdgeDeleted {
+                edge_type,
+                tail,
+                head,
+            } => {
+                if !graph.is_valid_edge_type_index(&edge_type)?
+                    || !graph.is_valid_vertex_index(&tail)?
+                    || !graph.is_valid_vertex_index(&head)?
+                    || overwritten_edge_types.contains(&edge_type)
+                {
+                    continue;
+                }
+                let value_type = graph
+                    .edge_store_ref()
+                    .adjacency_matrix_ref(&edge_type)?
+                    .value_type_identifier_ref()
+                    .to_owned();
+                if is_user_defined(&value_type) {
+                    return Ok(None);
+                }
+                records.push(call_generic_function_for_value_type_identifier!(
+                    value_type,
+                    edge_weight_record,
+                    (graph, edge_type, tail, head)
+                )?);
+            }
+            GraphChange::VertexVectorOverwritten { vertex_type } => {
+                if graph.is_valid_vertex_type_index(&vertex_type)?
+                    && !overwritten_vertex_types.contains(&vertex_type)
+                {
+                    overwritten_vertex_types.push(vertex_type);
+                }
+            }
+            GraphChange::AdjacencyMatrixOverwritten { edge_type } => {
+                if graph.is_valid_edge_type_index(&edge_type)?
+                    && !overwritten_edge_types.contains(&edge_type)
+                {
+                    overwritten_edge_types.push(edge_type);
+                }
+            }
+        }
+    }
+
+    for vertex_type in overwritten_vertex_types {
+        let mut vertex_vector = Vec::new();
+        encode_vertex_vector(
+            &mut vertex_vector,
+            graph.vertex_store_ref().vertex_vector_ref(&vertex_type)?,
+        )?;
+        records.push(LogRecord::VertexVectorOverwritten {
+            vertex_type,
+            vertex_vector,
+        });
+    }
+    for edge_type in overwritten_edge_types {
+        let mut adjacency_matrix = Vec::new();
+        encode_adjacency_matrix(
+            &mut adjacency_matrix,
+            graph.edge_store_ref().adjacency_matrix_ref(&edge_type)?,
+        )?;
+        records.push(LogRecord::AdjacencyMatrixOverwritten {
+            edge_type,
+            adjacency_matrix,
+        });
+    }
+
+    Ok(Some(records))
+}
+
+fn is_user_defined(value_type: &ValueTypeIdentifier) -> bool {
+    matches!(value_type, ValueTypeIdentifier::UserDefined(_))
+}
+
+fn vertex_value_record<T>(
+    graph: &Graph,
+    vertex_type: VertexTypeIndex,
+    vertex: VertexIndex,
+) -> Result<LogRecord, GraphComputingError>
+where
+    T: ValueType + EncodeValue + GetValueTypeIdentifier,
+    Graph: GetVertexValue<T>,
+{
+    match GetVertexValue::<T>::vertex_value(graph, &vertex_type, &vertex)? {
+        Some(value) => Ok(LogRecord::SetVertexValue {
+            vertex_type,
+            vertex,
+            value: LoggedValue::new(&value)?,
+        }),
+        None => Ok(LogRecord::DeleteVertexValue {
+            vertex_type,
+            vertex,
+        }),
+    }
+}
+
+fn edge_weight_record<T>(
+    graph: &Graph,
+    edge_type: EdgeTypeIndex,
+    tail: VertexIndex,
+    head: VertexIndex,
+) -> Result<LogRecord, GraphComputingError>
+where
+    T: ValueType + EncodeValue + GetValueTypeIdentifier,
+    Graph: GetEdgeWeight<T>,
+{
+    match GetEdgeWeight::<T>::edge_weight(graph, &edge_type, &tail, &head)? {
+        Some(weight) => Ok(LogRecord::SetEdgeWeight {
+            edge_type,
+            tail,
+            head,
+            weight: LoggedValue::new(&weight)?,
+        }),
+        None => Ok(LogRecord::DeleteEdge {
+            edge_type,
+            tail,
+            head,
+        }),
+    }
+}
@@ -0,0 +1,12 @@
+mod durable_graph;
+mod log_record;
+mod log_records_of_changes;
+mod operators;
+mod replay;
+mod write_ahead_log;
+
+pub use durable_graph::*;
+pub(crate) use log_record::*;
+pub(crate) use log_records_of_changes::*;
+pub(crate) use replay::*;
+pub use write_ahead_log::*;
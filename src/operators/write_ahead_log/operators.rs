@@ -0,0 +1,1238 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::Path;
+use std::str::FromStr;
+
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::SparseMatrix;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{
+    AccumulatorBinaryOperator, BinaryOperator,
+};
+use graphblas_sparse_linear_algebra::operators::index_unary_operator::IndexUnaryOperator;
+use graphblas_sparse_linear_algebra::operators::mask::MatrixMask;
+use graphblas_sparse_linear_algebra::operators::monoid::Monoid;
+use graphblas_sparse_linear_algebra::operators::options::OperatorOptions;
+use graphblas_sparse_linear_algebra::operators::semiring::Semiring;
+use graphblas_sparse_linear_algebra::operators::unary_operator::UnaryOperator;
+
+use crate::error::GraphComputingError;
+use crate::graph::edge::{GetDirectedEdgeCoordinateIndex, GetEdgeWeight};
+use crate::graph::graph::{EncodeValue, Graph};
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetIndex, GetVertexIndexIndex, GetVertexTypeIndex,
+    VertexIndex, VertexTypeIndex,
+};
+use crate::graph::value_type::{GetValueTypeIdentifier, ValueType};
+use crate::operators::in_memory::new::{
+    load_edge_list_with_external_ids, read_edge_list, read_edge_list_with_vertex_indices,
+};
+use crate::operators::operators::apply_operator::{
+    ApplyIndexUnaryOperatorToAdjacencyMatrix, ApplyIndexUnaryOperatorToVertexVector,
+    ApplyScalarBinaryOperatorToAdjacencyMatrix, ApplyScalarBinaryOperatorToVertexVector,
+    ApplyUnaryOperatorToAdjacencyMatrix, ApplyUnaryOperatorToVertexVector,
+};
+use crate::operators::operators::delete::{DeleteEdge, DeleteVertexValue, DropVertexIndex};
+use crate::operators::operators::drop::{DropEdgeType, DropVertexType};
+use crate::operators::operators::element_wise_addition::{
+    BinaryOperatorElementWiseAdjacencyMatrixAddition,
+    BinaryOperatorElementWiseVertexVectorAddition, MonoidElementWiseAdjacencyMatrixAddition,
+    MonoidElementWiseVertexVectorAddition, SemiringElementWiseAdjacencyMatrixAddition,
+    SemiringElementWiseVertexVectorAddition,
+};
+use crate::operators::operators::element_wise_multiplication::{
+    BinaryOperatorElementWiseAdjacencyMatrixMultiplication,
+    BinaryOperatorElementWiseVertexVectorMultiplication,
+    MonoidElementWiseAdjacencyMatrixMultiplication, MonoidElementWiseVertexVectorMultiplication,
+    SemiringElementWiseAdjacencyMatrixMultiplication,
+    SemiringElementWiseVertexVectorMultiplication,
+};
+use crate::operators::operators::indexing::CheckIndex;
+use crate::operators::operators::multiplication::{
+    AdjacencyMatrixMultiplication, AdjacencyMatrixVertexVectorMultiplication,
+    VertexVectorAdjacencyMatrixMultiplication,
+};
+use crate::operators::operators::new::{
+    LoadEdgeList, NewEdge, NewEdgeType, NewVertex, NewVertexIndex, NewVertexType,
+};
+use crate::operators::operators::select::{
+    SelectEdgesWithHeadVertex, SelectEdgesWithTailVertex, SelectFromAdjacencyMatrix,
+    SelectFromVertexVector,
+};
+use crate::operators::operators::set::{SetEdgeWeight, SetVertexValue};
+use crate::operators::operators::transpose::TransposeAdjacencyMatrix;
+use crate::operators::operators::update::{UpdateEdgeWeight, UpdateVertexValue};
+use crate::operators::options::{
+    OptionsForOperatorWithAdjacencyMatrixArgument, OptionsForOperatorWithAdjacencyMatrixArguments,
+    OptionsForOperatorWithAdjacencyMatrixAsLeftArgument,
+    OptionsForOperatorWithAdjacencyMatrixAsRightArgument,
+};
+
+use super::{DurableGraph, LogRecord, LoggedValue};
+
+impl<T> NewVertexType<T> for DurableGraph
+where
+    T: ValueType + GetValueTypeIdentifier,
+    Graph: NewVertexType<T>,
+{
+    fn apply(&mut self) -> Result<VertexTypeIndex, GraphComputingError> {
+        let vertex_type = NewVertexType::<T>::apply(self.graph_mut_ref()?)?;
+        self.log(LogRecord::NewVertexType {
+            value_type: T::value_type_identifier(),
+            vertex_type,
+        })?;
+        Ok(vertex_type)
+    }
+}
+
+impl<T> NewEdgeType<T> for DurableGraph
+where
+    T: ValueType + GetValueTypeIdentifier,
+    Graph: NewEdgeType<T>,
+{
+    fn apply(&mut self) -> Result<EdgeTypeIndex, GraphComputingError> {
+        let edge_type = NewEdgeType::<T>::apply(self.graph_mut_ref()?)?;
+        self.log(LogRecord::NewEdgeType {
+            value_type: T::value_type_identifier(),
+            edge_type,
+        })?;
+        Ok(edge_type)
+    }
+}
+
+impl NewVertexIndex for DurableGraph {
+    fn new_vertex_index(&mut self) -> Result<VertexIndex, GraphComputingError> {
+        let vertex = self.graph_mut_ref()?.new_vertex_index()?;
+        self.log(LogRecord::NewVertexIndex { vertex })?;
+        Ok(vertex)
+    }
+}
+
+impl<T> NewVertex<T> for DurableGraph
+where
+    T: ValueType + GetValueTypeIdentifier + EncodeValue,
+    Graph: NewVertex<T>,
+{
+    fn new_vertex(
+        &mut self,
+        vertex_type: &impl GetVertexTypeIndex,
+        value: T,
+    ) -> Result<VertexIndex, GraphComputingError> {
+        let value_to_log = LoggedValue::new(&value)?;
+        let vertex = self.graph_mut_ref()?.new_vertex(vertex_type, value)?;
+        self.log(LogRecord::NewVertex {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type.index(),
+                vertex_type.generation(),
+            ),
+            value: value_to_log,
+            vertex,
+        })?;
+        Ok(vertex)
+    }
+}
+
+impl<T> NewEdge<T> for DurableGraph
+where
+    T: ValueType + GetValueTypeIdentifier + EncodeValue,
+    Graph: NewEdge<T>,
+{
+    fn new_edge_from_edge(
+        &mut self,
+        edge: impl GetDirectedEdgeCoordinateIndex + GetEdgeWeight<T>,
+    ) -> Result<(), GraphComputingError> {
+        self.new_edge(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+            edge.weight(),
+        )
+    }
+
+    fn new_edge(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+        weight: T,
+    ) -> Result<(), GraphComputingError> {
+        let weight_to_log = LoggedValue::new(&weight)?;
+        self.graph_mut_ref()?
+            .new_edge(edge_type, tail, head, weight)?;
+        self.log(LogRecord::NewEdge {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            tail: VertexIndex::with_generation(tail.index(), tail.generation()),
+            head: VertexIndex::with_generation(head.index(), head.generation()),
+            weight: weight_to_log,
+        })
+    }
+}
+
+// The vertex indices for external ids are logged one by one, the loaded edges as an image of the adjacency matrix.
+impl<T> LoadEdgeList<T> for DurableGraph
+where
+    T: ValueType,
+    Graph: LoadEdgeList<T>,
+{
+    fn load_edge_list(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        edges: impl IntoIterator<Item = (VertexIndex, VertexIndex, T)>,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(edge_type, |graph| {
+            LoadEdgeList::<T>::load_edge_list(graph, edge_type, edges)
+        })
+    }
+
+    fn load_edge_list_with_external_ids<Id: Hash + Eq>(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        edges: impl IntoIterator<Item = (Id, Id, T)>,
+    ) -> Result<HashMap<Id, VertexIndex>, GraphComputingError> {
+        self.graph_ref().try_edge_type_index_validity(edge_type)?;
+        load_edge_list_with_external_ids(self, edge_type, edges)
+    }
+
+    fn load_edge_list_from_file(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+        delimiter: char,
+    ) -> Result<(), GraphComputingError>
+    where
+        T: FromStr,
+    {
+        let edges = read_edge_list_with_vertex_indices(path, delimiter)?;
+        LoadEdgeList::<T>::load_edge_list(self, edge_type, edges)
+    }
+
+    fn load_edge_list_from_file_with_external_ids(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        path: impl AsRef<Path>,
+        delimiter: char,
+    ) -> Result<HashMap<String, VertexIndex>, GraphComputingError>
+    where
+        T: FromStr,
+    {
+        let edges = read_edge_list::<T>(path, delimiter)?;
+        LoadEdgeList::<T>::load_edge_list_with_external_ids(self, edge_type, edges)
+    }
+}
+
+impl<T> SetVertexValue<T> for DurableGraph
+where
+    T: ValueType + GetValueTypeIdentifier + EncodeValue,
+    Graph: SetVertexValue<T>,
+{
+    fn set_vertex_value(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        value: T,
+    ) -> Result<(), GraphComputingError> {
+        let value_to_log = LoggedValue::new(&value)?;
+        self.graph_mut_ref()?
+            .set_vertex_value(vertex_type_index, vertex_index, value)?;
+        self.log(LogRecord::SetVertexValue {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type_index.index(),
+                vertex_type_index.generation(),
+            ),
+            vertex: VertexIndex::with_generation(vertex_index.index(), vertex_index.generation()),
+            value: value_to_log,
+        })
+    }
+}
+
+impl<T> SetEdgeWeight<T> for DurableGraph
+where
+    T: ValueType + GetValueTypeIdentifier + EncodeValue,
+    Graph: SetEdgeWeight<T>,
+{
+    fn set_edge_weight_from_edge(
+        &mut self,
+        edge: &(impl GetDirectedEdgeCoordinateIndex + GetEdgeWeight<T>),
+    ) -> Result<(), GraphComputingError> {
+        self.set_edge_weight(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+            edge.weight(),
+        )
+    }
+
+    fn set_edge_weight(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+        weight: T,
+    ) -> Result<(), GraphComputingError> {
+        let weight_to_log = LoggedValue::new(&weight)?;
+        self.graph_mut_ref()?
+            .set_edge_weight(edge_type, tail, head, weight)?;
+        self.log(LogRecord::SetEdgeWeight {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            tail: VertexIndex::with_generation(tail.index(), tail.generation()),
+            head: VertexIndex::with_generation(head.index(), head.generation()),
+            weight: weight_to_log,
+        })
+    }
+}
+
+impl<T> UpdateVertexValue<T> for DurableGraph
+where
+    T: ValueType + GetValueTypeIdentifier + EncodeValue,
+    Graph: UpdateVertexValue<T>,
+{
+    fn update_vertex_value(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_index: &impl GetVertexIndexIndex,
+        value: T,
+    ) -> Result<(), GraphComputingError> {
+        let value_to_log = LoggedValue::new(&value)?;
+        self.graph_mut_ref()?
+            .update_vertex_value(vertex_type_index, vertex_index, value)?;
+        self.log(LogRecord::UpdateVertexValue {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type_index.index(),
+                vertex_type_index.generation(),
+            ),
+            vertex: VertexIndex::with_generation(vertex_index.index(), vertex_index.generation()),
+            value: value_to_log,
+        })
+    }
+}
+
+impl<T> UpdateEdgeWeight<T> for DurableGraph
+where
+    T: ValueType + GetValueTypeIdentifier + EncodeValue,
+    Graph: UpdateEdgeWeight<T>,
+{
+    fn update_edge_weight_from_edge(
+        &mut self,
+        edge: &(impl GetDirectedEdgeCoordinateIndex + GetEdgeWeight<T>),
+    ) -> Result<(), GraphComputingError> {
+        self.update_edge_weight(
+            edge.edge_type_ref(),
+            edge.tail_ref(),
+            edge.head_ref(),
+            edge.weight(),
+        )
+    }
+
+    fn update_edge_weight(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+        weight: T,
+    ) -> Result<(), GraphComputingError> {
+        let weight_to_log = LoggedValue::new(&weight)?;
+        self.graph_mut_ref()?
+            .update_edge_weight(edge_type, tail, head, weight)?;
+        self.log(LogRecord::UpdateEdgeWeight {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            tail: VertexIndex::with_generation(tail.index(), tail.generation()),
+            head: VertexIndex::with_generation(head.index(), head.generation()),
+            weight: weight_to_log,
+        })
+    }
+}
+
+impl DeleteVertexValue for DurableGraph {
+    fn delete_vertex_value(
+        &mut self,
+        vertex_type_index: &impl GetVertexTypeIndex,
+        vertex_element_index: &impl GetVertexIndexIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref()?
+            .delete_vertex_value(vertex_type_index, vertex_element_index)?;
+        self.log(LogRecord::DeleteVertexValue {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type_index.index(),
+                vertex_type_index.generation(),
+            ),
+            vertex: VertexIndex::with_generation(
+                vertex_element_index.index(),
+                vertex_element_index.generation(),
+            ),
+        })
+    }
+}
+
+impl DropVertexIndex for DurableGraph {
+    fn drop_vertex_index_and_connected_edges(
+        &mut self,
+        vertex_index: &(impl GetVertexIndexIndex + Sync),
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref()?
+            .drop_vertex_index_and_connected_edges(vertex_index)?;
+        self.log(LogRecord::DropVertexIndex {
+            vertex: VertexIndex::with_generation(vertex_index.index(), vertex_index.generation()),
+        })
+    }
+}
+
+impl DeleteEdge for DurableGraph {
+    fn delete_edge(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        tail: &impl GetVertexIndexIndex,
+        head: &impl GetVertexIndexIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref()?.delete_edge(edge_type, tail, head)?;
+        self.log(LogRecord::DeleteEdge {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            tail: VertexIndex::with_generation(tail.index(), tail.generation()),
+            head: VertexIndex::with_generation(head.index(), head.generation()),
+        })
+    }
+
+    fn delete_edge_for_coordinate(
+        &mut self,
+        edge_to_delete: &impl GetDirectedEdgeCoordinateIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.delete_edge(
+            edge_to_delete.edge_type_ref(),
+            edge_to_delete.tail_ref(),
+            edge_to_delete.head_ref(),
+        )
+    }
+}
+
+impl DropVertexType for DurableGraph {
+    fn drop_vertex_type(
+        &mut self,
+        vertex_type: &impl GetVertexTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref()?.drop_vertex_type(vertex_type)?;
+        self.log(LogRecord::DropVertexType {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type.index(),
+                vertex_type.generation(),
+            ),
+        })
+    }
+}
+
+impl DropEdgeType for DurableGraph {
+    fn drop_edge_type(
+        &mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+    ) -> Result<(), GraphComputingError> {
+        self.graph_mut_ref()?.drop_edge_type(edge_type)?;
+        self.log(LogRecord::DropEdgeType {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+        })
+    }
+}
+
+impl<EvaluationDomain> AdjacencyMatrixMultiplication<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: AdjacencyMatrixMultiplication<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl Semiring<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArguments,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            AdjacencyMatrixMultiplication::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> AdjacencyMatrixVertexVectorMultiplication<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: AdjacencyMatrixVertexVectorMultiplication<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl Semiring<EvaluationDomain>,
+        right_argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixAsLeftArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            AdjacencyMatrixVertexVectorMultiplication::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> VertexVectorAdjacencyMatrixMultiplication<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: VertexVectorAdjacencyMatrixMultiplication<EvaluationDomain>,
+{
+    fn by_index(
+        &mut self,
+        left_argument: &impl GetVertexTypeIndex,
+        operator: &impl Semiring<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixAsRightArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            VertexVectorAdjacencyMatrixMultiplication::<EvaluationDomain>::by_index(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> ApplyIndexUnaryOperatorToAdjacencyMatrix<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: ApplyIndexUnaryOperatorToAdjacencyMatrix<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        adjacency_matrix: &impl GetEdgeTypeIndex,
+        operator: &impl IndexUnaryOperator<EvaluationDomain>,
+        argument: &EvaluationDomain,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            ApplyIndexUnaryOperatorToAdjacencyMatrix::<EvaluationDomain>::apply(
+                graph,
+                adjacency_matrix,
+                operator,
+                argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> ApplyIndexUnaryOperatorToVertexVector<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: ApplyIndexUnaryOperatorToVertexVector<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        vertex_vector: &impl GetVertexTypeIndex,
+        operator: &impl IndexUnaryOperator<EvaluationDomain>,
+        argument: &EvaluationDomain,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            ApplyIndexUnaryOperatorToVertexVector::<EvaluationDomain>::apply(
+                graph,
+                vertex_vector,
+                operator,
+                argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> ApplyScalarBinaryOperatorToAdjacencyMatrix<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: ApplyScalarBinaryOperatorToAdjacencyMatrix<EvaluationDomain>,
+{
+    fn with_adjacency_matrix_as_left_argument(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: EvaluationDomain,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixAsLeftArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            ApplyScalarBinaryOperatorToAdjacencyMatrix::<EvaluationDomain>::with_adjacency_matrix_as_left_argument(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+
+    fn with_adjacency_matrix_as_right_argument(
+        &mut self,
+        left_argument: EvaluationDomain,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixAsRightArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            ApplyScalarBinaryOperatorToAdjacencyMatrix::<EvaluationDomain>::with_adjacency_matrix_as_right_argument(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> ApplyScalarBinaryOperatorToVertexVector<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: ApplyScalarBinaryOperatorToVertexVector<EvaluationDomain>,
+{
+    fn with_vertex_vector_as_left_argument(
+        &mut self,
+        left_argument: &impl GetVertexTypeIndex,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: EvaluationDomain,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            ApplyScalarBinaryOperatorToVertexVector::<EvaluationDomain>::with_vertex_vector_as_left_argument(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+
+    fn with_vertex_vector_as_right_argument(
+        &mut self,
+        left_argument: EvaluationDomain,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            ApplyScalarBinaryOperatorToVertexVector::<EvaluationDomain>::with_vertex_vector_as_right_argument(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> ApplyUnaryOperatorToAdjacencyMatrix<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: ApplyUnaryOperatorToAdjacencyMatrix<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        operator: &impl UnaryOperator<EvaluationDomain>,
+        argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            ApplyUnaryOperatorToAdjacencyMatrix::<EvaluationDomain>::apply(
+                graph, operator, argument, accumlator, product, mask, options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> ApplyUnaryOperatorToVertexVector<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: ApplyUnaryOperatorToVertexVector<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        operator: &impl UnaryOperator<EvaluationDomain>,
+        argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            ApplyUnaryOperatorToVertexVector::<EvaluationDomain>::apply(
+                graph, operator, argument, accumlator, product, mask, options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> BinaryOperatorElementWiseAdjacencyMatrixAddition<EvaluationDomain>
+    for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: BinaryOperatorElementWiseAdjacencyMatrixAddition<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArguments,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            BinaryOperatorElementWiseAdjacencyMatrixAddition::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> BinaryOperatorElementWiseVertexVectorAddition<EvaluationDomain>
+    for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: BinaryOperatorElementWiseVertexVectorAddition<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetVertexTypeIndex,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            BinaryOperatorElementWiseVertexVectorAddition::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> MonoidElementWiseAdjacencyMatrixAddition<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: MonoidElementWiseAdjacencyMatrixAddition<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl Monoid<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArguments,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            MonoidElementWiseAdjacencyMatrixAddition::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> MonoidElementWiseVertexVectorAddition<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: MonoidElementWiseVertexVectorAddition<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetVertexTypeIndex,
+        operator: &impl Monoid<EvaluationDomain>,
+        right_argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            MonoidElementWiseVertexVectorAddition::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> SemiringElementWiseAdjacencyMatrixAddition<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: SemiringElementWiseAdjacencyMatrixAddition<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl Semiring<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArguments,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            SemiringElementWiseAdjacencyMatrixAddition::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> SemiringElementWiseVertexVectorAddition<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: SemiringElementWiseVertexVectorAddition<EvaluationDomain>,
+{
+    fn by_index(
+        &mut self,
+        left_argument: &impl GetVertexTypeIndex,
+        operator: &impl Semiring<EvaluationDomain>,
+        right_argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            SemiringElementWiseVertexVectorAddition::<EvaluationDomain>::by_index(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> BinaryOperatorElementWiseAdjacencyMatrixMultiplication<EvaluationDomain>
+    for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: BinaryOperatorElementWiseAdjacencyMatrixMultiplication<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArguments,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            BinaryOperatorElementWiseAdjacencyMatrixMultiplication::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> BinaryOperatorElementWiseVertexVectorMultiplication<EvaluationDomain>
+    for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: BinaryOperatorElementWiseVertexVectorMultiplication<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetVertexTypeIndex,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            BinaryOperatorElementWiseVertexVectorMultiplication::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> MonoidElementWiseAdjacencyMatrixMultiplication<EvaluationDomain>
+    for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: MonoidElementWiseAdjacencyMatrixMultiplication<EvaluationDomain>,
+{
+    fn by_index(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl Monoid<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArguments,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            MonoidElementWiseAdjacencyMatrixMultiplication::<EvaluationDomain>::by_index(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> MonoidElementWiseVertexVectorMultiplication<EvaluationDomain>
+    for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: MonoidElementWiseVertexVectorMultiplication<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetVertexTypeIndex,
+        operator: &impl Monoid<EvaluationDomain>,
+        right_argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            MonoidElementWiseVertexVectorMultiplication::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> SemiringElementWiseAdjacencyMatrixMultiplication<EvaluationDomain>
+    for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: SemiringElementWiseAdjacencyMatrixMultiplication<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        left_argument: &impl GetEdgeTypeIndex,
+        operator: &impl Semiring<EvaluationDomain>,
+        right_argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArguments,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            SemiringElementWiseAdjacencyMatrixMultiplication::<EvaluationDomain>::apply(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> SemiringElementWiseVertexVectorMultiplication<EvaluationDomain>
+    for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: SemiringElementWiseVertexVectorMultiplication<EvaluationDomain>,
+{
+    fn by_index(
+        &mut self,
+        left_argument: &impl GetVertexTypeIndex,
+        operator: &impl Semiring<EvaluationDomain>,
+        right_argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            SemiringElementWiseVertexVectorMultiplication::<EvaluationDomain>::by_index(
+                graph,
+                left_argument,
+                operator,
+                right_argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> SelectEdgesWithHeadVertex<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    SparseMatrix<EvaluationDomain>: MatrixMask,
+    Graph: SelectEdgesWithHeadVertex<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        adjacency_matrix: &impl GetEdgeTypeIndex,
+        head_vertex: &impl GetVertexIndexIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        extract_to: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(extract_to, |graph| {
+            SelectEdgesWithHeadVertex::<EvaluationDomain>::apply(
+                graph,
+                adjacency_matrix,
+                head_vertex,
+                accumlator,
+                extract_to,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> SelectEdgesWithTailVertex<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    SparseMatrix<EvaluationDomain>: MatrixMask,
+    Graph: SelectEdgesWithTailVertex<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        adjacency_matrix: &impl GetEdgeTypeIndex,
+        tail_vertex: &impl GetVertexIndexIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        extract_to: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(extract_to, |graph| {
+            SelectEdgesWithTailVertex::<EvaluationDomain>::apply(
+                graph,
+                adjacency_matrix,
+                tail_vertex,
+                accumlator,
+                extract_to,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> SelectFromAdjacencyMatrix<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: SelectFromAdjacencyMatrix<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        selector: &impl IndexUnaryOperator<EvaluationDomain>,
+        selector_argument: EvaluationDomain,
+        argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            SelectFromAdjacencyMatrix::<EvaluationDomain>::apply(
+                graph,
+                selector,
+                selector_argument,
+                argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> SelectFromVertexVector<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: SelectFromVertexVector<EvaluationDomain>,
+{
+    fn by_index(
+        &mut self,
+        selector: &impl IndexUnaryOperator<EvaluationDomain>,
+        selector_argument: EvaluationDomain,
+        argument: &impl GetVertexTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetVertexTypeIndex,
+        mask: Option<&VertexTypeIndex>,
+        options: &OperatorOptions,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_vertex_vector(product, |graph| {
+            SelectFromVertexVector::<EvaluationDomain>::by_index(
+                graph,
+                selector,
+                selector_argument,
+                argument,
+                accumlator,
+                product,
+                mask,
+                options,
+            )
+        })
+    }
+}
+
+impl<EvaluationDomain> TransposeAdjacencyMatrix<EvaluationDomain> for DurableGraph
+where
+    EvaluationDomain: ValueType,
+    Graph: TransposeAdjacencyMatrix<EvaluationDomain>,
+{
+    fn apply(
+        &mut self,
+        argument: &impl GetEdgeTypeIndex,
+        accumlator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &impl GetEdgeTypeIndex,
+        mask: Option<&EdgeTypeIndex>,
+        options: &OptionsForOperatorWithAdjacencyMatrixArgument,
+    ) -> Result<(), GraphComputingError> {
+        self.apply_to_adjacency_matrix(product, |graph| {
+            TransposeAdjacencyMatrix::<EvaluationDomain>::apply(
+                graph, argument, accumlator, product, mask, options,
+            )
+        })
+    }
+}
@@ -0,0 +1,266 @@
+use crate::error::GraphComputingError;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrixWithCachedAttributes;
+use crate::graph::graph::{
+    decode_adjacency_matrix, decode_vertex_vector, invalid_file_format_error, EncodeValue,
+    GetEdgeStore, GetGraphblasContext, GetVertexStore, Graph,
+};
+use crate::graph::indexing::{EdgeTypeIndex, VertexIndex, VertexTypeIndex};
+use crate::graph::value_type::{call_generic_function_for_value_type_identifier, ValueType};
+use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::operators::operators::delete::{DeleteEdge, DeleteVertexValue, DropVertexIndex};
+use crate::operators::operators::drop::{DropEdgeType, DropVertexType};
+use crate::operators::operators::new::{
+    NewEdge, NewEdgeType, NewVertex, NewVertexIndex, NewVertexType,
+};
+use crate::operators::operators::set::{SetEdgeWeight, SetVertexValue};
+use crate::operators::operators::update::{UpdateEdgeWeight, UpdateVertexValue};
+
+use super::{LogRecord, LoggedValue};
+
+pub(crate) fn replay_log_record(
+    graph: &mut Graph,
+    record: LogRecord,
+) -> Result<(), GraphComputingError> {
+    match record {
+        LogRecord::NewVertexType {
+            value_type,
+            vertex_type,
+        } => {
+            let replayed_vertex_type = call_generic_function_for_value_type_identifier!(
+                value_type,
+                replay_new_vertex_type,
+                (graph)
+            )?;
+            verify_replayed_index(vertex_type, replayed_vertex_type)
+        }
+        LogRecord::NewEdgeType {
+            value_type,
+            edge_type,
+        } => {
+            let replayed_edge_type = call_generic_function_for_value_type_identifier!(
+                value_type,
+                replay_new_edge_type,
+                (graph)
+            )?;
+            verify_replayed_index(edge_type, replayed_edge_type)
+        }
+        LogRecord::NewVertexIndex { vertex } => {
+            let replayed_vertex = graph.new_vertex_index()?;
+            verify_replayed_index(vertex, replayed_vertex)
+        }
+        LogRecord::NewVertex {
+            vertex_type,
+            value,
+            vertex,
+        } => {
+            let replayed_vertex = call_generic_function_for_value_type_identifier!(
+                value.value_type_ref(),
+                replay_new_vertex,
+                (graph, &vertex_type, &value)
+            )?;
+            verify_replayed_index(vertex, replayed_vertex)
+        }
+        LogRecord::NewEdge {
+            edge_type,
+            tail,
+            head,
+            weight,
+        } => call_generic_function_for_value_type_identifier!(
+            weight.value_type_ref(),
+            replay_new_edge,
+            (graph, &edge_type, &tail, &head, &weight)
+        ),
+        LogRecord::SetVertexValue {
+            vertex_type,
+            vertex,
+            value,
+        } => call_generic_function_for_value_type_identifier!(
+            value.value_type_ref(),
+            replay_set_vertex_value,
+            (graph, &vertex_type, &vertex, &value)
+        ),
+        LogRecord::SetEdgeWeight {
+            edge_type,
+            tail,
+            head,
+            weight,
+        } => call_generic_function_for_value_type_identifier!(
+            weight.value_type_ref(),
+            replay_set_edge_weight,
+            (graph, &edge_type, &tail, &head, &weight)
+        ),
+        LogRecord::UpdateVertexValue {
+            vertex_type,
+            vertex,
+            value,
+        } => call_generic_function_for_value_type_identifier!(
+            value.value_type_ref(),
+            replay_update_vertex_value,
+            (graph, &vertex_type, &vertex, &value)
+        ),
+        LogRecord::UpdateEdgeWeight {
+            edge_type,
+            tail,
+            head,
+            weight,
+        } => call_generic_function_for_value_type_identifier!(
+            weight.value_type_ref(),
+            replay_update_edge_weight,
+            (graph, &edge_type, &tail, &head, &weight)
+        ),
+        LogRecord::DeleteVertexValue {
+            vertex_type,
+            vertex,
+        } => graph.delete_vertex_value(&vertex_type, &vertex),
+        LogRecord::DeleteEdge {
+            edge_type,
+            tail,
+            head,
+        } => graph.delete_edge(&edge_type, &tail, &head),
+        LogRecord::DropVertexIndex { vertex } => {
+            graph.drop_vertex_index_and_connected_edges(&vertex)
+        }
+        LogRecord::DropVertexType { vertex_type } => graph.drop_vertex_type(&vertex_type),
+        LogRecord::DropEdgeType { edge_type } => graph.drop_edge_type(&edge_type),
+        LogRecord::VertexVectorOverwritten {
+            vertex_type,
+            vertex_vector,
+        } => {
+            let vertex_vector =
+                decode_vertex_vector(&mut vertex_vector.as_slice(), graph.graphblas_context())?;
+            *graph
+                .vertex_store_mut_ref()
+                .vertex_vector_mut_ref(&vertex_type)? = vertex_vector;
+            Ok(())
+        }
+        LogRecord::AdjacencyMatrixOverwritten {
+            edge_type,
+            adjacency_matrix,
+        } => {
+            let adjacency_matrix = decode_adjacency_matrix(
+                &mut adjacency_matrix.as_slice(),
+                graph.graphblas_context(),
+            )?;
+            *graph
+                .edge_store_mut_ref()
+                .adjacency_matrix_with_cached_attributes_mut_ref(&edge_type)? = adjacency_matrix;
+            Ok(())
+        }
+        LogRecord::Transaction { records } => {
+            for record in records {
+                replay_log_record(graph, record)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn verify_replayed_index<I: PartialEq + std::fmt::Debug>(
+    logged_index: I,
+    replayed_index: I,
+) -> Result<(), GraphComputingError> {
+    if logged_index == replayed_index {
+        Ok(())
+    } else {
+        Err(invalid_file_format_error(format!(
+            "The write-ahead log does not match the snapshot, logged index: {:?}, replayed index: {:?}",
+            logged_index, replayed_index
+        )))
+    }
+}
+
+fn replay_new_vertex_type<T>(graph: &mut Graph) -> Result<VertexTypeIndex, GraphComputingError>
+where
+    T: ValueType,
+    Graph: NewVertexType<T>,
+{
+    NewVertexType::<T>::apply(graph)
+}
+
+fn replay_new_edge_type<T>(graph: &mut Graph) -> Result<EdgeTypeIndex, GraphComputingError>
+where
+    T: ValueType,
+    Graph: NewEdgeType<T>,
+{
+    NewEdgeType::<T>::apply(graph)
+}
+
+fn replay_new_vertex<T>(
+    graph: &mut Graph,
+    vertex_type: &VertexTypeIndex,
+    value: &LoggedValue,
+) -> Result<VertexIndex, GraphComputingError>
+where
+    T: ValueType + EncodeValue,
+    Graph: NewVertex<T>,
+{
+    graph.new_vertex(vertex_type, value.value::<T>()?)
+}
+
+fn replay_new_edge<T>(
+    graph: &mut Graph,
+    edge_type: &EdgeTypeIndex,
+    tail: &VertexIndex,
+    head: &VertexIndex,
+    weight: &LoggedValue,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + EncodeValue,
+    Graph: NewEdge<T>,
+{
+    graph.new_edge(edge_type, tail, head, weight.value::<T>()?)
+}
+
+fn replay_set_vertex_value<T>(
+    graph: &mut Graph,
+    vertex_type: &VertexTypeIndex,
+    vertex: &VertexIndex,
+    value: &LoggedValue,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + EncodeValue,
+    Graph: SetVertexValue<T>,
+{
+    graph.set_vertex_value(vertex_type, vertex, value.value::<T>()?)
+}
+
+fn replay_set_edge_weight<T>(
+    graph: &mut Graph,
+    edge_type: &EdgeTypeIndex,
+    tail: &VertexIndex,
+    head: &VertexIndex,
+    weight: &LoggedValue,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + EncodeValue,
+    Graph: SetEdgeWeight<T>,
+{
+    graph.set_edge_weight(edge_type, tail, head, weight.value::<T>()?)
+}
+
+fn replay_update_vertex_value<T>(
+    graph: &mut Graph,
+    vertex_type: &VertexTypeIndex,
+    vertex: &VertexIndex,
+    value: &LoggedValue,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + EncodeValue,
+    Graph: UpdateVertexValue<T>,
+{
+    graph.update_vertex_value(vertex_type, vertex, value.value::<T>()?)
+}
+
+fn replay_update_edge_weight<T>(
+    graph: &mut Graph,
+    edge_type: &EdgeTypeIndex,
+    tail: &VertexIndex,
+    head: &VertexIndex,
+    weight: &LoggedValue,
+) -> Result<(), GraphComputingError>
+where
+    T: ValueType + EncodeValue,
+    Graph: UpdateEdgeWeight<T>,
+{
+    graph.update_edge_weight(edge_type, tail, head, weight.value::<T>()?)
+}
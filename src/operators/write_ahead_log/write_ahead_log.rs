@@ -0,0 +1,220 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::{invalid_file_format_error, EncodeValue};
+
+use super::LogRecord;
+
+const WRITE_AHEAD_LOG_MAGIC_BYTES: &[u8; 8] = b"SLAGWLOG";
+const WRITE_AHEAD_LOG_FORMAT_VERSION: u32 = 1;
+const WRITE_AHEAD_LOG_HEADER_LENGTH: u64 = 12;
+
+/// Determines when records appended to the write-ahead log are synced to disk.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncPolicy {
+    /// Sync after every record
+    Always,
+    /// Sync after every given number of records
+    EveryNumberOfRecords(usize),
+    /// Only sync on an explicit call to sync, or at a checkpoint
+    Manual,
+}
+
+/// An append-only log file.
+///
+/// Every record is framed by its length and a checksum.
+/// A partially written record at the end of the log, e.g. after a crash, is discarded when the log is opened.
+#[derive(Debug)]
+pub(crate) struct WriteAheadLog {
+    file: File,
+    sync_policy: SyncPolicy,
+    number_of_records_since_sync: usize,
+}
+
+impl WriteAheadLog {
+    /// Creates an empty log, overwriting any existing file at path.
+    pub(crate) fn create(
+        path: impl AsRef<Path>,
+        sync_policy: SyncPolicy,
+    ) -> Result<Self, GraphComputingError> {
+        let mut file = File::create(path)?;
+        file.write_all(WRITE_AHEAD_LOG_MAGIC_BYTES)?;
+        WRITE_AHEAD_LOG_FORMAT_VERSION.encode(&mut file)?;
+        file.sync_all()?;
+
+        Ok(Self {
+            file,
+            sync_policy,
+            number_of_records_since_sync: 0,
+        })
+    }
+
+    /// Opens an existing log for appending, and returns the records it holds.
+    pub(crate) fn open(
+        path: impl AsRef<Path>,
+        sync_policy: SyncPolicy,
+    ) -> Result<(Self, Vec<LogRecord>), GraphComputingError> {
+        let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+
+        let (records, length_of_complete_records) =
+            read_log_records(&mut BufReader::new(&mut file))?;
+
+        file.set_len(length_of_complete_records)?;
+        file.seek(SeekFrom::End(0))?;
+        file.sync_all()?;
+
+        Ok((
+            Self {
+                file,
+                sync_policy,
+                number_of_records_since_sync: 0,
+            },
+            records,
+        ))
+    }
+
+    pub(crate) fn append(&mut self, record: &LogRecord) -> Result<(), GraphComputingError> {
+        let mut payload = Vec::new();
+        record.encode(&mut payload)?;
+
+        let mut frame = Vec::with_capacity(payload.len() + 12);
+        (payload.len() as u64).encode(&mut frame)?;
+        checksum(payload.as_slice()).encode(&mut frame)?;
+        frame.extend_from_slice(payload.as_slice());
+
+        self.file.write_all(frame.as_slice())?;
+        self.number_of_records_since_sync += 1;
+
+        match self.sync_policy {
+            SyncPolicy::Always => self.sync(),
+            SyncPolicy::EveryNumberOfRecords(number_of_records) => {
+                if self.number_of_records_since_sync >= number_of_records {
+                    self.sync()
+                } else {
+                    Ok(())
+                }
+            }
+            SyncPolicy::Manual => Ok(()),
+        }
+    }
+
+    pub(crate) fn sync(&mut self) -> Result<(), GraphComputingError> {
+        self.file.sync_data()?;
+        self.number_of_records_since_sync = 0;
+        Ok(())
+    }
+}
+
+/// Returns all complete records, and the length of the log up to and including the last complete record.
+pub(crate) fn read_log_records(
+    reader: &mut impl Read,
+) -> Result<(Vec<LogRecord>, u64), GraphComputingError> {
+    let mut magic_bytes = [0u8; 8];
+    reader.read_exact(&mut magic_bytes)?;
+    if &magic_bytes != WRITE_AHEAD_LOG_MAGIC_BYTES {
+        return Err(invalid_file_format_error(String::from(
+            "The file is not a write-ahead log",
+        )));
+    }
+
+    let format_version = u32::decode(reader)?;
+    if format_version != WRITE_AHEAD_LOG_FORMAT_VERSION {
+        return Err(invalid_file_format_error(format!(
+            "Unsupported write-ahead log format version: {}, expected: {}",
+            format_version, WRITE_AHEAD_LOG_FORMAT_VERSION
+        )));
+    }
+
+    let mut records = Vec::new();
+    let mut length_of_complete_records = WRITE_AHEAD_LOG_HEADER_LENGTH;
+    while let Some(payload) = read_frame(reader)? {
+        records.push(LogRecord::decode(&mut payload.as_slice())?);
+        length_of_complete_records += 12 + payload.len() as u64;
+    }
+    Ok((records, length_of_complete_records))
+}
+
+// Returns None at the end of the log, and for a frame that was not written completely.
+fn read_frame(reader: &mut impl Read) -> Result<Option<Vec<u8>>, GraphComputingError> {
+    let mut frame_header = [0u8; 12];
+    match reader.read_exact(&mut frame_header) {
+        Ok(()) => {}
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error.into()),
+    }
+    let mut frame_header = frame_header.as_slice();
+    let payload_length = u64::decode(&mut frame_header)?;
+    let expected_checksum = u32::decode(&mut frame_header)?;
+
+    let mut payload = Vec::new();
+    reader.take(payload_length).read_to_end(&mut payload)?;
+    if payload.len() as u64 != payload_length || checksum(payload.as_slice()) != expected_checksum {
+        return Ok(None);
+    }
+    Ok(Some(payload))
+}
+
+// 32-bit FNV-1a
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c9dc5, |hash, byte| {
+        (hash ^ *byte as u32).wrapping_mul(0x01000193)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::graph::indexing::{EdgeTypeIndex, VertexIndex};
+
+    #[test]
+    fn discard_partially_written_record() {
+        let path = std::env::temp_dir().join(format!(
+            "stacked_linear_algebra_graph_write_ahead_log_{}.log",
+            std::process::id()
+        ));
+
+        let mut log = WriteAheadLog::create(&path, SyncPolicy::Always).unwrap();
+        log.append(&LogRecord::NewVertexIndex {
+            vertex: VertexIndex::new(3),
+        })
+        .unwrap();
+        log.append(&LogRecord::DropEdgeType {
+            edge_type: EdgeTypeIndex::new(1),
+        })
+        .unwrap();
+        drop(log);
+
+        let length = std::fs::metadata(&path).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(length - 1)
+            .unwrap();
+
+        let (mut log, records) = WriteAheadLog::open(&path, SyncPolicy::Always).unwrap();
+        assert_eq!(records.len(), 1);
+        assert!(matches!(
+            records[0],
+            LogRecord::NewVertexIndex { vertex } if vertex == VertexIndex::new(3)
+        ));
+
+        log.append(&LogRecord::DropEdgeType {
+            edge_type: EdgeTypeIndex::new(2),
+        })
+        .unwrap();
+        drop(log);
+
+        let (_log, records) = WriteAheadLog::open(&path, SyncPolicy::Always).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert!(matches!(
+            records[1],
+            LogRecord::DropEdgeType { edge_type } if edge_type == EdgeTypeIndex::new(2)
+        ));
+    }
+}
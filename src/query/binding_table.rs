@@ -0,0 +1,34 @@
+use crate::graph::indexing::VertexIndex;
+
+/// The matches of a pattern, with one row per match and one column per vertex of the pattern.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct BindingTable {
+    number_of_columns: usize,
+    rows: Vec<Vec<VertexIndex>>,
+}
+
+impl BindingTable {
+    pub(crate) fn new(number_of_columns: usize, rows: Vec<Vec<VertexIndex>>) -> Self {
+        Self {
+            number_of_columns,
+            rows,
+        }
+    }
+
+    pub fn number_of_columns(&self) -> usize {
+        self.number_of_columns
+    }
+
+    pub fn number_of_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn rows_ref(&self) -> &[Vec<VertexIndex>] {
+        &self.rows
+    }
+
+    /// Returns the vertex that the pattern vertex at the column binds to in the row.
+    pub fn binding(&self, row: usize, column: usize) -> Option<&VertexIndex> {
+        self.rows.get(row).and_then(|bindings| bindings.get(column))
+    }
+}
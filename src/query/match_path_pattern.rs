@@ -0,0 +1,527 @@
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::operations::{
+    FromMatrixElementList, GetSparseMatrixElementList,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_matrix::{
+    MatrixElementList, Size, SparseMatrix,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::{
+    GetSparseVectorElementIndices, GetSparseVectorLength,
+};
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::context::GetContext;
+use graphblas_sparse_linear_algebra::operators::apply::ApplyUnaryOperator;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{Assignment, First};
+use graphblas_sparse_linear_algebra::operators::multiplication::{
+    MultiplyMatrices, MultiplyMatrixByVector, MultiplyVectorByMatrix,
+};
+use graphblas_sparse_linear_algebra::operators::options::{
+    OperatorOptions, OptionsForOperatorWithMatrixArguments,
+    OptionsForOperatorWithMatrixAsFirstArgument, OptionsForOperatorWithMatrixAsSecondArgument,
+};
+use graphblas_sparse_linear_algebra::operators::unary_operator::{Identity, One};
+
+use crate::algorithms::graphblas_extensions::{diagonal_matrix, AnyPair};
+use crate::algorithms::results::write_to_vertex_vector;
+use crate::error::{GraphComputingError, UserError, UserErrorType};
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix::GetAdjacencyMatrix;
+use crate::graph::edge_store::operations::operations::edge_type::get_adjacency_matrix_cached_attributes::GetAdjacencyMatrixCachedAttributes;
+use crate::graph::edge_store::operations::operations::edge_type::indexing::Indexing as EdgeTypeIndexing;
+use crate::graph::graph::{GetGraphblasOperatorAppliers, Graph, GraphblasOperatorApplierCollection};
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{GetEdgeTypeIndex, GetIndex, VertexIndex, VertexTypeIndex};
+use crate::graph::vertex_store::operations::vertex_type::{CheckVertexTypeIndex, GetVertexVector};
+use crate::graph::vertex_store::GetVertexElementIndexer;
+use crate::operators::change_data_capture::GraphChange;
+
+use super::{BindingTable, EdgeDirection, PathPattern, PatternHop, VertexPattern};
+
+pub trait MatchPathPattern {
+    /// Returns a row for each match of the pattern.
+    fn match_path_pattern(
+        &mut self,
+        pattern: &PathPattern,
+    ) -> Result<BindingTable, GraphComputingError>;
+
+    /// Writes true into the n-th vertex type for each vertex that the n-th vertex of the pattern binds to in at least one match.
+    /// Requires one vertex type for each vertex of the pattern.
+    fn match_path_pattern_into_vertex_types(
+        &mut self,
+        pattern: &PathPattern,
+        vertex_types: &[VertexTypeIndex],
+    ) -> Result<(), GraphComputingError>;
+}
+
+impl MatchPathPattern for Graph {
+    fn match_path_pattern(
+        &mut self,
+        pattern: &PathPattern,
+    ) -> Result<BindingTable, GraphComputingError> {
+        let bindable_vertices = bindable_vertices(self, pattern)?;
+        binding_table(
+            &mut self.public_edge_store,
+            &self.graphblas_operator_applier_collection,
+            pattern,
+            &bindable_vertices,
+        )
+    }
+
+    fn match_path_pattern_into_vertex_types(
+        &mut self,
+        pattern: &PathPattern,
+        vertex_types: &[VertexTypeIndex],
+    ) -> Result<(), GraphComputingError> {
+        if vertex_types.len() != pattern.number_of_vertices() {
+            return Err(UserError::new(
+                UserErrorType::Other,
+                format!(
+                    "The pattern has {} vertices, but {} vertex types were given",
+                    pattern.number_of_vertices(),
+                    vertex_types.len()
+                ),
+                None,
+            )
+            .into());
+        }
+        for vertex_type in vertex_types {
+            self.public_vertex_store
+                .try_vertex_type_index_validity(vertex_type)?;
+        }
+
+        let bindable_vertices = bindable_vertices(self, pattern)?;
+        for (vertices, vertex_type) in bindable_vertices.iter().zip(vertex_types) {
+            write_to_vertex_vector(
+                &mut self.public_vertex_store,
+                &self.graphblas_operator_applier_collection,
+                vertices,
+                vertex_type,
+            )?;
+        }
+        self.publish_changes(
+            vertex_types
+                .iter()
+                .map(GraphChange::vertex_vector_overwritten)
+                .collect(),
+        )
+    }
+}
+
+/// Returns for each vertex of the pattern the vertices that it binds to in at least one match.
+/// A forward pass keeps the vertices reachable by a partial match from the start of the pattern,
+/// and a backward pass keeps the reachable vertices from which the rest of the pattern can be completed.
+pub(crate) fn bindable_vertices(
+    graph: &mut Graph,
+    pattern: &PathPattern,
+) -> Result<Vec<SparseVector<bool>>, GraphComputingError> {
+    for vertex in pattern.vertices() {
+        if let Some(vertex_type) = vertex.vertex_type_ref() {
+            graph
+                .public_vertex_store
+                .try_vertex_type_index_validity(vertex_type)?;
+        }
+    }
+    for hop in pattern.hops_ref() {
+        graph
+            .public_edge_store
+            .try_edge_type_index_validity(hop.edge_type_ref())?;
+    }
+
+    let candidates = pattern
+        .vertices()
+        .map(|vertex| candidate_vertices(graph, vertex))
+        .collect::<Result<Vec<SparseVector<bool>>, GraphComputingError>>()?;
+
+    let mut reachable = vec![candidates[0].clone()];
+    for (hop, candidates) in pattern.hops_ref().iter().zip(&candidates[1..]) {
+        let next = follow_edges(
+            &graph.public_edge_store,
+            &graph.graphblas_operator_applier_collection,
+            hop.edge_type_ref(),
+            hop.direction(),
+            &reachable[reachable.len() - 1],
+            candidates,
+        )?;
+        reachable.push(next);
+    }
+
+    let mut bindable = vec![reachable[reachable.len() - 1].clone()];
+    for (hop, reachable) in pattern.hops_ref().iter().zip(&reachable).rev() {
+        let previous = follow_edges(
+            &graph.public_edge_store,
+            &graph.graphblas_operator_applier_collection,
+            hop.edge_type_ref(),
+            hop.direction().reversed(),
+            &bindable[bindable.len() - 1],
+            reachable,
+        )?;
+        bindable.push(previous);
+    }
+    bindable.reverse();
+    Ok(bindable)
+}
+
+fn candidate_vertices(
+    graph: &Graph,
+    vertex: &VertexPattern,
+) -> Result<SparseVector<bool>, GraphComputingError> {
+    let appliers = &graph.graphblas_operator_applier_collection;
+    let valid_vertices = graph
+        .public_vertex_store
+        .element_indexer_ref()
+        .mask_with_valid_indices_ref();
+    let vertex_capacity = valid_vertices.length()?;
+
+    let mut candidates = match vertex.vertex_type_ref() {
+        Some(vertex_type) => {
+            let mut vertices_of_type =
+                SparseVector::<bool>::new(graph.graphblas_context.clone(), vertex_capacity)?;
+            appliers.unary_operator_applier().apply_to_vector(
+                &One::<bool>::new(),
+                graph
+                    .public_vertex_store
+                    .vertex_vector_ref_unchecked(vertex_type),
+                &Assignment::new(),
+                &mut vertices_of_type,
+                appliers.entire_vector_selector(),
+                &OperatorOptions::new_default(),
+            )?;
+            vertices_of_type
+        }
+        None => valid_vertices.clone(),
+    };
+
+    for condition in vertex.conditions_ref() {
+        let satisfying_vertices = condition.select_vertices(graph)?;
+        let mut restricted =
+            SparseVector::<bool>::new(graph.graphblas_context.clone(), vertex_capacity)?;
+        appliers.unary_operator_applier().apply_to_vector(
+            &Identity::<bool>::new(),
+            &candidates,
+            &Assignment::new(),
+            &mut restricted,
+            &satisfying_vertices,
+            &OperatorOptions::new_default(),
+        )?;
+        candidates = restricted;
+    }
+    Ok(candidates)
+}
+
+/// Returns the vertices in the mask that are connected to the given vertices by an edge of the edge type in the direction.
+fn follow_edges(
+    edge_store: &impl GetAdjacencyMatrix,
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    edge_type: &impl GetEdgeTypeIndex,
+    direction: EdgeDirection,
+    vertices: &SparseVector<bool>,
+    mask: &SparseVector<bool>,
+) -> Result<SparseVector<bool>, GraphComputingError> {
+    let mut connected_vertices =
+        SparseVector::<bool>::new(vertices.context_ref().to_owned(), vertices.length()?)?;
+    match direction {
+        EdgeDirection::Outgoing => graphblas_operator_applier_collection
+            .vector_matrix_multiplication_operator()
+            .apply(
+                vertices,
+                &AnyPair::new(),
+                edge_store.adjacency_matrix_ref_unchecked(edge_type),
+                &Assignment::new(),
+                &mut connected_vertices,
+                mask,
+                &OptionsForOperatorWithMatrixAsSecondArgument::new_default(),
+            )?,
+        EdgeDirection::Incoming => graphblas_operator_applier_collection
+            .matrix_vector_multiplication_operator()
+            .apply(
+                edge_store.adjacency_matrix_ref_unchecked(edge_type),
+                &AnyPair::new(),
+                vertices,
+                &Assignment::new(),
+                &mut connected_vertices,
+                mask,
+                &OptionsForOperatorWithMatrixAsFirstArgument::new_default(),
+            )?,
+    }
+    Ok(connected_vertices)
+}
+
+/// Extends the partial matches one hop at a time, multiplying a matrix with the last vertex of each partial match
+/// by the edges of the hop into bindable vertices.
+fn binding_table(
+    edge_store: &mut (impl GetAdjacencyMatrix + GetAdjacencyMatrixCachedAttributes),
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    pattern: &PathPattern,
+    bindable_vertices: &[SparseVector<bool>],
+) -> Result<BindingTable, GraphComputingError> {
+    let graphblas_context = bindable_vertices[0].context_ref().to_owned();
+    let vertex_capacity = bindable_vertices[0].length()?;
+
+    let mut rows: Vec<Vec<VertexIndex>> = bindable_vertices[0]
+        .element_indices()?
+        .into_iter()
+        .map(|index| vec![VertexIndex::new(index)])
+        .collect();
+
+    for (hop, bindable_heads) in pattern.hops_ref().iter().zip(&bindable_vertices[1..]) {
+        if rows.is_empty() {
+            break;
+        }
+
+        let size = Size::new(rows.len(), vertex_capacity);
+        let last_vertices = SparseMatrix::<bool>::from_element_list(
+            graphblas_context.clone(),
+            size,
+            MatrixElementList::from_vectors(
+                (0..rows.len()).collect(),
+                rows.iter().map(|row| row[row.len() - 1].index()).collect(),
+                vec![true; rows.len()],
+            )?,
+            &First::<bool>::new(),
+        )?;
+
+        let hop_edges = hop_edges(
+            edge_store,
+            graphblas_operator_applier_collection,
+            hop,
+            bindable_heads,
+        )?;
+        let mut extensions = SparseMatrix::<bool>::new(graphblas_context.clone(), size)?;
+        graphblas_operator_applier_collection
+            .matrix_multiplication_operator()
+            .apply(
+                &last_vertices,
+                &AnyPair::new(),
+                &hop_edges,
+                &Assignment::new(),
+                &mut extensions,
+                graphblas_operator_applier_collection.entire_matrix_selector(),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )?;
+
+        let extensions = extensions.element_list()?;
+        rows = extensions
+            .row_indices_ref()
+            .iter()
+            .zip(extensions.column_indices_ref())
+            .map(|(row_index, head)| {
+                let mut row = rows[*row_index].clone();
+                row.push(VertexIndex::new(*head));
+                row
+            })
+            .collect();
+    }
+
+    Ok(BindingTable::new(pattern.number_of_vertices(), rows))
+}
+
+/// Returns the edges of the hop, oriented from the previous vertex to the next, restricted to bindable heads
+/// by multiplying with a diagonal matrix.
+fn hop_edges(
+    edge_store: &mut (impl GetAdjacencyMatrix + GetAdjacencyMatrixCachedAttributes),
+    graphblas_operator_applier_collection: &GraphblasOperatorApplierCollection,
+    hop: &PatternHop,
+    bindable_heads: &SparseVector<bool>,
+) -> Result<SparseMatrix<bool>, GraphComputingError> {
+    let graphblas_context = bindable_heads.context_ref().to_owned();
+    let vertex_capacity = bindable_heads.length()?;
+    let size = Size::new(vertex_capacity, vertex_capacity);
+
+    let heads = diagonal_matrix(&graphblas_context, bindable_heads, vertex_capacity)?;
+
+    let oriented_edges = match hop.direction() {
+        EdgeDirection::Outgoing => edge_store.adjacency_matrix_ref_unchecked(hop.edge_type_ref()),
+        EdgeDirection::Incoming => {
+            edge_store.transposed_adjacency_matrix_ref_unchecked(hop.edge_type_ref())
+        }
+    };
+    let mut hop_edges = SparseMatrix::<bool>::new(graphblas_context, size)?;
+    graphblas_operator_applier_collection
+        .matrix_multiplication_operator()
+        .apply(
+            oriented_edges,
+            &AnyPair::new(),
+            &heads,
+            &Assignment::new(),
+            &mut hop_edges,
+            graphblas_operator_applier_collection.entire_matrix_selector(),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        )?;
+    Ok(hop_edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphblas_sparse_linear_algebra::operators::index_unary_operator::IsValueGreaterThan;
+
+    use crate::operators::operators::new::{NewEdge, NewEdgeType, NewVertex, NewVertexType};
+    use crate::operators::operators::read::GetVertexValue;
+    use crate::query::VertexValueCondition;
+
+    #[test]
+    fn match_path_pattern_with_value_condition() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let person = NewVertexType::<i32>::apply(&mut graph).unwrap();
+        let item = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        let knows = NewEdgeType::<bool>::apply(&mut graph).unwrap();
+        let likes = NewEdgeType::<bool>::apply(&mut graph).unwrap();
+
+        let alice = graph.new_vertex(&person, 10).unwrap();
+        let bob = graph.new_vertex(&person, 3).unwrap();
+        let carol = graph.new_vertex(&person, 7).unwrap();
+        let book = graph.new_vertex(&item, true).unwrap();
+        let film = graph.new_vertex(&item, true).unwrap();
+
+        graph.new_edge(&knows, &alice, &bob, true).unwrap();
+        graph.new_edge(&knows, &carol, &bob, true).unwrap();
+        graph.new_edge(&knows, &bob, &alice, true).unwrap();
+        graph.new_edge(&likes, &bob, &book, true).unwrap();
+        graph.new_edge(&likes, &alice, &film, true).unwrap();
+
+        // (a:person WHERE value(a) > 5)-[knows]->(b)-[likes]->(c)
+        let pattern = PathPattern::new(VertexPattern::with_vertex_type(&person).with_condition(
+            VertexValueCondition::new(&person, IsValueGreaterThan::<i32>::new(), 5),
+        ))
+        .outgoing(&knows, VertexPattern::any())
+        .outgoing(&likes, VertexPattern::any());
+
+        let matches = graph.match_path_pattern(&pattern).unwrap();
+        let mut rows = matches.rows_ref().to_vec();
+        rows.sort_by_key(|row| row[0].index());
+        assert_eq!(rows, vec![vec![alice, bob, book], vec![carol, bob, book]]);
+
+        let a = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        let b = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        let c = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        graph
+            .match_path_pattern_into_vertex_types(&pattern, &[a, b, c])
+            .unwrap();
+        assert_eq!(
+            GetVertexValue::<bool>::vertex_value(&graph, &a, &carol).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            GetVertexValue::<bool>::vertex_value(&graph, &a, &bob).unwrap(),
+            None
+        );
+        assert_eq!(
+            GetVertexValue::<bool>::vertex_value(&graph, &c, &film).unwrap(),
+            None
+        );
+
+        // (a:item)<-[likes]-(b)
+        let incoming = PathPattern::new(VertexPattern::with_vertex_type(&item))
+            .incoming(&likes, VertexPattern::any());
+        let mut rows = graph
+            .match_path_pattern(&incoming)
+            .unwrap()
+            .rows_ref()
+            .to_vec();
+        rows.sort_by_key(|row| row[0].index());
+        assert_eq!(rows, vec![vec![book, bob], vec![film, alice]]);
+
+        assert!(graph
+            .match_path_pattern_into_vertex_types(&pattern, &[a, b])
+            .is_err());
+    }
+
+    #[test]
+    fn match_path_patterns_of_different_lengths() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let person = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        let knows = NewEdgeType::<u8>::apply(&mut graph).unwrap();
+
+        let alice = graph.new_vertex(&person, true).unwrap();
+        let bob = graph.new_vertex(&person, true).unwrap();
+        let carol = graph.new_vertex(&person, true).unwrap();
+
+        graph.new_edge(&knows, &alice, &bob, 1u8).unwrap();
+        graph.new_edge(&knows, &bob, &carol, 2u8).unwrap();
+        graph.new_edge(&knows, &carol, &alice, 3u8).unwrap();
+        graph.new_edge(&knows, &alice, &carol, 4u8).unwrap();
+
+        let sorted_rows = |graph: &mut Graph, pattern: &PathPattern| {
+            let mut rows = graph
+                .match_path_pattern(pattern)
+                .unwrap()
+                .rows_ref()
+                .to_vec();
+            rows.sort_by_key(|row| row.iter().map(|vertex| vertex.index()).collect::<Vec<_>>());
+            rows
+        };
+
+        // (a:person)
+        let single_vertex = PathPattern::new(VertexPattern::with_vertex_type(&person));
+        assert_eq!(
+            sorted_rows(&mut graph, &single_vertex),
+            vec![vec![alice], vec![bob], vec![carol]]
+        );
+
+        // (a)-[knows]->(b)-[knows]->(c)-[knows]->(d)
+        let three_hops = PathPattern::new(VertexPattern::any())
+            .outgoing(&knows, VertexPattern::any())
+            .outgoing(&knows, VertexPattern::any())
+            .outgoing(&knows, VertexPattern::any());
+        assert_eq!(
+            sorted_rows(&mut graph, &three_hops),
+            vec![
+                vec![alice, bob, carol, alice],
+                vec![alice, carol, alice, bob],
+                vec![alice, carol, alice, carol],
+                vec![bob, carol, alice, bob],
+                vec![bob, carol, alice, carol],
+                vec![carol, alice, bob, carol],
+                vec![carol, alice, carol, alice],
+            ]
+        );
+    }
+
+    #[test]
+    fn match_path_pattern_without_matches() {
+        let mut graph = Graph::with_initial_capacity(5, 5, 5).unwrap();
+
+        let person = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        let item = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        let knows = NewEdgeType::<bool>::apply(&mut graph).unwrap();
+
+        let alice = graph.new_vertex(&person, true).unwrap();
+        let bob = graph.new_vertex(&person, true).unwrap();
+        graph.new_edge(&knows, &alice, &bob, true).unwrap();
+
+        // No vertex binds to the start of the pattern
+        let no_start = PathPattern::new(VertexPattern::with_vertex_type(&item))
+            .outgoing(&knows, VertexPattern::any());
+        assert!(graph
+            .match_path_pattern(&no_start)
+            .unwrap()
+            .rows_ref()
+            .is_empty());
+
+        // A partial match cannot be completed
+        let no_completion = PathPattern::new(VertexPattern::with_vertex_type(&person))
+            .outgoing(&knows, VertexPattern::any())
+            .outgoing(&knows, VertexPattern::any());
+        assert!(graph
+            .match_path_pattern(&no_completion)
+            .unwrap()
+            .rows_ref()
+            .is_empty());
+
+        let a = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        let b = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        let c = NewVertexType::<bool>::apply(&mut graph).unwrap();
+        graph
+            .match_path_pattern_into_vertex_types(&no_completion, &[a, b, c])
+            .unwrap();
+        for vertex in [alice, bob] {
+            for vertex_type in [a, b, c] {
+                assert_eq!(
+                    GetVertexValue::<bool>::vertex_value(&graph, &vertex_type, &vertex).unwrap(),
+                    None
+                );
+            }
+        }
+    }
+}
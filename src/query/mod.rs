@@ -0,0 +1,9 @@
+mod binding_table;
+mod match_path_pattern;
+mod path_pattern;
+mod vertex_condition;
+
+pub use binding_table::*;
+pub use match_path_pattern::*;
+pub use path_pattern::*;
+pub use vertex_condition::*;
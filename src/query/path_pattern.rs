@@ -0,0 +1,132 @@
+use crate::graph::indexing::{
+    EdgeTypeIndex, GetEdgeTypeIndex, GetVertexTypeIndex, VertexTypeIndex,
+};
+
+use super::VertexCondition;
+
+/// A vertex of a pattern, optionally restricted to a vertex type and to conditions.
+#[derive(Default)]
+pub struct VertexPattern {
+    vertex_type: Option<VertexTypeIndex>,
+    conditions: Vec<Box<dyn VertexCondition>>,
+}
+
+impl VertexPattern {
+    /// Binds to any valid vertex.
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Binds to vertices that have a value for the vertex type.
+    pub fn with_vertex_type(vertex_type: &impl GetVertexTypeIndex) -> Self {
+        Self {
+            vertex_type: Some(VertexTypeIndex::with_generation(
+                vertex_type.index(),
+                vertex_type.generation(),
+            )),
+            conditions: Vec::new(),
+        }
+    }
+
+    pub fn with_condition(mut self, condition: impl VertexCondition + 'static) -> Self {
+        self.conditions.push(Box::new(condition));
+        self
+    }
+
+    pub(crate) fn vertex_type_ref(&self) -> Option<&VertexTypeIndex> {
+        self.vertex_type.as_ref()
+    }
+
+    pub(crate) fn conditions_ref(&self) -> &[Box<dyn VertexCondition>] {
+        &self.conditions
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeDirection {
+    /// (previous)-[edge type]->(next)
+    Outgoing,
+    /// (previous)<-[edge type]-(next)
+    Incoming,
+}
+
+impl EdgeDirection {
+    pub(crate) fn reversed(self) -> Self {
+        match self {
+            EdgeDirection::Outgoing => EdgeDirection::Incoming,
+            EdgeDirection::Incoming => EdgeDirection::Outgoing,
+        }
+    }
+}
+
+pub(crate) struct PatternHop {
+    edge_type: EdgeTypeIndex,
+    direction: EdgeDirection,
+    vertex: VertexPattern,
+}
+
+impl PatternHop {
+    pub(crate) fn edge_type_ref(&self) -> &EdgeTypeIndex {
+        &self.edge_type
+    }
+
+    pub(crate) fn direction(&self) -> EdgeDirection {
+        self.direction
+    }
+
+    pub(crate) fn vertex_ref(&self) -> &VertexPattern {
+        &self.vertex
+    }
+}
+
+/// A chain of vertices connected by edges, like (a:VertexType1)-[EdgeType2]->(b)-[EdgeType3]->(c).
+/// The vertices of the pattern are numbered in order, starting at zero.
+pub struct PathPattern {
+    start: VertexPattern,
+    hops: Vec<PatternHop>,
+}
+
+impl PathPattern {
+    pub fn new(start: VertexPattern) -> Self {
+        Self {
+            start,
+            hops: Vec::new(),
+        }
+    }
+
+    /// Appends a vertex that is the head of an edge from the last vertex of the pattern.
+    pub fn outgoing(self, edge_type: &impl GetEdgeTypeIndex, vertex: VertexPattern) -> Self {
+        self.hop(edge_type, EdgeDirection::Outgoing, vertex)
+    }
+
+    /// Appends a vertex that is the tail of an edge to the last vertex of the pattern.
+    pub fn incoming(self, edge_type: &impl GetEdgeTypeIndex, vertex: VertexPattern) -> Self {
+        self.hop(edge_type, EdgeDirection::Incoming, vertex)
+    }
+
+    pub fn hop(
+        mut self,
+        edge_type: &impl GetEdgeTypeIndex,
+        direction: EdgeDirection,
+        vertex: VertexPattern,
+    ) -> Self {
+        self.hops.push(PatternHop {
+            edge_type: EdgeTypeIndex::with_generation(edge_type.index(), edge_type.generation()),
+            direction,
+            vertex,
+        });
+        self
+    }
+
+    pub fn number_of_vertices(&self) -> usize {
+        self.hops.len() + 1
+    }
+
+    pub(crate) fn hops_ref(&self) -> &[PatternHop] {
+        &self.hops
+    }
+
+    pub(crate) fn vertices(&self) -> impl Iterator<Item = &VertexPattern> {
+        std::iter::once(&self.start).chain(self.hops.iter().map(|hop| &hop.vertex))
+    }
+}
@@ -0,0 +1,93 @@
+use graphblas_sparse_linear_algebra::collections::sparse_vector::operations::GetSparseVectorLength;
+use graphblas_sparse_linear_algebra::collections::sparse_vector::SparseVector;
+use graphblas_sparse_linear_algebra::operators::apply::ApplyUnaryOperator;
+use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+use graphblas_sparse_linear_algebra::operators::index_unary_operator::IndexUnaryOperator;
+use graphblas_sparse_linear_algebra::operators::options::OperatorOptions;
+use graphblas_sparse_linear_algebra::operators::select::{SelectFromVector, VectorSelector};
+use graphblas_sparse_linear_algebra::operators::unary_operator::One;
+
+use crate::error::GraphComputingError;
+use crate::graph::graph::{GetGraphblasOperatorAppliers, Graph};
+use crate::graph::indexing::operations::GetValidIndices;
+use crate::graph::indexing::{GetVertexTypeIndex, VertexTypeIndex};
+use crate::graph::value_type::ValueType;
+use crate::graph::vertex_store::operations::vertex_type::GetVertexVector;
+use crate::graph::vertex_store::GetVertexElementIndexer;
+
+/// Restricts the vertices that a vertex of a pattern may bind to.
+pub trait VertexCondition {
+    /// Returns true for each vertex that satisfies the condition.
+    fn select_vertices(&self, graph: &Graph) -> Result<SparseVector<bool>, GraphComputingError>;
+}
+
+/// Selects the vertices of a vertex type for which the index unary operator returns true on their value, e.g. value > 5 with IsValueGreaterThan.
+#[derive(Clone, Debug)]
+pub struct VertexValueCondition<T, O>
+where
+    T: ValueType + Copy,
+    O: IndexUnaryOperator<T>,
+{
+    vertex_type: VertexTypeIndex,
+    operator: O,
+    argument: T,
+}
+
+impl<T, O> VertexValueCondition<T, O>
+where
+    T: ValueType + Copy,
+    O: IndexUnaryOperator<T>,
+{
+    pub fn new(vertex_type: &impl GetVertexTypeIndex, operator: O, argument: T) -> Self {
+        Self {
+            vertex_type: VertexTypeIndex::with_generation(
+                vertex_type.index(),
+                vertex_type.generation(),
+            ),
+            operator,
+            argument,
+        }
+    }
+}
+
+impl<T, O> VertexCondition for VertexValueCondition<T, O>
+where
+    T: ValueType + Copy,
+    O: IndexUnaryOperator<T>,
+    VectorSelector: SelectFromVector<T>,
+{
+    fn select_vertices(&self, graph: &Graph) -> Result<SparseVector<bool>, GraphComputingError> {
+        let vertex_vector = graph
+            .public_vertex_store
+            .vertex_vector_ref(&self.vertex_type)?;
+        let vertex_capacity = graph
+            .public_vertex_store
+            .element_indexer_ref()
+            .mask_with_valid_indices_ref()
+            .length()?;
+        let appliers = &graph.graphblas_operator_applier_collection;
+
+        let mut selected =
+            SparseVector::<T>::new(graph.graphblas_context.clone(), vertex_capacity)?;
+        appliers.vector_selector().apply(
+            &self.operator,
+            self.argument,
+            vertex_vector,
+            &Assignment::new(),
+            &mut selected,
+            appliers.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+
+        let mut mask = SparseVector::<bool>::new(graph.graphblas_context.clone(), vertex_capacity)?;
+        appliers.unary_operator_applier().apply_to_vector(
+            &One::<bool>::new(),
+            &selected,
+            &Assignment::new(),
+            &mut mask,
+            appliers.entire_vector_selector(),
+            &OperatorOptions::new_default(),
+        )?;
+        Ok(mask)
+    }
+}